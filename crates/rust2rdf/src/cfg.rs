@@ -0,0 +1,273 @@
+//! Conditional-compilation (`#[cfg(...)]`) modeling.
+//!
+//! rustdoc JSON does not give us a structured `cfg` field -- the predicate is
+//! just one more string in an item's `attrs` list (e.g. `"#[cfg(unix)]"`).
+//! This module parses those strings into a small propositional formula,
+//! simplifies it, and renders it back to a canonical string so that
+//! semantically identical predicates (`any(a, b)` vs `any(b, a)`) always
+//! produce the same literal.
+
+use std::collections::HashMap;
+
+/// A simplified propositional `cfg` predicate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Cfg {
+    True,
+    False,
+    Flag(String),
+    NameValue(String, String),
+    Not(Box<Cfg>),
+    All(Vec<Cfg>),
+    Any(Vec<Cfg>),
+}
+
+/// Parse every `cfg`/`cfg_attr` predicate on an item's raw attrs and conjoin
+/// them into a single simplified formula. Items with no `cfg` attrs are
+/// unconditionally compiled, so this returns [`Cfg::True`].
+pub fn parse_item_cfg(attrs: &[serde_json::Value]) -> Cfg {
+    let predicates: Vec<Cfg> = attrs
+        .iter()
+        .filter_map(|attr| attr.as_str())
+        .filter_map(extract_cfg_predicate)
+        .filter_map(|predicate| parse_cfg_expr(&predicate))
+        .collect();
+    simplify(Cfg::All(predicates))
+}
+
+/// Simplify a [`Cfg`] formula: flatten nested `All`/`Any`, drop identity
+/// elements (`True` in `All`, `False` in `Any`), deduplicate identical
+/// children, short-circuit (`False` in `All`, `True` in `Any`), collapse
+/// single-child `All`/`Any` to the child itself, and always push `Not`
+/// through `All`/`Any` via De Morgan's laws.
+pub fn simplify(cfg: Cfg) -> Cfg {
+    match cfg {
+        Cfg::True | Cfg::False | Cfg::Flag(_) | Cfg::NameValue(_, _) => cfg,
+        Cfg::Not(inner) => match simplify(*inner) {
+            Cfg::True => Cfg::False,
+            Cfg::False => Cfg::True,
+            Cfg::Not(x) => *x,
+            Cfg::All(xs) => simplify(Cfg::Any(xs.into_iter().map(negate).collect())),
+            Cfg::Any(xs) => simplify(Cfg::All(xs.into_iter().map(negate).collect())),
+            other => Cfg::Not(Box::new(other)),
+        },
+        Cfg::All(children) => {
+            let mut flat: Vec<Cfg> = Vec::new();
+            for child in children {
+                match simplify(child) {
+                    Cfg::True => {}
+                    Cfg::False => return Cfg::False,
+                    Cfg::All(inner) => push_dedup(&mut flat, inner),
+                    other => push_dedup(&mut flat, vec![other]),
+                }
+            }
+            match flat.len() {
+                0 => Cfg::True,
+                1 => flat.into_iter().next().unwrap(),
+                _ => Cfg::All(flat),
+            }
+        }
+        Cfg::Any(children) => {
+            let mut flat: Vec<Cfg> = Vec::new();
+            for child in children {
+                match simplify(child) {
+                    Cfg::False => {}
+                    Cfg::True => return Cfg::True,
+                    Cfg::Any(inner) => push_dedup(&mut flat, inner),
+                    other => push_dedup(&mut flat, vec![other]),
+                }
+            }
+            match flat.len() {
+                0 => Cfg::False,
+                1 => flat.into_iter().next().unwrap(),
+                _ => Cfg::Any(flat),
+            }
+        }
+    }
+}
+
+fn negate(cfg: Cfg) -> Cfg {
+    Cfg::Not(Box::new(cfg))
+}
+
+fn push_dedup(into: &mut Vec<Cfg>, items: Vec<Cfg>) {
+    for item in items {
+        if !into.contains(&item) {
+            into.push(item);
+        }
+    }
+}
+
+/// Render a [`Cfg`] as canonical Rust `cfg(...)` syntax, e.g.
+/// `all(unix, feature = "x")`. `All`/`Any` operands are sorted so the
+/// rendering is stable regardless of the source attribute's operand order.
+pub fn canonical_string(cfg: &Cfg) -> String {
+    match cfg {
+        Cfg::True => "true".to_string(),
+        Cfg::False => "false".to_string(),
+        Cfg::Flag(name) => name.clone(),
+        Cfg::NameValue(key, value) => format!("{key} = \"{value}\""),
+        Cfg::Not(inner) => format!("not({})", canonical_string(inner)),
+        Cfg::All(children) => format!("all({})", sorted_operands(children)),
+        Cfg::Any(children) => format!("any({})", sorted_operands(children)),
+    }
+}
+
+fn sorted_operands(children: &[Cfg]) -> String {
+    let mut parts: Vec<String> = children.iter().map(canonical_string).collect();
+    parts.sort();
+    parts.join(", ")
+}
+
+// ---------------------------------------------------------------------------
+// Parsing
+// ---------------------------------------------------------------------------
+
+/// Pull the predicate text out of a raw `#[cfg(...)]` or `#[cfg_attr(...)]`
+/// attribute string. For `cfg_attr`, only the leading predicate (before the
+/// first top-level comma) is kept -- the attrs it would apply are irrelevant
+/// to the compilation condition itself.
+fn extract_cfg_predicate(attr: &str) -> Option<String> {
+    let inner = attr.trim().strip_prefix("#[")?.strip_suffix(']')?;
+    if let Some(predicate) = strip_call(inner, "cfg") {
+        return Some(predicate.to_string());
+    }
+    if let Some(rest) = strip_call(inner, "cfg_attr") {
+        return split_top_level(rest, ',').into_iter().next();
+    }
+    None
+}
+
+fn strip_call<'a>(text: &'a str, name: &str) -> Option<&'a str> {
+    let rest = text.strip_prefix(name)?.strip_prefix('(')?;
+    rest.strip_suffix(')')
+}
+
+fn parse_cfg_expr(expr: &str) -> Option<Cfg> {
+    let expr = expr.trim();
+    if expr.is_empty() {
+        return None;
+    }
+    if let Some(inner) = strip_call(expr, "all") {
+        return Some(Cfg::All(parse_operand_list(inner)));
+    }
+    if let Some(inner) = strip_call(expr, "any") {
+        return Some(Cfg::Any(parse_operand_list(inner)));
+    }
+    if let Some(inner) = strip_call(expr, "not") {
+        return Some(Cfg::Not(Box::new(parse_cfg_expr(inner)?)));
+    }
+    if let Some(eq_pos) = find_top_level(expr, '=') {
+        let key = expr[..eq_pos].trim().to_string();
+        let value = expr[eq_pos + 1..].trim().trim_matches('"').to_string();
+        return Some(Cfg::NameValue(key, value));
+    }
+    Some(Cfg::Flag(expr.to_string()))
+}
+
+fn parse_operand_list(text: &str) -> Vec<Cfg> {
+    split_top_level(text, ',')
+        .iter()
+        .filter_map(|part| parse_cfg_expr(part))
+        .collect()
+}
+
+/// Split on `sep` at paren-depth 0, outside double-quoted strings.
+fn split_top_level(text: &str, sep: char) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut in_quotes = false;
+    let mut start = 0;
+    for (i, c) in text.char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            '(' if !in_quotes => depth += 1,
+            ')' if !in_quotes => depth -= 1,
+            c if c == sep && !in_quotes && depth == 0 => {
+                parts.push(text[start..i].trim().to_string());
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    let tail = text[start..].trim();
+    if !tail.is_empty() {
+        parts.push(tail.to_string());
+    }
+    parts
+}
+
+fn find_top_level(text: &str, needle: char) -> Option<usize> {
+    let mut depth = 0i32;
+    let mut in_quotes = false;
+    for (i, c) in text.char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            '(' if !in_quotes => depth += 1,
+            ')' if !in_quotes => depth -= 1,
+            c if c == needle && !in_quotes && depth == 0 => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Per-module ambient `cfg`, conjoined onto every item nested inside it so
+/// that, e.g., everything under a `#[cfg(windows)]` module inherits that
+/// condition even though the items themselves carry no `cfg` attr of their own.
+pub type ModuleCfgTable = HashMap<String, Cfg>;
+
+/// Combine a module's ambient `cfg` with an item's own, simplifying the result.
+pub fn conjoin(ambient: &Cfg, own: Cfg) -> Cfg {
+    simplify(Cfg::All(vec![ambient.clone(), own]))
+}
+
+/// Collect every `feature = "..."` value referenced anywhere in `cfg`,
+/// deduplicated and sorted. This is a textual "mentions" scan -- a feature
+/// named inside a `not(...)`/`any(...)` branch is still collected, even
+/// though it isn't strictly required in every satisfying assignment.
+pub fn referenced_features(cfg: &Cfg) -> Vec<String> {
+    let mut features = Vec::new();
+    collect_name_values(cfg, "feature", &mut features);
+    features.sort();
+    features.dedup();
+    features
+}
+
+/// Collect every non-`feature` predicate (`unix`, `target_os = "windows"`,
+/// ...) referenced anywhere in `cfg`, rendered in canonical `cfg(...)`
+/// syntax, deduplicated and sorted. Same "mentions" scan caveat as
+/// [`referenced_features`].
+pub fn target_only_predicates(cfg: &Cfg) -> Vec<String> {
+    let mut predicates = Vec::new();
+    collect_target_predicates(cfg, &mut predicates);
+    predicates.sort();
+    predicates.dedup();
+    predicates
+}
+
+fn collect_name_values(cfg: &Cfg, key: &str, into: &mut Vec<String>) {
+    match cfg {
+        Cfg::NameValue(k, v) if k == key => into.push(v.clone()),
+        Cfg::Not(inner) => collect_name_values(inner, key, into),
+        Cfg::All(children) | Cfg::Any(children) => {
+            for child in children {
+                collect_name_values(child, key, into);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn collect_target_predicates(cfg: &Cfg, into: &mut Vec<String>) {
+    match cfg {
+        Cfg::Flag(name) => into.push(name.clone()),
+        Cfg::NameValue(key, _) if key != "feature" => into.push(canonical_string(cfg)),
+        Cfg::Not(inner) => collect_target_predicates(inner, into),
+        Cfg::All(children) | Cfg::Any(children) => {
+            for child in children {
+                collect_target_predicates(child, into);
+            }
+        }
+        _ => {}
+    }
+}
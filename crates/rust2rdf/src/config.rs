@@ -0,0 +1,156 @@
+//! TOML configuration file support (`--config config.toml`).
+//!
+//! Lets a project pin down its extraction settings -- base URI, output
+//! format, and a set of include/exclude path filters -- instead of having to
+//! repeat the same flags on every invocation. CLI flags always take
+//! precedence over the config file when both are given.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use serde::Deserialize;
+
+/// Errors that can occur while loading a config file.
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(std::io::Error),
+    Toml(toml::de::Error),
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::Io(e) => write!(f, "IO error: {e}"),
+            ConfigError::Toml(e) => write!(f, "TOML parse error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl From<std::io::Error> for ConfigError {
+    fn from(e: std::io::Error) -> Self {
+        ConfigError::Io(e)
+    }
+}
+
+impl From<toml::de::Error> for ConfigError {
+    fn from(e: toml::de::Error) -> Self {
+        ConfigError::Toml(e)
+    }
+}
+
+/// Parsed contents of a `rust2rdf` config TOML file.
+#[derive(Debug, Default, Deserialize)]
+pub struct Config {
+    /// Base URI for minted IRIs.
+    #[serde(default)]
+    pub base_uri: Option<String>,
+    /// Output format: `ntriples`, `turtle`, `nquads`, `trig`, or `jsonld`.
+    #[serde(default)]
+    pub format: Option<String>,
+    /// Additional namespace prefixes to register (Turtle output only).
+    #[serde(default)]
+    pub prefixes: HashMap<String, String>,
+    /// Extra derive-name -> fully-qualified-trait-path mappings, for
+    /// ecosystem derive crates the built-in registry doesn't cover.
+    #[serde(default)]
+    pub derive_traits: HashMap<String, String>,
+    /// Path filter: see [`PathFilter`].
+    #[serde(default)]
+    pub include: Vec<String>,
+    #[serde(default)]
+    pub exclude: Vec<String>,
+}
+
+impl Config {
+    /// Load and parse a config file from disk.
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        let content = std::fs::read_to_string(path)?;
+        Ok(toml::from_str(&content)?)
+    }
+
+    /// Build the [`PathFilter`] described by this config's `include`/`exclude` lists.
+    pub fn path_filter(&self) -> PathFilter {
+        PathFilter::new(self.include.clone(), self.exclude.clone())
+    }
+}
+
+/// Include/exclude filter matched against each item's fully-qualified Rust
+/// path (e.g. `mycrate::module::MyStruct`).
+///
+/// Patterns are either a plain prefix (`mycrate::internal`) or a glob
+/// containing `*` (`mycrate::*::tests`), matched with [`glob_match`].
+#[derive(Debug, Default, Clone)]
+pub struct PathFilter {
+    include: Vec<String>,
+    exclude: Vec<String>,
+}
+
+impl PathFilter {
+    pub fn new(include: Vec<String>, exclude: Vec<String>) -> Self {
+        Self { include, exclude }
+    }
+
+    /// An empty filter that allows everything.
+    pub fn allow_all() -> Self {
+        Self::default()
+    }
+
+    /// Whether `full_path` should be extracted.
+    ///
+    /// An empty `include` list means "include everything by default"; a
+    /// non-empty one means the path must match at least one entry. Exclude
+    /// patterns always win over include patterns.
+    pub fn allows(&self, full_path: &str) -> bool {
+        let included = self.include.is_empty()
+            || self.include.iter().any(|p| path_matches(p, full_path));
+        let excluded = self.exclude.iter().any(|p| path_matches(p, full_path));
+        included && !excluded
+    }
+}
+
+/// Match `pattern` against `path`, treating `pattern` as a glob (if it
+/// contains `*`) or otherwise as a path prefix -- a bare prefix matches the
+/// path itself and anything nested under it (`foo` matches `foo::bar`).
+fn path_matches(pattern: &str, path: &str) -> bool {
+    if pattern.contains('*') {
+        glob_match(pattern, path)
+    } else {
+        path == pattern || path.starts_with(&format!("{pattern}::"))
+    }
+}
+
+/// Minimal glob matcher supporting `*` as "match any sequence of characters".
+/// No `?`, character classes, or `**` recursion -- that's all path filtering
+/// here needs.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut pi, mut ti) = (0, 0);
+    let (mut star_idx, mut star_ti) = (None, 0);
+
+    while ti < text.len() {
+        if pi < pattern.len() && (pattern[pi] == '*' || pattern[pi] == text[ti]) {
+            if pattern[pi] == '*' {
+                star_idx = Some(pi);
+                star_ti = ti;
+                pi += 1;
+            } else {
+                pi += 1;
+                ti += 1;
+            }
+        } else if let Some(si) = star_idx {
+            pi = si + 1;
+            star_ti += 1;
+            ti = star_ti;
+        } else {
+            return false;
+        }
+    }
+
+    while pi < pattern.len() && pattern[pi] == '*' {
+        pi += 1;
+    }
+    pi == pattern.len()
+}
@@ -0,0 +1,54 @@
+//! Semantic diff between two extracted, canonicalized triple graphs.
+//!
+//! This operates purely on already-rendered, already-sorted triple lines (as
+//! produced by [`crate::emitter::canonical::CanonicalEmitter`]) -- it has no
+//! knowledge of the underlying RDF model. That keeps it reusable across
+//! serialization formats: whatever text two canonical runs produce, the same
+//! merge-join finds what changed between them.
+
+use std::cmp::Ordering;
+
+/// The set of lines present in one canonicalized run but not the other.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct TripleDelta {
+    /// Lines present in `new` but not in `old`.
+    pub added: Vec<String>,
+    /// Lines present in `old` but not in `new`.
+    pub removed: Vec<String>,
+}
+
+impl TripleDelta {
+    /// True if the two inputs produced identical output.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Merge-join two lexicographically sorted line sets into added/removed sets.
+///
+/// Both `old` and `new` must already be sorted (canonical emitter output is).
+/// Lines common to both -- including identical `@prefix`/comment header lines
+/// emitted by both runs -- are walked past without being classified as changes.
+pub fn diff_lines(old: &[String], new: &[String]) -> TripleDelta {
+    let mut delta = TripleDelta::default();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        match old[i].cmp(&new[j]) {
+            Ordering::Equal => {
+                i += 1;
+                j += 1;
+            }
+            Ordering::Less => {
+                delta.removed.push(old[i].clone());
+                i += 1;
+            }
+            Ordering::Greater => {
+                delta.added.push(new[j].clone());
+                j += 1;
+            }
+        }
+    }
+    delta.removed.extend(old[i..].iter().cloned());
+    delta.added.extend(new[j..].iter().cloned());
+    delta
+}
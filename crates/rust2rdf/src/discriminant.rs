@@ -0,0 +1,220 @@
+//! Enum discriminant and literal constant evaluation.
+//!
+//! rustdoc JSON often leaves `Discriminant.value` (and `ConstExpr.value`)
+//! empty for implicitly-assigned enum variants and simple literal constants
+//! -- only `expr`'s source text is recorded. [`evaluate_enum_discriminants`]
+//! fills those in: each variant gets a concrete value (its own explicit
+//! `expr` if present, otherwise a running counter), honoring the enum's
+//! `#[repr(...)]` for width/signedness and wrapping on overflow.
+//! [`evaluate_const_values`] reuses the same literal parser to do the
+//! equivalent for `Constant`/`AssocConst` items.
+
+use crate::extraction::rustdoc_model::{Crate, Discriminant, ItemEnum};
+
+/// The integer representation an enum's discriminants are computed in,
+/// parsed from its `#[repr(...)]` attribute. Defaults to [`Repr::ISize`],
+/// matching rustc's default discriminant type when no `repr` is given.
+/// `repr(C)` alone doesn't change the discriminant type, so it's treated
+/// the same as no repr.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Repr {
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    I64,
+    U64,
+    I128,
+    U128,
+    ISize,
+    USize,
+}
+
+impl Repr {
+    fn signed(self) -> bool {
+        matches!(
+            self,
+            Repr::I8 | Repr::I16 | Repr::I32 | Repr::I64 | Repr::I128 | Repr::ISize
+        )
+    }
+
+    /// Bit width; `isize`/`usize` are assumed 64-bit (the common target --
+    /// rustdoc JSON doesn't record the target's pointer width).
+    fn bits(self) -> u32 {
+        match self {
+            Repr::I8 | Repr::U8 => 8,
+            Repr::I16 | Repr::U16 => 16,
+            Repr::I32 | Repr::U32 => 32,
+            Repr::I64 | Repr::U64 | Repr::ISize | Repr::USize => 64,
+            Repr::I128 | Repr::U128 => 128,
+        }
+    }
+
+    /// Wrap `value` into this representation's range, the way a C-like enum
+    /// wraps an out-of-range discriminant. 128-bit reprs are passed through
+    /// unwrapped: the full `u128` range doesn't fit in our `i128`
+    /// accumulator, and overflowing one in practice is vanishingly rare.
+    fn wrap(self, value: i128) -> i128 {
+        if self.bits() >= 128 {
+            return value;
+        }
+        let modulus = 1i128 << self.bits();
+        let wrapped = value.rem_euclid(modulus);
+        if self.signed() && wrapped >= modulus / 2 {
+            wrapped - modulus
+        } else {
+            wrapped
+        }
+    }
+}
+
+/// Parse an enum's `#[repr(...)]` attribute from its raw `attrs`, picking
+/// the first recognized integer type keyword (`C`, `packed`, `align(N)` and
+/// the like are ignored). Defaults to [`Repr::ISize`] if no integer repr is
+/// given.
+pub fn parse_repr(attrs: &[serde_json::Value]) -> Repr {
+    attrs
+        .iter()
+        .filter_map(|attr| attr.as_str())
+        .find_map(parse_repr_attr)
+        .unwrap_or(Repr::ISize)
+}
+
+fn parse_repr_attr(attr: &str) -> Option<Repr> {
+    let inner = attr.trim().strip_prefix("#[")?.strip_suffix(']')?;
+    let inner = inner.strip_prefix("repr")?.trim().strip_prefix('(')?.strip_suffix(')')?;
+    inner.split(',').map(str::trim).find_map(|word| {
+        Some(match word {
+            "i8" => Repr::I8,
+            "u8" => Repr::U8,
+            "i16" => Repr::I16,
+            "u16" => Repr::U16,
+            "i32" => Repr::I32,
+            "u32" => Repr::U32,
+            "i64" => Repr::I64,
+            "u64" => Repr::U64,
+            "i128" => Repr::I128,
+            "u128" => Repr::U128,
+            "isize" => Repr::ISize,
+            "usize" => Repr::USize,
+            _ => return None,
+        })
+    })
+}
+
+const TYPE_SUFFIXES: &[&str] = &[
+    "i8", "u8", "i16", "u16", "i32", "u32", "i64", "u64", "i128", "u128", "isize", "usize",
+];
+
+fn strip_type_suffix(text: &str) -> &str {
+    for suffix in TYPE_SUFFIXES {
+        if let Some(stripped) = text.strip_suffix(suffix) {
+            return stripped;
+        }
+    }
+    text
+}
+
+/// Parse an integer literal the way rustdoc's `expr`/`value` source text
+/// encodes it: an optional leading `-`, an optional `0x`/`0o`/`0b` prefix,
+/// digit-group underscores, and an optional trailing type suffix (`i32`,
+/// `u8`, ...). Returns `None` for anything that isn't a plain integer
+/// literal (e.g. a non-literal const expression like `FOO + 1`).
+pub fn parse_int_literal(text: &str) -> Option<i128> {
+    let text = text.trim();
+    let (negative, text) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text),
+    };
+    let text = strip_type_suffix(text).replace('_', "");
+
+    let magnitude = if let Some(digits) = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        i128::from_str_radix(digits, 16).ok()?
+    } else if let Some(digits) = text.strip_prefix("0o").or_else(|| text.strip_prefix("0O")) {
+        i128::from_str_radix(digits, 8).ok()?
+    } else if let Some(digits) = text.strip_prefix("0b").or_else(|| text.strip_prefix("0B")) {
+        i128::from_str_radix(digits, 2).ok()?
+    } else {
+        text.parse::<i128>().ok()?
+    };
+
+    Some(if negative { -magnitude } else { magnitude })
+}
+
+/// Assign a concrete [`Discriminant::value`] to every variant of every
+/// `ItemEnum::Enum` in `crate_data.index`: a counter starts at `0`, and for
+/// each variant in declaration order, an explicit `Discriminant::expr`
+/// resets the counter to that (parsed) value; otherwise the running counter
+/// is used. The counter increments by one after each variant, wrapping per
+/// the enum's `#[repr(...)]` (see [`parse_repr`]) on overflow. Variants
+/// whose `expr` isn't a literal integer (or who have no `Discriminant` at
+/// all yet) still get one created so the counter is recorded.
+pub fn evaluate_enum_discriminants(crate_data: &mut Crate) {
+    let enums: Vec<(Repr, Vec<String>)> = crate_data
+        .index
+        .values()
+        .filter_map(|item| match &item.inner {
+            ItemEnum::Enum { variants, .. } => Some((
+                parse_repr(&item.attrs),
+                variants.iter().map(|id| id.0.clone()).collect(),
+            )),
+            _ => None,
+        })
+        .collect();
+
+    for (repr, variant_ids) in enums {
+        let mut counter: i128 = 0;
+        for variant_id in variant_ids {
+            let Some(item) = crate_data.index.get_mut(&variant_id) else {
+                continue;
+            };
+            let ItemEnum::Variant(variant) = &mut item.inner else {
+                continue;
+            };
+
+            let explicit = variant
+                .discriminant
+                .as_ref()
+                .and_then(|d| d.expr.as_deref())
+                .and_then(parse_int_literal);
+            let value = repr.wrap(explicit.unwrap_or(counter));
+
+            let discriminant = variant.discriminant.get_or_insert(Discriminant {
+                expr: None,
+                value: None,
+            });
+            discriminant.value = Some(value.to_string());
+
+            counter = repr.wrap(value.wrapping_add(1));
+        }
+    }
+}
+
+/// Fill in `Constant`'s `ConstExpr::value` from its `expr` when missing, and
+/// normalize `AssocConst::value` through the same literal parser, using
+/// [`parse_int_literal`]. Non-literal expressions (anything it can't parse,
+/// e.g. `FOO + 1`) are left untouched.
+pub fn evaluate_const_values(crate_data: &mut Crate) {
+    for item in crate_data.index.values_mut() {
+        match &mut item.inner {
+            ItemEnum::Constant {
+                const_: Some(const_expr),
+                ..
+            } => {
+                if const_expr.value.is_none() {
+                    if let Some(value) = const_expr.expr.as_deref().and_then(parse_int_literal) {
+                        const_expr.value = Some(value.to_string());
+                    }
+                }
+            }
+            ItemEnum::AssocConst { value, .. } => {
+                if let Some(parsed) = value.as_deref().and_then(parse_int_literal) {
+                    *value = Some(parsed.to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,89 @@
+//! Memoized [`type_display_name`](crate::extraction::extractor::type_display_name)
+//! rendering, backed by a red-green style cache.
+//!
+//! Large graphs re-display the same nested `Type` subtrees constantly (the
+//! same `Option<T>`, the same resolved path to a common trait, and so on).
+//! Borrowing the red-green tree insight from Roslyn/rowan: treat a `Type` as
+//! an immutable "green" node and cache its rendered display string by the
+//! node's *identity* rather than its structure. Identity here is an `Rc`
+//! pointer -- two separately-constructed `Type` values that happen to be
+//! structurally equal are cached independently, exactly as two unrelated
+//! green nodes would be in a rowan tree; sharing only happens when callers
+//! actually reuse the same `Rc`, which is the point: reused subtrees format
+//! once, and cheap `Rc` clones of that result are handed back on every
+//! subsequent render.
+//!
+//! `Type`'s own recursive fields are `Box`-owned rather than `Rc`-owned, so
+//! this cache cannot memoize nested subtrees inside a single `Type` value --
+//! only whole [`GreenType`] handles that a caller has wrapped in `Rc` (e.g.
+//! a commonly-recurring generic instantiation built once and shared across
+//! many call sites) get the identity-cached treatment.
+
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::extraction::extractor::type_display_name;
+use crate::extraction::rustdoc_model::Type;
+
+/// A "green" type node: reference-counted so the same subtree can be shared
+/// across multiple parents, and so its pointer identity -- rather than a
+/// structural `Hash`/`Eq` that `Type` doesn't implement -- can serve as a
+/// cache key.
+pub type GreenType = Rc<Type>;
+
+/// Lazily-populated cache from a [`GreenType`]'s identity to its
+/// already-formatted [`type_display_name`] output.
+#[derive(Debug, Default)]
+pub struct DisplayCache {
+    entries: HashMap<usize, Rc<str>>,
+}
+
+impl DisplayCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn key(node: &GreenType) -> usize {
+        Rc::as_ptr(node) as usize
+    }
+
+    /// Return the display string for `node`, computing and caching it on
+    /// first use. A later call with a node that shares the same identity
+    /// (a clone of the same `Rc`) reuses the cached string instead of
+    /// re-walking `node`'s subtree.
+    pub fn display(&mut self, node: &GreenType) -> Rc<str> {
+        let key = Self::key(node);
+        if let Some(cached) = self.entries.get(&key) {
+            return Rc::clone(cached);
+        }
+        let rendered: Rc<str> = Rc::from(type_display_name(node));
+        self.entries.insert(key, Rc::clone(&rendered));
+        rendered
+    }
+
+    /// Drop the cached entry for `node`, if any, so the next [`Self::display`]
+    /// call recomputes it. Entries for every other node are left untouched.
+    pub fn invalidate(&mut self, node: &GreenType) {
+        self.entries.remove(&Self::key(node));
+    }
+
+    /// Invalidate every node on `path` -- typically the spine from a tree's
+    /// root down to a node that is about to be replaced -- leaving all other
+    /// cached entries, including unrelated subtrees hanging off that spine,
+    /// untouched. This mirrors how a red-green tree edit only rebuilds nodes
+    /// along the path to the change and shares the rest of the tree as-is.
+    pub fn invalidate_path(&mut self, path: &[GreenType]) {
+        for node in path {
+            self.invalidate(node);
+        }
+    }
+
+    /// Number of distinct green nodes currently cached.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
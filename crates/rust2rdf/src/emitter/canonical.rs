@@ -0,0 +1,184 @@
+use super::TriplesEmitter;
+
+/// The object half of a buffered triple, enough to both sort it deterministically
+/// and replay it into an inner [`TriplesEmitter`].
+#[derive(Clone)]
+enum Object {
+    Iri(String),
+    Literal(String),
+    TypedLiteral(String, String),
+    LangLiteral(String, String),
+    Bool(bool),
+    Int(i64),
+}
+
+impl Object {
+    /// N-Triples-shaped rendering of the object, used only as a sort key so
+    /// triples order the same way regardless of which concrete emitter wraps us.
+    fn sort_key(&self) -> String {
+        match self {
+            Object::Iri(iri) => format!("<{iri}>"),
+            Object::Literal(v) => format!("\"{v}\""),
+            Object::TypedLiteral(v, dt) => format!("\"{v}\"^^<{dt}>"),
+            Object::LangLiteral(v, lang) => format!("\"{v}\"@{lang}"),
+            Object::Bool(b) => format!("\"{b}\"^^<{}>", super::super::model::ontology::standard::XSD_BOOLEAN),
+            Object::Int(i) => format!("\"{i}\"^^<{}>", super::super::model::ontology::standard::XSD_INTEGER),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct BufferedTriple {
+    subject: String,
+    predicate: String,
+    object: Object,
+}
+
+/// Wraps any [`TriplesEmitter`] to make its output byte-stable across runs.
+///
+/// Triples are buffered in memory as they're emitted, then on [`flush`](Self::flush)
+/// sorted lexicographically by `(subject, predicate, object)` and replayed into the
+/// inner emitter. Unlike a general-purpose RDF canonicalizer, no blank-node
+/// relabeling pass is done: blank nodes (such as the list cells
+/// [`TriplesEmitter::emit_collection`] mints for cfg operand lists) keep the labels
+/// the inner emitter assigned in emission order, and sort lexicographically like any
+/// other subject/object string -- so their labels repeat across identical runs but
+/// are not canonical on their own.
+pub struct CanonicalEmitter<E: TriplesEmitter> {
+    inner: E,
+    buffer: Vec<BufferedTriple>,
+}
+
+impl<E: TriplesEmitter> CanonicalEmitter<E> {
+    pub fn new(inner: E) -> Self {
+        Self {
+            inner,
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Consume the emitter, returning the wrapped inner emitter. Call
+    /// [`flush`](TriplesEmitter::flush) first to ensure everything's replayed.
+    pub fn into_inner(self) -> E {
+        self.inner
+    }
+
+    fn push(&mut self, subject: &str, predicate: &str, object: Object) {
+        self.buffer.push(BufferedTriple {
+            subject: subject.to_string(),
+            predicate: predicate.to_string(),
+            object,
+        });
+    }
+}
+
+impl<E: TriplesEmitter> TriplesEmitter for CanonicalEmitter<E> {
+    fn emit_iri(&mut self, subject: &str, predicate: &str, object: &str) -> std::io::Result<()> {
+        self.push(subject, predicate, Object::Iri(object.to_string()));
+        Ok(())
+    }
+
+    fn emit_literal(&mut self, subject: &str, predicate: &str, value: &str) -> std::io::Result<()> {
+        self.push(subject, predicate, Object::Literal(value.to_string()));
+        Ok(())
+    }
+
+    fn emit_typed_literal(
+        &mut self,
+        subject: &str,
+        predicate: &str,
+        value: &str,
+        datatype: &str,
+    ) -> std::io::Result<()> {
+        self.push(
+            subject,
+            predicate,
+            Object::TypedLiteral(value.to_string(), datatype.to_string()),
+        );
+        Ok(())
+    }
+
+    fn emit_lang_literal(
+        &mut self,
+        subject: &str,
+        predicate: &str,
+        value: &str,
+        lang: &str,
+    ) -> std::io::Result<()> {
+        self.push(
+            subject,
+            predicate,
+            Object::LangLiteral(value.to_string(), lang.to_string()),
+        );
+        Ok(())
+    }
+
+    fn emit_bool(&mut self, subject: &str, predicate: &str, value: bool) -> std::io::Result<()> {
+        self.push(subject, predicate, Object::Bool(value));
+        Ok(())
+    }
+
+    fn emit_int(&mut self, subject: &str, predicate: &str, value: i64) -> std::io::Result<()> {
+        self.push(subject, predicate, Object::Int(value));
+        Ok(())
+    }
+
+    fn add_prefix(&mut self, prefix: &str, iri: &str) -> std::io::Result<()> {
+        // Prefixes aren't part of the sort; forward them immediately so the
+        // inner emitter can write its header before the first replayed triple.
+        self.inner.add_prefix(prefix, iri)
+    }
+
+    fn set_graph(&mut self, graph: Option<&str>) {
+        // The graph isn't part of the sort key either; forward it immediately
+        // so graph-aware inner emitters (N-Quads, TriG) still see the switch
+        // at the right point relative to add_prefix. Buffered triples replay
+        // in sorted order regardless of which graph was active when emitted,
+        // so graph-aware formats should combine this with per-graph sorting
+        // upstream if that distinction matters.
+        self.inner.set_graph(graph);
+    }
+
+    fn fresh_blank_node(&mut self) -> String {
+        // Not part of the sort; minted directly off the inner emitter's own
+        // counter so labels stay unique regardless of buffering here.
+        self.inner.fresh_blank_node()
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.buffer.sort_by(|a, b| {
+            (&a.subject, &a.predicate, a.object.sort_key()).cmp(&(
+                &b.subject,
+                &b.predicate,
+                b.object.sort_key(),
+            ))
+        });
+        for triple in self.buffer.drain(..) {
+            match triple.object {
+                Object::Iri(o) => self.inner.emit_iri(&triple.subject, &triple.predicate, &o)?,
+                Object::Literal(v) => {
+                    self.inner.emit_literal(&triple.subject, &triple.predicate, &v)?
+                }
+                Object::TypedLiteral(v, dt) => self.inner.emit_typed_literal(
+                    &triple.subject,
+                    &triple.predicate,
+                    &v,
+                    &dt,
+                )?,
+                Object::LangLiteral(v, lang) => self.inner.emit_lang_literal(
+                    &triple.subject,
+                    &triple.predicate,
+                    &v,
+                    &lang,
+                )?,
+                Object::Bool(b) => self.inner.emit_bool(&triple.subject, &triple.predicate, b)?,
+                Object::Int(i) => self.inner.emit_int(&triple.subject, &triple.predicate, i)?,
+            }
+        }
+        self.inner.flush()
+    }
+
+    fn triple_count(&self) -> u64 {
+        self.inner.triple_count() + self.buffer.len() as u64
+    }
+}
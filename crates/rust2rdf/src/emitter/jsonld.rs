@@ -0,0 +1,175 @@
+use std::collections::HashMap;
+use std::io::Write;
+use serde_json::{json, Value};
+use super::turtle::compact_iri;
+use super::TriplesEmitter;
+
+/// A single subject's accumulated predicate/object pairs, keyed by
+/// (compacted) predicate with object values folded into an array.
+#[derive(Default)]
+struct Node {
+    properties: HashMap<String, Vec<Value>>,
+}
+
+/// JSON-LD format emitter.
+///
+/// Unlike the line-oriented emitters in this module, JSON-LD can't be
+/// streamed statement-by-statement: every triple about a subject has to
+/// land in that subject's single node object before the document is
+/// written. So `emit_*` just accumulates triples into `nodes`, keyed by
+/// subject (first-seen subject order is kept in `subject_order`), and
+/// [`flush`](TriplesEmitter::flush) is where the
+/// whole flattened document (`@context` plus a `@graph` array of node
+/// objects) is serialized and written out in one shot.
+pub struct JsonLdEmitter<W: Write> {
+    writer: W,
+    count: u64,
+    prefixes: HashMap<String, String>,
+    nodes: HashMap<String, Node>,
+    subject_order: Vec<String>,
+    blank_counter: u64,
+}
+
+impl<W: Write> JsonLdEmitter<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            count: 0,
+            prefixes: HashMap::new(),
+            nodes: HashMap::new(),
+            subject_order: Vec::new(),
+            blank_counter: 0,
+        }
+    }
+
+    /// Consume the emitter, returning the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    fn compact(&self, iri: &str) -> String {
+        compact_iri(&self.prefixes, iri)
+    }
+
+    /// Record `value` under `predicate` on `subject`'s node, creating the
+    /// node (and remembering its position in `subject_order`) the first
+    /// time it's seen.
+    fn push(&mut self, subject: &str, predicate: &str, value: Value) {
+        let subject = self.compact(subject);
+        let predicate = self.compact(predicate);
+        if !self.nodes.contains_key(&subject) {
+            self.subject_order.push(subject.clone());
+        }
+        self.nodes
+            .entry(subject)
+            .or_default()
+            .properties
+            .entry(predicate)
+            .or_default()
+            .push(value);
+        self.count += 1;
+    }
+}
+
+impl<W: Write> TriplesEmitter for JsonLdEmitter<W> {
+    fn emit_iri(&mut self, subject: &str, predicate: &str, object: &str) -> std::io::Result<()> {
+        let o = self.compact(object);
+        self.push(subject, predicate, json!({ "@id": o }));
+        Ok(())
+    }
+
+    fn emit_literal(&mut self, subject: &str, predicate: &str, value: &str) -> std::io::Result<()> {
+        self.push(subject, predicate, json!({ "@value": value }));
+        Ok(())
+    }
+
+    fn emit_typed_literal(
+        &mut self,
+        subject: &str,
+        predicate: &str,
+        value: &str,
+        datatype: &str,
+    ) -> std::io::Result<()> {
+        let dt = self.compact(datatype);
+        self.push(subject, predicate, json!({ "@value": value, "@type": dt }));
+        Ok(())
+    }
+
+    fn emit_lang_literal(
+        &mut self,
+        subject: &str,
+        predicate: &str,
+        value: &str,
+        lang: &str,
+    ) -> std::io::Result<()> {
+        self.push(
+            subject,
+            predicate,
+            json!({ "@value": value, "@language": lang }),
+        );
+        Ok(())
+    }
+
+    fn emit_bool(&mut self, subject: &str, predicate: &str, value: bool) -> std::io::Result<()> {
+        self.emit_typed_literal(
+            subject,
+            predicate,
+            if value { "true" } else { "false" },
+            "http://www.w3.org/2001/XMLSchema#boolean",
+        )
+    }
+
+    fn emit_int(&mut self, subject: &str, predicate: &str, value: i64) -> std::io::Result<()> {
+        self.emit_typed_literal(
+            subject,
+            predicate,
+            &value.to_string(),
+            "http://www.w3.org/2001/XMLSchema#integer",
+        )
+    }
+
+    fn add_prefix(&mut self, prefix: &str, iri: &str) -> std::io::Result<()> {
+        self.prefixes.insert(prefix.to_string(), iri.to_string());
+        Ok(())
+    }
+
+    fn fresh_blank_node(&mut self) -> String {
+        let label = format!("_:b{}", self.blank_counter);
+        self.blank_counter += 1;
+        label
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        let mut context = serde_json::Map::new();
+        let mut prefixes: Vec<_> = self.prefixes.iter().collect();
+        prefixes.sort_by_key(|(k, _)| (*k).clone());
+        for (prefix, iri) in prefixes {
+            context.insert(prefix.clone(), json!(iri));
+        }
+
+        let mut graph = Vec::with_capacity(self.subject_order.len());
+        for subject in &self.subject_order {
+            let node = self.nodes.remove(subject).unwrap_or_default();
+            let mut obj = serde_json::Map::new();
+            obj.insert("@id".to_string(), json!(subject));
+            let mut predicates: Vec<_> = node.properties.into_iter().collect();
+            predicates.sort_by(|(a, _), (b, _)| a.cmp(b));
+            for (predicate, values) in predicates {
+                obj.insert(predicate, Value::Array(values));
+            }
+            graph.push(Value::Object(obj));
+        }
+
+        let document = json!({
+            "@context": Value::Object(context),
+            "@graph": graph,
+        });
+        serde_json::to_writer_pretty(&mut self.writer, &document).map_err(std::io::Error::other)?;
+        writeln!(self.writer)?;
+        self.writer.flush()
+    }
+
+    fn triple_count(&self) -> u64 {
+        self.count
+    }
+}
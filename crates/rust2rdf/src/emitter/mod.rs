@@ -1,23 +1,144 @@
+pub mod canonical;
+pub mod jsonld;
+pub mod nquads;
 pub mod ntriples;
+pub mod trig;
 pub mod turtle;
 
+use crate::model::ontology::standard::{RDF_FIRST, RDF_NIL, RDF_REST};
+
+/// The object half of an [`emit_collection`](TriplesEmitter::emit_collection)
+/// member: everything a single `rdf:first` triple in the resulting list can
+/// point at. Mirrors the object variants already spread across
+/// [`TriplesEmitter`]'s `emit_*` methods.
+#[derive(Clone)]
+pub enum ObjectTerm {
+    Iri(String),
+    Literal(String),
+    TypedLiteral(String, String),
+    LangLiteral(String, String),
+    Bool(bool),
+    Int(i64),
+}
+
 /// Trait for emitting RDF triples in different serialization formats.
 /// Direct port of the .NET ITriplesEmitter interface.
 pub trait TriplesEmitter {
     /// Emit a triple with an IRI object.
-    fn emit_iri(&mut self, subject: &str, predicate: &str, object: &str);
+    fn emit_iri(&mut self, subject: &str, predicate: &str, object: &str) -> std::io::Result<()>;
     /// Emit a triple with a plain string literal object.
-    fn emit_literal(&mut self, subject: &str, predicate: &str, value: &str);
+    fn emit_literal(&mut self, subject: &str, predicate: &str, value: &str) -> std::io::Result<()>;
     /// Emit a triple with a typed literal object.
-    fn emit_typed_literal(&mut self, subject: &str, predicate: &str, value: &str, datatype: &str);
+    fn emit_typed_literal(
+        &mut self,
+        subject: &str,
+        predicate: &str,
+        value: &str,
+        datatype: &str,
+    ) -> std::io::Result<()>;
+    /// Emit a triple with a language-tagged string literal object (e.g.
+    /// `"hello"@en`). Formats without language-tag support may fall back to
+    /// a plain literal, so callers should not rely on the tag round-tripping
+    /// through every emitter.
+    fn emit_lang_literal(
+        &mut self,
+        subject: &str,
+        predicate: &str,
+        value: &str,
+        lang: &str,
+    ) -> std::io::Result<()> {
+        let _ = lang;
+        self.emit_literal(subject, predicate, value)
+    }
     /// Emit a triple with a boolean literal object.
-    fn emit_bool(&mut self, subject: &str, predicate: &str, value: bool);
+    fn emit_bool(&mut self, subject: &str, predicate: &str, value: bool) -> std::io::Result<()>;
     /// Emit a triple with an integer literal object.
-    fn emit_int(&mut self, subject: &str, predicate: &str, value: i64);
+    fn emit_int(&mut self, subject: &str, predicate: &str, value: i64) -> std::io::Result<()>;
     /// Register a namespace prefix (used by Turtle format).
-    fn add_prefix(&mut self, prefix: &str, iri: &str);
+    fn add_prefix(&mut self, prefix: &str, iri: &str) -> std::io::Result<()>;
+    /// Mint a fresh blank-node identifier (`_:b0`, `_:b1`, ...), unique
+    /// within this emitter instance. Backed by a per-emitter counter since
+    /// the label must stay stable as the node is referenced from multiple
+    /// triples (e.g. the `rdf:rest` chain built by
+    /// [`emit_collection`](Self::emit_collection)).
+    fn fresh_blank_node(&mut self) -> String;
+    /// Emit `members` as an ordered `rdf:first`/`rdf:rest` linked list
+    /// rooted at `subject`/`predicate`, terminated by `rdf:nil`. Lets
+    /// extraction preserve Rust's inherently ordered constructs (tuple
+    /// fields, parameter lists, generic parameter lists, supertrait lists)
+    /// instead of flattening them into an unordered multi-valued property.
+    ///
+    /// The default implementation expands the full blank-node chain via
+    /// [`fresh_blank_node`](Self::fresh_blank_node) and the other `emit_*`
+    /// methods, so every format gets a correct (if verbose) rendering for
+    /// free; [`turtle::TurtleEmitter`] overrides this to use Turtle's native
+    /// `( a b c )` list syntax instead.
+    fn emit_collection(
+        &mut self,
+        subject: &str,
+        predicate: &str,
+        members: &[ObjectTerm],
+    ) -> std::io::Result<()> {
+        if members.is_empty() {
+            return self.emit_iri(subject, predicate, RDF_NIL);
+        }
+        let nodes: Vec<String> = members.iter().map(|_| self.fresh_blank_node()).collect();
+        self.emit_iri(subject, predicate, &nodes[0])?;
+        for (i, member) in members.iter().enumerate() {
+            let node = &nodes[i];
+            match member {
+                ObjectTerm::Iri(iri) => self.emit_iri(node, RDF_FIRST, iri)?,
+                ObjectTerm::Literal(v) => self.emit_literal(node, RDF_FIRST, v)?,
+                ObjectTerm::TypedLiteral(v, dt) => self.emit_typed_literal(node, RDF_FIRST, v, dt)?,
+                ObjectTerm::LangLiteral(v, lang) => self.emit_lang_literal(node, RDF_FIRST, v, lang)?,
+                ObjectTerm::Bool(b) => self.emit_bool(node, RDF_FIRST, *b)?,
+                ObjectTerm::Int(n) => self.emit_int(node, RDF_FIRST, *n)?,
+            }
+            let rest = nodes.get(i + 1).map(|s| s.as_str()).unwrap_or(RDF_NIL);
+            self.emit_iri(node, RDF_REST, rest)?;
+        }
+        Ok(())
+    }
+    /// Set the active named graph for every subsequent `emit_*` call --
+    /// `None` selects the default graph. Formats without named-graph
+    /// support (N-Triples, Turtle) ignore this and stay in the default
+    /// graph; [`nquads::NQuadsEmitter`] and [`trig::TriGEmitter`] honor it.
+    fn set_graph(&mut self, graph: Option<&str>) {
+        let _ = graph;
+    }
     /// Flush any buffered output.
     fn flush(&mut self) -> std::io::Result<()>;
     /// Return the number of triples emitted so far.
     fn triple_count(&self) -> u64;
+    /// Mark the start of a self-contained "definition" -- a node (and
+    /// everything nested under it, including any blank-node structure) that
+    /// more than one caller may independently try to emit, keyed by `key`
+    /// (typically the node's own IRI). Every concrete format emitter ignores
+    /// this and emits unconditionally; only the internal recording emitter
+    /// used by [`extraction::extractor::CrateExtractor::walk_root_parallel`](
+    /// crate::extraction::extractor::CrateExtractor::walk_root_parallel) acts
+    /// on it, to let a later merge step keep just the first copy when
+    /// several parallel workers raced to define the same shared node.
+    fn begin_definition(&mut self, key: &str) {
+        let _ = key;
+    }
+    /// Close the scope opened by the most recently unclosed
+    /// [`begin_definition`](Self::begin_definition) call.
+    fn end_definition(&mut self) {}
+}
+
+/// Whether `lang` looks like a valid (simplified) BCP-47 language tag: a
+/// lowercase alphabetic primary subtag, optionally followed by `-`-separated
+/// alphanumeric subtags (e.g. `en`, `en-US`, `zh-Hans`). Good enough to catch
+/// garbage input without pulling in a full BCP-47 parser; emitters fall back
+/// to a plain literal when this returns `false`.
+pub(crate) fn is_valid_lang_tag(lang: &str) -> bool {
+    let mut subtags = lang.split('-');
+    let Some(primary) = subtags.next() else {
+        return false;
+    };
+    if primary.is_empty() || !primary.bytes().all(|b| b.is_ascii_lowercase()) {
+        return false;
+    }
+    subtags.all(|sub| !sub.is_empty() && sub.bytes().all(|b| b.is_ascii_alphanumeric()))
 }
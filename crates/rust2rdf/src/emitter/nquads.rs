@@ -0,0 +1,141 @@
+use std::io::Write;
+use super::{is_valid_lang_tag, TriplesEmitter};
+use super::ntriples::{escape_literal, render_term};
+
+/// N-Quads format emitter. Like [`super::ntriples::NTriplesEmitter`], but
+/// each line carries an optional fourth "graph" term (`<s> <p> <o> <g> .`)
+/// set via [`TriplesEmitter::set_graph`]. Triples emitted while no graph is
+/// set land in the default graph and are written without a fourth term,
+/// same as plain N-Triples.
+pub struct NQuadsEmitter<W: Write> {
+    writer: W,
+    count: u64,
+    graph: Option<String>,
+    blank_counter: u64,
+}
+
+impl<W: Write> NQuadsEmitter<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            count: 0,
+            graph: None,
+            blank_counter: 0,
+        }
+    }
+
+    /// Consume the emitter, returning the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    /// The ` <graph>` term to splice before the trailing ` .`, or empty
+    /// when in the default graph.
+    fn graph_suffix(&self) -> String {
+        match &self.graph {
+            Some(g) => format!(" <{g}>"),
+            None => String::new(),
+        }
+    }
+}
+
+impl<W: Write> TriplesEmitter for NQuadsEmitter<W> {
+    fn emit_iri(&mut self, subject: &str, predicate: &str, object: &str) -> std::io::Result<()> {
+        let s = render_term(subject);
+        let o = render_term(object);
+        let g = self.graph_suffix();
+        writeln!(self.writer, "{s} <{predicate}> {o}{g} .")?;
+        self.count += 1;
+        Ok(())
+    }
+
+    fn emit_literal(&mut self, subject: &str, predicate: &str, value: &str) -> std::io::Result<()> {
+        let s = render_term(subject);
+        let escaped = escape_literal(value);
+        let g = self.graph_suffix();
+        writeln!(self.writer, "{s} <{predicate}> \"{escaped}\"{g} .")?;
+        self.count += 1;
+        Ok(())
+    }
+
+    fn emit_typed_literal(
+        &mut self,
+        subject: &str,
+        predicate: &str,
+        value: &str,
+        datatype: &str,
+    ) -> std::io::Result<()> {
+        let s = render_term(subject);
+        let escaped = escape_literal(value);
+        let g = self.graph_suffix();
+        writeln!(
+            self.writer,
+            "{s} <{predicate}> \"{escaped}\"^^<{datatype}>{g} ."
+        )?;
+        self.count += 1;
+        Ok(())
+    }
+
+    fn emit_lang_literal(
+        &mut self,
+        subject: &str,
+        predicate: &str,
+        value: &str,
+        lang: &str,
+    ) -> std::io::Result<()> {
+        if !is_valid_lang_tag(lang) {
+            return self.emit_literal(subject, predicate, value);
+        }
+        let s = render_term(subject);
+        let escaped = escape_literal(value);
+        let g = self.graph_suffix();
+        writeln!(
+            self.writer,
+            "{s} <{predicate}> \"{escaped}\"@{lang}{g} ."
+        )?;
+        self.count += 1;
+        Ok(())
+    }
+
+    fn emit_bool(&mut self, subject: &str, predicate: &str, value: bool) -> std::io::Result<()> {
+        let val = if value { "true" } else { "false" };
+        self.emit_typed_literal(
+            subject,
+            predicate,
+            val,
+            "http://www.w3.org/2001/XMLSchema#boolean",
+        )
+    }
+
+    fn emit_int(&mut self, subject: &str, predicate: &str, value: i64) -> std::io::Result<()> {
+        self.emit_typed_literal(
+            subject,
+            predicate,
+            &value.to_string(),
+            "http://www.w3.org/2001/XMLSchema#integer",
+        )
+    }
+
+    fn add_prefix(&mut self, prefix: &str, iri: &str) -> std::io::Result<()> {
+        // N-Quads doesn't use prefixes, but emit as comment for readability
+        writeln!(self.writer, "# @prefix {prefix}: <{iri}> .")
+    }
+
+    fn set_graph(&mut self, graph: Option<&str>) {
+        self.graph = graph.map(|g| g.to_string());
+    }
+
+    fn fresh_blank_node(&mut self) -> String {
+        let label = format!("_:b{}", self.blank_counter);
+        self.blank_counter += 1;
+        label
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.writer.flush()
+    }
+
+    fn triple_count(&self) -> u64 {
+        self.count
+    }
+}
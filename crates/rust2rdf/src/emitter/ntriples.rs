@@ -1,48 +1,80 @@
 use std::io::Write;
-use super::TriplesEmitter;
+use super::{is_valid_lang_tag, TriplesEmitter};
 
 /// N-Triples format emitter. Streams triples as `<s> <p> <o> .` lines.
 pub struct NTriplesEmitter<W: Write> {
     writer: W,
     count: u64,
+    blank_counter: u64,
 }
 
 impl<W: Write> NTriplesEmitter<W> {
     pub fn new(writer: W) -> Self {
-        Self { writer, count: 0 }
+        Self {
+            writer,
+            count: 0,
+            blank_counter: 0,
+        }
+    }
+
+    /// Consume the emitter, returning the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
     }
 
-    /// Escape a string for N-Triples literal (per RDF 1.1 N-Triples spec).
     fn escape_literal(s: &str) -> String {
-        let mut out = String::with_capacity(s.len());
-        for c in s.chars() {
-            match c {
-                '\\' => out.push_str("\\\\"),
-                '"' => out.push_str("\\\""),
-                '\n' => out.push_str("\\n"),
-                '\r' => out.push_str("\\r"),
-                '\t' => out.push_str("\\t"),
-                c if (c as u32) < 0x20 => {
-                    // Control chars: \uXXXX
-                    out.push_str(&format!("\\u{:04X}", c as u32));
-                }
-                _ => out.push(c),
+        escape_literal(s)
+    }
+}
+
+/// Render an IRI or blank-node term for N-Triples/N-Quads: a blank-node
+/// label (`_:b0`, minted by [`TriplesEmitter::fresh_blank_node`]) is written
+/// as-is, everything else is wrapped in `<...>`. Shared with
+/// [`super::nquads::NQuadsEmitter`].
+pub(crate) fn render_term(s: &str) -> String {
+    if s.starts_with("_:") {
+        s.to_string()
+    } else {
+        format!("<{s}>")
+    }
+}
+
+/// Escape a string for an N-Triples/N-Quads literal (per RDF 1.1 N-Triples
+/// spec). Shared with [`super::nquads::NQuadsEmitter`].
+pub(crate) fn escape_literal(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '"' => out.push_str("\\\""),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                // Control chars: \uXXXX
+                out.push_str(&format!("\\u{:04X}", c as u32));
             }
+            _ => out.push(c),
         }
-        out
     }
+    out
 }
 
 impl<W: Write> TriplesEmitter for NTriplesEmitter<W> {
-    fn emit_iri(&mut self, subject: &str, predicate: &str, object: &str) {
-        writeln!(self.writer, "<{subject}> <{predicate}> <{object}> .").unwrap();
+    fn emit_iri(&mut self, subject: &str, predicate: &str, object: &str) -> std::io::Result<()> {
+        let s = render_term(subject);
+        let o = render_term(object);
+        writeln!(self.writer, "{s} <{predicate}> {o} .")?;
         self.count += 1;
+        Ok(())
     }
 
-    fn emit_literal(&mut self, subject: &str, predicate: &str, value: &str) {
+    fn emit_literal(&mut self, subject: &str, predicate: &str, value: &str) -> std::io::Result<()> {
+        let s = render_term(subject);
         let escaped = Self::escape_literal(value);
-        writeln!(self.writer, "<{subject}> <{predicate}> \"{escaped}\" .").unwrap();
+        writeln!(self.writer, "{s} <{predicate}> \"{escaped}\" .")?;
         self.count += 1;
+        Ok(())
     }
 
     fn emit_typed_literal(
@@ -51,38 +83,65 @@ impl<W: Write> TriplesEmitter for NTriplesEmitter<W> {
         predicate: &str,
         value: &str,
         datatype: &str,
-    ) {
+    ) -> std::io::Result<()> {
+        let s = render_term(subject);
         let escaped = Self::escape_literal(value);
         writeln!(
             self.writer,
-            "<{subject}> <{predicate}> \"{escaped}\"^^<{datatype}> ."
-        )
-        .unwrap();
+            "{s} <{predicate}> \"{escaped}\"^^<{datatype}> ."
+        )?;
         self.count += 1;
+        Ok(())
     }
 
-    fn emit_bool(&mut self, subject: &str, predicate: &str, value: bool) {
+    fn emit_lang_literal(
+        &mut self,
+        subject: &str,
+        predicate: &str,
+        value: &str,
+        lang: &str,
+    ) -> std::io::Result<()> {
+        if !is_valid_lang_tag(lang) {
+            return self.emit_literal(subject, predicate, value);
+        }
+        let s = render_term(subject);
+        let escaped = Self::escape_literal(value);
+        writeln!(
+            self.writer,
+            "{s} <{predicate}> \"{escaped}\"@{lang} ."
+        )?;
+        self.count += 1;
+        Ok(())
+    }
+
+    fn emit_bool(&mut self, subject: &str, predicate: &str, value: bool) -> std::io::Result<()> {
         let val = if value { "true" } else { "false" };
         self.emit_typed_literal(
             subject,
             predicate,
             val,
             "http://www.w3.org/2001/XMLSchema#boolean",
-        );
+        )
     }
 
-    fn emit_int(&mut self, subject: &str, predicate: &str, value: i64) {
+    fn emit_int(&mut self, subject: &str, predicate: &str, value: i64) -> std::io::Result<()> {
         self.emit_typed_literal(
             subject,
             predicate,
             &value.to_string(),
             "http://www.w3.org/2001/XMLSchema#integer",
-        );
+        )
     }
 
-    fn add_prefix(&mut self, prefix: &str, iri: &str) {
+    fn add_prefix(&mut self, prefix: &str, iri: &str) -> std::io::Result<()> {
         // N-Triples doesn't use prefixes, but emit as comment for readability
-        writeln!(self.writer, "# @prefix {prefix}: <{iri}> .").unwrap();
+        writeln!(self.writer, "# @prefix {prefix}: <{iri}> .")
+    }
+
+    fn fresh_blank_node(&mut self) -> String {
+        let label = format!("_:b{}", self.blank_counter);
+        self.blank_counter += 1;
+        label
     }
 
     fn flush(&mut self) -> std::io::Result<()> {
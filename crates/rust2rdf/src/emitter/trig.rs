@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+use std::io::Write;
+use super::turtle::{compact_iri, escape_literal};
+use super::{is_valid_lang_tag, TriplesEmitter};
+
+/// TriG format emitter: Turtle with named-graph blocks (`g { ... }`). Like
+/// [`super::turtle::TurtleEmitter`], but triples emitted while
+/// [`TriplesEmitter::set_graph`] names a graph are grouped into that graph's
+/// block; triples in the default graph are written as bare Turtle-style
+/// statements.
+pub struct TriGEmitter<W: Write> {
+    writer: W,
+    count: u64,
+    prefixes: HashMap<String, String>,
+    prefix_written: bool,
+    /// The graph the next triple belongs to, and whether its block is open.
+    graph: Option<String>,
+    open_graph: Option<String>,
+    blank_counter: u64,
+}
+
+impl<W: Write> TriGEmitter<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            count: 0,
+            prefixes: HashMap::new(),
+            prefix_written: false,
+            graph: None,
+            open_graph: None,
+            blank_counter: 0,
+        }
+    }
+
+    /// Consume the emitter, returning the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    /// Write all registered prefixes (called before first triple).
+    fn write_prefixes(&mut self) -> std::io::Result<()> {
+        if self.prefix_written {
+            return Ok(());
+        }
+        self.prefix_written = true;
+        let mut prefixes: Vec<_> = self.prefixes.iter().collect();
+        prefixes.sort_by_key(|(k, _)| (*k).clone());
+        for (prefix, iri) in prefixes {
+            writeln!(self.writer, "@prefix {prefix}: <{iri}> .")?;
+        }
+        if !self.prefixes.is_empty() {
+            writeln!(self.writer)?;
+        }
+        Ok(())
+    }
+
+    /// Close the currently open graph block, if any, and open the one the
+    /// next triple needs (writing `graph {` or closing back to the default
+    /// graph as needed). Must run before every emitted statement.
+    fn sync_graph_block(&mut self) -> std::io::Result<()> {
+        if self.open_graph == self.graph {
+            return Ok(());
+        }
+        if self.open_graph.is_some() {
+            writeln!(self.writer, "}}")?;
+        }
+        if let Some(g) = &self.graph {
+            let compacted = compact_iri(&self.prefixes, g);
+            writeln!(self.writer, "{compacted} {{")?;
+        }
+        self.open_graph = self.graph.clone();
+        Ok(())
+    }
+
+    fn compact(&self, iri: &str) -> String {
+        compact_iri(&self.prefixes, iri)
+    }
+}
+
+impl<W: Write> TriplesEmitter for TriGEmitter<W> {
+    fn emit_iri(&mut self, subject: &str, predicate: &str, object: &str) -> std::io::Result<()> {
+        self.write_prefixes()?;
+        self.sync_graph_block()?;
+        let s = self.compact(subject);
+        let p = self.compact(predicate);
+        let o = self.compact(object);
+        writeln!(self.writer, "{s} {p} {o} .")?;
+        self.count += 1;
+        Ok(())
+    }
+
+    fn emit_literal(&mut self, subject: &str, predicate: &str, value: &str) -> std::io::Result<()> {
+        self.write_prefixes()?;
+        self.sync_graph_block()?;
+        let s = self.compact(subject);
+        let p = self.compact(predicate);
+        let escaped = escape_literal(value);
+        writeln!(self.writer, "{s} {p} \"{escaped}\" .")?;
+        self.count += 1;
+        Ok(())
+    }
+
+    fn emit_typed_literal(
+        &mut self,
+        subject: &str,
+        predicate: &str,
+        value: &str,
+        datatype: &str,
+    ) -> std::io::Result<()> {
+        self.write_prefixes()?;
+        self.sync_graph_block()?;
+        let s = self.compact(subject);
+        let p = self.compact(predicate);
+        let dt = self.compact(datatype);
+        let escaped = escape_literal(value);
+        writeln!(self.writer, "{s} {p} \"{escaped}\"^^{dt} .")?;
+        self.count += 1;
+        Ok(())
+    }
+
+    fn emit_lang_literal(
+        &mut self,
+        subject: &str,
+        predicate: &str,
+        value: &str,
+        lang: &str,
+    ) -> std::io::Result<()> {
+        if !is_valid_lang_tag(lang) {
+            return self.emit_literal(subject, predicate, value);
+        }
+        self.write_prefixes()?;
+        self.sync_graph_block()?;
+        let s = self.compact(subject);
+        let p = self.compact(predicate);
+        let escaped = escape_literal(value);
+        writeln!(self.writer, "{s} {p} \"{escaped}\"@{lang} .")?;
+        self.count += 1;
+        Ok(())
+    }
+
+    fn emit_bool(&mut self, subject: &str, predicate: &str, value: bool) -> std::io::Result<()> {
+        let val = if value { "true" } else { "false" };
+        self.emit_typed_literal(
+            subject,
+            predicate,
+            val,
+            "http://www.w3.org/2001/XMLSchema#boolean",
+        )
+    }
+
+    fn emit_int(&mut self, subject: &str, predicate: &str, value: i64) -> std::io::Result<()> {
+        self.emit_typed_literal(
+            subject,
+            predicate,
+            &value.to_string(),
+            "http://www.w3.org/2001/XMLSchema#integer",
+        )
+    }
+
+    fn add_prefix(&mut self, prefix: &str, iri: &str) -> std::io::Result<()> {
+        self.prefixes.insert(prefix.to_string(), iri.to_string());
+        Ok(())
+    }
+
+    fn set_graph(&mut self, graph: Option<&str>) {
+        self.graph = graph.map(|g| g.to_string());
+    }
+
+    fn fresh_blank_node(&mut self) -> String {
+        let label = format!("_:b{}", self.blank_counter);
+        self.blank_counter += 1;
+        label
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        if self.open_graph.is_some() {
+            writeln!(self.writer, "}}")?;
+            self.open_graph = None;
+        }
+        self.writer.flush()
+    }
+
+    fn triple_count(&self) -> u64 {
+        self.count
+    }
+}
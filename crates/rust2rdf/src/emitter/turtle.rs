@@ -1,13 +1,32 @@
 use std::collections::HashMap;
 use std::io::Write;
-use super::TriplesEmitter;
+use super::{is_valid_lang_tag, ObjectTerm, TriplesEmitter};
+
+/// A subject awaiting its closing `.`, with its predicate-object pairs
+/// collected so far. Kept in insertion order so objects sharing a predicate
+/// end up in one `,`-joined list and distinct predicates end up `;`-joined,
+/// without reordering unrelated predicates.
+struct PendingSubject {
+    subject: String,
+    predicates: Vec<(String, Vec<String>)>,
+}
 
 /// Turtle format emitter with prefix support.
+///
+/// Triples are buffered one subject at a time -- [`TriplesEmitter::emit_iri`]
+/// et al. don't write anything until the subject changes or
+/// [`flush`](TriplesEmitter::flush) is called, since Turtle's `;`/`,`
+/// predicate-object-list syntax can't be decided on a single triple in
+/// isolation (whether to end the statement with `.` or continue it depends
+/// on what comes next). Callers must call `flush()` to see the final
+/// subject's triples in the output.
 pub struct TurtleEmitter<W: Write> {
     writer: W,
     count: u64,
     prefixes: HashMap<String, String>,
     prefix_written: bool,
+    blank_counter: u64,
+    pending: Option<PendingSubject>,
 }
 
 impl<W: Write> TurtleEmitter<W> {
@@ -17,80 +36,233 @@ impl<W: Write> TurtleEmitter<W> {
             count: 0,
             prefixes: HashMap::new(),
             prefix_written: false,
+            blank_counter: 0,
+            pending: None,
         }
     }
 
+    /// Consume the emitter, returning the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
     /// Write all registered prefixes (called before first triple).
-    fn write_prefixes(&mut self) {
+    fn write_prefixes(&mut self) -> std::io::Result<()> {
         if self.prefix_written {
-            return;
+            return Ok(());
         }
         self.prefix_written = true;
         // Sort for deterministic output
         let mut prefixes: Vec<_> = self.prefixes.iter().collect();
         prefixes.sort_by_key(|(k, _)| (*k).clone());
         for (prefix, iri) in prefixes {
-            writeln!(self.writer, "@prefix {prefix}: <{iri}> .").unwrap();
+            writeln!(self.writer, "@prefix {prefix}: <{iri}> .")?;
         }
         if !self.prefixes.is_empty() {
-            writeln!(self.writer).unwrap();
+            writeln!(self.writer)?;
         }
+        Ok(())
     }
 
     /// Try to compact an IRI using registered prefixes.
     fn compact_iri(&self, iri: &str) -> String {
-        // Find longest matching prefix
-        let mut best: Option<(&str, &str)> = None;
-        for (prefix, ns) in &self.prefixes {
-            if iri.starts_with(ns.as_str())
-                && best.is_none_or(|(_, prev_ns)| ns.len() > prev_ns.len())
-            {
-                best = Some((prefix.as_str(), ns.as_str()));
-            }
+        compact_iri(&self.prefixes, iri)
+    }
+
+    /// Queue one triple (subject/predicate already compacted, `object` fully
+    /// rendered) into the pending subject group, flushing the previous group
+    /// first if `subject` differs from it. An existing predicate entry for
+    /// `subject` is reused (its object list grows with `,`) regardless of
+    /// whether it's the most recently queued one, so interleaved calls for
+    /// the same predicate still compact into a single list.
+    fn push(&mut self, subject: String, predicate: String, object: String) -> std::io::Result<()> {
+        self.write_prefixes()?;
+        if self.pending.as_ref().is_some_and(|p| p.subject != subject) {
+            self.flush_pending()?;
         }
-        if let Some((prefix, ns)) = best {
-            let local = &iri[ns.len()..];
-            // Only compact if local name is valid (alphanumeric + _)
-            if !local.is_empty() && local.chars().all(|c| c.is_alphanumeric() || c == '_') {
-                return format!("{prefix}:{local}");
-            }
+        let group = self.pending.get_or_insert_with(|| PendingSubject {
+            subject: subject.clone(),
+            predicates: Vec::new(),
+        });
+        match group.predicates.iter_mut().find(|(p, _)| *p == predicate) {
+            Some((_, objects)) => objects.push(object),
+            None => group.predicates.push((predicate, vec![object])),
         }
-        format!("<{iri}>")
+        Ok(())
+    }
+
+    /// Write out and clear the pending subject group, if any, as a single
+    /// Turtle statement using `;`/`,` predicate-object lists.
+    fn flush_pending(&mut self) -> std::io::Result<()> {
+        let Some(group) = self.pending.take() else {
+            return Ok(());
+        };
+        write!(self.writer, "{}", group.subject)?;
+        for (i, (predicate, objects)) in group.predicates.iter().enumerate() {
+            let sep = if i == 0 { " " } else { " ;\n    " };
+            write!(self.writer, "{sep}{predicate} {}", objects.join(", "))?;
+        }
+        writeln!(self.writer, " .")?;
+        Ok(())
     }
 
     fn escape_literal(s: &str) -> String {
-        let mut out = String::with_capacity(s.len());
-        for c in s.chars() {
-            match c {
-                '\\' => out.push_str("\\\\"),
-                '"' => out.push_str("\\\""),
-                '\n' => out.push_str("\\n"),
-                '\r' => out.push_str("\\r"),
-                '\t' => out.push_str("\\t"),
-                _ => out.push(c),
+        escape_literal(s)
+    }
+
+    /// Render a single [`ObjectTerm`] the way it would appear inline as the
+    /// object of a triple, for use inside a native `( ... )` list.
+    fn render_member(&self, member: &ObjectTerm) -> String {
+        match member {
+            ObjectTerm::Iri(iri) => self.compact_iri(iri),
+            ObjectTerm::Literal(v) => format!("\"{}\"", Self::escape_literal(v)),
+            ObjectTerm::TypedLiteral(v, dt) => {
+                format!("\"{}\"^^{}", Self::escape_literal(v), self.compact_iri(dt))
+            }
+            ObjectTerm::LangLiteral(v, lang) if is_valid_lang_tag(lang) => {
+                format!("\"{}\"@{lang}", Self::escape_literal(v))
             }
+            ObjectTerm::LangLiteral(v, _) => format!("\"{}\"", Self::escape_literal(v)),
+            ObjectTerm::Bool(b) => (if *b { "true" } else { "false" }).to_string(),
+            ObjectTerm::Int(n) => n.to_string(),
+        }
+    }
+}
+
+/// Try to compact an IRI against a prefix table, using the longest matching
+/// namespace. A blank-node label (`_:b0`, minted by
+/// [`TriplesEmitter::fresh_blank_node`](super::TriplesEmitter::fresh_blank_node))
+/// is passed through unchanged, since it's already a valid Turtle term and
+/// has no namespace to compact. Otherwise falls back to a full `<iri>` if no
+/// prefix matches or the local name can't be represented as a Turtle
+/// `PN_LOCAL` even with escaping (e.g. it contains whitespace). Shared by
+/// [`TurtleEmitter`] and [`super::trig::TriGEmitter`], which use the same
+/// prefix-compaction rules.
+pub(crate) fn compact_iri(prefixes: &HashMap<String, String>, iri: &str) -> String {
+    if iri.starts_with("_:") {
+        return iri.to_string();
+    }
+    let mut best: Option<(&str, &str)> = None;
+    for (prefix, ns) in prefixes {
+        if iri.starts_with(ns.as_str()) && best.is_none_or(|(_, prev_ns)| ns.len() > prev_ns.len())
+        {
+            best = Some((prefix.as_str(), ns.as_str()));
+        }
+    }
+    if let Some((prefix, ns)) = best {
+        let local = &iri[ns.len()..];
+        // Fast path: the common case of a simple alphanumeric/underscore
+        // local name needs no PN_LOCAL escaping at all.
+        if !local.is_empty() && local.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            return format!("{prefix}:{local}");
+        }
+        if let Some(escaped) = escape_pn_local(local) {
+            return format!("{prefix}:{escaped}");
+        }
+    }
+    format!("<{iri}>")
+}
+
+/// Characters from Turtle's `PN_LOCAL_ESC` production that [`escape_pn_local`]
+/// backslash-escapes in the local part of a compacted IRI.
+const PN_LOCAL_RESERVED: &str = "~.-!$&'()*+,;=/?#@%_";
+
+/// Whether `c` is a `PN_CHARS_BASE` character. Approximates the Turtle 1.1
+/// grammar's long list of allowed Unicode ranges with Rust's own alphabetic
+/// classification, which covers ASCII letters plus the non-ASCII scripts a
+/// Rust identifier can legally contain.
+fn is_pn_chars_base(c: char) -> bool {
+    c.is_alphabetic()
+}
+
+/// Whether `c` is a `PN_CHARS` character: `PN_CHARS_BASE`, `_`, `-`, a digit,
+/// or one of the combining-mark ranges the grammar carves out for
+/// diacritics.
+fn is_pn_chars(c: char) -> bool {
+    is_pn_chars_base(c)
+        || c == '_'
+        || c == '-'
+        || c.is_ascii_digit()
+        || c == '\u{B7}'
+        || ('\u{0300}'..='\u{036F}').contains(&c)
+        || ('\u{203F}'..='\u{2040}').contains(&c)
+}
+
+/// Render `local` as a valid, escaped Turtle `PN_LOCAL`, or `None` if some
+/// character in it (e.g. whitespace) can't be represented even with
+/// escaping, in which case the caller should fall back to a full `<iri>`.
+/// Every reserved-set character is backslash-escaped unconditionally rather
+/// than only where the bare grammar requires it (e.g. an interior `.`) --
+/// simpler to get right, and an escaped `PN_LOCAL_ESC` is legal in any
+/// position. Percent-encoded `PLX` triples already in `local` are passed
+/// through unescaped, since they're valid as-is.
+fn escape_pn_local(local: &str) -> Option<String> {
+    if local.is_empty() {
+        return None;
+    }
+    let chars: Vec<char> = local.chars().collect();
+    let mut out = String::with_capacity(local.len());
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '%'
+            && i + 2 < chars.len()
+            && chars[i + 1].is_ascii_hexdigit()
+            && chars[i + 2].is_ascii_hexdigit()
+        {
+            out.push('%');
+            out.push(chars[i + 1]);
+            out.push(chars[i + 2]);
+            i += 3;
+            continue;
+        }
+        if PN_LOCAL_RESERVED.contains(c) {
+            out.push('\\');
+            out.push(c);
+        } else if is_pn_chars(c) || c == ':' {
+            out.push(c);
+        } else {
+            return None;
+        }
+        i += 1;
+    }
+    Some(out)
+}
+
+/// Escape a string for a Turtle-family (Turtle/TriG) quoted literal. Shared
+/// by [`TurtleEmitter`] and [`super::trig::TriGEmitter`].
+pub(crate) fn escape_literal(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '"' => out.push_str("\\\""),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            _ => out.push(c),
         }
-        out
     }
+    out
 }
 
 impl<W: Write> TriplesEmitter for TurtleEmitter<W> {
-    fn emit_iri(&mut self, subject: &str, predicate: &str, object: &str) {
-        self.write_prefixes();
+    fn emit_iri(&mut self, subject: &str, predicate: &str, object: &str) -> std::io::Result<()> {
         let s = self.compact_iri(subject);
         let p = self.compact_iri(predicate);
         let o = self.compact_iri(object);
-        writeln!(self.writer, "{s} {p} {o} .").unwrap();
+        self.push(s, p, o)?;
         self.count += 1;
+        Ok(())
     }
 
-    fn emit_literal(&mut self, subject: &str, predicate: &str, value: &str) {
-        self.write_prefixes();
+    fn emit_literal(&mut self, subject: &str, predicate: &str, value: &str) -> std::io::Result<()> {
         let s = self.compact_iri(subject);
         let p = self.compact_iri(predicate);
         let escaped = Self::escape_literal(value);
-        writeln!(self.writer, "{s} {p} \"{escaped}\" .").unwrap();
+        self.push(s, p, format!("\"{escaped}\""))?;
         self.count += 1;
+        Ok(())
     }
 
     fn emit_typed_literal(
@@ -99,40 +271,86 @@ impl<W: Write> TriplesEmitter for TurtleEmitter<W> {
         predicate: &str,
         value: &str,
         datatype: &str,
-    ) {
-        self.write_prefixes();
+    ) -> std::io::Result<()> {
         let s = self.compact_iri(subject);
         let p = self.compact_iri(predicate);
         let dt = self.compact_iri(datatype);
         let escaped = Self::escape_literal(value);
-        writeln!(self.writer, "{s} {p} \"{escaped}\"^^{dt} .").unwrap();
+        self.push(s, p, format!("\"{escaped}\"^^{dt}"))?;
         self.count += 1;
+        Ok(())
     }
 
-    fn emit_bool(&mut self, subject: &str, predicate: &str, value: bool) {
+    fn emit_lang_literal(
+        &mut self,
+        subject: &str,
+        predicate: &str,
+        value: &str,
+        lang: &str,
+    ) -> std::io::Result<()> {
+        if !is_valid_lang_tag(lang) {
+            return self.emit_literal(subject, predicate, value);
+        }
+        let s = self.compact_iri(subject);
+        let p = self.compact_iri(predicate);
+        let escaped = Self::escape_literal(value);
+        self.push(s, p, format!("\"{escaped}\"@{lang}"))?;
+        self.count += 1;
+        Ok(())
+    }
+
+    fn emit_bool(&mut self, subject: &str, predicate: &str, value: bool) -> std::io::Result<()> {
         let val = if value { "true" } else { "false" };
         self.emit_typed_literal(
             subject,
             predicate,
             val,
             "http://www.w3.org/2001/XMLSchema#boolean",
-        );
+        )
     }
 
-    fn emit_int(&mut self, subject: &str, predicate: &str, value: i64) {
+    fn emit_int(&mut self, subject: &str, predicate: &str, value: i64) -> std::io::Result<()> {
         self.emit_typed_literal(
             subject,
             predicate,
             &value.to_string(),
             "http://www.w3.org/2001/XMLSchema#integer",
-        );
+        )
     }
 
-    fn add_prefix(&mut self, prefix: &str, iri: &str) {
+    fn add_prefix(&mut self, prefix: &str, iri: &str) -> std::io::Result<()> {
         self.prefixes.insert(prefix.to_string(), iri.to_string());
+        Ok(())
+    }
+
+    fn fresh_blank_node(&mut self) -> String {
+        let label = format!("_:b{}", self.blank_counter);
+        self.blank_counter += 1;
+        label
+    }
+
+    /// Overrides the default blank-node chain with Turtle's native `( a b c )`
+    /// round-bracket list syntax, which reads far better than a spelled-out
+    /// `rdf:first`/`rdf:rest` chain and is exactly what that syntax exists for.
+    fn emit_collection(
+        &mut self,
+        subject: &str,
+        predicate: &str,
+        members: &[ObjectTerm],
+    ) -> std::io::Result<()> {
+        let s = self.compact_iri(subject);
+        let p = self.compact_iri(predicate);
+        let mut rendered = Vec::with_capacity(members.len());
+        for member in members {
+            rendered.push(self.render_member(member));
+        }
+        self.push(s, p, format!("( {} )", rendered.join(" ")))?;
+        self.count += if members.is_empty() { 1 } else { 2 * members.len() as u64 + 1 };
+        Ok(())
     }
 
     fn flush(&mut self) -> std::io::Result<()> {
+        self.flush_pending()?;
         self.writer.flush()
     }
 
@@ -0,0 +1,188 @@
+//! Opt-in content-addressed cache for [`rustdoc_loader::load_crate`], so
+//! re-extracting a large workspace doesn't re-run `cargo +nightly rustdoc`
+//! on every member every time.
+//!
+//! A [`Fingerprint`] is computed from the crate's resolved version, the
+//! format-version ceiling this build models (bumping [`MAX_MODELED_FORMAT_VERSION`]
+//! invalidates every cache entry, since the modeled schema changed), and the
+//! `(relative path, mtime, length)` of every file under `src/**` plus
+//! `Cargo.toml` -- changing a source file's content without touching its
+//! mtime (e.g. `git checkout` of an old commit) isn't detected, the same
+//! tradeoff `cargo` itself makes for its own fingerprinting. On a
+//! fingerprint miss, [`CrateCache::get_or_load`] runs the real rustdoc build
+//! and stores the result; on a hit, it deserializes the cached [`Crate`]
+//! straight from disk and skips rustdoc entirely.
+
+use std::collections::BTreeMap;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use super::rustdoc_loader::{self, LoadError, MAX_MODELED_FORMAT_VERSION};
+use super::rustdoc_model::Crate;
+
+/// A fingerprint of everything that can change a crate's rustdoc JSON
+/// output: its resolved version, the format-version ceiling this build
+/// models, and an mtime/length summary of its source files. Rendered as a
+/// hex string for use as a cache filename.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fingerprint(u64);
+
+impl Fingerprint {
+    /// Hex-encoded form, suitable as a cache entry's filename.
+    pub fn to_hex(self) -> String {
+        format!("{:016x}", self.0)
+    }
+}
+
+/// Compute the [`Fingerprint`] for `crate_dir`. Walks `src/**` (recursively)
+/// plus `Cargo.toml` at the crate root, hashing each file's relative path,
+/// mtime, and length -- not its content, so this stays cheap even for large
+/// source trees. Returns `None` if `src/` doesn't exist or can't be walked;
+/// callers should treat that as an unconditional cache miss.
+pub fn fingerprint(crate_dir: &Path, crate_version: Option<&str>) -> Option<Fingerprint> {
+    let src_dir = crate_dir.join("src");
+    if !src_dir.is_dir() {
+        return None;
+    }
+
+    let mut files = BTreeMap::new();
+    collect_file_stamps(&src_dir, crate_dir, &mut files).ok()?;
+    if let Ok(meta) = std::fs::metadata(crate_dir.join("Cargo.toml")) {
+        files.insert(PathBuf::from("Cargo.toml"), file_stamp(&meta));
+    }
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    MAX_MODELED_FORMAT_VERSION.hash(&mut hasher);
+    crate_version.hash(&mut hasher);
+    for (relative_path, stamp) in &files {
+        relative_path.hash(&mut hasher);
+        stamp.hash(&mut hasher);
+    }
+    Some(Fingerprint(hasher.finish()))
+}
+
+/// `(mtime as nanoseconds since the epoch, file length)` -- cheap to compute
+/// and sensitive to the same edits `cargo`'s own fingerprinting reacts to.
+type FileStamp = (u128, u64);
+
+fn file_stamp(metadata: &std::fs::Metadata) -> FileStamp {
+    let mtime_nanos = metadata
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_nanos())
+        .unwrap_or(0);
+    (mtime_nanos, metadata.len())
+}
+
+fn collect_file_stamps(
+    dir: &Path,
+    crate_dir: &Path,
+    into: &mut BTreeMap<PathBuf, FileStamp>,
+) -> std::io::Result<()> {
+    if !dir.is_dir() {
+        return Ok(());
+    }
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        if path.is_dir() {
+            collect_file_stamps(&path, crate_dir, into)?;
+        } else {
+            let metadata = entry.metadata()?;
+            let relative = path
+                .strip_prefix(crate_dir)
+                .unwrap_or(&path)
+                .to_path_buf();
+            into.insert(relative, file_stamp(&metadata));
+        }
+    }
+    Ok(())
+}
+
+/// An on-disk cache of previously-loaded [`Crate`]s, keyed by [`Fingerprint`].
+/// Each entry is one file, `<cache_dir>/<fingerprint>.json`, holding the
+/// `Crate` exactly as deserialized from rustdoc's own JSON output.
+pub struct CrateCache {
+    cache_dir: PathBuf,
+}
+
+impl CrateCache {
+    /// Use (creating if necessary) `cache_dir` as the cache location.
+    pub fn new(cache_dir: impl Into<PathBuf>) -> std::io::Result<Self> {
+        let cache_dir = cache_dir.into();
+        std::fs::create_dir_all(&cache_dir)?;
+        Ok(Self { cache_dir })
+    }
+
+    pub fn cache_dir(&self) -> &Path {
+        &self.cache_dir
+    }
+
+    fn entry_path(&self, fingerprint: Fingerprint) -> PathBuf {
+        self.cache_dir.join(format!("{}.json", fingerprint.to_hex()))
+    }
+
+    /// Load `crate_dir`, reusing a cached [`Crate`] when its current
+    /// [`fingerprint`] matches a stored entry; otherwise runs
+    /// [`rustdoc_loader::load_crate`] and stores the result under that
+    /// fingerprint before returning it. A crate whose fingerprint can't be
+    /// computed (see [`fingerprint`]) always falls back to a full build.
+    pub fn get_or_load(&self, crate_dir: &Path) -> Result<Crate, LoadError> {
+        let metadata = rustdoc_loader::resolve_package_metadata(crate_dir)?;
+        let Some(fp) = fingerprint(crate_dir, metadata.version.as_deref()) else {
+            return rustdoc_loader::load_crate(crate_dir);
+        };
+
+        if let Some(krate) = self.get(fp) {
+            return Ok(krate);
+        }
+
+        let krate = rustdoc_loader::load_crate(crate_dir)?;
+        let _ = self.put(fp, &krate); // a cache-write failure shouldn't fail the load
+        Ok(krate)
+    }
+
+    /// Look up a previously-[`put`](Self::put) entry by its exact fingerprint.
+    pub fn get(&self, fingerprint: Fingerprint) -> Option<Crate> {
+        let content = std::fs::read_to_string(self.entry_path(fingerprint)).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    /// Store `krate` under `fingerprint`, overwriting any existing entry.
+    pub fn put(&self, fingerprint: Fingerprint, krate: &Crate) -> std::io::Result<()> {
+        let content = serde_json::to_string(krate)?;
+        std::fs::write(self.entry_path(fingerprint), content)
+    }
+
+    /// Remove every cached entry, forcing the next [`get_or_load`](Self::get_or_load)
+    /// for any crate to run a full rustdoc build.
+    pub fn invalidate_all(&self) -> std::io::Result<()> {
+        for entry in std::fs::read_dir(&self.cache_dir)? {
+            let path = entry?.path();
+            if path.extension().and_then(|e| e.to_str()) == Some("json") {
+                std::fs::remove_file(path)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Remove the cached entry for `crate_dir` at its *current* fingerprint,
+    /// if one exists. Does nothing (successfully) if the crate isn't
+    /// cached, or if its fingerprint can't be computed.
+    pub fn invalidate(&self, crate_dir: &Path) -> std::io::Result<()> {
+        let metadata = match rustdoc_loader::resolve_package_metadata(crate_dir) {
+            Ok(metadata) => metadata,
+            Err(_) => return Ok(()),
+        };
+        let Some(fp) = fingerprint(crate_dir, metadata.version.as_deref()) else {
+            return Ok(());
+        };
+        let path = self.entry_path(fp);
+        if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+}
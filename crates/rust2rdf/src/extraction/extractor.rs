@@ -5,26 +5,126 @@
 
 use std::collections::HashSet;
 
-use crate::emitter::TriplesEmitter;
+use crate::cfg::{self, Cfg};
+use crate::config::PathFilter;
+use crate::emitter::{ObjectTerm, TriplesEmitter};
 use crate::model::iri::IriMinter;
-use crate::model::ontology::{rt, standard, tg};
+use crate::model::ontology::{owl, rt, standard, tg};
+use crate::stability;
 
 use super::rustdoc_model::{
-    Crate, FunctionHeader, FunctionSignature, GenericBound, GenericParamDefKind, Generics, Item,
-    ItemEnum, ResolvedPath, StructKind, Type, VariantData, VariantKind, Visibility,
+    Crate, FunctionHeader, FunctionSignature, GenericArg, GenericArgs, GenericBound,
+    GenericParamDef, GenericParamDefKind, Generics, Id, Item, ItemEnum, MacroKind, ResolvedPath,
+    Span, StructKind, Type, TypeBindingKind, VariantData, VariantKind, Visibility,
+    WherePredicate,
 };
 
 // ---------------------------------------------------------------------------
 // ExtractionOptions
 // ---------------------------------------------------------------------------
 
+/// Which items, by doc/public-API reachability from the crate root, get
+/// emitted. See [`CrateExtractor::compute_reachable_ids`] for how the
+/// reachability closure itself is computed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VisibilityMode {
+    /// Emit every item in the index, regardless of visibility.
+    #[default]
+    All,
+    /// Emit only items reachable from the crate's public surface: `pub`
+    /// items transitively re-exported from the root, plus any item --
+    /// public or not -- referenced from a reachable item's signature (a
+    /// private type returned by a public function still needs to be
+    /// documented for the signature to make sense, so rustdoc includes it
+    /// too).
+    DocReachable,
+    /// Like [`Self::DocReachable`], but additionally requires the item
+    /// itself be `pub` -- excludes private types that merely leak into a
+    /// public signature.
+    PublicApi,
+}
+
 /// Options controlling what gets extracted.
+#[derive(Clone)]
 pub struct ExtractionOptions {
     pub base_uri: String,
     pub include_impls: bool,
     pub include_attributes: bool,
+    pub include_spans: bool,
     pub extract_error_types: bool,
     pub extract_derives: bool,
+    /// Emit `rt:isDeprecated`/`rt:stabilityLevel` and friends from each
+    /// item's `deprecation` field and `#[stable]`/`#[unstable]` attrs.
+    pub extract_stability: bool,
+    /// Emit `tg:documentation`/`tg:summary` language-tagged literals from
+    /// each item's combined doc comment.
+    pub extract_docs: bool,
+    /// Language tag used for `tg:documentation`/`tg:summary` literals
+    /// (e.g. `"en"`).
+    pub doc_language: String,
+    /// Describe every referenced primitive type node (`rt:PrimitiveType`)
+    /// with `rt:primitiveCategory`/`rt:bitWidth`/`rt:isSigned`.
+    pub extract_primitive_metadata: bool,
+    /// Emit a synthetic `rt:DerivedImpl` + `rt:implementsTrait` edge for each
+    /// `#[derive(...)]`-sourced impl, in addition to the `rt:derives`
+    /// literal. `rt:derives` is always emitted regardless of this flag.
+    pub extract_derive_impls: bool,
+    /// Extra derive-name -> fully-qualified-trait-path mappings (e.g. for
+    /// `derive_more`'s `From`/`Display`/... family), consulted when an
+    /// auto-derived impl's trait couldn't be resolved to an `Id` rustdoc
+    /// already knows about.
+    pub extra_derive_traits: std::collections::HashMap<String, String>,
+    /// Synthesize `rt:AutoTraitImpl` nodes for Send/Sync/Unpin/UnwindSafe by
+    /// walking each type's fields (see
+    /// [`CrateExtractor::infer_auto_traits`]). Manual impls (positive or
+    /// `impl !Trait for Type`) always take precedence over the synthesized
+    /// result for that type.
+    pub synthesize_auto_traits: bool,
+    /// Resolve blanket impls (`impl<T: Bound> Trait for T`) the crate itself
+    /// defines: emit an `rt:BlanketImpl` node for the impl and `rt:impliesImplFor`
+    /// edges to every in-crate type already known to satisfy the blanket's
+    /// where-clause (see [`CrateExtractor::resolve_blanket_impls`]).
+    pub include_blanket_impls: bool,
+    /// Compute and emit `rt:objectSafe`/`rt:objectSafetyViolation` for every
+    /// trait node, plus `rt:excludedFromObject` on methods carrying a `where
+    /// Self: Sized` bound (see [`CrateExtractor::analyze_object_safety`]).
+    pub analyze_object_safety: bool,
+    /// Emit `rt:isDefaultImpl`/`rt:isSpecializable` specialization metadata
+    /// on impl blocks and their `default`-marked items, plus `rt:specializes`
+    /// edges between impls of the same trait ordered by self-type/bound
+    /// specificity (see [`CrateExtractor::resolve_specialization`]).
+    pub extract_specialization: bool,
+    /// Which items to emit, based on doc/public-API reachability from the
+    /// crate root (see [`VisibilityMode`]).
+    pub visibility: VisibilityMode,
+    /// Include/exclude patterns matched against each item's fully-qualified
+    /// Rust path, suppressing extraction of whatever they exclude.
+    pub path_filter: PathFilter,
+    /// Extra namespace prefixes to register alongside the built-in ones
+    /// (e.g. from a `--config` file's `[prefixes]` table). Only affects
+    /// Turtle-style emitters that compact IRIs using registered prefixes.
+    pub extra_prefixes: std::collections::HashMap<String, String>,
+    /// Resolve each locally-defined item's canonical path from rustdoc's
+    /// `paths` index and, when it differs from the path the item was
+    /// actually walked at (e.g. a `pub use`-facade re-export makes a type
+    /// doc-canonical somewhere other than where it's defined), emit an
+    /// `owl:sameAs` triple from the defining-site IRI to the canonical one
+    /// (see [`CrateExtractor::resolve_canonical_paths`]). Off by default
+    /// since it adds an extra IRI per aliased item.
+    pub canonicalize_paths: bool,
+    /// Mint hashed `.../type/<crate>/<version>/<shortname>-<hash>` IRIs for
+    /// heavily-generic type names (see [`IriMinter::type_iri`]) instead of
+    /// percent-encoding the whole generic signature. Off by default, since
+    /// it trades a longer but fully self-describing IRI for a short,
+    /// store-friendly one.
+    pub hash_complex_iris: bool,
+    /// Number of worker threads used to walk the crate root's direct
+    /// children (see [`CrateExtractor::walk_root_parallel`]). `1` (the
+    /// default) walks single-threaded exactly as before; values above `1`
+    /// split that work across a thread pool but always flush triples in
+    /// item-id order, so output bytes are identical regardless of this
+    /// setting.
+    pub jobs: usize,
 }
 
 impl Default for ExtractionOptions {
@@ -33,8 +133,25 @@ impl Default for ExtractionOptions {
             base_uri: "http://rust.example".to_string(),
             include_impls: true,
             include_attributes: true,
+            include_spans: true,
             extract_error_types: true,
             extract_derives: true,
+            extract_stability: true,
+            extract_docs: true,
+            doc_language: "en".to_string(),
+            extract_primitive_metadata: true,
+            extract_derive_impls: true,
+            extra_derive_traits: std::collections::HashMap::new(),
+            synthesize_auto_traits: true,
+            include_blanket_impls: true,
+            analyze_object_safety: true,
+            extract_specialization: true,
+            visibility: VisibilityMode::All,
+            path_filter: PathFilter::allow_all(),
+            extra_prefixes: std::collections::HashMap::new(),
+            canonicalize_paths: false,
+            hash_complex_iris: false,
+            jobs: 1,
         }
     }
 }
@@ -53,6 +170,76 @@ pub struct CrateExtractor<'a, E: TriplesEmitter> {
     options: ExtractionOptions,
     emitted_types: HashSet<String>,
     emitted_modules: HashSet<String>,
+    /// Ambient `cfg` predicate of each module, keyed by module path. Child
+    /// items conjoin this onto their own `cfg` so that, e.g., everything
+    /// inside a `#[cfg(windows)]` module inherits that condition.
+    module_cfgs: cfg::ModuleCfgTable,
+    /// Field types of each local struct/enum/union, keyed by its type IRI,
+    /// collected while walking fields/variants and consumed by
+    /// [`infer_auto_traits`](Self::infer_auto_traits).
+    type_fields: std::collections::HashMap<String, Vec<Type>>,
+    /// Generic parameters of each local struct/enum/union, keyed by its type
+    /// IRI, in declaration order (the same order [`IriMinter::type_parameter_iri`]
+    /// ordinals are assigned in). Used to resolve a conditional auto-trait
+    /// bound's parameter name back to the `typeparam/N` node it was minted
+    /// for.
+    type_generic_params: std::collections::HashMap<String, Vec<GenericParamDef>>,
+    /// `(type IRI, trait name)` pairs with an explicit `impl !Trait for Type`,
+    /// found while processing impls.
+    negative_impls: HashSet<(String, String)>,
+    /// `(type IRI, trait name)` pairs with an explicit manual (positive) impl
+    /// of an auto trait, found while processing impls. These take precedence
+    /// over whatever [`CrateExtractor::infer_auto_traits`] would synthesize.
+    manual_auto_trait_impls: HashSet<(String, String)>,
+    /// Trait IRIs each in-crate type is known to implement, keyed by its type
+    /// IRI. Populated as real (non-blanket, non-negative) trait impls and
+    /// unconditional synthesized auto-trait impls are processed; consumed by
+    /// [`CrateExtractor::resolve_blanket_impls`] to decide whether a type's
+    /// own bounds satisfy a blanket impl's where-clause.
+    implemented_traits: std::collections::HashMap<String, HashSet<String>>,
+    /// `(impl IRI, required bound trait IRIs)` for each `impl<T: Bound> Trait
+    /// for T` found while processing impls, collected during
+    /// [`CrateExtractor::process_all_impls`] and resolved against every local
+    /// type's [`CrateExtractor::implemented_traits`] afterwards.
+    blanket_impls: Vec<(String, HashSet<String>)>,
+    /// Every trait impl found while processing impls, keyed by its trait IRI,
+    /// for pairwise specificity comparison in
+    /// [`CrateExtractor::resolve_specialization`].
+    trait_impls_by_trait: std::collections::HashMap<String, Vec<SpecializationCandidate>>,
+    /// `cfg` condition-node IRIs already emitted. Nodes are keyed by their
+    /// canonical string, so identical guards across many items share one
+    /// node and are only emitted once.
+    emitted_cfg_nodes: HashSet<String>,
+    /// Item ids reachable from the crate root per [`ExtractionOptions::visibility`],
+    /// computed once up front by [`CrateExtractor::compute_reachable_ids`].
+    /// `None` when [`VisibilityMode::All`] means every item passes.
+    reachable_ids: Option<HashSet<String>>,
+    /// Defining-site `module_path::name` of every locally-extracted item,
+    /// keyed by item id, recorded while walking the module tree and
+    /// consumed by [`CrateExtractor::resolve_canonical_paths`] when
+    /// [`ExtractionOptions::canonicalize_paths`] is set.
+    item_full_paths: std::collections::HashMap<String, String>,
+}
+
+/// A trait impl's self-type, recorded for
+/// [`CrateExtractor::resolve_specialization`]'s pairwise specificity
+/// comparison.
+#[derive(Clone)]
+enum ImplSelfType {
+    /// A concrete, in-crate `for_iri`.
+    Concrete(String),
+    /// `impl<T: Bound> Trait for T` -- matches every type satisfying
+    /// `required_bounds`.
+    Blanket(HashSet<String>),
+}
+
+/// One trait impl found while processing impls, grouped by trait IRI in
+/// [`CrateExtractor::trait_impls_by_trait`] for
+/// [`CrateExtractor::resolve_specialization`].
+#[derive(Clone)]
+struct SpecializationCandidate {
+    impl_iri: String,
+    self_type: ImplSelfType,
 }
 
 impl<'a, E: TriplesEmitter> CrateExtractor<'a, E> {
@@ -70,7 +257,8 @@ impl<'a, E: TriplesEmitter> CrateExtractor<'a, E> {
             .clone()
             .unwrap_or_else(|| "0.0.0".to_string());
 
-        let iris = IriMinter::new(&options.base_uri);
+        let mut iris = IriMinter::new(&options.base_uri);
+        iris.set_hash_complex_iris(options.hash_complex_iris);
 
         Self {
             emitter,
@@ -81,6 +269,17 @@ impl<'a, E: TriplesEmitter> CrateExtractor<'a, E> {
             options,
             emitted_types: HashSet::new(),
             emitted_modules: HashSet::new(),
+            module_cfgs: cfg::ModuleCfgTable::new(),
+            type_fields: std::collections::HashMap::new(),
+            type_generic_params: std::collections::HashMap::new(),
+            negative_impls: HashSet::new(),
+            manual_auto_trait_impls: HashSet::new(),
+            implemented_traits: std::collections::HashMap::new(),
+            blanket_impls: Vec::new(),
+            trait_impls_by_trait: std::collections::HashMap::new(),
+            emitted_cfg_nodes: HashSet::new(),
+            reachable_ids: None,
+            item_full_paths: std::collections::HashMap::new(),
         }
     }
 
@@ -89,69 +288,225 @@ impl<'a, E: TriplesEmitter> CrateExtractor<'a, E> {
     // -----------------------------------------------------------------------
 
     /// Run the full extraction, emitting all triples.
-    pub fn extract(&mut self) {
-        self.register_prefixes();
-        self.emit_crate_node();
-        self.emit_external_crates();
-        self.walk_module(&self.crate_data.root.0.clone(), &self.crate_name.clone());
+    pub fn extract(&mut self) -> std::io::Result<()> {
+        if self.options.visibility != VisibilityMode::All {
+            self.reachable_ids = Some(self.compute_reachable_ids());
+        }
+        self.register_prefixes()?;
+        self.emit_crate_node()?;
+        self.emit_external_crates()?;
+        if self.options.jobs > 1 {
+            self.walk_root_parallel(&self.crate_data.root.0.clone())?;
+        } else {
+            self.walk_module(&self.crate_data.root.0.clone(), &self.crate_name.clone())?;
+        }
         if self.options.include_impls {
-            self.process_all_impls();
+            self.process_all_impls()?;
+            if self.options.synthesize_auto_traits {
+                self.infer_auto_traits()?;
+            }
+            if self.options.include_blanket_impls {
+                self.resolve_blanket_impls()?;
+            }
+            if self.options.extract_specialization {
+                self.resolve_specialization()?;
+            }
+        }
+        if self.options.canonicalize_paths {
+            self.resolve_canonical_paths()?;
+        }
+        Ok(())
+    }
+
+    // -----------------------------------------------------------------------
+    // Visibility filtering / doc-reachability
+    // -----------------------------------------------------------------------
+
+    /// Whether `item_id` should be emitted under the configured
+    /// [`ExtractionOptions::visibility`] mode. Always `true` under
+    /// [`VisibilityMode::All`] (no reachability set computed).
+    fn passes_visibility_filter(&self, item_id: &str, item: &Item) -> bool {
+        let Some(reachable) = &self.reachable_ids else {
+            return true;
+        };
+        if !reachable.contains(item_id) {
+            return false;
+        }
+        if self.options.visibility == VisibilityMode::PublicApi {
+            return matches!(item.visibility, Visibility::Public);
+        }
+        true
+    }
+
+    /// Compute the set of item ids reachable from the crate root: `pub`
+    /// items transitively declared or re-exported from the root module, plus
+    /// any item -- public or not -- referenced from a reachable item's
+    /// signature (struct/variant field types, function parameter/return
+    /// types, type alias targets, const/static types). This mirrors what
+    /// rustdoc itself documents: a private type leaked through a public
+    /// function's return type still needs a page.
+    fn compute_reachable_ids(&self) -> HashSet<String> {
+        let mut reachable: HashSet<String> = HashSet::new();
+        let mut worklist: std::collections::VecDeque<String> = std::collections::VecDeque::new();
+
+        let root_id = self.crate_data.root.0.clone();
+        reachable.insert(root_id.clone());
+        worklist.push_back(root_id);
+
+        while let Some(id) = worklist.pop_front() {
+            let Some(item) = self.crate_data.index.get(&id) else {
+                continue;
+            };
+            match &item.inner {
+                ItemEnum::Module { items, .. } => {
+                    for child in items {
+                        if self
+                            .crate_data
+                            .index
+                            .get(&child.0)
+                            .is_some_and(|child_item| matches!(child_item.visibility, Visibility::Public))
+                        {
+                            enqueue(&child.0, &mut reachable, &mut worklist);
+                        }
+                    }
+                }
+                ItemEnum::Struct { kind, .. } => match kind {
+                    StructKind::Plain { fields, .. } => {
+                        for field in fields {
+                            enqueue(&field.0, &mut reachable, &mut worklist);
+                        }
+                    }
+                    StructKind::Tuple(fields) => {
+                        for field in fields.iter().flatten() {
+                            enqueue(&field.0, &mut reachable, &mut worklist);
+                        }
+                    }
+                    StructKind::Unit => {}
+                },
+                ItemEnum::Union { fields, .. } => {
+                    for field in fields {
+                        enqueue(&field.0, &mut reachable, &mut worklist);
+                    }
+                }
+                ItemEnum::Enum { variants, .. } => {
+                    for variant in variants {
+                        enqueue(&variant.0, &mut reachable, &mut worklist);
+                    }
+                }
+                ItemEnum::Variant(data) => match &data.kind {
+                    VariantKind::Struct { fields, .. } => {
+                        for field in fields {
+                            enqueue(&field.0, &mut reachable, &mut worklist);
+                        }
+                    }
+                    VariantKind::Tuple(fields) => {
+                        for field in fields.iter().flatten() {
+                            enqueue(&field.0, &mut reachable, &mut worklist);
+                        }
+                    }
+                    VariantKind::Plain => {}
+                },
+                ItemEnum::StructField(ty) => {
+                    enqueue_type_refs(ty, &mut reachable, &mut worklist);
+                }
+                ItemEnum::Function { sig, .. } => {
+                    for (_, ty) in &sig.inputs {
+                        enqueue_type_refs(ty, &mut reachable, &mut worklist);
+                    }
+                    if let Some(ref ty) = sig.output {
+                        enqueue_type_refs(ty, &mut reachable, &mut worklist);
+                    }
+                }
+                ItemEnum::Trait { items, .. } => {
+                    for trait_item in items {
+                        enqueue(&trait_item.0, &mut reachable, &mut worklist);
+                    }
+                }
+                ItemEnum::TypeAlias { type_: Some(ty), .. } => {
+                    enqueue_type_refs(ty, &mut reachable, &mut worklist);
+                }
+                ItemEnum::Constant { type_, .. } | ItemEnum::Static { type_, .. } => {
+                    enqueue_type_refs(type_, &mut reachable, &mut worklist);
+                }
+                ItemEnum::Use { id: Some(target), .. } => {
+                    enqueue(&target.0, &mut reachable, &mut worklist);
+                }
+                _ => {}
+            }
         }
+
+        reachable
     }
 
     // -----------------------------------------------------------------------
     // Prefix registration
     // -----------------------------------------------------------------------
 
-    fn register_prefixes(&mut self) {
-        self.emitter.add_prefix("rdf", standard::RDF);
-        self.emitter.add_prefix("rdfs", standard::RDFS);
-        self.emitter.add_prefix("xsd", standard::XSD);
-        self.emitter.add_prefix(tg::PREFIX, tg::NS);
-        self.emitter.add_prefix(rt::PREFIX, rt::NS);
+    fn register_prefixes(&mut self) -> std::io::Result<()> {
+        self.emitter.add_prefix("rdf", standard::RDF)?;
+        self.emitter.add_prefix("rdfs", standard::RDFS)?;
+        self.emitter.add_prefix("xsd", standard::XSD)?;
+        self.emitter.add_prefix(tg::PREFIX, tg::NS)?;
+        self.emitter.add_prefix(rt::PREFIX, rt::NS)?;
+        if self.options.canonicalize_paths {
+            self.emitter.add_prefix(owl::PREFIX, owl::NS)?;
+        }
+        let mut extra: Vec<_> = self.options.extra_prefixes.iter().collect();
+        extra.sort_by_key(|(prefix, _)| (*prefix).clone());
+        for (prefix, iri) in extra {
+            self.emitter.add_prefix(prefix, iri)?;
+        }
+        Ok(())
     }
 
     // -----------------------------------------------------------------------
     // Crate node
     // -----------------------------------------------------------------------
 
-    fn emit_crate_node(&mut self) {
+    fn emit_crate_node(&mut self) -> std::io::Result<()> {
         let crate_iri = self.iris.crate_iri(&self.crate_name, &self.crate_version);
         self.emitter
-            .emit_iri(&crate_iri, standard::RDF_TYPE, rt::CRATE);
+            .emit_iri(&crate_iri, standard::RDF_TYPE, rt::CRATE)?;
         self.emitter
-            .emit_iri(&crate_iri, standard::RDF_TYPE, tg::ASSEMBLY);
+            .emit_iri(&crate_iri, standard::RDF_TYPE, tg::ASSEMBLY)?;
         self.emitter
-            .emit_literal(&crate_iri, tg::NAME, &self.crate_name);
+            .emit_literal(&crate_iri, tg::NAME, &self.crate_name)?;
         self.emitter
-            .emit_literal(&crate_iri, tg::LANGUAGE, "rust");
+            .emit_literal(&crate_iri, tg::LANGUAGE, "rust")?;
         self.emitter
-            .emit_literal(&crate_iri, tg::VERSION, &self.crate_version);
+            .emit_literal(&crate_iri, tg::VERSION, &self.crate_version)?;
+        self.emitter.emit_int(
+            &crate_iri,
+            rt::RUSTDOC_FORMAT_VERSION,
+            self.crate_data.format_version as i64,
+        )?;
+        Ok(())
     }
 
     // -----------------------------------------------------------------------
     // External crate dependencies
     // -----------------------------------------------------------------------
 
-    fn emit_external_crates(&mut self) {
+    fn emit_external_crates(&mut self) -> std::io::Result<()> {
         let crate_iri = self.iris.crate_iri(&self.crate_name, &self.crate_version);
         for ext in self.crate_data.external_crates.values() {
             let dep_iri = self.iris.crate_iri(&ext.name, "0.0.0");
-            self.emitter.emit_iri(&crate_iri, rt::DEPENDS_ON, &dep_iri);
+            self.emitter.emit_iri(&crate_iri, rt::DEPENDS_ON, &dep_iri)?;
             self.emitter
-                .emit_iri(&dep_iri, standard::RDF_TYPE, rt::CRATE);
-            self.emitter.emit_literal(&dep_iri, tg::NAME, &ext.name);
+                .emit_iri(&dep_iri, standard::RDF_TYPE, rt::CRATE)?;
+            self.emitter.emit_literal(&dep_iri, tg::NAME, &ext.name)?;
         }
+        Ok(())
     }
 
     // -----------------------------------------------------------------------
     // Module walking
     // -----------------------------------------------------------------------
 
-    fn walk_module(&mut self, item_id: &str, module_path: &str) {
+    fn walk_module(&mut self, item_id: &str, module_path: &str) -> std::io::Result<()> {
         let item = match self.crate_data.index.get(item_id) {
             Some(i) => i,
-            None => return,
+            None => return Ok(()),
         };
 
         if let ItemEnum::Module { ref items, .. } = item.inner {
@@ -159,19 +514,20 @@ impl<'a, E: TriplesEmitter> CrateExtractor<'a, E> {
             // doubles as the crate itself).
             let is_root = item_id == self.crate_data.root.0;
             if !is_root {
-                self.emit_module_node(module_path, item);
+                self.emit_module_node(module_path, item)?;
             }
 
             let child_ids: Vec<String> = items.iter().map(|id| id.0.clone()).collect();
             for child_id in &child_ids {
-                self.walk_item(child_id, module_path);
+                self.walk_item(child_id, module_path)?;
             }
         }
+        Ok(())
     }
 
-    fn emit_module_node(&mut self, module_path: &str, item: &Item) {
+    fn emit_module_node(&mut self, module_path: &str, item: &Item) -> std::io::Result<()> {
         if self.emitted_modules.contains(module_path) {
-            return;
+            return Ok(());
         }
         self.emitted_modules.insert(module_path.to_string());
 
@@ -181,21 +537,21 @@ impl<'a, E: TriplesEmitter> CrateExtractor<'a, E> {
         let crate_iri = self.iris.crate_iri(&self.crate_name, &self.crate_version);
 
         self.emitter
-            .emit_iri(&module_iri, standard::RDF_TYPE, rt::MODULE);
+            .emit_iri(&module_iri, standard::RDF_TYPE, rt::MODULE)?;
         self.emitter
-            .emit_iri(&module_iri, standard::RDF_TYPE, tg::NAMESPACE);
+            .emit_iri(&module_iri, standard::RDF_TYPE, tg::NAMESPACE)?;
 
         let name = item.name.as_deref().unwrap_or(module_path);
-        self.emitter.emit_literal(&module_iri, tg::NAME, name);
+        self.emitter.emit_literal(&module_iri, tg::NAME, name)?;
         self.emitter
-            .emit_literal(&module_iri, tg::FULL_NAME, module_path);
+            .emit_literal(&module_iri, tg::FULL_NAME, module_path)?;
         self.emitter
-            .emit_iri(&module_iri, tg::DEFINED_IN_ASSEMBLY, &crate_iri);
+            .emit_iri(&module_iri, tg::DEFINED_IN_ASSEMBLY, &crate_iri)?;
 
         // Emit accessibility
         let vis = visibility_str(&item.visibility);
         self.emitter
-            .emit_literal(&module_iri, tg::ACCESSIBILITY, vis);
+            .emit_literal(&module_iri, tg::ACCESSIBILITY, vis)?;
 
         // Parent namespace
         if let Some(parent_path) = module_path.rsplit_once("::").map(|(p, _)| p) {
@@ -203,73 +559,126 @@ impl<'a, E: TriplesEmitter> CrateExtractor<'a, E> {
                 self.iris
                     .module_iri(&self.crate_name, &self.crate_version, parent_path);
             self.emitter
-                .emit_iri(&module_iri, tg::PARENT_NAMESPACE, &parent_iri);
+                .emit_iri(&module_iri, tg::PARENT_NAMESPACE, &parent_iri)?;
         }
+
+        self.extract_and_emit_docs(item, &module_iri)?;
+        Ok(())
     }
 
     // -----------------------------------------------------------------------
     // Item dispatch
     // -----------------------------------------------------------------------
 
-    fn walk_item(&mut self, item_id: &str, parent_module_path: &str) {
+    fn walk_item(&mut self, item_id: &str, parent_module_path: &str) -> std::io::Result<()> {
         let item = match self.crate_data.index.get(item_id) {
             Some(i) => i,
-            None => return,
+            None => return Ok(()),
         };
 
+        // Modules are always walked (to reach whatever reachable items they
+        // contain); every other item kind is subject to the visibility
+        // filter.
+        if !matches!(item.inner, ItemEnum::Module { .. })
+            && !self.passes_visibility_filter(item_id, item)
+        {
+            return Ok(());
+        }
+
+        if !matches!(item.inner, ItemEnum::Module { .. } | ItemEnum::Use { .. } | ItemEnum::Impl { .. }) {
+            if let Some(name) = &item.name {
+                self.item_full_paths
+                    .insert(item_id.to_string(), format!("{parent_module_path}::{name}"));
+            }
+        }
+
         match &item.inner {
             ItemEnum::Module { .. } => {
                 let name = item.name.as_deref().unwrap_or("unnamed");
                 let child_path = format!("{parent_module_path}::{name}");
-                self.walk_module(item_id, &child_path);
+                if !self.options.path_filter.allows(&child_path) {
+                    return Ok(());
+                }
+                let ambient = self.ambient_cfg(parent_module_path);
+                let child_cfg = cfg::conjoin(&ambient, self.own_cfg(item));
+                self.module_cfgs.insert(child_path.clone(), child_cfg);
+                self.walk_module(item_id, &child_path)?;
             }
             ItemEnum::Struct { .. } => {
-                self.extract_struct(item_id, item, parent_module_path);
+                self.extract_struct(item_id, item, parent_module_path)?;
             }
             ItemEnum::Enum { .. } => {
-                self.extract_enum(item_id, item, parent_module_path);
+                self.extract_enum(item_id, item, parent_module_path)?;
             }
             ItemEnum::Trait { .. } => {
-                self.extract_trait(item_id, item, parent_module_path);
+                self.extract_trait(item_id, item, parent_module_path)?;
             }
             ItemEnum::Function { .. } => {
-                self.extract_module_function(item_id, item, parent_module_path);
+                self.extract_module_function(item_id, item, parent_module_path)?;
             }
             ItemEnum::Constant { .. } => {
-                self.extract_constant(item, parent_module_path);
+                self.extract_constant(item, parent_module_path)?;
             }
             ItemEnum::Static { .. } => {
-                self.extract_static(item, parent_module_path);
+                self.extract_static(item, parent_module_path)?;
             }
             ItemEnum::TypeAlias { .. } => {
-                self.extract_type_alias(item, parent_module_path);
+                self.extract_type_alias(item, parent_module_path)?;
             }
             ItemEnum::Union { .. } => {
-                self.extract_union(item_id, item, parent_module_path);
+                self.extract_union(item_id, item, parent_module_path)?;
+            }
+            ItemEnum::Use { .. } => {
+                self.extract_use(item, parent_module_path)?;
+            }
+            ItemEnum::ExternCrate { .. } => {
+                self.extract_extern_crate(item, parent_module_path)?;
+            }
+            ItemEnum::TraitAlias { .. } => {
+                self.extract_trait_alias(item, parent_module_path)?;
             }
-            ItemEnum::Use { .. } | ItemEnum::Impl { .. } => {
-                // Impls processed in a separate pass; Use items are skipped.
+            ItemEnum::Macro(_) => {
+                self.extract_macro(item, parent_module_path)?;
+            }
+            ItemEnum::ProcMacro { .. } => {
+                self.extract_proc_macro(item, parent_module_path)?;
+            }
+            ItemEnum::ExternType => {
+                self.extract_extern_type(item, parent_module_path)?;
+            }
+            ItemEnum::Primitive { .. } => {
+                self.extract_primitive(item, parent_module_path)?;
+            }
+            ItemEnum::Keyword => {
+                self.extract_keyword(item, parent_module_path)?;
+            }
+            ItemEnum::Impl { .. } => {
+                // Impls processed in a separate pass.
             }
             _ => {}
         }
+        Ok(())
     }
 
     // -----------------------------------------------------------------------
     // Struct extraction
     // -----------------------------------------------------------------------
 
-    fn extract_struct(&mut self, item_id: &str, item: &Item, module_path: &str) {
+    fn extract_struct(&mut self, item_id: &str, item: &Item, module_path: &str) -> std::io::Result<()> {
         let name = match &item.name {
             Some(n) => n.clone(),
-            None => return,
+            None => return Ok(()),
         };
         let full_path = format!("{module_path}::{name}");
+        if !self.options.path_filter.allows(&full_path) {
+            return Ok(());
+        }
         let type_iri = self
             .iris
             .type_iri(&self.crate_name, &self.crate_version, &full_path);
 
         if !self.emitted_types.insert(type_iri.clone()) {
-            return;
+            return Ok(());
         }
 
         let crate_iri = self.iris.crate_iri(&self.crate_name, &self.crate_version);
@@ -278,17 +687,22 @@ impl<'a, E: TriplesEmitter> CrateExtractor<'a, E> {
             .module_iri(&self.crate_name, &self.crate_version, module_path);
 
         self.emitter
-            .emit_iri(&type_iri, standard::RDF_TYPE, tg::STRUCT);
-        self.emitter.emit_literal(&type_iri, tg::NAME, &name);
+            .emit_iri(&type_iri, standard::RDF_TYPE, tg::STRUCT)?;
+        self.emitter.emit_literal(&type_iri, tg::NAME, &name)?;
         self.emitter
-            .emit_literal(&type_iri, tg::FULL_NAME, &full_path);
+            .emit_literal(&type_iri, tg::FULL_NAME, &full_path)?;
         self.emitter
-            .emit_iri(&type_iri, tg::DEFINED_IN_ASSEMBLY, &crate_iri);
+            .emit_iri(&type_iri, tg::DEFINED_IN_ASSEMBLY, &crate_iri)?;
         self.emitter
-            .emit_iri(&type_iri, tg::IN_NAMESPACE, &module_iri);
+            .emit_iri(&type_iri, tg::IN_NAMESPACE, &module_iri)?;
 
         let vis = visibility_str(&item.visibility);
-        self.emitter.emit_literal(&type_iri, tg::ACCESSIBILITY, vis);
+        self.emitter.emit_literal(&type_iri, tg::ACCESSIBILITY, vis)?;
+        self.extract_and_emit_cfg(item, module_path, &type_iri)?;
+        self.extract_and_emit_span(item, &type_iri)?;
+        self.extract_and_emit_docs(item, &type_iri)?;
+        self.extract_and_emit_stability(item, &type_iri)?;
+        self.type_fields.entry(type_iri.clone()).or_default();
 
         if let ItemEnum::Struct {
             ref kind,
@@ -302,21 +716,23 @@ impl<'a, E: TriplesEmitter> CrateExtractor<'a, E> {
                 .iter()
                 .any(|p| matches!(p.kind, GenericParamDefKind::Type { .. }));
             if has_type_params {
-                self.emitter.emit_bool(&type_iri, tg::IS_GENERIC, true);
+                self.emitter.emit_bool(&type_iri, tg::IS_GENERIC, true)?;
             }
 
-            self.extract_generics(generics, &type_iri);
+            self.extract_generics(generics, &type_iri)?;
+            self.type_generic_params
+                .insert(type_iri.clone(), generics.params.clone());
 
             // Extract fields
             match kind {
                 StructKind::Plain { ref fields, .. } => {
                     for field_id in fields {
-                        self.extract_field(&field_id.0, &type_iri);
+                        self.extract_field(&field_id.0, &type_iri)?;
                     }
                 }
                 StructKind::Tuple(ref field_ids) => {
                     for field_id in field_ids.iter().flatten() {
-                        self.extract_field(&field_id.0, &type_iri);
+                        self.extract_field(&field_id.0, &type_iri)?;
                     }
                 }
                 StructKind::Unit => {}
@@ -325,26 +741,30 @@ impl<'a, E: TriplesEmitter> CrateExtractor<'a, E> {
 
         // Extract derives from impls associated with this struct
         if self.options.extract_derives {
-            self.extract_derives_for_item(item_id, &type_iri);
+            self.extract_derives_for_item(item_id, &type_iri)?;
         }
+        Ok(())
     }
 
     // -----------------------------------------------------------------------
     // Enum extraction
     // -----------------------------------------------------------------------
 
-    fn extract_enum(&mut self, item_id: &str, item: &Item, module_path: &str) {
+    fn extract_enum(&mut self, item_id: &str, item: &Item, module_path: &str) -> std::io::Result<()> {
         let name = match &item.name {
             Some(n) => n.clone(),
-            None => return,
+            None => return Ok(()),
         };
         let full_path = format!("{module_path}::{name}");
+        if !self.options.path_filter.allows(&full_path) {
+            return Ok(());
+        }
         let type_iri = self
             .iris
             .type_iri(&self.crate_name, &self.crate_version, &full_path);
 
         if !self.emitted_types.insert(type_iri.clone()) {
-            return;
+            return Ok(());
         }
 
         let crate_iri = self.iris.crate_iri(&self.crate_name, &self.crate_version);
@@ -353,17 +773,22 @@ impl<'a, E: TriplesEmitter> CrateExtractor<'a, E> {
             .module_iri(&self.crate_name, &self.crate_version, module_path);
 
         self.emitter
-            .emit_iri(&type_iri, standard::RDF_TYPE, tg::ENUM);
-        self.emitter.emit_literal(&type_iri, tg::NAME, &name);
+            .emit_iri(&type_iri, standard::RDF_TYPE, tg::ENUM)?;
+        self.emitter.emit_literal(&type_iri, tg::NAME, &name)?;
         self.emitter
-            .emit_literal(&type_iri, tg::FULL_NAME, &full_path);
+            .emit_literal(&type_iri, tg::FULL_NAME, &full_path)?;
         self.emitter
-            .emit_iri(&type_iri, tg::DEFINED_IN_ASSEMBLY, &crate_iri);
+            .emit_iri(&type_iri, tg::DEFINED_IN_ASSEMBLY, &crate_iri)?;
         self.emitter
-            .emit_iri(&type_iri, tg::IN_NAMESPACE, &module_iri);
+            .emit_iri(&type_iri, tg::IN_NAMESPACE, &module_iri)?;
 
         let vis = visibility_str(&item.visibility);
-        self.emitter.emit_literal(&type_iri, tg::ACCESSIBILITY, vis);
+        self.emitter.emit_literal(&type_iri, tg::ACCESSIBILITY, vis)?;
+        self.extract_and_emit_cfg(item, module_path, &type_iri)?;
+        self.extract_and_emit_span(item, &type_iri)?;
+        self.extract_and_emit_docs(item, &type_iri)?;
+        self.extract_and_emit_stability(item, &type_iri)?;
+        self.type_fields.entry(type_iri.clone()).or_default();
 
         if let ItemEnum::Enum {
             ref generics,
@@ -376,69 +801,75 @@ impl<'a, E: TriplesEmitter> CrateExtractor<'a, E> {
                 .iter()
                 .any(|p| matches!(p.kind, GenericParamDefKind::Type { .. }));
             if has_type_params {
-                self.emitter.emit_bool(&type_iri, tg::IS_GENERIC, true);
+                self.emitter.emit_bool(&type_iri, tg::IS_GENERIC, true)?;
             }
 
-            self.extract_generics(generics, &type_iri);
+            self.extract_generics(generics, &type_iri)?;
+            self.type_generic_params
+                .insert(type_iri.clone(), generics.params.clone());
 
             // Extract variants
             for variant_id in variants {
-                self.extract_variant(&variant_id.0, &type_iri);
+                self.extract_variant(&variant_id.0, &type_iri)?;
             }
         }
 
         if self.options.extract_derives {
-            self.extract_derives_for_item(item_id, &type_iri);
+            self.extract_derives_for_item(item_id, &type_iri)?;
         }
+        Ok(())
     }
 
-    fn extract_variant(&mut self, variant_id: &str, enum_iri: &str) {
+    fn extract_variant(&mut self, variant_id: &str, enum_iri: &str) -> std::io::Result<()> {
         let item = match self.crate_data.index.get(variant_id) {
             Some(i) => i,
-            None => return,
+            None => return Ok(()),
         };
 
         let name = match &item.name {
             Some(n) => n.clone(),
-            None => return,
+            None => return Ok(()),
         };
 
         let variant_iri = self.iris.variant_iri(enum_iri, &name);
 
         self.emitter
-            .emit_iri(&variant_iri, standard::RDF_TYPE, rt::ENUM_VARIANT);
-        self.emitter.emit_literal(&variant_iri, tg::NAME, &name);
+            .emit_iri(&variant_iri, standard::RDF_TYPE, rt::ENUM_VARIANT)?;
+        self.emitter.emit_literal(&variant_iri, tg::NAME, &name)?;
         self.emitter
-            .emit_iri(enum_iri, rt::HAS_VARIANT, &variant_iri);
+            .emit_iri(enum_iri, rt::HAS_VARIANT, &variant_iri)?;
+        self.extract_and_emit_span(item, &variant_iri)?;
+        self.extract_and_emit_docs(item, &variant_iri)?;
 
         if let ItemEnum::Variant(VariantData { ref kind, .. }) = item.inner {
             match kind {
                 VariantKind::Plain => {
                     self.emitter
-                        .emit_literal(&variant_iri, rt::VARIANT_KIND, "plain");
+                        .emit_literal(&variant_iri, rt::VARIANT_KIND, "plain")?;
                 }
                 VariantKind::Tuple(ref field_ids) => {
                     self.emitter
-                        .emit_literal(&variant_iri, rt::VARIANT_KIND, "tuple");
+                        .emit_literal(&variant_iri, rt::VARIANT_KIND, "tuple")?;
                     for field_id in field_ids.iter().flatten() {
-                        self.extract_variant_field(&field_id.0, &variant_iri);
+                        self.extract_variant_field(&field_id.0, &variant_iri, enum_iri)?;
                     }
                 }
                 VariantKind::Struct { ref fields, .. } => {
                     self.emitter
-                        .emit_literal(&variant_iri, rt::VARIANT_KIND, "struct");
+                        .emit_literal(&variant_iri, rt::VARIANT_KIND, "struct")?;
                     for field_id in fields {
-                        self.extract_variant_field(&field_id.0, &variant_iri);
+                        self.extract_variant_field(&field_id.0, &variant_iri, enum_iri)?;
                     }
                 }
             }
         }
+        Ok(())
     }
 
-    fn extract_variant_field(&mut self, field_id: &str, variant_iri: &str) {
+    fn extract_variant_field(&mut self, field_id: &str, variant_iri: &str, enum_iri: &str) -> std::io::Result<()> {
         let item = match self.crate_data.index.get(field_id) {
             Some(i) => i,
-            None => return,
+            None => return Ok(()),
         };
 
         let name = item.name.as_deref().unwrap_or("unnamed");
@@ -446,34 +877,42 @@ impl<'a, E: TriplesEmitter> CrateExtractor<'a, E> {
         if let ItemEnum::StructField(ref ty) = item.inner {
             let field_iri = self.iris.member_iri(variant_iri, name, "");
             self.emitter
-                .emit_iri(&field_iri, standard::RDF_TYPE, tg::FIELD);
-            self.emitter.emit_literal(&field_iri, tg::NAME, name);
+                .emit_iri(&field_iri, standard::RDF_TYPE, tg::FIELD)?;
+            self.emitter.emit_literal(&field_iri, tg::NAME, name)?;
             self.emitter
-                .emit_iri(variant_iri, rt::VARIANT_FIELD, &field_iri);
+                .emit_iri(variant_iri, rt::VARIANT_FIELD, &field_iri)?;
+            self.extract_and_emit_span(item, &field_iri)?;
+            self.extract_and_emit_docs(item, &field_iri)?;
+            self.extract_and_emit_stability(item, &field_iri)?;
+            self.record_field_type(enum_iri, ty);
 
-            if let Some(type_iri) = self.resolve_type_to_iri(ty) {
+            if let Some(type_iri) = self.resolve_type_to_iri(ty)? {
                 self.emitter
-                    .emit_iri(&field_iri, tg::FIELD_TYPE, &type_iri);
+                    .emit_iri(&field_iri, tg::FIELD_TYPE, &type_iri)?;
             }
         }
+        Ok(())
     }
 
     // -----------------------------------------------------------------------
     // Trait extraction
     // -----------------------------------------------------------------------
 
-    fn extract_trait(&mut self, _item_id: &str, item: &Item, module_path: &str) {
+    fn extract_trait(&mut self, _item_id: &str, item: &Item, module_path: &str) -> std::io::Result<()> {
         let name = match &item.name {
             Some(n) => n.clone(),
-            None => return,
+            None => return Ok(()),
         };
         let full_path = format!("{module_path}::{name}");
+        if !self.options.path_filter.allows(&full_path) {
+            return Ok(());
+        }
         let type_iri = self
             .iris
             .type_iri(&self.crate_name, &self.crate_version, &full_path);
 
         if !self.emitted_types.insert(type_iri.clone()) {
-            return;
+            return Ok(());
         }
 
         let crate_iri = self.iris.crate_iri(&self.crate_name, &self.crate_version);
@@ -482,19 +921,23 @@ impl<'a, E: TriplesEmitter> CrateExtractor<'a, E> {
             .module_iri(&self.crate_name, &self.crate_version, module_path);
 
         self.emitter
-            .emit_iri(&type_iri, standard::RDF_TYPE, tg::INTERFACE);
+            .emit_iri(&type_iri, standard::RDF_TYPE, tg::INTERFACE)?;
         self.emitter
-            .emit_iri(&type_iri, standard::RDF_TYPE, rt::TRAIT);
-        self.emitter.emit_literal(&type_iri, tg::NAME, &name);
+            .emit_iri(&type_iri, standard::RDF_TYPE, rt::TRAIT)?;
+        self.emitter.emit_literal(&type_iri, tg::NAME, &name)?;
         self.emitter
-            .emit_literal(&type_iri, tg::FULL_NAME, &full_path);
+            .emit_literal(&type_iri, tg::FULL_NAME, &full_path)?;
         self.emitter
-            .emit_iri(&type_iri, tg::DEFINED_IN_ASSEMBLY, &crate_iri);
+            .emit_iri(&type_iri, tg::DEFINED_IN_ASSEMBLY, &crate_iri)?;
         self.emitter
-            .emit_iri(&type_iri, tg::IN_NAMESPACE, &module_iri);
+            .emit_iri(&type_iri, tg::IN_NAMESPACE, &module_iri)?;
 
         let vis = visibility_str(&item.visibility);
-        self.emitter.emit_literal(&type_iri, tg::ACCESSIBILITY, vis);
+        self.emitter.emit_literal(&type_iri, tg::ACCESSIBILITY, vis)?;
+        self.extract_and_emit_cfg(item, module_path, &type_iri)?;
+        self.extract_and_emit_span(item, &type_iri)?;
+        self.extract_and_emit_docs(item, &type_iri)?;
+        self.extract_and_emit_stability(item, &type_iri)?;
 
         if let ItemEnum::Trait {
             ref generics,
@@ -505,7 +948,7 @@ impl<'a, E: TriplesEmitter> CrateExtractor<'a, E> {
         } = item.inner
         {
             if is_unsafe {
-                self.emitter.emit_bool(&type_iri, rt::IS_UNSAFE, true);
+                self.emitter.emit_bool(&type_iri, rt::IS_UNSAFE, true)?;
             }
 
             let has_type_params = generics
@@ -513,10 +956,10 @@ impl<'a, E: TriplesEmitter> CrateExtractor<'a, E> {
                 .iter()
                 .any(|p| matches!(p.kind, GenericParamDefKind::Type { .. }));
             if has_type_params {
-                self.emitter.emit_bool(&type_iri, tg::IS_GENERIC, true);
+                self.emitter.emit_bool(&type_iri, tg::IS_GENERIC, true)?;
             }
 
-            self.extract_generics(generics, &type_iri);
+            self.extract_generics(generics, &type_iri)?;
 
             // Supertraits
             for bound in bounds {
@@ -524,31 +967,137 @@ impl<'a, E: TriplesEmitter> CrateExtractor<'a, E> {
                     let supertrait_name = &trait_.path;
                     let supertrait_iri = self.resolve_path_to_iri(trait_);
                     self.emitter
-                        .emit_iri(&type_iri, rt::SUPER_TRAIT, &supertrait_iri);
+                        .emit_iri(&type_iri, rt::SUPER_TRAIT, &supertrait_iri)?;
                     // Ensure the supertrait node exists minimally
-                    self.ensure_external_type_emitted(&supertrait_iri, supertrait_name);
+                    self.ensure_external_type_emitted(&supertrait_iri, supertrait_name)?;
                 }
             }
 
             // Trait methods
             let method_ids: Vec<String> = items.iter().map(|id| id.0.clone()).collect();
             for method_id in &method_ids {
-                self.extract_type_method(method_id, &type_iri);
+                self.extract_type_method(method_id, &type_iri)?;
+            }
+
+            if self.options.analyze_object_safety {
+                self.analyze_object_safety(&type_iri, &method_ids)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Compute and emit object-safety for a trait: `rt:objectSafe` plus one
+    /// `rt:objectSafetyViolation` literal per offending method. Implements
+    /// the standard rules -- no `self` receiver, returning `Self`, taking
+    /// `Self` by value outside the receiver, or a generic method -- and
+    /// excludes any method with a `where Self: Sized` bound from the check
+    /// entirely (marking it `rt:excludedFromObject` instead of letting it
+    /// make the whole trait unsafe).
+    fn analyze_object_safety(&mut self, trait_iri: &str, method_ids: &[String]) -> std::io::Result<()> {
+        let mut violations: Vec<String> = Vec::new();
+
+        for method_id in method_ids {
+            let Some(item) = self.crate_data.index.get(method_id) else {
+                continue;
+            };
+            let Some(ref name) = item.name else {
+                continue;
+            };
+            let ItemEnum::Function {
+                ref sig,
+                ref generics,
+                ..
+            } = item.inner
+            else {
+                continue;
+            };
+
+            let method_iri = self.iris.member_iri(trait_iri, name, "");
+
+            if Self::requires_self_sized(generics) {
+                self.emitter
+                    .emit_bool(&method_iri, rt::EXCLUDED_FROM_OBJECT, true)?;
+                continue;
+            }
+
+            let has_self_receiver = sig
+                .inputs
+                .first()
+                .is_some_and(|(param_name, _)| param_name == "self");
+            if !has_self_receiver {
+                violations.push(format!("{name}: no `self` receiver (associated function)"));
+            }
+
+            if sig
+                .output
+                .as_ref()
+                .is_some_and(|ty| matches!(ty, Type::Generic(name) if name == "Self"))
+            {
+                violations.push(format!("{name}: returns `Self`"));
+            }
+
+            let takes_self_by_value = sig
+                .inputs
+                .iter()
+                .skip(1)
+                .any(|(_, ty)| matches!(ty, Type::Generic(name) if name == "Self"));
+            if takes_self_by_value {
+                violations.push(format!(
+                    "{name}: takes `Self` by value outside the receiver position"
+                ));
+            }
+
+            let has_generic_params = generics
+                .params
+                .iter()
+                .any(|p| matches!(p.kind, GenericParamDefKind::Type { .. }));
+            if has_generic_params {
+                violations.push(format!("{name}: has generic type parameters"));
             }
         }
+
+        self.emitter
+            .emit_bool(trait_iri, rt::OBJECT_SAFE, violations.is_empty())?;
+        for violation in &violations {
+            self.emitter
+                .emit_literal(trait_iri, rt::OBJECT_SAFETY_VIOLATION, violation)?;
+        }
+        Ok(())
+    }
+
+    /// Whether a method's own generics carry a `where Self: Sized` bound,
+    /// which excludes it from object-safety consideration entirely.
+    fn requires_self_sized(generics: &Generics) -> bool {
+        generics.where_predicates.iter().any(|predicate| {
+            let WherePredicate::BoundPredicate {
+                ref type_,
+                ref bounds,
+                ..
+            } = predicate
+            else {
+                return false;
+            };
+            matches!(type_, Type::Generic(name) if name == "Self")
+                && bounds.iter().any(|bound| {
+                    matches!(bound, GenericBound::TraitBound { ref trait_, .. } if trait_.path == "Sized")
+                })
+        })
     }
 
     // -----------------------------------------------------------------------
     // Function extraction (module-level)
     // -----------------------------------------------------------------------
 
-    fn extract_module_function(&mut self, _item_id: &str, item: &Item, module_path: &str) {
+    fn extract_module_function(&mut self, _item_id: &str, item: &Item, module_path: &str) -> std::io::Result<()> {
         let name = match &item.name {
             Some(n) => n.clone(),
-            None => return,
+            None => return Ok(()),
         };
 
         let full_path = format!("{module_path}::{name}");
+        if !self.options.path_filter.allows(&full_path) {
+            return Ok(());
+        }
         // Module-level functions: use module_iri as the "owner".
         let module_iri =
             self.iris
@@ -556,17 +1105,21 @@ impl<'a, E: TriplesEmitter> CrateExtractor<'a, E> {
         let fn_iri = self.iris.member_iri(&module_iri, &name, "");
 
         self.emitter
-            .emit_iri(&fn_iri, standard::RDF_TYPE, tg::METHOD);
-        self.emitter.emit_literal(&fn_iri, tg::NAME, &name);
+            .emit_iri(&fn_iri, standard::RDF_TYPE, tg::METHOD)?;
+        self.emitter.emit_literal(&fn_iri, tg::NAME, &name)?;
         self.emitter
-            .emit_literal(&fn_iri, tg::FULL_NAME, &full_path);
+            .emit_literal(&fn_iri, tg::FULL_NAME, &full_path)?;
         self.emitter
-            .emit_iri(&module_iri, tg::HAS_MEMBER, &fn_iri);
+            .emit_iri(&module_iri, tg::HAS_MEMBER, &fn_iri)?;
         self.emitter
-            .emit_iri(&fn_iri, tg::MEMBER_OF, &module_iri);
+            .emit_iri(&fn_iri, tg::MEMBER_OF, &module_iri)?;
 
         let vis = visibility_str(&item.visibility);
-        self.emitter.emit_literal(&fn_iri, tg::ACCESSIBILITY, vis);
+        self.emitter.emit_literal(&fn_iri, tg::ACCESSIBILITY, vis)?;
+        self.extract_and_emit_cfg(item, module_path, &fn_iri)?;
+        self.extract_and_emit_span(item, &fn_iri)?;
+        self.extract_and_emit_docs(item, &fn_iri)?;
+        self.extract_and_emit_stability(item, &fn_iri)?;
 
         if let ItemEnum::Function {
             ref sig,
@@ -575,73 +1128,79 @@ impl<'a, E: TriplesEmitter> CrateExtractor<'a, E> {
             ..
         } = item.inner
         {
-            self.extract_function_details(&fn_iri, sig, generics, header);
+            self.extract_function_details(&fn_iri, sig, generics, header)?;
 
             // Error type extraction for Result return types
             if self.options.extract_error_types {
-                self.extract_error_type(&fn_iri, sig);
+                self.extract_error_type(&fn_iri, sig)?;
             }
         }
+        Ok(())
     }
 
     // -----------------------------------------------------------------------
     // Type method extraction (trait methods, impl methods)
     // -----------------------------------------------------------------------
 
-    fn extract_type_method(&mut self, method_id: &str, owner_iri: &str) {
+    fn extract_type_method(&mut self, method_id: &str, owner_iri: &str) -> std::io::Result<()> {
         let item = match self.crate_data.index.get(method_id) {
             Some(i) => i,
-            None => return,
+            None => return Ok(()),
         };
 
         let name = match &item.name {
             Some(n) => n.clone(),
-            None => return,
+            None => return Ok(()),
         };
 
         let method_iri = self.iris.member_iri(owner_iri, &name, "");
 
         self.emitter
-            .emit_iri(&method_iri, standard::RDF_TYPE, tg::METHOD);
-        self.emitter.emit_literal(&method_iri, tg::NAME, &name);
+            .emit_iri(&method_iri, standard::RDF_TYPE, tg::METHOD)?;
+        self.emitter.emit_literal(&method_iri, tg::NAME, &name)?;
         self.emitter
-            .emit_iri(owner_iri, tg::HAS_MEMBER, &method_iri);
+            .emit_iri(owner_iri, tg::HAS_MEMBER, &method_iri)?;
         self.emitter
-            .emit_iri(&method_iri, tg::MEMBER_OF, owner_iri);
+            .emit_iri(&method_iri, tg::MEMBER_OF, owner_iri)?;
 
         let vis = visibility_str(&item.visibility);
         self.emitter
-            .emit_literal(&method_iri, tg::ACCESSIBILITY, vis);
+            .emit_literal(&method_iri, tg::ACCESSIBILITY, vis)?;
+        self.extract_and_emit_span(item, &method_iri)?;
+        self.extract_and_emit_docs(item, &method_iri)?;
+        self.extract_and_emit_stability(item, &method_iri)?;
 
         if let ItemEnum::Function {
             ref sig,
             ref generics,
             ref header,
             has_body,
+            ..
         } = item.inner
         {
-            self.extract_function_details(&method_iri, sig, generics, header);
+            self.extract_function_details(&method_iri, sig, generics, header)?;
 
             // For trait methods: has_body means "provided" (default impl)
             if !has_body {
                 self.emitter
-                    .emit_bool(&method_iri, tg::IS_ABSTRACT, true);
+                    .emit_bool(&method_iri, tg::IS_ABSTRACT, true)?;
             }
 
             if self.options.extract_error_types {
-                self.extract_error_type(&method_iri, sig);
+                self.extract_error_type(&method_iri, sig)?;
             }
         }
+        Ok(())
     }
 
     // -----------------------------------------------------------------------
     // Constant extraction
     // -----------------------------------------------------------------------
 
-    fn extract_constant(&mut self, item: &Item, module_path: &str) {
+    fn extract_constant(&mut self, item: &Item, module_path: &str) -> std::io::Result<()> {
         let name = match &item.name {
             Some(n) => n.clone(),
-            None => return,
+            None => return Ok(()),
         };
 
         let module_iri =
@@ -650,36 +1209,54 @@ impl<'a, E: TriplesEmitter> CrateExtractor<'a, E> {
         let const_iri = self.iris.member_iri(&module_iri, &name, "");
 
         self.emitter
-            .emit_iri(&const_iri, standard::RDF_TYPE, tg::FIELD);
+            .emit_iri(&const_iri, standard::RDF_TYPE, tg::FIELD)?;
         self.emitter
-            .emit_iri(&const_iri, standard::RDF_TYPE, rt::CONSTANT);
-        self.emitter.emit_literal(&const_iri, tg::NAME, &name);
-        self.emitter.emit_bool(&const_iri, tg::IS_CONST, true);
+            .emit_iri(&const_iri, standard::RDF_TYPE, rt::CONSTANT)?;
+        self.emitter.emit_literal(&const_iri, tg::NAME, &name)?;
+        self.emitter.emit_bool(&const_iri, tg::IS_CONST, true)?;
         self.emitter
-            .emit_iri(&module_iri, tg::HAS_MEMBER, &const_iri);
+            .emit_iri(&module_iri, tg::HAS_MEMBER, &const_iri)?;
         self.emitter
-            .emit_iri(&const_iri, tg::MEMBER_OF, &module_iri);
+            .emit_iri(&const_iri, tg::MEMBER_OF, &module_iri)?;
 
         let vis = visibility_str(&item.visibility);
         self.emitter
-            .emit_literal(&const_iri, tg::ACCESSIBILITY, vis);
+            .emit_literal(&const_iri, tg::ACCESSIBILITY, vis)?;
+        self.extract_and_emit_cfg(item, module_path, &const_iri)?;
+        self.extract_and_emit_span(item, &const_iri)?;
+        self.extract_and_emit_docs(item, &const_iri)?;
+        self.extract_and_emit_stability(item, &const_iri)?;
 
-        if let ItemEnum::Constant { ref type_, .. } = item.inner {
-            if let Some(type_iri) = self.resolve_type_to_iri(type_) {
+        if let ItemEnum::Constant {
+            ref type_,
+            ref const_,
+        } = item.inner
+        {
+            if let Some(type_iri) = self.resolve_type_to_iri(type_)? {
                 self.emitter
-                    .emit_iri(&const_iri, tg::FIELD_TYPE, &type_iri);
+                    .emit_iri(&const_iri, tg::FIELD_TYPE, &type_iri)?;
+            }
+            if let Some(ref const_expr) = const_ {
+                if let Some(ref expr) = const_expr.expr {
+                    self.emitter.emit_literal(&const_iri, tg::CONST_EXPR, expr)?;
+                }
+                if let Some(ref value) = const_expr.value {
+                    self.emitter
+                        .emit_literal(&const_iri, tg::CONST_VALUE, value)?;
+                }
             }
         }
+        Ok(())
     }
 
     // -----------------------------------------------------------------------
     // Static extraction
     // -----------------------------------------------------------------------
 
-    fn extract_static(&mut self, item: &Item, module_path: &str) {
+    fn extract_static(&mut self, item: &Item, module_path: &str) -> std::io::Result<()> {
         let name = match &item.name {
             Some(n) => n.clone(),
-            None => return,
+            None => return Ok(()),
         };
 
         let module_iri =
@@ -688,50 +1265,63 @@ impl<'a, E: TriplesEmitter> CrateExtractor<'a, E> {
         let static_iri = self.iris.member_iri(&module_iri, &name, "");
 
         self.emitter
-            .emit_iri(&static_iri, standard::RDF_TYPE, rt::STATIC);
-        self.emitter.emit_literal(&static_iri, tg::NAME, &name);
+            .emit_iri(&static_iri, standard::RDF_TYPE, rt::STATIC)?;
+        self.emitter.emit_literal(&static_iri, tg::NAME, &name)?;
         self.emitter
-            .emit_iri(&module_iri, tg::HAS_MEMBER, &static_iri);
+            .emit_iri(&module_iri, tg::HAS_MEMBER, &static_iri)?;
         self.emitter
-            .emit_iri(&static_iri, tg::MEMBER_OF, &module_iri);
+            .emit_iri(&static_iri, tg::MEMBER_OF, &module_iri)?;
 
         let vis = visibility_str(&item.visibility);
         self.emitter
-            .emit_literal(&static_iri, tg::ACCESSIBILITY, vis);
+            .emit_literal(&static_iri, tg::ACCESSIBILITY, vis)?;
+        self.extract_and_emit_cfg(item, module_path, &static_iri)?;
+        self.extract_and_emit_span(item, &static_iri)?;
+        self.extract_and_emit_docs(item, &static_iri)?;
+        self.extract_and_emit_stability(item, &static_iri)?;
 
         if let ItemEnum::Static {
             ref type_,
             is_mutable,
+            ref expr,
             ..
         } = item.inner
         {
             if is_mutable {
-                self.emitter.emit_bool(&static_iri, rt::IS_MUTABLE, true);
+                self.emitter.emit_bool(&static_iri, rt::IS_MUTABLE, true)?;
             }
-            if let Some(type_iri) = self.resolve_type_to_iri(type_) {
+            if let Some(type_iri) = self.resolve_type_to_iri(type_)? {
                 self.emitter
-                    .emit_iri(&static_iri, tg::FIELD_TYPE, &type_iri);
+                    .emit_iri(&static_iri, tg::FIELD_TYPE, &type_iri)?;
+            }
+            if let Some(ref expr) = expr {
+                self.emitter
+                    .emit_literal(&static_iri, tg::CONST_EXPR, expr)?;
             }
         }
+        Ok(())
     }
 
     // -----------------------------------------------------------------------
     // TypeAlias extraction
     // -----------------------------------------------------------------------
 
-    fn extract_type_alias(&mut self, item: &Item, module_path: &str) {
+    fn extract_type_alias(&mut self, item: &Item, module_path: &str) -> std::io::Result<()> {
         let name = match &item.name {
             Some(n) => n.clone(),
-            None => return,
+            None => return Ok(()),
         };
 
         let full_path = format!("{module_path}::{name}");
+        if !self.options.path_filter.allows(&full_path) {
+            return Ok(());
+        }
         let type_iri = self
             .iris
             .type_iri(&self.crate_name, &self.crate_version, &full_path);
 
         if !self.emitted_types.insert(type_iri.clone()) {
-            return;
+            return Ok(());
         }
 
         let crate_iri = self.iris.crate_iri(&self.crate_name, &self.crate_version);
@@ -740,46 +1330,53 @@ impl<'a, E: TriplesEmitter> CrateExtractor<'a, E> {
                 .module_iri(&self.crate_name, &self.crate_version, module_path);
 
         self.emitter
-            .emit_iri(&type_iri, standard::RDF_TYPE, rt::TYPE_ALIAS);
-        self.emitter.emit_literal(&type_iri, tg::NAME, &name);
+            .emit_iri(&type_iri, standard::RDF_TYPE, rt::TYPE_ALIAS)?;
+        self.emitter.emit_literal(&type_iri, tg::NAME, &name)?;
         self.emitter
-            .emit_literal(&type_iri, tg::FULL_NAME, &full_path);
+            .emit_literal(&type_iri, tg::FULL_NAME, &full_path)?;
         self.emitter
-            .emit_iri(&type_iri, tg::DEFINED_IN_ASSEMBLY, &crate_iri);
+            .emit_iri(&type_iri, tg::DEFINED_IN_ASSEMBLY, &crate_iri)?;
         self.emitter
-            .emit_iri(&type_iri, tg::IN_NAMESPACE, &module_iri);
+            .emit_iri(&type_iri, tg::IN_NAMESPACE, &module_iri)?;
 
         let vis = visibility_str(&item.visibility);
-        self.emitter.emit_literal(&type_iri, tg::ACCESSIBILITY, vis);
+        self.emitter.emit_literal(&type_iri, tg::ACCESSIBILITY, vis)?;
+        self.extract_and_emit_cfg(item, module_path, &type_iri)?;
+        self.extract_and_emit_span(item, &type_iri)?;
+        self.extract_and_emit_docs(item, &type_iri)?;
 
         if let ItemEnum::TypeAlias {
             type_: Some(ref target_type),
             ..
         } = item.inner
         {
-            if let Some(target_iri) = self.resolve_type_to_iri(target_type) {
+            if let Some(target_iri) = self.resolve_type_to_iri(target_type)? {
                 self.emitter
-                    .emit_iri(&type_iri, tg::RELATED_TO, &target_iri);
+                    .emit_iri(&type_iri, tg::RELATED_TO, &target_iri)?;
             }
         }
+        Ok(())
     }
 
     // -----------------------------------------------------------------------
     // Union extraction
     // -----------------------------------------------------------------------
 
-    fn extract_union(&mut self, _item_id: &str, item: &Item, module_path: &str) {
+    fn extract_union(&mut self, _item_id: &str, item: &Item, module_path: &str) -> std::io::Result<()> {
         let name = match &item.name {
             Some(n) => n.clone(),
-            None => return,
+            None => return Ok(()),
         };
         let full_path = format!("{module_path}::{name}");
+        if !self.options.path_filter.allows(&full_path) {
+            return Ok(());
+        }
         let type_iri = self
             .iris
             .type_iri(&self.crate_name, &self.crate_version, &full_path);
 
         if !self.emitted_types.insert(type_iri.clone()) {
-            return;
+            return Ok(());
         }
 
         let crate_iri = self.iris.crate_iri(&self.crate_name, &self.crate_version);
@@ -788,17 +1385,21 @@ impl<'a, E: TriplesEmitter> CrateExtractor<'a, E> {
                 .module_iri(&self.crate_name, &self.crate_version, module_path);
 
         self.emitter
-            .emit_iri(&type_iri, standard::RDF_TYPE, rt::UNION);
-        self.emitter.emit_literal(&type_iri, tg::NAME, &name);
+            .emit_iri(&type_iri, standard::RDF_TYPE, rt::UNION)?;
+        self.emitter.emit_literal(&type_iri, tg::NAME, &name)?;
         self.emitter
-            .emit_literal(&type_iri, tg::FULL_NAME, &full_path);
+            .emit_literal(&type_iri, tg::FULL_NAME, &full_path)?;
         self.emitter
-            .emit_iri(&type_iri, tg::DEFINED_IN_ASSEMBLY, &crate_iri);
+            .emit_iri(&type_iri, tg::DEFINED_IN_ASSEMBLY, &crate_iri)?;
         self.emitter
-            .emit_iri(&type_iri, tg::IN_NAMESPACE, &module_iri);
+            .emit_iri(&type_iri, tg::IN_NAMESPACE, &module_iri)?;
 
         let vis = visibility_str(&item.visibility);
-        self.emitter.emit_literal(&type_iri, tg::ACCESSIBILITY, vis);
+        self.emitter.emit_literal(&type_iri, tg::ACCESSIBILITY, vis)?;
+        self.extract_and_emit_cfg(item, module_path, &type_iri)?;
+        self.extract_and_emit_span(item, &type_iri)?;
+        self.extract_and_emit_docs(item, &type_iri)?;
+        self.type_fields.entry(type_iri.clone()).or_default();
 
         if let ItemEnum::Union {
             ref generics,
@@ -806,458 +1407,2639 @@ impl<'a, E: TriplesEmitter> CrateExtractor<'a, E> {
             ..
         } = item.inner
         {
-            self.extract_generics(generics, &type_iri);
+            self.extract_generics(generics, &type_iri)?;
+            self.type_generic_params
+                .insert(type_iri.clone(), generics.params.clone());
 
             for field_id in fields {
-                self.extract_field(&field_id.0, &type_iri);
+                self.extract_field(&field_id.0, &type_iri)?;
             }
         }
+        Ok(())
     }
 
     // -----------------------------------------------------------------------
-    // Field extraction
+    // Trait alias extraction
     // -----------------------------------------------------------------------
 
-    fn extract_field(&mut self, field_id: &str, owner_iri: &str) {
-        let item = match self.crate_data.index.get(field_id) {
-            Some(i) => i,
-            None => return,
+    fn extract_trait_alias(&mut self, item: &Item, module_path: &str) -> std::io::Result<()> {
+        let name = match &item.name {
+            Some(n) => n.clone(),
+            None => return Ok(()),
         };
+        let full_path = format!("{module_path}::{name}");
+        if !self.options.path_filter.allows(&full_path) {
+            return Ok(());
+        }
+        let type_iri = self
+            .iris
+            .type_iri(&self.crate_name, &self.crate_version, &full_path);
 
-        let name = item.name.as_deref().unwrap_or("unnamed");
+        if !self.emitted_types.insert(type_iri.clone()) {
+            return Ok(());
+        }
 
-        if let ItemEnum::StructField(ref ty) = item.inner {
-            let field_iri = self.iris.member_iri(owner_iri, name, "");
+        let crate_iri = self.iris.crate_iri(&self.crate_name, &self.crate_version);
+        let module_iri =
+            self.iris
+                .module_iri(&self.crate_name, &self.crate_version, module_path);
 
-            self.emitter
-                .emit_iri(&field_iri, standard::RDF_TYPE, tg::FIELD);
-            self.emitter.emit_literal(&field_iri, tg::NAME, name);
-            self.emitter
-                .emit_iri(owner_iri, tg::HAS_MEMBER, &field_iri);
-            self.emitter
-                .emit_iri(&field_iri, tg::MEMBER_OF, owner_iri);
+        self.emitter
+            .emit_iri(&type_iri, standard::RDF_TYPE, rt::TRAIT_ALIAS)?;
+        self.emitter.emit_literal(&type_iri, tg::NAME, &name)?;
+        self.emitter
+            .emit_literal(&type_iri, tg::FULL_NAME, &full_path)?;
+        self.emitter
+            .emit_iri(&type_iri, tg::DEFINED_IN_ASSEMBLY, &crate_iri)?;
+        self.emitter
+            .emit_iri(&type_iri, tg::IN_NAMESPACE, &module_iri)?;
 
-            let vis = visibility_str(&item.visibility);
-            self.emitter
-                .emit_literal(&field_iri, tg::ACCESSIBILITY, vis);
+        let vis = visibility_str(&item.visibility);
+        self.emitter.emit_literal(&type_iri, tg::ACCESSIBILITY, vis)?;
+        self.extract_and_emit_cfg(item, module_path, &type_iri)?;
+        self.extract_and_emit_span(item, &type_iri)?;
+        self.extract_and_emit_docs(item, &type_iri)?;
 
-            if let Some(type_iri) = self.resolve_type_to_iri(ty) {
-                self.emitter
-                    .emit_iri(&field_iri, tg::FIELD_TYPE, &type_iri);
+        if let ItemEnum::TraitAlias {
+            ref generics,
+            ref params,
+        } = item.inner
+        {
+            self.extract_generics(generics, &type_iri)?;
+            for bound in params {
+                if let GenericBound::TraitBound { ref trait_, .. } = bound {
+                    self.extract_trait_bound(&type_iri, trait_)?;
+                }
             }
         }
+        Ok(())
     }
 
     // -----------------------------------------------------------------------
-    // Impl block processing
+    // Extern crate declarations (`extern crate foo as bar;`)
     // -----------------------------------------------------------------------
 
-    fn process_all_impls(&mut self) {
-        // Collect all impl item IDs first to avoid borrow issues
-        let impl_ids: Vec<String> = self
-            .crate_data
-            .index
-            .iter()
-            .filter(|(_, item)| matches!(item.inner, ItemEnum::Impl { .. }))
-            .map(|(id, _)| id.clone())
-            .collect();
+    fn extract_extern_crate(&mut self, item: &Item, module_path: &str) -> std::io::Result<()> {
+        let local_name = match &item.name {
+            Some(n) => n.clone(),
+            None => return Ok(()),
+        };
+        let full_path = format!("{module_path}::{local_name}");
+        if !self.options.path_filter.allows(&full_path) {
+            return Ok(());
+        }
+        let type_iri = self
+            .iris
+            .type_iri(&self.crate_name, &self.crate_version, &full_path);
 
-        for impl_id in &impl_ids {
-            self.process_impl(impl_id);
+        if !self.emitted_types.insert(type_iri.clone()) {
+            return Ok(());
         }
-    }
 
-    fn process_impl(&mut self, impl_id: &str) {
-        let item = match self.crate_data.index.get(impl_id) {
-            Some(i) => i,
-            None => return,
-        };
+        let crate_iri = self.iris.crate_iri(&self.crate_name, &self.crate_version);
+        let module_iri =
+            self.iris
+                .module_iri(&self.crate_name, &self.crate_version, module_path);
 
-        if let ItemEnum::Impl {
-            ref trait_,
-            ref for_,
-            ref items,
-            is_synthetic,
-            ref blanket_impl,
-            ..
-        } = item.inner
-        {
-            // Skip synthetic impls (auto traits like Send/Sync) and blanket impls
-            if is_synthetic || blanket_impl.is_some() {
-                return;
+        self.emitter
+            .emit_iri(&type_iri, standard::RDF_TYPE, rt::EXTERN_CRATE)?;
+        self.emitter.emit_literal(&type_iri, tg::NAME, &local_name)?;
+        self.emitter
+            .emit_iri(&type_iri, tg::DEFINED_IN_ASSEMBLY, &crate_iri)?;
+        self.emitter
+            .emit_iri(&type_iri, tg::IN_NAMESPACE, &module_iri)?;
+        self.extract_and_emit_span(item, &type_iri)?;
+        self.extract_and_emit_docs(item, &type_iri)?;
+
+        if let ItemEnum::ExternCrate { ref name, .. } = item.inner {
+            if let Some(dep) = self.crate_data.external_crates.values().find(|c| &c.name == name) {
+                let dep_iri = self.iris.crate_iri(&dep.name, "0.0.0");
+                self.emitter.emit_iri(&type_iri, rt::DEPENDS_ON, &dep_iri)?;
+                self.emitter
+                    .emit_iri(&dep_iri, standard::RDF_TYPE, rt::CRATE)?;
+                self.emitter.emit_literal(&dep_iri, tg::NAME, &dep.name)?;
             }
+        }
+        Ok(())
+    }
 
-            // Resolve the "for" type to an IRI
-            let for_iri = match self.resolve_type_to_iri(for_) {
-                Some(iri) => iri,
-                None => return,
-            };
-
-            // Only process impls for types defined in this crate
-            if !self.emitted_types.contains(&for_iri) {
-                return;
-            }
+    // -----------------------------------------------------------------------
+    // Function-like macro extraction (`macro_rules!` and `macro`)
+    // -----------------------------------------------------------------------
 
-            let impl_iri =
-                self.iris
-                    .impl_iri(&self.crate_name, &self.crate_version, impl_id);
+    fn extract_macro(&mut self, item: &Item, module_path: &str) -> std::io::Result<()> {
+        let name = match &item.name {
+            Some(n) => n.clone(),
+            None => return Ok(()),
+        };
+        let full_path = format!("{module_path}::{name}");
+        if !self.options.path_filter.allows(&full_path) {
+            return Ok(());
+        }
+        let type_iri = self
+            .iris
+            .type_iri(&self.crate_name, &self.crate_version, &full_path);
 
-            if let Some(ref trait_path) = trait_ {
-                // Trait impl
-                let trait_iri = self.resolve_path_to_iri(trait_path);
+        if !self.emitted_types.insert(type_iri.clone()) {
+            return Ok(());
+        }
 
-                self.emitter
-                    .emit_iri(&impl_iri, standard::RDF_TYPE, rt::TRAIT_IMPL);
-                self.emitter.emit_iri(&impl_iri, rt::IMPL_FOR, &for_iri);
-                self.emitter
-                    .emit_iri(&impl_iri, rt::IMPL_TRAIT, &trait_iri);
-                self.emitter
-                    .emit_iri(&for_iri, tg::IMPLEMENTS, &trait_iri);
+        let crate_iri = self.iris.crate_iri(&self.crate_name, &self.crate_version);
+        let module_iri =
+            self.iris
+                .module_iri(&self.crate_name, &self.crate_version, module_path);
 
-                // Ensure trait node exists
-                self.ensure_external_type_emitted(&trait_iri, &trait_path.path);
-            } else {
-                // Inherent impl — add methods directly as members of the type
-                self.emitter
-                    .emit_iri(&impl_iri, standard::RDF_TYPE, rt::INHERENT_IMPL);
-                self.emitter.emit_iri(&impl_iri, rt::IMPL_FOR, &for_iri);
-            }
+        self.emitter
+            .emit_iri(&type_iri, standard::RDF_TYPE, rt::MACRO)?;
+        self.emitter.emit_literal(&type_iri, tg::NAME, &name)?;
+        self.emitter
+            .emit_literal(&type_iri, tg::FULL_NAME, &full_path)?;
+        self.emitter
+            .emit_iri(&type_iri, tg::DEFINED_IN_ASSEMBLY, &crate_iri)?;
+        self.emitter
+            .emit_iri(&type_iri, tg::IN_NAMESPACE, &module_iri)?;
 
-            // Process impl items (methods, associated types, etc.)
-            let method_ids: Vec<String> = items.iter().map(|id| id.0.clone()).collect();
-            for method_id in &method_ids {
-                // For inherent impls, methods belong to the type directly.
-                // For trait impls, methods belong to the impl node.
-                let owner_iri = if trait_.is_none() {
-                    for_iri.clone()
-                } else {
-                    impl_iri.clone()
-                };
-                self.extract_impl_item(method_id, &owner_iri);
-            }
-        }
+        let vis = visibility_str(&item.visibility);
+        self.emitter.emit_literal(&type_iri, tg::ACCESSIBILITY, vis)?;
+        self.extract_and_emit_cfg(item, module_path, &type_iri)?;
+        self.extract_and_emit_span(item, &type_iri)?;
+        self.extract_and_emit_docs(item, &type_iri)?;
+        Ok(())
     }
 
-    fn extract_impl_item(&mut self, item_id: &str, owner_iri: &str) {
-        let item = match self.crate_data.index.get(item_id) {
-            Some(i) => i,
-            None => return,
-        };
+    // -----------------------------------------------------------------------
+    // Procedural macro extraction (`#[proc_macro]`, `#[proc_macro_attribute]`,
+    // `#[proc_macro_derive]`)
+    // -----------------------------------------------------------------------
+
+    fn extract_proc_macro(&mut self, item: &Item, module_path: &str) -> std::io::Result<()> {
+        let name = match &item.name {
+            Some(n) => n.clone(),
+            None => return Ok(()),
+        };
+        let full_path = format!("{module_path}::{name}");
+        if !self.options.path_filter.allows(&full_path) {
+            return Ok(());
+        }
+        let type_iri = self
+            .iris
+            .type_iri(&self.crate_name, &self.crate_version, &full_path);
+
+        if !self.emitted_types.insert(type_iri.clone()) {
+            return Ok(());
+        }
+
+        let crate_iri = self.iris.crate_iri(&self.crate_name, &self.crate_version);
+        let module_iri =
+            self.iris
+                .module_iri(&self.crate_name, &self.crate_version, module_path);
+
+        self.emitter
+            .emit_iri(&type_iri, standard::RDF_TYPE, rt::PROC_MACRO)?;
+        self.emitter.emit_literal(&type_iri, tg::NAME, &name)?;
+        self.emitter
+            .emit_literal(&type_iri, tg::FULL_NAME, &full_path)?;
+        self.emitter
+            .emit_iri(&type_iri, tg::DEFINED_IN_ASSEMBLY, &crate_iri)?;
+        self.emitter
+            .emit_iri(&type_iri, tg::IN_NAMESPACE, &module_iri)?;
+        self.extract_and_emit_span(item, &type_iri)?;
+        self.extract_and_emit_docs(item, &type_iri)?;
+
+        if let ItemEnum::ProcMacro { kind, ref helpers } = item.inner {
+            let kind_str = match kind {
+                MacroKind::Bang => "bang",
+                MacroKind::Attr => "attr",
+                MacroKind::Derive => "derive",
+            };
+            self.emitter
+                .emit_literal(&type_iri, rt::MACRO_KIND, kind_str)?;
+            for helper in helpers {
+                self.emitter
+                    .emit_literal(&type_iri, rt::DERIVE_HELPER, helper)?;
+            }
+        }
+        Ok(())
+    }
+
+    // -----------------------------------------------------------------------
+    // Foreign types (`extern { type Foo; }`)
+    // -----------------------------------------------------------------------
+
+    fn extract_extern_type(&mut self, item: &Item, module_path: &str) -> std::io::Result<()> {
+        let name = match &item.name {
+            Some(n) => n.clone(),
+            None => return Ok(()),
+        };
+        let full_path = format!("{module_path}::{name}");
+        if !self.options.path_filter.allows(&full_path) {
+            return Ok(());
+        }
+        let type_iri = self
+            .iris
+            .type_iri(&self.crate_name, &self.crate_version, &full_path);
+
+        if !self.emitted_types.insert(type_iri.clone()) {
+            return Ok(());
+        }
+
+        let crate_iri = self.iris.crate_iri(&self.crate_name, &self.crate_version);
+        let module_iri =
+            self.iris
+                .module_iri(&self.crate_name, &self.crate_version, module_path);
+
+        self.emitter
+            .emit_iri(&type_iri, standard::RDF_TYPE, rt::EXTERN_TYPE)?;
+        self.emitter.emit_literal(&type_iri, tg::NAME, &name)?;
+        self.emitter
+            .emit_literal(&type_iri, tg::FULL_NAME, &full_path)?;
+        self.emitter
+            .emit_iri(&type_iri, tg::DEFINED_IN_ASSEMBLY, &crate_iri)?;
+        self.emitter
+            .emit_iri(&type_iri, tg::IN_NAMESPACE, &module_iri)?;
+
+        let vis = visibility_str(&item.visibility);
+        self.emitter.emit_literal(&type_iri, tg::ACCESSIBILITY, vis)?;
+        self.extract_and_emit_cfg(item, module_path, &type_iri)?;
+        self.extract_and_emit_span(item, &type_iri)?;
+        self.extract_and_emit_docs(item, &type_iri)?;
+        Ok(())
+    }
+
+    // -----------------------------------------------------------------------
+    // Built-in primitive type doc pages (e.g. the `i32`/`str` item in `core`)
+    // -----------------------------------------------------------------------
+
+    fn extract_primitive(&mut self, item: &Item, module_path: &str) -> std::io::Result<()> {
+        let name = match &item.name {
+            Some(n) => n.clone(),
+            None => return Ok(()),
+        };
+        let type_iri = self.ensure_primitive_type_emitted(&name)?;
+
+        let crate_iri = self.iris.crate_iri(&self.crate_name, &self.crate_version);
+        let module_iri =
+            self.iris
+                .module_iri(&self.crate_name, &self.crate_version, module_path);
+        self.emitter
+            .emit_iri(&type_iri, tg::DEFINED_IN_ASSEMBLY, &crate_iri)?;
+        self.emitter
+            .emit_iri(&type_iri, tg::IN_NAMESPACE, &module_iri)?;
+        self.extract_and_emit_cfg(item, module_path, &type_iri)?;
+        self.extract_and_emit_span(item, &type_iri)?;
+        self.extract_and_emit_docs(item, &type_iri)?;
+        Ok(())
+    }
+
+    // -----------------------------------------------------------------------
+    // Standard-library keyword doc pages (e.g. the `match` item in `std`)
+    // -----------------------------------------------------------------------
+
+    fn extract_keyword(&mut self, item: &Item, module_path: &str) -> std::io::Result<()> {
+        let name = match &item.name {
+            Some(n) => n.clone(),
+            None => return Ok(()),
+        };
+        let full_path = format!("{module_path}::{name}");
+        if !self.options.path_filter.allows(&full_path) {
+            return Ok(());
+        }
+        let type_iri = self
+            .iris
+            .type_iri(&self.crate_name, &self.crate_version, &full_path);
+
+        if !self.emitted_types.insert(type_iri.clone()) {
+            return Ok(());
+        }
+
+        let crate_iri = self.iris.crate_iri(&self.crate_name, &self.crate_version);
+        let module_iri =
+            self.iris
+                .module_iri(&self.crate_name, &self.crate_version, module_path);
+
+        self.emitter
+            .emit_iri(&type_iri, standard::RDF_TYPE, rt::KEYWORD)?;
+        self.emitter.emit_literal(&type_iri, tg::NAME, &name)?;
+        self.emitter
+            .emit_iri(&type_iri, tg::DEFINED_IN_ASSEMBLY, &crate_iri)?;
+        self.emitter
+            .emit_iri(&type_iri, tg::IN_NAMESPACE, &module_iri)?;
+        self.extract_and_emit_span(item, &type_iri)?;
+        self.extract_and_emit_docs(item, &type_iri)?;
+        Ok(())
+    }
+
+    // -----------------------------------------------------------------------
+    // Field extraction
+    // -----------------------------------------------------------------------
+
+    fn extract_field(&mut self, field_id: &str, owner_iri: &str) -> std::io::Result<()> {
+        let item = match self.crate_data.index.get(field_id) {
+            Some(i) => i,
+            None => return Ok(()),
+        };
+
+        let name = item.name.as_deref().unwrap_or("unnamed");
+
+        if let ItemEnum::StructField(ref ty) = item.inner {
+            let field_iri = self.iris.member_iri(owner_iri, name, "");
+
+            self.emitter
+                .emit_iri(&field_iri, standard::RDF_TYPE, tg::FIELD)?;
+            self.emitter.emit_literal(&field_iri, tg::NAME, name)?;
+            self.emitter
+                .emit_iri(owner_iri, tg::HAS_MEMBER, &field_iri)?;
+            self.emitter
+                .emit_iri(&field_iri, tg::MEMBER_OF, owner_iri)?;
+
+            let vis = visibility_str(&item.visibility);
+            self.emitter
+                .emit_literal(&field_iri, tg::ACCESSIBILITY, vis)?;
+            self.extract_and_emit_span(item, &field_iri)?;
+            self.extract_and_emit_docs(item, &field_iri)?;
+            self.extract_and_emit_stability(item, &field_iri)?;
+            self.record_field_type(owner_iri, ty);
+
+            if let Some(type_iri) = self.resolve_type_to_iri(ty)? {
+                self.emitter
+                    .emit_iri(&field_iri, tg::FIELD_TYPE, &type_iri)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Record a field's type against its owning struct/enum/union IRI, for
+    /// later auto-trait inference (see [`infer_auto_traits`](Self::infer_auto_traits)).
+    fn record_field_type(&mut self, owner_type_iri: &str, ty: &Type) {
+        self.type_fields
+            .entry(owner_type_iri.to_string())
+            .or_default()
+            .push(ty.clone());
+    }
+
+    // -----------------------------------------------------------------------
+    // Re-exports (`use` items, including glob imports)
+    // -----------------------------------------------------------------------
+
+    fn extract_use(&mut self, item: &Item, module_path: &str) -> std::io::Result<()> {
+        let module_iri = self
+            .iris
+            .module_iri(&self.crate_name, &self.crate_version, module_path);
+
+        if let ItemEnum::Use {
+            ref source,
+            ref name,
+            ref id,
+            is_glob,
+        } = item.inner
+        {
+            if is_glob {
+                self.extract_glob_reexport(id.as_ref(), &module_iri)?;
+                return Ok(());
+            }
+
+            let resolved = match id.as_ref() {
+                Some(id) => self.resolve_referenced_item(id)?,
+                None => None,
+            };
+            let (target_iri, original_name) = match resolved {
+                Some(resolved) => resolved,
+                None => match self.resolve_use_source(source)? {
+                    Some(resolved) => resolved,
+                    None => return Ok(()),
+                },
+            };
+
+            let alias = name.clone().unwrap_or(original_name);
+            self.emit_reexport(&module_iri, &target_iri, &alias)?;
+        }
+        Ok(())
+    }
+
+    /// Expand `use foo::*` into one re-export edge per public child of the
+    /// referenced module, keeping each child's own name as the alias.
+    fn extract_glob_reexport(&mut self, target_module_id: Option<&Id>, module_iri: &str) -> std::io::Result<()> {
+        let target_module_id = match target_module_id {
+            Some(id) => id,
+            None => return Ok(()),
+        };
+        let target_item = match self.crate_data.index.get(&target_module_id.0) {
+            Some(i) => i,
+            None => return Ok(()),
+        };
+        let child_ids: Vec<Id> = match &target_item.inner {
+            ItemEnum::Module { ref items, .. } => items.clone(),
+            _ => return Ok(()),
+        };
+
+        for child_id in &child_ids {
+            let child_item = match self.crate_data.index.get(&child_id.0) {
+                Some(i) => i,
+                None => continue,
+            };
+            if !matches!(child_item.visibility, Visibility::Public) {
+                continue;
+            }
+            let name = match &child_item.name {
+                Some(n) => n.clone(),
+                None => continue,
+            };
+            if let Some((target_iri, _)) = self.resolve_referenced_item(child_id)? {
+                self.emit_reexport(module_iri, &target_iri, &name)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Resolve a `use` target's `Id` to (IRI, original name), minting a
+    /// minimal external-type node via [`Self::ensure_external_type_emitted`]
+    /// when the target isn't an item we've already emitted ourselves.
+    fn resolve_referenced_item(&mut self, id: &Id) -> std::io::Result<Option<(String, String)>> {
+        if let Some(summary) = self.crate_data.paths.get(&id.0) {
+            let full_path = summary.path.join("::");
+            let name = summary
+                .path
+                .last()
+                .cloned()
+                .unwrap_or_else(|| full_path.clone());
+            let iri = self
+                .iris
+                .type_iri(&self.crate_name, &self.crate_version, &full_path);
+            self.ensure_external_type_emitted(&iri, &name)?;
+            return Ok(Some((iri, name)));
+        }
+        if let Some(item) = self.crate_data.index.get(&id.0) {
+            if let Some(ref name) = item.name {
+                let iri = self
+                    .iris
+                    .type_iri(&self.crate_name, &self.crate_version, name);
+                self.ensure_external_type_emitted(&iri, name)?;
+                return Ok(Some((iri, name.clone())));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Fall back to the textual `use` source path (e.g. `std::collections::HashMap`)
+    /// when rustdoc didn't resolve the `id` (can happen for some macro- or
+    /// cfg-stripped re-exports).
+    fn resolve_use_source(&mut self, source: &str) -> std::io::Result<Option<(String, String)>> {
+        let name = source.rsplit("::").next().unwrap_or(source).to_string();
+        if name.is_empty() {
+            return Ok(None);
+        }
+        let iri = self
+            .iris
+            .type_iri(&self.crate_name, &self.crate_version, source);
+        self.ensure_external_type_emitted(&iri, &name)?;
+        Ok(Some((iri, name)))
+    }
+
+    /// Resolution pass for [`ExtractionOptions::canonicalize_paths`]: for
+    /// every locally-extracted item, compare the `module_path::name` it was
+    /// actually walked at ([`Self::item_full_paths`]) against rustdoc's
+    /// `paths`-index path for that same item id. The two diverge when a
+    /// `pub use` facade makes a type canonical at a shorter or differently
+    /// nested path than where it's defined (the same path rust-analyzer's
+    /// import map resolves to). When they differ, link the defining-site IRI
+    /// to the canonical one with `owl:sameAs` so consumers can follow either
+    /// alias to the same node.
+    fn resolve_canonical_paths(&mut self) -> std::io::Result<()> {
+        let mut links: Vec<(String, String)> = Vec::new();
+        for (item_id, local_full_path) in &self.item_full_paths {
+            let Some(summary) = self.crate_data.paths.get(item_id) else {
+                continue;
+            };
+            let canonical_full_path = summary.path.join("::");
+            if &canonical_full_path == local_full_path {
+                continue;
+            }
+            let local_iri =
+                self.iris
+                    .type_iri(&self.crate_name, &self.crate_version, local_full_path);
+            let canonical_iri = self.iris.type_iri(
+                &self.crate_name,
+                &self.crate_version,
+                &canonical_full_path,
+            );
+            if local_iri != canonical_iri {
+                links.push((local_iri, canonical_iri));
+            }
+        }
+        links.sort();
+        for (local_iri, canonical_iri) in links {
+            self.emitter
+                .emit_iri(&local_iri, owl::SAME_AS, &canonical_iri)?;
+        }
+        Ok(())
+    }
+
+    fn emit_reexport(&mut self, module_iri: &str, target_iri: &str, alias: &str) -> std::io::Result<()> {
+        self.emitter
+            .emit_iri(module_iri, rt::RE_EXPORTS, target_iri)?;
+
+        let reexport_iri = self.iris.reexport_iri(module_iri, alias);
+        self.emitter
+            .emit_iri(&reexport_iri, standard::RDF_TYPE, rt::RE_EXPORT)?;
+        self.emitter
+            .emit_literal(&reexport_iri, rt::RE_EXPORT_ALIAS, alias)?;
+        self.emitter
+            .emit_iri(&reexport_iri, rt::RE_EXPORT_TARGET, target_iri)?;
+        self.emitter
+            .emit_iri(module_iri, rt::HAS_RE_EXPORT, &reexport_iri)?;
+        Ok(())
+    }
+
+    // -----------------------------------------------------------------------
+    // Impl block processing
+    // -----------------------------------------------------------------------
+
+    fn process_all_impls(&mut self) -> std::io::Result<()> {
+        // Collect all impl item IDs first to avoid borrow issues
+        let impl_ids: Vec<String> = self
+            .crate_data
+            .index
+            .iter()
+            .filter(|(_, item)| matches!(item.inner, ItemEnum::Impl { .. }))
+            .map(|(id, _)| id.clone())
+            .collect();
+
+        for impl_id in &impl_ids {
+            self.process_impl(impl_id)?;
+        }
+        Ok(())
+    }
+
+    fn process_impl(&mut self, impl_id: &str) -> std::io::Result<()> {
+        let item = match self.crate_data.index.get(impl_id) {
+            Some(i) => i,
+            None => return Ok(()),
+        };
+
+        if let ItemEnum::Impl {
+            ref generics,
+            ref trait_,
+            ref for_,
+            ref items,
+            is_synthetic,
+            is_negative,
+            ref blanket_impl,
+            ..
+        } = item.inner
+        {
+            // `impl<T: Bound> Trait for T` -- a blanket impl the crate itself
+            // defines, as opposed to `blanket_impl` above (rustdoc's report
+            // of a *derived*, per-concrete-type realization of someone
+            // else's blanket impl). `for_` being a bare generic naming one of
+            // the impl's own type parameters is what distinguishes it.
+            if let (Type::Generic(ref generic_name), Some(ref trait_path)) = (for_, trait_) {
+                if self.options.include_blanket_impls {
+                    self.process_blanket_impl(impl_id, generics, trait_path, generic_name)?;
+                }
+                return Ok(());
+            }
+
+            // Resolve the "for" type to an IRI
+            let for_iri = match self.resolve_type_to_iri(for_)? {
+                Some(iri) => iri,
+                None => return Ok(()),
+            };
+
+            // Only process impls for types defined in this crate
+            if !self.emitted_types.contains(&for_iri) {
+                return Ok(());
+            }
+
+            let impl_iri =
+                self.iris
+                    .impl_iri(&self.crate_name, &self.crate_version, impl_id);
+
+            // An explicit `impl !Trait for Type` opts the type out of that
+            // trait. It has no body to extract members from, but is still
+            // reported as a node (flagged `rt:isNegative`) so a consumer can
+            // tell "doesn't implement" apart from "no impl at all".
+            if is_negative {
+                self.emitter
+                    .emit_bool(&impl_iri, rt::IS_NEGATIVE, true)?;
+                self.emitter.emit_iri(&impl_iri, rt::IMPL_FOR, &for_iri)?;
+                if let Some(ref trait_path) = trait_ {
+                    let trait_iri = self.resolve_path_to_iri(trait_path);
+                    self.emitter
+                        .emit_iri(&impl_iri, rt::IMPL_TRAIT, &trait_iri)?;
+                    self.negative_impls
+                        .insert((for_iri.clone(), trait_path.path.clone()));
+                }
+                return Ok(());
+            }
+
+            self.extract_generics(generics, &impl_iri)?;
+
+            if let Some(ref trait_path) = trait_ {
+                // Trait impl
+                let trait_iri = self.resolve_path_to_iri(trait_path);
+
+                self.emitter
+                    .emit_iri(&impl_iri, standard::RDF_TYPE, rt::TRAIT_IMPL)?;
+                self.emitter.emit_iri(&impl_iri, rt::IMPL_FOR, &for_iri)?;
+                self.emitter
+                    .emit_iri(&impl_iri, rt::IMPL_TRAIT, &trait_iri)?;
+                self.emitter
+                    .emit_iri(&for_iri, tg::IMPLEMENTS, &trait_iri)?;
+                self.implemented_traits
+                    .entry(for_iri.clone())
+                    .or_default()
+                    .insert(trait_iri.clone());
+
+                if self.options.extract_specialization {
+                    self.trait_impls_by_trait
+                        .entry(trait_iri.clone())
+                        .or_default()
+                        .push(SpecializationCandidate {
+                            impl_iri: impl_iri.clone(),
+                            self_type: ImplSelfType::Concrete(for_iri.clone()),
+                        });
+                }
+
+                // Ensure trait node exists
+                self.ensure_external_type_emitted(&trait_iri, &trait_path.path)?;
+
+                // A real impl (manual or compiler-synthesized) of an auto
+                // trait always wins over whatever we'd otherwise synthesize
+                // for this type in `infer_auto_traits`.
+                if let Some(&(auto_trait_name, _)) = AUTO_TRAITS
+                    .iter()
+                    .find(|(name, _)| *name == last_path_segment(&trait_path.path))
+                {
+                    self.manual_auto_trait_impls
+                        .insert((for_iri.clone(), auto_trait_name.to_string()));
+                }
+
+                // rustdoc marks compiler-synthesized auto-trait impls
+                // (Send/Sync/...) with `is_synthetic` rather than giving
+                // them a real body — flag them rather than dropping them.
+                if is_synthetic {
+                    self.emitter.emit_bool(&impl_iri, rt::SYNTHESIZED_IMPL, true)?;
+                }
+
+                // A blanket impl (`impl<T: Bound> Trait for T`) is reported
+                // per concrete type with `blanket_impl` set to the
+                // generic/bound type the blanket rule was written against.
+                if let Some(blanket_ty) = blanket_impl {
+                    self.emitter
+                        .emit_iri(&impl_iri, standard::RDF_TYPE, rt::BLANKET_IMPL)?;
+                    if let Some(blanket_iri) = self.resolve_type_to_iri(blanket_ty)? {
+                        self.ensure_external_type_emitted(
+                            &blanket_iri,
+                            &type_display_name(blanket_ty),
+                        )?;
+                        self.emitter
+                            .emit_iri(&impl_iri, rt::BLANKET_SOURCE_TYPE, &blanket_iri)?;
+                    }
+                }
+            } else {
+                // Inherent impl — add methods directly as members of the type
+                self.emitter
+                    .emit_iri(&impl_iri, standard::RDF_TYPE, rt::INHERENT_IMPL)?;
+                self.emitter.emit_iri(&impl_iri, rt::IMPL_FOR, &for_iri)?;
+            }
+
+            // Process impl items (methods, associated types, etc.)
+            let trait_iri = trait_
+                .as_ref()
+                .map(|trait_path| self.resolve_path_to_iri(trait_path));
+            let method_ids: Vec<String> = items.iter().map(|id| id.0.clone()).collect();
+            let mut item_defaults: Vec<bool> = Vec::new();
+            for method_id in &method_ids {
+                // For inherent impls, methods belong to the type directly.
+                // For trait impls, methods belong to the impl node.
+                let owner_iri = if trait_.is_none() {
+                    for_iri.clone()
+                } else {
+                    impl_iri.clone()
+                };
+                self.extract_impl_item(method_id, &owner_iri, trait_iri.as_deref())?;
+                if self.options.extract_specialization {
+                    item_defaults.push(self.mark_specializable_item(method_id, &owner_iri)?);
+                }
+            }
+
+            // An `impl` is a `default impl` in spirit when every one of its
+            // items is individually `default` -- the whole block only ever
+            // supplies fallback behavior for a more specific impl.
+            if self.options.extract_specialization
+                && !item_defaults.is_empty()
+                && item_defaults.iter().all(|is_default| *is_default)
+            {
+                self.emitter.emit_bool(&impl_iri, rt::IS_DEFAULT_IMPL, true)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Emit [`rt::IS_SPECIALIZABLE`] on `item_id`'s member node if it's
+    /// declared `default` inside its impl block, returning whether it was.
+    /// Used by [`CrateExtractor::process_impl`] to also decide whether the
+    /// whole impl qualifies as [`rt::IS_DEFAULT_IMPL`].
+    fn mark_specializable_item(&mut self, item_id: &str, owner_iri: &str) -> std::io::Result<bool> {
+        let Some(item) = self.crate_data.index.get(item_id) else {
+            return Ok(false);
+        };
+        let is_default = match item.inner {
+            ItemEnum::Function { is_default, .. } => is_default,
+            ItemEnum::AssocConst { is_default, .. } => is_default,
+            ItemEnum::AssocType { is_default, .. } => is_default,
+            _ => return Ok(false),
+        };
+        if is_default {
+            if let Some(ref name) = item.name {
+                let member_iri = self.iris.member_iri(owner_iri, name, "");
+                self.emitter
+                    .emit_bool(&member_iri, rt::IS_SPECIALIZABLE, true)?;
+            }
+        }
+        Ok(is_default)
+    }
+
+    /// Record an `impl<T: Bound> Trait for T` the crate itself defines —
+    /// `for_` is a bare generic naming one of the impl's own type parameters,
+    /// as opposed to the `blanket_impl` field on a per-concrete-type impl
+    /// (rustdoc's report of a *derived realization* of someone else's
+    /// blanket impl, handled in [`CrateExtractor::process_impl`]). Emits an
+    /// [`rt::BLANKET_IMPL`] node carrying the impl's generics/bounds, and
+    /// queues its required bound traits for [`CrateExtractor::resolve_blanket_impls`]
+    /// to match against every in-crate type once all impls are known.
+    fn process_blanket_impl(
+        &mut self,
+        impl_id: &str,
+        generics: &Generics,
+        trait_path: &ResolvedPath,
+        generic_name: &str,
+    ) -> std::io::Result<()> {
+        let impl_iri = self
+            .iris
+            .impl_iri(&self.crate_name, &self.crate_version, impl_id);
+        let trait_iri = self.resolve_path_to_iri(trait_path);
+
+        self.emitter
+            .emit_iri(&impl_iri, standard::RDF_TYPE, rt::BLANKET_IMPL)?;
+        self.emitter.emit_iri(&impl_iri, rt::IMPL_TRAIT, &trait_iri)?;
+        self.ensure_external_type_emitted(&trait_iri, &trait_path.path)?;
+
+        self.extract_generics(generics, &impl_iri)?;
+
+        let required_bounds = self.generic_param_bound_traits(generics, generic_name);
+        if self.options.extract_specialization {
+            self.trait_impls_by_trait
+                .entry(trait_iri.clone())
+                .or_default()
+                .push(SpecializationCandidate {
+                    impl_iri: impl_iri.clone(),
+                    self_type: ImplSelfType::Blanket(required_bounds.clone()),
+                });
+        }
+        self.blanket_impls.push((impl_iri, required_bounds));
+        Ok(())
+    }
+
+    /// Collect the IRIs of every trait bound on `generics`'s parameter named
+    /// `param_name`, combining bounds written directly on the parameter
+    /// (`<T: Bound>`) with equivalent `where T: Bound` clauses.
+    fn generic_param_bound_traits(&self, generics: &Generics, param_name: &str) -> HashSet<String> {
+        let mut required = HashSet::new();
+
+        if let Some(param) = generics.params.iter().find(|p| p.name == param_name) {
+            if let GenericParamDefKind::Type { ref bounds, .. } = param.kind {
+                for bound in bounds {
+                    if let GenericBound::TraitBound { ref trait_, .. } = bound {
+                        required.insert(self.resolve_path_to_iri(trait_));
+                    }
+                }
+            }
+        }
+        for predicate in &generics.where_predicates {
+            if let WherePredicate::BoundPredicate {
+                ref type_,
+                ref bounds,
+                ..
+            } = predicate
+            {
+                if matches!(type_, Type::Generic(name) if name == param_name) {
+                    for bound in bounds {
+                        if let GenericBound::TraitBound { ref trait_, .. } = bound {
+                            required.insert(self.resolve_path_to_iri(trait_));
+                        }
+                    }
+                }
+            }
+        }
+
+        required
+    }
+
+    /// Match every blanket impl recorded by [`CrateExtractor::process_blanket_impl`]
+    /// against every in-crate type's [`CrateExtractor::implemented_traits`],
+    /// emitting an [`rt::IMPLIES_IMPL_FOR`] edge wherever a type's known
+    /// implemented traits satisfy ALL of the blanket impl's required bounds.
+    /// A type whose trait set can't be shown to satisfy the bounds is simply
+    /// left unlinked rather than guessed at.
+    fn resolve_blanket_impls(&mut self) -> std::io::Result<()> {
+        let candidates: Vec<String> = self.type_fields.keys().cloned().collect();
+        for (impl_iri, required_bounds) in self.blanket_impls.clone() {
+            for type_iri in &candidates {
+                let implemented = self
+                    .implemented_traits
+                    .get(type_iri)
+                    .cloned()
+                    .unwrap_or_default();
+                if required_bounds.iter().all(|b| implemented.contains(b)) {
+                    self.emitter
+                        .emit_iri(&impl_iri, rt::IMPLIES_IMPL_FOR, type_iri)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Order every trait's impls by self-type/bound specificity, emitting an
+    /// [`rt::SPECIALIZES`] edge from the narrower impl to the broader one it
+    /// specializes: a concrete impl specializes any blanket impl of the same
+    /// trait whose bounds the concrete type is known to satisfy, and one
+    /// blanket impl specializes another whose required bounds are a strict
+    /// subset of its own (tighter bounds match fewer types, all of which the
+    /// looser impl also matches).
+    fn resolve_specialization(&mut self) -> std::io::Result<()> {
+        for candidates in self.trait_impls_by_trait.clone().into_values() {
+            for a in &candidates {
+                for b in &candidates {
+                    if a.impl_iri == b.impl_iri {
+                        continue;
+                    }
+                    if self.specializes(a, b) {
+                        self.emitter.emit_iri(&a.impl_iri, rt::SPECIALIZES, &b.impl_iri)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether impl `a` specializes impl `b` -- i.e. `a`'s self-type is a
+    /// strictly narrower match than `b`'s, so `a`'s items should win when
+    /// both apply. See [`CrateExtractor::resolve_specialization`].
+    fn specializes(&self, a: &SpecializationCandidate, b: &SpecializationCandidate) -> bool {
+        match (&a.self_type, &b.self_type) {
+            (ImplSelfType::Concrete(a_ty), ImplSelfType::Blanket(b_bounds)) => {
+                let implemented = self.implemented_traits.get(a_ty).cloned().unwrap_or_default();
+                b_bounds.iter().all(|bound| implemented.contains(bound))
+            }
+            (ImplSelfType::Blanket(a_bounds), ImplSelfType::Blanket(b_bounds)) => {
+                a_bounds != b_bounds && b_bounds.iter().all(|bound| a_bounds.contains(bound))
+            }
+            (ImplSelfType::Concrete(_), ImplSelfType::Concrete(_))
+            | (ImplSelfType::Blanket(_), ImplSelfType::Concrete(_)) => false,
+        }
+    }
+
+    fn extract_impl_item(&mut self, item_id: &str, owner_iri: &str, trait_iri: Option<&str>) -> std::io::Result<()> {
+        let item = match self.crate_data.index.get(item_id) {
+            Some(i) => i,
+            None => return Ok(()),
+        };
+
+        match &item.inner {
+            ItemEnum::Function { .. } => {
+                self.extract_type_method(item_id, owner_iri)?;
+
+                // Link the concrete method back to the trait's declared
+                // method of the same name, so "which required methods does
+                // this type actually provide" is a direct edge walk.
+                if let (Some(trait_iri), Some(ref name)) = (trait_iri, &item.name) {
+                    let method_iri = self.iris.member_iri(owner_iri, name, "");
+                    let trait_method_iri = self.iris.member_iri(trait_iri, name, "");
+                    self.emitter.emit_iri(
+                        &method_iri,
+                        rt::IMPLEMENTS_TRAIT_METHOD,
+                        &trait_method_iri,
+                    )?;
+                }
+            }
+            ItemEnum::AssocType { .. } | ItemEnum::AssocConst { .. } => {
+                // Associated types and consts — emit minimal info
+                if let Some(ref name) = item.name {
+                    let member_iri = self.iris.member_iri(owner_iri, name, "");
+                    self.emitter
+                        .emit_iri(&member_iri, standard::RDF_TYPE, tg::MEMBER)?;
+                    self.emitter.emit_literal(&member_iri, tg::NAME, name)?;
+                    self.emitter
+                        .emit_iri(owner_iri, tg::HAS_MEMBER, &member_iri)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    // -----------------------------------------------------------------------
+    // Derive macro extraction
+    // -----------------------------------------------------------------------
+
+    fn extract_derives_for_item(&mut self, item_id: &str, type_iri: &str) -> std::io::Result<()> {
+        // Find impl blocks associated with this type that have `automatically_derived`
+        // in their attrs — these are derive macro impls.
+        let item = match self.crate_data.index.get(item_id) {
+            Some(i) => i,
+            None => return Ok(()),
+        };
+
+        // Get the impl IDs from the item
+        let impl_ids = match &item.inner {
+            ItemEnum::Struct { ref impls, .. } => {
+                impls.iter().map(|id| id.0.clone()).collect::<Vec<_>>()
+            }
+            ItemEnum::Enum { ref impls, .. } => {
+                impls.iter().map(|id| id.0.clone()).collect::<Vec<_>>()
+            }
+            _ => return Ok(()),
+        };
+
+        for imp_id in &impl_ids {
+            let imp_item = match self.crate_data.index.get(imp_id) {
+                Some(i) => i,
+                None => continue,
+            };
+
+            // Check if it's an automatically_derived impl
+            let is_auto_derived = imp_item.attrs.iter().any(|attr| match attr {
+                serde_json::Value::String(s) => s == "automatically_derived",
+                _ => false,
+            });
+
+            if !is_auto_derived {
+                continue;
+            }
+
+            // Extract the trait name from the impl
+            if let ItemEnum::Impl {
+                trait_: Some(ref trait_path),
+                ..
+            } = imp_item.inner
+            {
+                self.emitter
+                    .emit_literal(type_iri, rt::DERIVES, &trait_path.path)?;
+
+                if self.options.extract_derive_impls {
+                    self.emit_derived_impl(type_iri, trait_path)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Emit a synthetic `rt:DerivedImpl` node linking `type_iri` to the
+    /// derived trait via `rt:implementsTrait`, with `rt:implSource` set to
+    /// `"derive"`. The trait IRI is resolved the same way a normal `impl`
+    /// block's trait would be when rustdoc gave us an `Id`; when it didn't
+    /// (common for derive-macro-generated impls whose trait reference isn't
+    /// indexed), fall back to the built-in/user-registered derive registry.
+    fn emit_derived_impl(&mut self, type_iri: &str, trait_path: &ResolvedPath) -> std::io::Result<()> {
+        let trait_iri = if trait_path.id.is_some() {
+            self.resolve_path_to_iri(trait_path)
+        } else if let Some(full_path) = lookup_derive_trait(&trait_path.path, &self.options.extra_derive_traits)
+        {
+            self.iris
+                .type_iri(&self.crate_name, &self.crate_version, &full_path)
+        } else {
+            self.resolve_path_to_iri(trait_path)
+        };
+        self.ensure_external_type_emitted(&trait_iri, &trait_path.path)?;
+
+        let impl_iri = self.iris.synthesized_impl_iri(type_iri, &trait_path.path);
+        self.emitter
+            .emit_iri(&impl_iri, standard::RDF_TYPE, rt::DERIVED_IMPL)?;
+        self.emitter.emit_iri(&impl_iri, rt::IMPL_FOR, type_iri)?;
+        self.emitter.emit_iri(&impl_iri, rt::IMPL_TRAIT, &trait_iri)?;
+        self.emitter
+            .emit_literal(&impl_iri, rt::IMPL_SOURCE, "derive")?;
+        self.emitter
+            .emit_iri(type_iri, rt::IMPLEMENTS_TRAIT, &trait_iri)?;
+        Ok(())
+    }
+
+    // -----------------------------------------------------------------------
+    // Auto-trait inference (Send / Sync / Unpin / UnwindSafe)
+    // -----------------------------------------------------------------------
+
+    /// Run a fixpoint over every local struct/enum/union, inferring which of
+    /// the four structural auto traits it implements from its field types,
+    /// then emit a synthesized impl for each one found.
+    ///
+    /// A type gets an auto trait if every field type does, recursing through
+    /// local types until nothing changes. Raw pointers, `Rc`, `RefCell` and
+    /// `Cell` are seeded as structural blockers the same way the compiler
+    /// treats them; an explicit `impl !Trait for Type` blocks it outright.
+    /// Field types we can't see through (external types, generics, function
+    /// pointers, ...) leave the outcome unknown rather than asserting a
+    /// trait we can't actually vouch for.
+    fn infer_auto_traits(&mut self) -> std::io::Result<()> {
+        let type_iris: Vec<String> = self.type_fields.keys().cloned().collect();
+
+        for &(trait_name, trait_full_path) in AUTO_TRAITS {
+            let mut status: std::collections::HashMap<String, AutoTraitStatus> =
+                std::collections::HashMap::new();
+
+            loop {
+                let snapshot = status.clone();
+                let mut changed = false;
+
+                for type_iri in &type_iris {
+                    let mut new_status = self.type_fields[type_iri]
+                        .iter()
+                        .map(|field_ty| {
+                            self.classify_field_for_trait(field_ty, trait_name, &snapshot)
+                        })
+                        .fold(AutoTraitStatus::Implemented, AutoTraitStatus::combine);
+
+                    if self
+                        .negative_impls
+                        .contains(&(type_iri.clone(), trait_name.to_string()))
+                    {
+                        new_status = AutoTraitStatus::Blocked;
+                    }
+
+                    if snapshot.get(type_iri) != Some(&new_status) {
+                        status.insert(type_iri.clone(), new_status.clone());
+                        changed = true;
+                    }
+                }
+
+                if !changed {
+                    break;
+                }
+            }
+
+            for (type_iri, result) in &status {
+                // A manual impl (positive or negative) of this trait for
+                // this type always wins over the synthesized result.
+                if self
+                    .manual_auto_trait_impls
+                    .contains(&(type_iri.clone(), trait_name.to_string()))
+                    || self
+                        .negative_impls
+                        .contains(&(type_iri.clone(), trait_name.to_string()))
+                {
+                    continue;
+                }
+
+                match result {
+                    AutoTraitStatus::Implemented => {
+                        self.emit_synthesized_impl(
+                            type_iri,
+                            trait_name,
+                            trait_full_path,
+                            &[],
+                            false,
+                        )?;
+                    }
+                    AutoTraitStatus::Conditional(params) => {
+                        let params: Vec<String> = params.iter().cloned().collect();
+                        self.emit_synthesized_impl(
+                            type_iri,
+                            trait_name,
+                            trait_full_path,
+                            &params,
+                            false,
+                        )?;
+                    }
+                    AutoTraitStatus::Blocked => {
+                        self.emit_synthesized_impl(
+                            type_iri,
+                            trait_name,
+                            trait_full_path,
+                            &[],
+                            true,
+                        )?;
+                    }
+                    AutoTraitStatus::Unknown => {}
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Determine whether a field's type blocks, implements, or leaves
+    /// unknown a given auto trait, given the previous round's statuses for
+    /// local types.
+    fn classify_field_for_trait(
+        &self,
+        ty: &Type,
+        trait_name: &str,
+        status: &std::collections::HashMap<String, AutoTraitStatus>,
+    ) -> AutoTraitStatus {
+        if structural_auto_trait_blockers(ty).contains(&trait_name) {
+            return AutoTraitStatus::Blocked;
+        }
+
+        match ty {
+            Type::Primitive(_) => AutoTraitStatus::Implemented,
+
+            Type::Tuple(types) => types
+                .iter()
+                .map(|t| self.classify_field_for_trait(t, trait_name, status))
+                .fold(AutoTraitStatus::Implemented, AutoTraitStatus::combine),
+
+            Type::Slice(inner) | Type::Array { type_: inner, .. } => {
+                self.classify_field_for_trait(inner, trait_name, status)
+            }
+
+            Type::BorrowedRef { type_, .. } => {
+                self.classify_field_for_trait(type_, trait_name, status)
+            }
+
+            Type::ResolvedPath(path) => {
+                let dep_iri = self.resolve_path_to_iri(path);
+                match status.get(&dep_iri) {
+                    // A dependency's own bound is expressed in terms of
+                    // *its* type parameters, which we have no mapping back
+                    // to this field's generic arguments — too conservative
+                    // to assert either way.
+                    Some(AutoTraitStatus::Conditional(_)) => AutoTraitStatus::Unknown,
+                    Some(known) => known.clone(),
+                    None if self.type_fields.contains_key(&dep_iri) => {
+                        // A local type not yet settled this round — assume the
+                        // best case and let subsequent rounds tighten it.
+                        AutoTraitStatus::Implemented
+                    }
+                    // External or unresolved: we can't vouch for it.
+                    None => AutoTraitStatus::Unknown,
+                }
+            }
+
+            // A bare generic type parameter (`T` in `struct Foo<T> { t: T }`)
+            // makes `Foo<T>: Trait` conditional on `T: Trait` rather than
+            // settling it either way.
+            Type::Generic(ref name) => {
+                AutoTraitStatus::Conditional(std::collections::BTreeSet::from([name.clone()]))
+            }
+
+            // Raw pointers are handled by `structural_auto_trait_blockers`
+            // above; function pointers, `impl Trait`, etc. depend on
+            // information we don't have here.
+            _ => AutoTraitStatus::Unknown,
+        }
+    }
+
+    /// Emit a synthesized `rt:AutoTraitImpl` node for `trait_name` on
+    /// `type_iri` (trait IRI minted from `trait_full_path`, e.g.
+    /// `"core::marker::Send"`).
+    ///
+    /// `bound_params` are the type parameter names (see
+    /// [`CrateExtractor::type_generic_params`]) this result is conditional
+    /// on, carried as `rt:autoBound` edges to their `typeparam/N` nodes; a
+    /// positive, unconditional result additionally asserts `tg:implements`
+    /// on the type itself. `is_negative` flags a structurally-blocked type
+    /// (e.g. one with a raw-pointer field) the same way a hand-written
+    /// `impl !Trait for Type` would be.
+    fn emit_synthesized_impl(
+        &mut self,
+        type_iri: &str,
+        trait_name: &str,
+        trait_full_path: &str,
+        bound_params: &[String],
+        is_negative: bool,
+    ) -> std::io::Result<()> {
+        let trait_iri = self
+            .iris
+            .type_iri(&self.crate_name, &self.crate_version, trait_full_path);
+        self.ensure_external_type_emitted(&trait_iri, trait_name)?;
+
+        let impl_iri = self.iris.synthesized_impl_iri(type_iri, trait_name);
+        self.emitter
+            .emit_iri(&impl_iri, standard::RDF_TYPE, rt::AUTO_TRAIT_IMPL)?;
+        self.emitter.emit_iri(&impl_iri, rt::IMPL_FOR, type_iri)?;
+        self.emitter
+            .emit_iri(&impl_iri, rt::IMPLEMENTS_AUTO, &trait_iri)?;
+        self.emitter
+            .emit_bool(&impl_iri, rt::IS_NEGATIVE, is_negative)?;
+
+        for param_name in bound_params {
+            if let Some(tp_iri) = self.type_parameter_iri_by_name(type_iri, param_name) {
+                self.emitter.emit_iri(&impl_iri, rt::AUTO_BOUND, &tp_iri)?;
+            }
+        }
+
+        if !is_negative && bound_params.is_empty() {
+            self.emitter.emit_iri(type_iri, tg::IMPLEMENTS, &trait_iri)?;
+            self.implemented_traits
+                .entry(type_iri.to_string())
+                .or_default()
+                .insert(trait_iri.clone());
+        }
+        Ok(())
+    }
+
+    /// Look up the `typeparam/N` node minted for `owner_iri`'s type
+    /// parameter named `name` (see [`CrateExtractor::extract_generics`]).
+    fn type_parameter_iri_by_name(&self, owner_iri: &str, name: &str) -> Option<String> {
+        let params = self.type_generic_params.get(owner_iri)?;
+        let ordinal = params.iter().position(|p| p.name == name)?;
+        Some(self.iris.type_parameter_iri(owner_iri, ordinal))
+    }
+
+    // -----------------------------------------------------------------------
+    // Generics extraction (Phase 8)
+    // -----------------------------------------------------------------------
+
+    fn extract_generics(&mut self, generics: &Generics, owner_iri: &str) -> std::io::Result<()> {
+        for (ordinal, param) in generics.params.iter().enumerate() {
+            match &param.kind {
+                GenericParamDefKind::Type {
+                    ref bounds,
+                    ref default,
+                    ..
+                } => {
+                    let tp_iri = self.iris.type_parameter_iri(owner_iri, ordinal);
+                    self.emitter
+                        .emit_iri(&tp_iri, standard::RDF_TYPE, tg::TYPE_PARAMETER)?;
+                    self.emitter.emit_literal(&tp_iri, tg::NAME, &param.name)?;
+                    self.emitter.emit_int(&tp_iri, tg::ORDINAL, ordinal as i64)?;
+                    self.emitter
+                        .emit_iri(owner_iri, tg::HAS_TYPE_PARAMETER, &tp_iri)?;
+                    self.emitter
+                        .emit_iri(&tp_iri, tg::TYPE_PARAMETER_OF, owner_iri)?;
+
+                    // Trait bounds
+                    for bound in bounds {
+                        if let GenericBound::TraitBound { ref trait_, .. } = bound {
+                            self.extract_trait_bound(&tp_iri, trait_)?;
+                        }
+                    }
+
+                    // Default type (`T = Default`)
+                    if let Some(ref default_ty) = default {
+                        if let Some(default_iri) = self.resolve_type_to_iri(default_ty)? {
+                            self.emitter
+                                .emit_iri(&tp_iri, rt::DEFAULT_TYPE, &default_iri)?;
+                        }
+                    }
+                }
+                GenericParamDefKind::Lifetime { ref outlives } => {
+                    let lt_iri = self.iris.lifetime_iri(owner_iri, &param.name);
+                    self.emitter
+                        .emit_iri(&lt_iri, standard::RDF_TYPE, rt::LIFETIME)?;
+                    self.emitter.emit_literal(&lt_iri, tg::NAME, &param.name)?;
+                    self.emitter
+                        .emit_iri(owner_iri, rt::HAS_LIFETIME, &lt_iri)?;
+
+                    // Inline outlives bounds (`'a: 'b` declared in the
+                    // generic param list itself, as opposed to a
+                    // `where 'a: 'b` clause -- see `extract_where_predicates`).
+                    for target in outlives {
+                        let target_iri = self.iris.lifetime_iri(owner_iri, target);
+                        self.emitter.emit_iri(&lt_iri, rt::OUTLIVES, &target_iri)?;
+                    }
+                }
+                GenericParamDefKind::Const {
+                    ref type_,
+                    ref default,
+                } => {
+                    let cp_iri = self.iris.type_parameter_iri(owner_iri, ordinal);
+                    self.emitter
+                        .emit_iri(&cp_iri, standard::RDF_TYPE, rt::CONST_PARAM)?;
+                    self.emitter.emit_literal(&cp_iri, tg::NAME, &param.name)?;
+                    self.emitter.emit_int(&cp_iri, tg::ORDINAL, ordinal as i64)?;
+                    self.emitter
+                        .emit_iri(owner_iri, tg::HAS_TYPE_PARAMETER, &cp_iri)?;
+
+                    if let Some(type_iri) = self.resolve_type_to_iri(type_)? {
+                        self.emitter
+                            .emit_iri(&cp_iri, tg::PARAMETER_TYPE, &type_iri)?;
+                    }
+                    if let Some(ref default_value) = default {
+                        self.emitter
+                            .emit_literal(&cp_iri, tg::DEFAULT_VALUE, default_value)?;
+                    }
+                }
+                GenericParamDefKind::Unknown => {}
+            }
+        }
+
+        self.extract_where_predicates(generics, owner_iri)?;
+        Ok(())
+    }
+
+    /// Emit a single trait bound edge from `bounded_iri` to the trait named
+    /// by `trait_`, plus an [`rt::ASSOC_TYPE_BINDING`] node for each
+    /// associated-type-equality constraint carried in the trait's own
+    /// generic args (the `Item = T` part of `Iterator<Item = T>`).
+    fn extract_trait_bound(&mut self, bounded_iri: &str, trait_: &ResolvedPath) -> std::io::Result<()> {
+        let bound_iri = self.resolve_path_to_iri(trait_);
+        self.emitter
+            .emit_iri(bounded_iri, rt::TRAIT_BOUND, &bound_iri)?;
+        self.ensure_external_type_emitted(&bound_iri, &trait_.path)?;
+
+        if let Some(ref args) = trait_.args {
+            if let GenericArgs::AngleBracketed { ref constraints, .. } = **args {
+                for constraint in constraints {
+                    if let TypeBindingKind::Equality(ref eq_type) = constraint.binding {
+                        let binding_iri = self.iris.assoc_binding_iri(
+                            bounded_iri,
+                            &trait_.path,
+                            &constraint.name,
+                        );
+                        self.emitter.emit_iri(
+                            &binding_iri,
+                            standard::RDF_TYPE,
+                            rt::ASSOC_TYPE_BINDING,
+                        )?;
+                        self.emitter
+                            .emit_literal(&binding_iri, tg::NAME, &constraint.name)?;
+                        self.emitter
+                            .emit_iri(bounded_iri, rt::HAS_ASSOC_BINDING, &binding_iri)?;
+                        if let Some(eq_iri) = self.resolve_type_to_iri(eq_type)? {
+                            self.emitter.emit_iri(
+                                &binding_iri,
+                                rt::ASSOC_BINDING_TYPE,
+                                &eq_iri,
+                            )?;
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Resolve the type a where-predicate or bound applies to. Plain generic
+    /// parameters (`T` in `where T: Iterator`) don't go through
+    /// [`Self::resolve_type_to_iri`] since they're not a standalone type node
+    /// elsewhere in the graph; instead we point back at the type-parameter
+    /// node already minted for them in [`Self::extract_generics`].
+    fn bounded_type_iri(
+        &mut self,
+        ty: &Type,
+        generics: &Generics,
+        owner_iri: &str,
+    ) -> std::io::Result<Option<String>> {
+        if let Type::Generic(ref name) = ty {
+            let Some(ordinal) = generics.params.iter().position(|p| &p.name == name) else {
+                return Ok(None);
+            };
+            return Ok(Some(self.iris.type_parameter_iri(owner_iri, ordinal)));
+        }
+        self.resolve_type_to_iri(ty)
+    }
+
+    /// Walk `generics.where_predicates`, emitting bound, associated-type
+    /// equality, and lifetime-outlives edges that `extract_generics` itself
+    /// doesn't see (since where-clauses can constrain types that aren't
+    /// themselves a direct generic parameter, e.g. `T::Item: Clone`).
+    fn extract_where_predicates(&mut self, generics: &Generics, owner_iri: &str) -> std::io::Result<()> {
+        for predicate in &generics.where_predicates {
+            match predicate {
+                WherePredicate::BoundPredicate {
+                    ref type_,
+                    ref bounds,
+                    ref generic_params,
+                } => {
+                    let bounded_iri = match self.bounded_type_iri(type_, generics, owner_iri)? {
+                        Some(iri) => iri,
+                        None => continue,
+                    };
+
+                    // Higher-ranked `for<'a>` lifetime binders on this predicate.
+                    for gp in generic_params {
+                        if let GenericParamDefKind::Lifetime { .. } = gp.kind {
+                            let lt_iri = self.iris.lifetime_iri(&bounded_iri, &gp.name);
+                            self.emitter
+                                .emit_iri(&lt_iri, standard::RDF_TYPE, rt::LIFETIME)?;
+                            self.emitter.emit_literal(&lt_iri, tg::NAME, &gp.name)?;
+                            self.emitter
+                                .emit_iri(&bounded_iri, rt::HAS_LIFETIME, &lt_iri)?;
+                        }
+                    }
+
+                    for bound in bounds {
+                        match bound {
+                            GenericBound::TraitBound { ref trait_, .. } => {
+                                self.extract_trait_bound(&bounded_iri, trait_)?;
+                            }
+                            GenericBound::Outlives(ref lifetime) => {
+                                let lt_iri = self.iris.lifetime_iri(owner_iri, lifetime);
+                                self.emitter
+                                    .emit_iri(&bounded_iri, rt::OUTLIVES, &lt_iri)?;
+                            }
+                            GenericBound::Use(_) => {}
+                        }
+                    }
+                }
+
+                WherePredicate::LifetimePredicate {
+                    ref lifetime,
+                    ref outlives,
+                } => {
+                    let lt_iri = self.iris.lifetime_iri(owner_iri, lifetime);
+                    for target in outlives {
+                        let target_iri = self.iris.lifetime_iri(owner_iri, target);
+                        self.emitter.emit_iri(&lt_iri, rt::OUTLIVES, &target_iri)?;
+                    }
+                }
+
+                WherePredicate::EqPredicate { ref lhs, ref rhs } => {
+                    if let (Some(lhs_iri), Some(rhs_iri)) = (
+                        self.bounded_type_iri(lhs, generics, owner_iri)?,
+                        self.resolve_type_to_iri(rhs)?,
+                    ) {
+                        self.emitter
+                            .emit_iri(&lhs_iri, rt::ASSOC_TYPE_EQUALS, &rhs_iri)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // -----------------------------------------------------------------------
+    // Function signature extraction (Phase 8)
+    // -----------------------------------------------------------------------
+
+    fn extract_function_details(
+        &mut self,
+        fn_iri: &str,
+        sig: &FunctionSignature,
+        generics: &Generics,
+        header: &FunctionHeader,
+    ) -> std::io::Result<()> {
+        // Header flags
+        if header.is_unsafe {
+            self.emitter.emit_bool(fn_iri, rt::IS_UNSAFE, true)?;
+        }
+        if header.is_async {
+            self.emitter.emit_bool(fn_iri, tg::IS_ASYNC, true)?;
+            self.emitter.emit_bool(fn_iri, rt::IS_ASYNC, true)?;
+        }
+        if header.is_const {
+            self.emitter.emit_bool(fn_iri, tg::IS_CONST, true)?;
+            self.emitter.emit_bool(fn_iri, rt::IS_CONST_FN, true)?;
+        }
+
+        // Calling convention. rustdoc omits `abi` for plain Rust functions,
+        // so default to "Rust" (matching the explicit string it reports for
+        // `extern "Rust" fn`) and only flag `rt:isExtern` for anything else.
+        let abi = header.abi.as_deref().unwrap_or("Rust");
+        self.emitter.emit_literal(fn_iri, rt::ABI, abi)?;
+        if abi != "Rust" {
+            self.emitter.emit_bool(fn_iri, rt::IS_EXTERN, true)?;
+        }
+
+        // Generics on the function itself
+        let has_type_params = generics
+            .params
+            .iter()
+            .any(|p| matches!(p.kind, GenericParamDefKind::Type { .. }));
+        if has_type_params {
+            self.emitter.emit_bool(fn_iri, tg::IS_GENERIC, true)?;
+        }
+        self.extract_generics(generics, fn_iri)?;
+
+        // Parameters
+        for (ordinal, (name, ty)) in sig.inputs.iter().enumerate() {
+            // Skip `self` parameters (they don't get a separate parameter node)
+            if name == "self" {
+                continue;
+            }
 
-        match &item.inner {
-            ItemEnum::Function { .. } => {
-                self.extract_type_method(item_id, owner_iri);
+            let param_iri = self.iris.parameter_iri(fn_iri, ordinal);
+            self.emitter
+                .emit_iri(&param_iri, standard::RDF_TYPE, tg::PARAMETER)?;
+            self.emitter.emit_literal(&param_iri, tg::NAME, name)?;
+            self.emitter
+                .emit_int(&param_iri, tg::ORDINAL, ordinal as i64)?;
+            self.emitter
+                .emit_iri(fn_iri, tg::HAS_PARAMETER, &param_iri)?;
+            self.emitter
+                .emit_iri(&param_iri, tg::PARAMETER_OF, fn_iri)?;
+
+            if let Some(type_iri) = self.resolve_type_to_iri(ty)? {
+                self.emitter
+                    .emit_iri(&param_iri, tg::PARAMETER_TYPE, &type_iri)?;
             }
-            ItemEnum::AssocType { .. } | ItemEnum::AssocConst { .. } => {
-                // Associated types and consts — emit minimal info
-                if let Some(ref name) = item.name {
-                    let member_iri = self.iris.member_iri(owner_iri, name, "");
-                    self.emitter
-                        .emit_iri(&member_iri, standard::RDF_TYPE, tg::MEMBER);
-                    self.emitter.emit_literal(&member_iri, tg::NAME, name);
-                    self.emitter
-                        .emit_iri(owner_iri, tg::HAS_MEMBER, &member_iri);
-                }
+        }
+
+        // Return type
+        if let Some(ref ret_type) = sig.output {
+            if let Some(type_iri) = self.resolve_type_to_iri(ret_type)? {
+                self.emitter.emit_iri(fn_iri, tg::RETURN_TYPE, &type_iri)?;
             }
-            _ => {}
         }
+        Ok(())
     }
 
     // -----------------------------------------------------------------------
-    // Derive macro extraction
+    // Error type extraction (Phase 9)
     // -----------------------------------------------------------------------
 
-    fn extract_derives_for_item(&mut self, item_id: &str, type_iri: &str) {
-        // Find impl blocks associated with this type that have `automatically_derived`
-        // in their attrs — these are derive macro impls.
-        let item = match self.crate_data.index.get(item_id) {
-            Some(i) => i,
-            None => return,
-        };
-
-        // Get the impl IDs from the item
-        let impl_ids = match &item.inner {
-            ItemEnum::Struct { ref impls, .. } => {
-                impls.iter().map(|id| id.0.clone()).collect::<Vec<_>>()
+    fn extract_error_type(&mut self, fn_iri: &str, sig: &FunctionSignature) -> std::io::Result<()> {
+        if let Some(Type::ResolvedPath(ref path)) = sig.output {
+            // Check if this is a Result type
+            if path.path == "Result" || path.path.ends_with("::Result") {
+                if let Some(ref args) = path.args {
+                    if let super::rustdoc_model::GenericArgs::AngleBracketed { ref args, .. } =
+                        **args
+                    {
+                        // The second type arg of Result<T, E> is the error type
+                        if args.len() >= 2 {
+                            if let super::rustdoc_model::GenericArg::Type(ref err_type) = args[1] {
+                                if let Some(err_iri) = self.resolve_type_to_iri(err_type)? {
+                                    self.emitter
+                                        .emit_iri(fn_iri, rt::ERROR_TYPE, &err_iri)?;
+                                }
+                            }
+                        }
+                    }
+                }
             }
-            ItemEnum::Enum { ref impls, .. } => {
-                impls.iter().map(|id| id.0.clone()).collect::<Vec<_>>()
+        }
+        Ok(())
+    }
+
+    // -----------------------------------------------------------------------
+    // Type resolution (Phase 8)
+    // -----------------------------------------------------------------------
+
+    fn resolve_type_to_iri(&mut self, ty: &Type) -> std::io::Result<Option<String>> {
+        let iri = match ty {
+            Type::ResolvedPath(ref path) => {
+                let iri = self.resolve_path_to_iri(path);
+                self.emit_complex_path_label(&iri, &path.path)?;
+                Some(iri)
             }
-            _ => return,
-        };
 
-        for imp_id in &impl_ids {
-            let imp_item = match self.crate_data.index.get(imp_id) {
-                Some(i) => i,
-                None => continue,
-            };
+            Type::Primitive(ref name) => Some(self.ensure_primitive_type_emitted(name)?),
 
-            // Check if it's an automatically_derived impl
-            let is_auto_derived = imp_item.attrs.iter().any(|attr| match attr {
-                serde_json::Value::String(s) => s == "automatically_derived",
-                _ => false,
-            });
+            Type::Tuple(ref types) => Some(self.iris.tuple_type_iri(types.len())),
 
-            if !is_auto_derived {
-                continue;
+            Type::Slice(ref inner) => {
+                let elem_name = type_display_name(inner);
+                Some(self.iris.slice_type_iri(&elem_name))
             }
 
-            // Extract the trait name from the impl
-            if let ItemEnum::Impl {
-                trait_: Some(ref trait_path),
-                ..
-            } = imp_item.inner
-            {
-                self.emitter
-                    .emit_literal(type_iri, rt::DERIVES, &trait_path.path);
+            Type::Array {
+                ref type_, ref len, ..
+            } => {
+                let elem_name = type_display_name(type_);
+                Some(self.iris.array_type_iri(&elem_name, len))
             }
-        }
-    }
 
-    // -----------------------------------------------------------------------
-    // Generics extraction (Phase 8)
-    // -----------------------------------------------------------------------
+            Type::RawPointer {
+                is_mutable,
+                ref type_,
+            } => {
+                let target_name = type_display_name(type_);
+                Some(self.iris.raw_pointer_type_iri(&target_name, *is_mutable))
+            }
 
-    fn extract_generics(&mut self, generics: &Generics, owner_iri: &str) {
-        for (ordinal, param) in generics.params.iter().enumerate() {
-            match &param.kind {
-                GenericParamDefKind::Type { ref bounds, .. } => {
-                    let tp_iri = self.iris.type_parameter_iri(owner_iri, ordinal);
-                    self.emitter
-                        .emit_iri(&tp_iri, standard::RDF_TYPE, tg::TYPE_PARAMETER);
-                    self.emitter.emit_literal(&tp_iri, tg::NAME, &param.name);
-                    self.emitter.emit_int(&tp_iri, tg::ORDINAL, ordinal as i64);
-                    self.emitter
-                        .emit_iri(owner_iri, tg::HAS_TYPE_PARAMETER, &tp_iri);
-                    self.emitter
-                        .emit_iri(&tp_iri, tg::TYPE_PARAMETER_OF, owner_iri);
+            Type::BorrowedRef {
+                is_mutable,
+                ref type_,
+                ..
+            } => {
+                let target_name = type_display_name(type_);
+                Some(self.iris.ref_type_iri(&target_name, *is_mutable))
+            }
 
-                    // Trait bounds
+            Type::Generic(_) => {
+                // Generic type parameters reference the owner's type parameter.
+                // We don't mint a separate IRI for them here.
+                None
+            }
+
+            Type::ImplTrait(ref bounds) => {
+                let mut trait_paths: Vec<String> = bounds
+                    .iter()
+                    .filter_map(|bound| match bound {
+                        GenericBound::TraitBound { ref trait_, .. } => Some(trait_.path.clone()),
+                        _ => None,
+                    })
+                    .collect();
+                trait_paths.sort();
+
+                let impl_iri = self.iris.impl_trait_type_iri(&trait_paths);
+                let already_emitted = self.emitted_types.contains(&impl_iri);
+                self.ensure_external_type_emitted(
+                    &impl_iri,
+                    &format!("impl {}", trait_paths.join(" + ")),
+                )?;
+                if !already_emitted {
+                    self.emitter.begin_definition(&format!("{impl_iri}#bounds"));
                     for bound in bounds {
                         if let GenericBound::TraitBound { ref trait_, .. } = bound {
-                            let bound_iri = self.resolve_path_to_iri(trait_);
+                            let trait_iri = self.resolve_path_to_iri(trait_);
+                            self.ensure_external_type_emitted(&trait_iri, &trait_.path)?;
                             self.emitter
-                                .emit_iri(&tp_iri, rt::TRAIT_BOUND, &bound_iri);
-                            self.ensure_external_type_emitted(&bound_iri, &trait_.path);
+                                .emit_iri(&impl_iri, rt::IMPL_TRAIT_BOUND, &trait_iri)?;
                         }
                     }
+                    self.emitter.end_definition();
                 }
-                GenericParamDefKind::Lifetime { .. } => {
-                    let lt_iri = self.iris.lifetime_iri(owner_iri, &param.name);
-                    self.emitter
-                        .emit_iri(&lt_iri, standard::RDF_TYPE, rt::LIFETIME);
-                    self.emitter.emit_literal(&lt_iri, tg::NAME, &param.name);
-                    self.emitter
-                        .emit_iri(owner_iri, rt::HAS_LIFETIME, &lt_iri);
+                Some(impl_iri)
+            }
+
+            Type::DynTrait(ref dyn_trait) => {
+                let mut trait_paths: Vec<String> = dyn_trait
+                    .traits
+                    .iter()
+                    .map(|poly| poly.trait_.path.clone())
+                    .collect();
+                trait_paths.sort();
+
+                let dyn_iri = self
+                    .iris
+                    .dyn_trait_type_iri(&trait_paths, dyn_trait.lifetime.as_deref());
+                let already_emitted = self.emitted_types.contains(&dyn_iri);
+                self.ensure_external_type_emitted(
+                    &dyn_iri,
+                    &format!("dyn {}", trait_paths.join(" + ")),
+                )?;
+                if !already_emitted {
+                    self.emitter.begin_definition(&format!("{dyn_iri}#bounds"));
+                    for poly in &dyn_trait.traits {
+                        let trait_iri = self.resolve_path_to_iri(&poly.trait_);
+                        self.ensure_external_type_emitted(&trait_iri, &poly.trait_.path)?;
+                        self.emitter
+                            .emit_iri(&dyn_iri, rt::DYN_TRAIT_BOUND, &trait_iri)?;
+                    }
+                    self.emitter.end_definition();
                 }
-                GenericParamDefKind::Const { ref type_, .. } => {
-                    let cp_iri = self.iris.type_parameter_iri(owner_iri, ordinal);
+                Some(dyn_iri)
+            }
+
+            Type::QualifiedPath {
+                ref name,
+                ref self_type,
+                ref trait_,
+                ..
+            } => {
+                let Some(self_iri) = self.resolve_type_to_iri(self_type)? else {
+                    return Ok(None);
+                };
+                let trait_name = trait_.as_ref().map_or("_", |t| t.path.as_str());
+                let projection_iri = self.iris.projection_iri(&self_iri, trait_name, name);
+
+                let already_emitted = self.emitted_types.contains(&projection_iri);
+                self.ensure_external_type_emitted(&projection_iri, name)?;
+                if !already_emitted {
                     self.emitter
-                        .emit_iri(&cp_iri, standard::RDF_TYPE, rt::CONST_PARAM);
-                    self.emitter.emit_literal(&cp_iri, tg::NAME, &param.name);
-                    self.emitter.emit_int(&cp_iri, tg::ORDINAL, ordinal as i64);
+                        .begin_definition(&format!("{projection_iri}#bounds"));
                     self.emitter
-                        .emit_iri(owner_iri, tg::HAS_TYPE_PARAMETER, &cp_iri);
-
-                    if let Some(type_iri) = self.resolve_type_to_iri(type_) {
+                        .emit_iri(&projection_iri, rt::PROJECTION_BASE, &self_iri)?;
+                    if let Some(ref trait_path) = trait_ {
+                        let trait_iri = self.resolve_path_to_iri(trait_path);
+                        self.ensure_external_type_emitted(&trait_iri, &trait_path.path)?;
                         self.emitter
-                            .emit_iri(&cp_iri, tg::PARAMETER_TYPE, &type_iri);
+                            .emit_iri(&projection_iri, rt::PROJECTION_TRAIT, &trait_iri)?;
                     }
+                    self.emitter.end_definition();
                 }
-                GenericParamDefKind::Unknown => {}
+                Some(projection_iri)
+            }
+
+            Type::FunctionPointer(_) | Type::Infer | Type::Unknown { .. } => None,
+        };
+        Ok(iri)
+    }
+
+    /// When [`ExtractionOptions::hash_complex_iris`] is set and
+    /// `display_name` is complex enough to have triggered
+    /// [`IriMinter::type_iri`]'s hashed-IRI mode, attach an `rdfs:label`
+    /// triple carrying the original human-readable name -- the hash alone
+    /// doesn't tell a reader that a node is `HashMap<K, V>`.
+    fn emit_complex_path_label(
+        &mut self,
+        type_iri: &str,
+        display_name: &str,
+    ) -> std::io::Result<()> {
+        if !self.options.hash_complex_iris || !IriMinter::is_complex_path(display_name) {
+            return Ok(());
+        }
+        if !self.emitted_types.insert(type_iri.to_string()) {
+            return Ok(());
+        }
+        self.emitter.begin_definition(type_iri);
+        self.emitter
+            .emit_literal(type_iri, standard::RDFS_LABEL, display_name)?;
+        self.emitter.end_definition();
+        Ok(())
+    }
+
+    /// Resolve a [`ResolvedPath`] to an IRI.
+    fn resolve_path_to_iri(&self, path: &ResolvedPath) -> String {
+        // If the item is in our crate's index or paths, build a fully qualified IRI
+        if let Some(ref id) = path.id {
+            // Check paths first (works for both local and external items)
+            if let Some(summary) = self.crate_data.paths.get(&id.0) {
+                let full_path = summary.path.join("::");
+                return self
+                    .iris
+                    .type_iri(&self.crate_name, &self.crate_version, &full_path);
+            }
+            // Check index for local items. Prefer the fully qualified path
+            // recorded while walking the module tree; fall back to the bare
+            // item name only if that item was never walked (e.g. filtered
+            // out by visibility).
+            if let Some(item) = self.crate_data.index.get(&id.0) {
+                if let Some(full_path) = self.item_full_paths.get(&id.0) {
+                    return self
+                        .iris
+                        .type_iri(&self.crate_name, &self.crate_version, full_path);
+                }
+                if let Some(ref name) = item.name {
+                    return self
+                        .iris
+                        .type_iri(&self.crate_name, &self.crate_version, name);
+                }
+            }
+        }
+
+        // Fallback: use the path string directly
+        self.iris
+            .type_iri(&self.crate_name, &self.crate_version, &path.path)
+    }
+
+    /// Ensure a type node has been minimally emitted (for external types).
+    fn ensure_external_type_emitted(&mut self, type_iri: &str, name: &str) -> std::io::Result<()> {
+        if self.emitted_types.contains(type_iri) {
+            return Ok(());
+        }
+        self.emitted_types.insert(type_iri.to_string());
+        self.emitter.begin_definition(type_iri);
+        self.emitter
+            .emit_iri(type_iri, standard::RDF_TYPE, tg::TYPE)?;
+        self.emitter.emit_literal(type_iri, tg::NAME, name)?;
+        self.emitter.end_definition();
+        Ok(())
+    }
+
+    /// Mint `name`'s primitive-type IRI and, unless already emitted or
+    /// [`ExtractionOptions::extract_primitive_metadata`] is off, describe it
+    /// with `rt:PrimitiveType` plus category/width/signedness triples from
+    /// [`primitive_info`].
+    fn ensure_primitive_type_emitted(&mut self, name: &str) -> std::io::Result<String> {
+        let type_iri = self.iris.primitive_type_iri(name);
+        if !self.options.extract_primitive_metadata {
+            return Ok(type_iri);
+        }
+        if !self.emitted_types.insert(type_iri.clone()) {
+            return Ok(type_iri);
+        }
+        self.emitter.begin_definition(&type_iri);
+        if let Some(info) = primitive_info(name) {
+            self.emitter
+                .emit_iri(&type_iri, standard::RDF_TYPE, rt::PRIMITIVE_TYPE)?;
+            self.emitter
+                .emit_literal(&type_iri, rt::PRIMITIVE_CATEGORY, info.category)?;
+            if info.pointer_sized {
+                self.emitter.emit_bool(&type_iri, rt::IS_POINTER_SIZED, true)?;
+            } else if let Some(width) = info.bit_width {
+                self.emitter.emit_int(&type_iri, rt::BIT_WIDTH, width)?;
+            }
+            if let Some(signed) = info.is_signed {
+                self.emitter.emit_bool(&type_iri, rt::IS_SIGNED, signed)?;
             }
         }
+        self.emitter.end_definition();
+        Ok(type_iri)
     }
 
     // -----------------------------------------------------------------------
-    // Function signature extraction (Phase 8)
+    // Conditional compilation (`#[cfg(...)]`)
     // -----------------------------------------------------------------------
 
-    fn extract_function_details(
-        &mut self,
-        fn_iri: &str,
-        sig: &FunctionSignature,
-        generics: &Generics,
-        header: &FunctionHeader,
-    ) {
-        // Header flags
-        if header.is_unsafe {
-            self.emitter.emit_bool(fn_iri, rt::IS_UNSAFE, true);
+    /// The `cfg` an item carries from its own `attrs`, ignoring ambient
+    /// module context.
+    fn own_cfg(&self, item: &Item) -> Cfg {
+        cfg::parse_item_cfg(&item.attrs)
+    }
+
+    /// The ambient `cfg` of a module (conjoined from all of its ancestors),
+    /// or [`Cfg::True`] if it's unconditional or unknown.
+    fn ambient_cfg(&self, module_path: &str) -> Cfg {
+        self.module_cfgs
+            .get(module_path)
+            .cloned()
+            .unwrap_or(Cfg::True)
+    }
+
+    /// Compute `item`'s effective `cfg` (its own, conjoined with its
+    /// module's ambient condition) and emit it on `target_iri` unless
+    /// `include_attributes` is off or the condition is unconditionally true.
+    fn extract_and_emit_cfg(&mut self, item: &Item, module_path: &str, target_iri: &str) -> std::io::Result<()> {
+        if !self.options.include_attributes {
+            return Ok(());
         }
-        if header.is_async {
-            self.emitter.emit_bool(fn_iri, tg::IS_ASYNC, true);
+        let effective = cfg::conjoin(&self.ambient_cfg(module_path), self.own_cfg(item));
+        if !matches!(effective, Cfg::True) {
+            self.emit_cfg(target_iri, &effective)?;
         }
-        if header.is_const {
-            self.emitter.emit_bool(fn_iri, tg::IS_CONST, true);
+        Ok(())
+    }
+
+    /// Emit `rt:cfgCondition` as a canonical string literal plus structured
+    /// triples describing the formula tree, rooted at a node keyed by the
+    /// canonical string (so identical predicates across items share a node).
+    /// Also emits `rt:isCfgGated` (always `true` here -- this is only called
+    /// for a non-`true` effective condition), plus one `rt:requiresFeature`
+    /// per distinct `feature = "..."` and one `rt:targetOnly` per distinct
+    /// non-feature predicate the condition mentions, so a consumer can filter
+    /// the graph down to a configuration without re-parsing `rt:cfgCondition`.
+    fn emit_cfg(&mut self, target_iri: &str, condition: &Cfg) -> std::io::Result<()> {
+        let canonical = cfg::canonical_string(condition);
+        self.emitter
+            .emit_literal(target_iri, rt::CFG_CONDITION, &canonical)?;
+        self.emitter.emit_literal(target_iri, tg::CFG, &canonical)?;
+        self.emitter.emit_bool(target_iri, rt::IS_CFG_GATED, true)?;
+
+        for feature in cfg::referenced_features(condition) {
+            self.emitter
+                .emit_literal(target_iri, rt::REQUIRES_FEATURE, &feature)?;
+        }
+        for predicate in cfg::target_only_predicates(condition) {
+            self.emitter
+                .emit_literal(target_iri, rt::TARGET_ONLY, &predicate)?;
         }
 
-        // Generics on the function itself
-        let has_type_params = generics
-            .params
-            .iter()
-            .any(|p| matches!(p.kind, GenericParamDefKind::Type { .. }));
-        if has_type_params {
-            self.emitter.emit_bool(fn_iri, tg::IS_GENERIC, true);
+        let cfg_iri = self.iris.cfg_iri(&canonical);
+        self.emitter.emit_iri(target_iri, rt::HAS_CFG_NODE, &cfg_iri)?;
+        self.emit_cfg_node(&cfg_iri, condition)?;
+        Ok(())
+    }
+
+    /// Emit a `cfg` condition node's `rdf:type` (`rt:CfgAll`/`CfgAny`/`CfgNot`/
+    /// `CfgOption`) plus its operator-specific triples, recursing into
+    /// operands. Nodes are keyed by canonical string in
+    /// [`emitted_cfg_nodes`](Self::emitted_cfg_nodes), so a guard shared by
+    /// many items is only emitted once.
+    fn emit_cfg_node(&mut self, node_iri: &str, condition: &Cfg) -> std::io::Result<()> {
+        if !self.emitted_cfg_nodes.insert(node_iri.to_string()) {
+            return Ok(());
+        }
+        self.emitter.begin_definition(node_iri);
+        match condition {
+            Cfg::True => self.emitter.emit_literal(node_iri, rt::CFG_OPERATOR, "true")?,
+            Cfg::False => self
+                .emitter
+                .emit_literal(node_iri, rt::CFG_OPERATOR, "false")?,
+            Cfg::Flag(name) => {
+                self.emitter
+                    .emit_iri(node_iri, standard::RDF_TYPE, rt::CFG_OPTION)?;
+                self.emitter
+                    .emit_literal(node_iri, rt::CFG_OPERATOR, "flag")?;
+                self.emitter.emit_literal(node_iri, rt::CFG_FLAG, name)?;
+            }
+            Cfg::NameValue(key, value) => {
+                self.emitter
+                    .emit_iri(node_iri, standard::RDF_TYPE, rt::CFG_OPTION)?;
+                self.emitter
+                    .emit_literal(node_iri, rt::CFG_OPERATOR, "nameValue")?;
+                self.emitter.emit_literal(node_iri, rt::CFG_KEY, key)?;
+                self.emitter.emit_literal(node_iri, rt::CFG_VALUE, value)?;
+            }
+            Cfg::Not(inner) => {
+                self.emitter
+                    .emit_iri(node_iri, standard::RDF_TYPE, rt::CFG_NOT)?;
+                self.emitter
+                    .emit_literal(node_iri, rt::CFG_OPERATOR, "not")?;
+                let operand_iri = self.iris.cfg_iri(&cfg::canonical_string(inner));
+                self.emitter
+                    .emit_iri(node_iri, rt::CFG_OPERAND, &operand_iri)?;
+                self.emit_cfg_node(&operand_iri, inner)?;
+            }
+            Cfg::All(children) => {
+                self.emitter
+                    .emit_iri(node_iri, standard::RDF_TYPE, rt::CFG_ALL)?;
+                self.emitter
+                    .emit_literal(node_iri, rt::CFG_OPERATOR, "all")?;
+                self.emit_cfg_operands(node_iri, children)?;
+            }
+            Cfg::Any(children) => {
+                self.emitter
+                    .emit_iri(node_iri, standard::RDF_TYPE, rt::CFG_ANY)?;
+                self.emitter
+                    .emit_literal(node_iri, rt::CFG_OPERATOR, "any")?;
+                self.emit_cfg_operands(node_iri, children)?;
+            }
+        }
+        self.emitter.end_definition();
+        Ok(())
+    }
+
+    /// Emit `children` as an ordered `rt:cfgOperand` list, preserving the
+    /// source order of the `all(...)`/`any(...)` operands rather than
+    /// flattening them into an unordered multi-valued property -- operand
+    /// order is irrelevant to the formula's truth value, but matters for a
+    /// reader reconstructing the original `cfg(...)` syntax.
+    fn emit_cfg_operands(&mut self, node_iri: &str, children: &[Cfg]) -> std::io::Result<()> {
+        let mut operands = Vec::with_capacity(children.len());
+        for child in children {
+            let operand_iri = self.iris.cfg_iri(&cfg::canonical_string(child));
+            self.emit_cfg_node(&operand_iri, child)?;
+            operands.push(ObjectTerm::Iri(operand_iri));
         }
-        self.extract_generics(generics, fn_iri);
+        self.emitter
+            .emit_collection(node_iri, rt::CFG_OPERAND, &operands)
+    }
+
+    // -----------------------------------------------------------------------
+    // Source location (`span`)
+    // -----------------------------------------------------------------------
+
+    /// Emit `span`-derived location triples on `target_iri`, if the item
+    /// carries one and [`ExtractionOptions::include_spans`] is enabled.
+    /// Macro-generated and foreign items typically have no `span`, in which
+    /// case this emits nothing.
+    fn extract_and_emit_span(&mut self, item: &Item, target_iri: &str) -> std::io::Result<()> {
+        if !self.options.include_spans {
+            return Ok(());
+        }
+        if let Some(ref span) = item.span {
+            self.emit_span(target_iri, span)?;
+        }
+        Ok(())
+    }
+
+    fn emit_span(&mut self, target_iri: &str, span: &Span) -> std::io::Result<()> {
+        self.emitter
+            .emit_literal(target_iri, tg::DEFINED_IN_FILE, &span.filename)?;
+        self.emitter
+            .emit_int(target_iri, rt::LINE_START, span.begin.0 as i64)?;
+        self.emitter
+            .emit_int(target_iri, rt::LINE_END, span.end.0 as i64)?;
+        Ok(())
+    }
+
+    // -----------------------------------------------------------------------
+    // Stability and deprecation
+    // -----------------------------------------------------------------------
 
-        // Parameters
-        for (ordinal, (name, ty)) in sig.inputs.iter().enumerate() {
-            // Skip `self` parameters (they don't get a separate parameter node)
-            if name == "self" {
-                continue;
+    /// Emit deprecation and stability-level triples on `target_iri` from the
+    /// item's structured `deprecation` field and its raw `#[stable]`/
+    /// `#[unstable]` attrs, if present and
+    /// [`ExtractionOptions::extract_stability`] is enabled.
+    fn extract_and_emit_stability(&mut self, item: &Item, target_iri: &str) -> std::io::Result<()> {
+        if !self.options.extract_stability {
+            return Ok(());
+        }
+        if let Some(ref deprecation) = item.deprecation {
+            self.emitter.emit_bool(target_iri, rt::DEPRECATED, true)?;
+            self.emitter.emit_bool(target_iri, tg::DEPRECATED, true)?;
+            if let Some(ref since) = deprecation.since {
+                self.emitter
+                    .emit_literal(target_iri, rt::DEPRECATED_SINCE, since)?;
+                self.emitter
+                    .emit_literal(target_iri, tg::DEPRECATED_SINCE, since)?;
+            }
+            if let Some(ref note) = deprecation.note {
+                self.emitter
+                    .emit_literal(target_iri, rt::DEPRECATION_NOTE, note)?;
+                self.emitter
+                    .emit_literal(target_iri, tg::DEPRECATION_NOTE, note)?;
             }
+        }
 
-            let param_iri = self.iris.parameter_iri(fn_iri, ordinal);
-            self.emitter
-                .emit_iri(&param_iri, standard::RDF_TYPE, tg::PARAMETER);
-            self.emitter.emit_literal(&param_iri, tg::NAME, name);
-            self.emitter
-                .emit_int(&param_iri, tg::ORDINAL, ordinal as i64);
+        if let Some(stability) = stability::parse_item_stability(item) {
             self.emitter
-                .emit_iri(fn_iri, tg::HAS_PARAMETER, &param_iri);
+                .emit_literal(target_iri, rt::STABILITY_LEVEL, &stability.level)?;
+
+            let stability_value = if stability.level == "stable" {
+                "Stable"
+            } else {
+                "Unstable"
+            };
             self.emitter
-                .emit_iri(&param_iri, tg::PARAMETER_OF, fn_iri);
+                .emit_literal(target_iri, tg::STABILITY, stability_value)?;
 
-            if let Some(type_iri) = self.resolve_type_to_iri(ty) {
+            if let Some(ref feature) = stability.feature {
+                self.emitter
+                    .emit_literal(target_iri, rt::FEATURE_GATE, feature)?;
                 self.emitter
-                    .emit_iri(&param_iri, tg::PARAMETER_TYPE, &type_iri);
+                    .emit_literal(target_iri, tg::UNSTABLE_FEATURE, feature)?;
             }
-        }
-
-        // Return type
-        if let Some(ref ret_type) = sig.output {
-            if let Some(type_iri) = self.resolve_type_to_iri(ret_type) {
-                self.emitter.emit_iri(fn_iri, tg::RETURN_TYPE, &type_iri);
+            if let Some(ref since) = stability.since {
+                self.emitter
+                    .emit_literal(target_iri, rt::STABLE_SINCE, since)?;
+                self.emitter
+                    .emit_literal(target_iri, tg::STABLE_SINCE, since)?;
             }
         }
+        Ok(())
     }
 
     // -----------------------------------------------------------------------
-    // Error type extraction (Phase 9)
+    // Documentation comments and intra-doc links
     // -----------------------------------------------------------------------
 
-    fn extract_error_type(&mut self, fn_iri: &str, sig: &FunctionSignature) {
-        if let Some(Type::ResolvedPath(ref path)) = sig.output {
-            // Check if this is a Result type
-            if path.path == "Result" || path.path.ends_with("::Result") {
-                if let Some(ref args) = path.args {
-                    if let super::rustdoc_model::GenericArgs::AngleBracketed { ref args, .. } =
-                        **args
-                    {
-                        // The second type arg of Result<T, E> is the error type
-                        if args.len() >= 2 {
-                            if let super::rustdoc_model::GenericArg::Type(ref err_type) = args[1] {
-                                if let Some(err_iri) = self.resolve_type_to_iri(err_type) {
-                                    self.emitter
-                                        .emit_iri(fn_iri, rt::ERROR_TYPE, &err_iri);
-                                }
-                            }
-                        }
+    /// Emit the item's doc comment as `rdfs:comment`, plus (when
+    /// [`ExtractionOptions::extract_docs`] is enabled) the dedented text as
+    /// a language-tagged `tg:documentation` literal and its first paragraph
+    /// as `tg:summary`. Also emits a `rt:docLink` edge for each intra-doc
+    /// link rustdoc resolved in `item.links`.
+    fn extract_and_emit_docs(&mut self, item: &Item, target_iri: &str) -> std::io::Result<()> {
+        if let Some(ref docs) = item.docs {
+            if !docs.is_empty() {
+                self.emitter
+                    .emit_literal(target_iri, standard::RDFS_COMMENT, docs)?;
+
+                if self.options.extract_docs {
+                    let dedented = dedent(docs);
+                    self.emitter.emit_lang_literal(
+                        target_iri,
+                        tg::DOCUMENTATION,
+                        &dedented,
+                        &self.options.doc_language,
+                    )?;
+                    let summary = first_paragraph(&dedented);
+                    if !summary.is_empty() {
+                        self.emitter.emit_lang_literal(
+                            target_iri,
+                            tg::SUMMARY,
+                            summary,
+                            &self.options.doc_language,
+                        )?;
                     }
                 }
             }
         }
+
+        let link_ids: Vec<Id> = item.links.values().cloned().collect();
+        for id in &link_ids {
+            if let Some(link_iri) = self.resolve_doc_link_target(id) {
+                self.emitter.emit_iri(target_iri, rt::DOC_LINK, &link_iri)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Best-effort resolution of an intra-doc link's target `Id` to the IRI
+    /// we'd mint for that item, mirroring [`Self::resolve_path_to_iri`]:
+    /// check the crate-wide `paths` summary first (covers external items),
+    /// then fall back to the local index.
+    fn resolve_doc_link_target(&self, id: &Id) -> Option<String> {
+        if let Some(summary) = self.crate_data.paths.get(&id.0) {
+            let full_path = summary.path.join("::");
+            return Some(
+                self.iris
+                    .type_iri(&self.crate_name, &self.crate_version, &full_path),
+            );
+        }
+        if let Some(item) = self.crate_data.index.get(&id.0) {
+            if let Some(ref name) = item.name {
+                return Some(
+                    self.iris
+                        .type_iri(&self.crate_name, &self.crate_version, name),
+                );
+            }
+        }
+        None
     }
 
     // -----------------------------------------------------------------------
-    // Type resolution (Phase 8)
+    // Parallel extraction (Phase 11)
     // -----------------------------------------------------------------------
 
-    fn resolve_type_to_iri(&mut self, ty: &Type) -> Option<String> {
-        match ty {
-            Type::ResolvedPath(ref path) => Some(self.resolve_path_to_iri(path)),
+    /// Parallel counterpart of [`walk_module`](Self::walk_module) for the
+    /// crate root: splits the root module's direct children across
+    /// [`ExtractionOptions::jobs`] worker threads, each walking its slice
+    /// into a private, in-memory [`BufferedEmitter`] instead of `self.emitter`
+    /// directly, then replays every worker's buffered triples through
+    /// `self.emitter` -- in the children's original item-id order -- so the
+    /// result is byte-identical to [`walk_module`](Self::walk_module)
+    /// regardless of how many jobs were used. Submodules are still walked
+    /// single-threaded, within whichever worker owns their top-level
+    /// ancestor; only the root's own fan-out is parallelized.
+    ///
+    /// A shared type, trait, or `cfg` condition node referenced from more
+    /// than one worker's slice would otherwise get defined redundantly once
+    /// per worker; [`replay_ops`] collapses those duplicates back down to a
+    /// single definition by honoring the [`TriplesEmitter::begin_definition`]
+    /// scopes the relevant `ensure_*`/`emit_cfg_node` call sites already open.
+    fn walk_root_parallel(&mut self, root_id: &str) -> std::io::Result<()> {
+        let Some(item) = self.crate_data.index.get(root_id) else {
+            return Ok(());
+        };
+        let ItemEnum::Module { ref items, .. } = item.inner else {
+            return self.walk_item(root_id, &self.crate_name.clone());
+        };
+        let child_ids: Vec<String> = items.iter().map(|id| id.0.clone()).collect();
+        let jobs = self.options.jobs.max(1).min(child_ids.len().max(1));
+        if jobs <= 1 || child_ids.len() < 2 {
+            return self.walk_module(root_id, &self.crate_name.clone());
+        }
 
-            Type::Primitive(ref name) => Some(self.iris.primitive_type_iri(name)),
+        let chunks = chunk_evenly(&child_ids, jobs);
+        let crate_data = self.crate_data;
+        let crate_name = self.crate_name.as_str();
+        let crate_version = self.crate_version.as_str();
+        let options = &self.options;
+        let iris = &self.iris;
+        let reachable_ids = &self.reachable_ids;
+        // Root-level children are walked with the crate name itself as their
+        // module path, same as the serial `self.walk_module(root, &self.crate_name)`
+        // call in `extract`.
+        let module_path = crate_name;
+
+        let outputs: Vec<std::io::Result<WorkerOutput>> = std::thread::scope(|scope| {
+            let handles: Vec<_> = chunks
+                .into_iter()
+                .map(|chunk| {
+                    scope.spawn(move || {
+                        extract_chunk(
+                            crate_data,
+                            crate_name,
+                            crate_version,
+                            options,
+                            iris,
+                            reachable_ids,
+                            module_path,
+                            chunk,
+                        )
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|h| {
+                    h.join()
+                        .unwrap_or_else(|_| Err(std::io::Error::other("extraction worker panicked")))
+                })
+                .collect()
+        });
+
+        let mut seen_scopes: HashSet<String> = HashSet::new();
+        let mut blank_map: std::collections::HashMap<String, String> =
+            std::collections::HashMap::new();
+        for output in outputs {
+            let output = output?;
+            self.merge_worker_state(output.state);
+            replay_ops(&output.ops, &mut *self.emitter, &mut blank_map, &mut seen_scopes)?;
+        }
+        Ok(())
+    }
 
-            Type::Tuple(ref types) => Some(self.iris.tuple_type_iri(types.len())),
+    /// Fold a worker's accumulated bookkeeping (populated by
+    /// [`extract_chunk`] walking its slice of the root's children) into
+    /// `self`'s own, so the post-walk passes in [`Self::extract`] (impl
+    /// resolution, auto-trait synthesis, canonical-path resolution, ...) see
+    /// the same state they would have after a single-threaded walk.
+    fn merge_worker_state(&mut self, state: WorkerState) {
+        for (iri, fields) in state.type_fields {
+            self.type_fields.entry(iri).or_insert(fields);
+        }
+        for (iri, params) in state.type_generic_params {
+            self.type_generic_params.entry(iri).or_insert(params);
+        }
+        self.item_full_paths.extend(state.item_full_paths);
+        self.negative_impls.extend(state.negative_impls);
+        self.manual_auto_trait_impls
+            .extend(state.manual_auto_trait_impls);
+        for (iri, traits) in state.implemented_traits {
+            self.implemented_traits
+                .entry(iri)
+                .or_default()
+                .extend(traits);
+        }
+        self.blanket_impls.extend(state.blanket_impls);
+        for (trait_iri, candidates) in state.trait_impls_by_trait {
+            self.trait_impls_by_trait
+                .entry(trait_iri)
+                .or_default()
+                .extend(candidates);
+        }
+        self.emitted_types.extend(state.emitted_types);
+    }
+}
 
-            Type::Slice(ref inner) => {
-                let elem_name = type_display_name(inner);
-                Some(self.iris.slice_type_iri(&elem_name))
-            }
+// ---------------------------------------------------------------------------
+// Parallel extraction support
+// ---------------------------------------------------------------------------
 
-            Type::Array {
-                ref type_, ref len, ..
-            } => {
-                let elem_name = type_display_name(type_);
-                Some(self.iris.array_type_iri(&elem_name, len))
-            }
+/// Split `items` into `jobs` roughly-equal, order-preserving, contiguous
+/// slices (never more than `jobs` of them, never empty unless `items` is).
+/// Used by [`CrateExtractor::walk_root_parallel`] to hand each worker thread
+/// a disjoint run of the root module's children.
+fn chunk_evenly(items: &[String], jobs: usize) -> Vec<&[String]> {
+    if items.is_empty() || jobs == 0 {
+        return Vec::new();
+    }
+    let jobs = jobs.min(items.len());
+    let base = items.len() / jobs;
+    let remainder = items.len() % jobs;
+    let mut chunks = Vec::with_capacity(jobs);
+    let mut start = 0;
+    for i in 0..jobs {
+        let size = base + usize::from(i < remainder);
+        chunks.push(&items[start..start + size]);
+        start += size;
+    }
+    chunks
+}
 
-            Type::RawPointer {
-                is_mutable,
-                ref type_,
-            } => {
-                let target_name = type_display_name(type_);
-                Some(self.iris.raw_pointer_type_iri(&target_name, *is_mutable))
-            }
+/// One triple (or namespace-prefix registration) recorded by a
+/// [`BufferedEmitter`] in place of writing it straight to an output stream.
+/// Subject/object strings that start with `_:` are per-buffer blank-node
+/// placeholders, remapped to freshly-minted real blank nodes by
+/// [`replay_ops`] when the buffer is drained into the real emitter.
+enum RecordedOp {
+    Iri(String, String, String),
+    Literal(String, String, String),
+    TypedLiteral(String, String, String, String),
+    LangLiteral(String, String, String, String),
+    Bool(String, String, bool),
+    Int(String, String, i64),
+    Prefix(String, String),
+    /// A self-contained "definition" opened by
+    /// [`TriplesEmitter::begin_definition`]/`end_definition`: when
+    /// [`replay_ops`] encounters one whose `key` it has already replayed
+    /// (from an earlier worker's buffer), it skips the whole thing --
+    /// including any blank nodes nested inside -- instead of writing a
+    /// second, redundant copy of a shared node.
+    Scope { key: String, ops: Vec<RecordedOp> },
+}
 
-            Type::BorrowedRef {
-                is_mutable,
-                ref type_,
-                ..
-            } => {
-                let target_name = type_display_name(type_);
-                Some(self.iris.ref_type_iri(&target_name, *is_mutable))
-            }
+/// An in-memory [`TriplesEmitter`] that records every call instead of
+/// serializing it, so a worker thread in [`CrateExtractor::walk_root_parallel`]
+/// can run the ordinary extraction code against its slice of items and have
+/// the result merged into the real output afterward. Blank nodes are given
+/// buffer-local placeholder labels (unique per instance via `worker_tag`),
+/// not real ones -- [`replay_ops`] mints the real labels, in final replay
+/// order, when the buffer is drained.
+struct BufferedEmitter {
+    worker_tag: String,
+    scopes: Vec<Vec<RecordedOp>>,
+    pending_keys: Vec<String>,
+    triple_count: u64,
+    blank_counter: u64,
+}
 
-            Type::Generic(_) => {
-                // Generic type parameters reference the owner's type parameter.
-                // We don't mint a separate IRI for them here.
-                None
-            }
+impl BufferedEmitter {
+    fn new(worker_tag: &str) -> Self {
+        Self {
+            worker_tag: worker_tag.to_string(),
+            scopes: vec![Vec::new()],
+            pending_keys: Vec::new(),
+            triple_count: 0,
+            blank_counter: 0,
+        }
+    }
 
-            Type::ImplTrait(_) | Type::DynTrait(_) | Type::QualifiedPath { .. } => {
-                // Complex types — skip for now
-                None
-            }
+    fn push(&mut self, op: RecordedOp) {
+        self.scopes
+            .last_mut()
+            .expect("BufferedEmitter always has at least one open scope")
+            .push(op);
+    }
 
-            Type::FunctionPointer(_) | Type::Infer | Type::Unknown => None,
-        }
+    /// Consume the buffer, returning its top-level recorded ops. Panics if a
+    /// `begin_definition` was never matched by `end_definition` -- every
+    /// call site that opens one always closes it before returning.
+    fn into_ops(mut self) -> Vec<RecordedOp> {
+        assert_eq!(
+            self.scopes.len(),
+            1,
+            "BufferedEmitter dropped with an unclosed begin_definition scope"
+        );
+        self.scopes.pop().unwrap_or_default()
     }
+}
 
-    /// Resolve a [`ResolvedPath`] to an IRI.
-    fn resolve_path_to_iri(&self, path: &ResolvedPath) -> String {
-        // If the item is in our crate's index or paths, build a fully qualified IRI
-        if let Some(ref id) = path.id {
-            // Check paths first (works for both local and external items)
-            if let Some(summary) = self.crate_data.paths.get(&id.0) {
-                let full_path = summary.path.join("::");
-                return self
-                    .iris
-                    .type_iri(&self.crate_name, &self.crate_version, &full_path);
-            }
-            // Check index for local items
-            if let Some(item) = self.crate_data.index.get(&id.0) {
-                if let Some(ref name) = item.name {
-                    return self
-                        .iris
-                        .type_iri(&self.crate_name, &self.crate_version, name);
-                }
-            }
-        }
+impl TriplesEmitter for BufferedEmitter {
+    fn emit_iri(&mut self, subject: &str, predicate: &str, object: &str) -> std::io::Result<()> {
+        self.triple_count += 1;
+        self.push(RecordedOp::Iri(
+            subject.to_string(),
+            predicate.to_string(),
+            object.to_string(),
+        ));
+        Ok(())
+    }
 
-        // Fallback: use the path string directly
-        self.iris
-            .type_iri(&self.crate_name, &self.crate_version, &path.path)
+    fn emit_literal(&mut self, subject: &str, predicate: &str, value: &str) -> std::io::Result<()> {
+        self.triple_count += 1;
+        self.push(RecordedOp::Literal(
+            subject.to_string(),
+            predicate.to_string(),
+            value.to_string(),
+        ));
+        Ok(())
     }
 
-    /// Ensure a type node has been minimally emitted (for external types).
-    fn ensure_external_type_emitted(&mut self, type_iri: &str, name: &str) {
-        if self.emitted_types.contains(type_iri) {
-            return;
+    fn emit_typed_literal(
+        &mut self,
+        subject: &str,
+        predicate: &str,
+        value: &str,
+        datatype: &str,
+    ) -> std::io::Result<()> {
+        self.triple_count += 1;
+        self.push(RecordedOp::TypedLiteral(
+            subject.to_string(),
+            predicate.to_string(),
+            value.to_string(),
+            datatype.to_string(),
+        ));
+        Ok(())
+    }
+
+    fn emit_lang_literal(
+        &mut self,
+        subject: &str,
+        predicate: &str,
+        value: &str,
+        lang: &str,
+    ) -> std::io::Result<()> {
+        self.triple_count += 1;
+        self.push(RecordedOp::LangLiteral(
+            subject.to_string(),
+            predicate.to_string(),
+            value.to_string(),
+            lang.to_string(),
+        ));
+        Ok(())
+    }
+
+    fn emit_bool(&mut self, subject: &str, predicate: &str, value: bool) -> std::io::Result<()> {
+        self.triple_count += 1;
+        self.push(RecordedOp::Bool(
+            subject.to_string(),
+            predicate.to_string(),
+            value,
+        ));
+        Ok(())
+    }
+
+    fn emit_int(&mut self, subject: &str, predicate: &str, value: i64) -> std::io::Result<()> {
+        self.triple_count += 1;
+        self.push(RecordedOp::Int(
+            subject.to_string(),
+            predicate.to_string(),
+            value,
+        ));
+        Ok(())
+    }
+
+    fn add_prefix(&mut self, prefix: &str, iri: &str) -> std::io::Result<()> {
+        self.push(RecordedOp::Prefix(prefix.to_string(), iri.to_string()));
+        Ok(())
+    }
+
+    fn fresh_blank_node(&mut self) -> String {
+        self.blank_counter += 1;
+        format!("_:w{}_{}", self.worker_tag, self.blank_counter)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn triple_count(&self) -> u64 {
+        self.triple_count
+    }
+
+    fn begin_definition(&mut self, key: &str) {
+        self.scopes.push(Vec::new());
+        self.pending_keys.push(key.to_string());
+    }
+
+    fn end_definition(&mut self) {
+        let ops = self
+            .scopes
+            .pop()
+            .expect("end_definition without a matching begin_definition");
+        let key = self
+            .pending_keys
+            .pop()
+            .expect("end_definition without a matching begin_definition");
+        self.push(RecordedOp::Scope { key, ops });
+    }
+}
+
+/// Crate-wide bookkeeping a worker accumulates while walking its slice of
+/// the root module's children, destined for
+/// [`CrateExtractor::merge_worker_state`]. Mirrors the subset of
+/// [`CrateExtractor`]'s own fields that later passes in
+/// [`CrateExtractor::extract`] (impl resolution, auto-trait synthesis,
+/// canonicalization, ...) read back out after the walk completes.
+struct WorkerState {
+    type_fields: std::collections::HashMap<String, Vec<Type>>,
+    type_generic_params: std::collections::HashMap<String, Vec<GenericParamDef>>,
+    item_full_paths: std::collections::HashMap<String, String>,
+    negative_impls: HashSet<(String, String)>,
+    manual_auto_trait_impls: HashSet<(String, String)>,
+    implemented_traits: std::collections::HashMap<String, HashSet<String>>,
+    blanket_impls: Vec<(String, HashSet<String>)>,
+    trait_impls_by_trait: std::collections::HashMap<String, Vec<SpecializationCandidate>>,
+    emitted_types: HashSet<String>,
+}
+
+/// What one [`extract_chunk`] worker hands back to
+/// [`CrateExtractor::walk_root_parallel`]: its buffered triples plus the
+/// bookkeeping to fold into the main extractor.
+struct WorkerOutput {
+    ops: Vec<RecordedOp>,
+    state: WorkerState,
+}
+
+/// Walk `child_ids` (a slice of the root module's children) into a fresh,
+/// private [`CrateExtractor`] backed by a [`BufferedEmitter`], sharing
+/// `crate_data`/`options`/`iris`/`reachable_ids` with the caller. Runs on a
+/// worker thread spawned by [`CrateExtractor::walk_root_parallel`].
+#[allow(clippy::too_many_arguments)]
+fn extract_chunk(
+    crate_data: &Crate,
+    crate_name: &str,
+    crate_version: &str,
+    options: &ExtractionOptions,
+    iris: &IriMinter,
+    reachable_ids: &Option<HashSet<String>>,
+    module_path: &str,
+    child_ids: &[String],
+) -> std::io::Result<WorkerOutput> {
+    // Tag blank-node placeholders with the first child id so two workers'
+    // buffers never mint the same placeholder label.
+    let worker_tag = child_ids.first().map(String::as_str).unwrap_or("0");
+    let mut buffered = BufferedEmitter::new(worker_tag);
+    let mut worker = CrateExtractor::new(&mut buffered, crate_data, options.clone());
+    worker.crate_name = crate_name.to_string();
+    worker.crate_version = crate_version.to_string();
+    worker.iris = iris.clone();
+    worker.reachable_ids = reachable_ids.clone();
+    for child_id in child_ids {
+        worker.walk_item(child_id, module_path)?;
+    }
+    let state = WorkerState {
+        type_fields: worker.type_fields,
+        type_generic_params: worker.type_generic_params,
+        item_full_paths: worker.item_full_paths,
+        negative_impls: worker.negative_impls,
+        manual_auto_trait_impls: worker.manual_auto_trait_impls,
+        implemented_traits: worker.implemented_traits,
+        blanket_impls: worker.blanket_impls,
+        trait_impls_by_trait: worker.trait_impls_by_trait,
+        emitted_types: worker.emitted_types,
+    };
+    Ok(WorkerOutput {
+        ops: buffered.into_ops(),
+        state,
+    })
+}
+
+/// Replace a [`BufferedEmitter`] placeholder blank node (`_:w<tag>_<n>`)
+/// with the real emitter's own freshly-minted label the first time it's
+/// seen during replay, reusing that mapping for every later reference to
+/// the same placeholder.
+fn resolve_blank(
+    term: &str,
+    blank_map: &mut std::collections::HashMap<String, String>,
+    emitter: &mut impl TriplesEmitter,
+) -> String {
+    if !term.starts_with("_:") {
+        return term.to_string();
+    }
+    blank_map
+        .entry(term.to_string())
+        .or_insert_with(|| emitter.fresh_blank_node())
+        .clone()
+}
+
+/// Drain a worker's recorded ops into the real `emitter`, in order,
+/// resolving blank-node placeholders via `blank_map` and collapsing
+/// `Scope`s keyed in `seen_scopes` down to their first occurrence -- the
+/// step that makes [`CrateExtractor::walk_root_parallel`]'s output
+/// byte-identical to a single-threaded walk.
+fn replay_ops(
+    ops: &[RecordedOp],
+    emitter: &mut impl TriplesEmitter,
+    blank_map: &mut std::collections::HashMap<String, String>,
+    seen_scopes: &mut HashSet<String>,
+) -> std::io::Result<()> {
+    for op in ops {
+        match op {
+            RecordedOp::Iri(s, p, o) => {
+                let (s, o) = (
+                    resolve_blank(s, blank_map, emitter),
+                    resolve_blank(o, blank_map, emitter),
+                );
+                emitter.emit_iri(&s, p, &o)?;
+            }
+            RecordedOp::Literal(s, p, v) => {
+                let s = resolve_blank(s, blank_map, emitter);
+                emitter.emit_literal(&s, p, v)?;
+            }
+            RecordedOp::TypedLiteral(s, p, v, dt) => {
+                let s = resolve_blank(s, blank_map, emitter);
+                emitter.emit_typed_literal(&s, p, v, dt)?;
+            }
+            RecordedOp::LangLiteral(s, p, v, lang) => {
+                let s = resolve_blank(s, blank_map, emitter);
+                emitter.emit_lang_literal(&s, p, v, lang)?;
+            }
+            RecordedOp::Bool(s, p, v) => {
+                let s = resolve_blank(s, blank_map, emitter);
+                emitter.emit_bool(&s, p, *v)?;
+            }
+            RecordedOp::Int(s, p, v) => {
+                let s = resolve_blank(s, blank_map, emitter);
+                emitter.emit_int(&s, p, *v)?;
+            }
+            RecordedOp::Prefix(prefix, iri) => {
+                emitter.add_prefix(prefix, iri)?;
+            }
+            RecordedOp::Scope { key, ops } => {
+                if !seen_scopes.insert(key.clone()) {
+                    continue;
+                }
+                replay_ops(ops, emitter, blank_map, seen_scopes)?;
+            }
         }
-        self.emitted_types.insert(type_iri.to_string());
-        self.emitter
-            .emit_iri(type_iri, standard::RDF_TYPE, tg::TYPE);
-        self.emitter.emit_literal(type_iri, tg::NAME, name);
     }
+    Ok(())
 }
 
 // ---------------------------------------------------------------------------
 // Free functions
 // ---------------------------------------------------------------------------
 
+/// Strip the longest common leading-whitespace prefix shared by every
+/// non-empty line, mirroring rustdoc's own doc-fragment dedenting so
+/// indentation picked up from a `/** ... */`-style block comment doesn't
+/// leak into the emitted text.
+fn dedent(docs: &str) -> String {
+    let common_indent = docs
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    docs.lines()
+        .map(|line| {
+            if line.trim().is_empty() {
+                ""
+            } else {
+                &line[common_indent..]
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The first paragraph of a doc comment: everything up to the first blank
+/// line, used as a short one-line summary.
+fn first_paragraph(docs: &str) -> &str {
+    match docs.find("\n\n") {
+        Some(idx) => docs[..idx].trim(),
+        None => docs.trim(),
+    }
+}
+
+/// Fixed metadata for one of Rust's primitive type names.
+struct PrimitiveInfo {
+    category: &'static str,
+    bit_width: Option<i64>,
+    is_signed: Option<bool>,
+    pointer_sized: bool,
+}
+
+/// Look up a primitive's category/width/signedness from a fixed table.
+/// Returns `None` for anything not a recognized Rust primitive name.
+fn primitive_info(name: &str) -> Option<PrimitiveInfo> {
+    let (category, bit_width, is_signed, pointer_sized) = match name {
+        "i8" => ("signed-integer", Some(8), Some(true), false),
+        "i16" => ("signed-integer", Some(16), Some(true), false),
+        "i32" => ("signed-integer", Some(32), Some(true), false),
+        "i64" => ("signed-integer", Some(64), Some(true), false),
+        "i128" => ("signed-integer", Some(128), Some(true), false),
+        "isize" => ("signed-integer", None, Some(true), true),
+        "u8" => ("unsigned-integer", Some(8), Some(false), false),
+        "u16" => ("unsigned-integer", Some(16), Some(false), false),
+        "u32" => ("unsigned-integer", Some(32), Some(false), false),
+        "u64" => ("unsigned-integer", Some(64), Some(false), false),
+        "u128" => ("unsigned-integer", Some(128), Some(false), false),
+        "usize" => ("unsigned-integer", None, Some(false), true),
+        "f32" => ("float", Some(32), None, false),
+        "f64" => ("float", Some(64), None, false),
+        "bool" => ("bool", Some(8), None, false),
+        "char" => ("char", Some(32), None, false),
+        "str" => ("str", None, None, false),
+        "unit" => ("unit", None, None, false),
+        "never" => ("never", None, None, false),
+        _ => return None,
+    };
+    Some(PrimitiveInfo {
+        category,
+        bit_width,
+        is_signed,
+        pointer_sized,
+    })
+}
+
 /// Map a [`Visibility`] to a display string.
 fn visibility_str(vis: &Visibility) -> &'static str {
     match vis {
@@ -1270,10 +4052,19 @@ fn visibility_str(vis: &Visibility) -> &'static str {
 }
 
 /// Get a human-readable display name for a type (used for composite type IRIs).
-fn type_display_name(ty: &Type) -> String {
+///
+/// `pub(crate)` so [`crate::display_cache`] can memoize it without
+/// duplicating this match.
+pub(crate) fn type_display_name(ty: &Type) -> String {
     match ty {
         Type::Primitive(name) => name.clone(),
-        Type::ResolvedPath(path) => path.path.clone(),
+        Type::ResolvedPath(path) => {
+            let mut name = path.path.clone();
+            if let Some(ref args) = path.args {
+                name.push_str(&generic_args_display_name(args));
+            }
+            name
+        }
         Type::Generic(name) => name.clone(),
         Type::Tuple(types) => {
             let parts: Vec<String> = types.iter().map(type_display_name).collect();
@@ -1301,6 +4092,267 @@ fn type_display_name(ty: &Type) -> String {
                 format!("*const {}", type_display_name(type_))
             }
         }
-        _ => "unknown".to_string(),
+        Type::FunctionPointer(fp) => {
+            let params: Vec<String> = fp
+                .sig
+                .inputs
+                .iter()
+                .map(|(_, param_ty)| type_display_name(param_ty))
+                .collect();
+            match fp.sig.output {
+                Some(ref ret) => format!("fn({}) -> {}", params.join(","), type_display_name(ret)),
+                None => format!("fn({})", params.join(",")),
+            }
+        }
+        Type::DynTrait(dyn_trait) => {
+            let parts: Vec<String> = dyn_trait
+                .traits
+                .iter()
+                .map(|poly| poly.trait_.path.clone())
+                .collect();
+            format!("dyn {}", parts.join(" + "))
+        }
+        Type::ImplTrait(bounds) => {
+            let parts: Vec<String> = bounds
+                .iter()
+                .filter_map(|bound| match bound {
+                    GenericBound::TraitBound { ref trait_, .. } => Some(trait_.path.clone()),
+                    GenericBound::Outlives(ref lifetime) => Some(lifetime.clone()),
+                    GenericBound::Use(_) => None,
+                })
+                .collect();
+            format!("impl {}", parts.join(" + "))
+        }
+        Type::QualifiedPath {
+            name,
+            self_type,
+            trait_,
+            ..
+        } => {
+            let self_name = type_display_name(self_type);
+            match trait_ {
+                Some(ref trait_path) => {
+                    format!("<{} as {}>::{}", self_name, trait_path.path, name)
+                }
+                None => format!("{self_name}::{name}"),
+            }
+        }
+        Type::Infer => "_".to_string(),
+        Type::Unknown { .. } => "unknown".to_string(),
+    }
+}
+
+/// Render a [`GenericArgs`] as it would appear source-side: `<A,B>` for
+/// angle-bracketed args, or `(A,B) -> C` for the parenthesized form used by
+/// `Fn`-family trait bounds.
+fn generic_args_display_name(args: &GenericArgs) -> String {
+    match args {
+        GenericArgs::AngleBracketed { args, .. } => {
+            if args.is_empty() {
+                return String::new();
+            }
+            let parts: Vec<String> = args.iter().map(generic_arg_display_name).collect();
+            format!("<{}>", parts.join(","))
+        }
+        GenericArgs::Parenthesized { inputs, output } => {
+            let parts: Vec<String> = inputs.iter().map(type_display_name).collect();
+            match output {
+                Some(ref ret) => format!("({}) -> {}", parts.join(","), type_display_name(ret)),
+                None => format!("({})", parts.join(",")),
+            }
+        }
+    }
+}
+
+fn generic_arg_display_name(arg: &GenericArg) -> String {
+    match arg {
+        GenericArg::Lifetime(lifetime) => lifetime.clone(),
+        GenericArg::Type(ty) => type_display_name(ty),
+        GenericArg::Const(value) => value.value.clone().unwrap_or_default(),
+        GenericArg::Infer => "_".to_string(),
+    }
+}
+
+/// Built-in derive-name -> fully-qualified-trait-path registry, consulted by
+/// [`CrateExtractor::emit_derived_impl`] when rustdoc didn't give the
+/// auto-derived impl's trait an `Id` to resolve directly. Covers the std
+/// derivable traits plus the `derive_more` family, which third-party derive
+/// crates can extend further via [`ExtractionOptions::extra_derive_traits`].
+const DERIVE_TRAIT_REGISTRY: &[(&str, &str)] = &[
+    ("Debug", "core::fmt::Debug"),
+    ("Clone", "core::clone::Clone"),
+    ("Copy", "core::marker::Copy"),
+    ("PartialEq", "core::cmp::PartialEq"),
+    ("Eq", "core::cmp::Eq"),
+    ("Hash", "core::hash::Hash"),
+    ("Default", "core::default::Default"),
+    ("PartialOrd", "core::cmp::PartialOrd"),
+    ("Ord", "core::cmp::Ord"),
+    // `derive_more` family
+    ("From", "core::convert::From"),
+    ("Into", "core::convert::Into"),
+    ("Display", "core::fmt::Display"),
+    ("Deref", "core::ops::Deref"),
+    ("DerefMut", "core::ops::DerefMut"),
+    ("FromStr", "core::str::FromStr"),
+    ("Index", "core::ops::Index"),
+    ("IsVariant", "derive_more::IsVariant"),
+];
+
+/// Resolve a derive name to its trait's fully-qualified path via the
+/// user-registered `extra` mappings first, falling back to the built-in
+/// [`DERIVE_TRAIT_REGISTRY`] -- so an `extra` entry overrides a built-in one.
+fn lookup_derive_trait(
+    derive_name: &str,
+    extra: &std::collections::HashMap<String, String>,
+) -> Option<String> {
+    if let Some(path) = extra.get(derive_name) {
+        return Some(path.clone());
+    }
+    DERIVE_TRAIT_REGISTRY
+        .iter()
+        .find(|(name, _)| *name == derive_name)
+        .map(|(_, path)| path.to_string())
+}
+
+/// The four structural auto traits [`CrateExtractor::infer_auto_traits`]
+/// computes, paired with the fully-qualified path their IRI is minted under.
+const AUTO_TRAITS: &[(&str, &str)] = &[
+    ("Send", "core::marker::Send"),
+    ("Sync", "core::marker::Sync"),
+    ("Unpin", "core::marker::Unpin"),
+    ("UnwindSafe", "core::panic::UnwindSafe"),
+];
+
+/// Outcome of inferring whether a type implements a given auto trait.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum AutoTraitStatus {
+    /// Definitely does not implement the trait.
+    Blocked,
+    /// Can't be determined from what we can see (an external or otherwise
+    /// opaque field type) — too conservative to assert either way.
+    Unknown,
+    /// Implements the trait only if every named type parameter does too
+    /// (e.g. `Foo<T>` is `Send` iff `T: Send`).
+    Conditional(std::collections::BTreeSet<String>),
+    /// Every field type implements the trait, so this type does too.
+    Implemented,
+}
+
+impl AutoTraitStatus {
+    /// Combine the statuses of a type's field types: blocked wins over
+    /// unknown, which wins over conditional, which wins over implemented.
+    /// Two conditional statuses merge their parameter sets, since the type
+    /// is conditional on *all* of them together.
+    fn combine(acc: Self, next: Self) -> Self {
+        match (acc, next) {
+            (Self::Blocked, _) | (_, Self::Blocked) => Self::Blocked,
+            (Self::Unknown, _) | (_, Self::Unknown) => Self::Unknown,
+            (Self::Conditional(mut a), Self::Conditional(b)) => {
+                a.extend(b);
+                Self::Conditional(a)
+            }
+            (Self::Conditional(params), Self::Implemented)
+            | (Self::Implemented, Self::Conditional(params)) => Self::Conditional(params),
+            (Self::Implemented, Self::Implemented) => Self::Implemented,
+        }
+    }
+}
+
+/// Field types that structurally block an auto trait regardless of their own
+/// fields, mirroring a handful of std types the compiler special-cases.
+fn structural_auto_trait_blockers(ty: &Type) -> &'static [&'static str] {
+    match ty {
+        Type::RawPointer { .. } => &["Send", "Sync"],
+        Type::ResolvedPath(path) => match last_path_segment(&path.path) {
+            "Rc" => &["Send", "Sync"],
+            "RefCell" | "Cell" => &["Sync"],
+            _ => &[],
+        },
+        _ => &[],
+    }
+}
+
+/// The final `::`-separated segment of a type path, e.g. `"Rc"` from
+/// `"alloc::rc::Rc"`.
+fn last_path_segment(path: &str) -> &str {
+    path.rsplit("::").next().unwrap_or(path)
+}
+
+/// Add `id` to the doc-reachability worklist/set if it isn't already there
+/// (see [`CrateExtractor::compute_reachable_ids`]).
+fn enqueue(
+    id: &str,
+    reachable: &mut HashSet<String>,
+    worklist: &mut std::collections::VecDeque<String>,
+) {
+    if reachable.insert(id.to_string()) {
+        worklist.push_back(id.to_string());
+    }
+}
+
+/// Walk `ty`, enqueueing the item id of every local type it references
+/// (directly, or nested inside tuples/slices/arrays/refs/generic args) as
+/// doc-reachable -- this is what lets a private type leaked through a public
+/// signature still make it into the reachable set.
+fn enqueue_type_refs(
+    ty: &Type,
+    reachable: &mut HashSet<String>,
+    worklist: &mut std::collections::VecDeque<String>,
+) {
+    match ty {
+        Type::ResolvedPath(path) => {
+            if let Some(ref id) = path.id {
+                enqueue(&id.0, reachable, worklist);
+            }
+            if let Some(ref args) = path.args {
+                enqueue_generic_args_refs(args, reachable, worklist);
+            }
+        }
+        Type::Tuple(types) => {
+            for inner in types {
+                enqueue_type_refs(inner, reachable, worklist);
+            }
+        }
+        Type::Slice(inner) | Type::RawPointer { type_: inner, .. } => {
+            enqueue_type_refs(inner, reachable, worklist);
+        }
+        Type::Array { type_, .. } | Type::BorrowedRef { type_, .. } => {
+            enqueue_type_refs(type_, reachable, worklist);
+        }
+        Type::QualifiedPath { self_type, .. } => {
+            enqueue_type_refs(self_type, reachable, worklist);
+        }
+        Type::Primitive(_)
+        | Type::Generic(_)
+        | Type::FunctionPointer(_)
+        | Type::ImplTrait(_)
+        | Type::DynTrait(_)
+        | Type::Infer
+        | Type::Unknown { .. } => {}
+    }
+}
+
+fn enqueue_generic_args_refs(
+    args: &GenericArgs,
+    reachable: &mut HashSet<String>,
+    worklist: &mut std::collections::VecDeque<String>,
+) {
+    match args {
+        GenericArgs::AngleBracketed { args, .. } => {
+            for arg in args {
+                if let GenericArg::Type(ref ty) = arg {
+                    enqueue_type_refs(ty, reachable, worklist);
+                }
+            }
+        }
+        GenericArgs::Parenthesized { inputs, output } => {
+            for input in inputs {
+                enqueue_type_refs(input, reachable, worklist);
+            }
+            if let Some(ref ty) = output {
+                enqueue_type_refs(ty, reachable, worklist);
+            }
+        }
     }
 }
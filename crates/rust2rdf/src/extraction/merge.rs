@@ -0,0 +1,419 @@
+//! Merge several [`Crate`]s -- each potentially referencing the others via
+//! `external_crates` -- into one unified index.
+//!
+//! Every crate numbers its own items independently, so raw `Id`s collide
+//! the instant more than one crate shares an index. [`merge_crates`] first
+//! rewrites every `Id` into a `<crate_name>::<original_id>` namespace, then
+//! resolves each crate's own cross-crate `paths` entries -- matched by full
+//! dotted path, since rustdoc's numbering gives us nothing comparable across
+//! crates -- to either the real item's rewritten `Id` (if that crate was
+//! also supplied) or a docs URL synthesized from `ExternalCrate::html_root_url`
+//! plus the `ItemSummary`'s path and kind.
+
+use std::collections::HashMap;
+
+use super::rustdoc_model::{
+    Crate, FunctionSignature, GenericArg, GenericArgs, GenericBound, GenericParamDef,
+    GenericParamDefKind, Generics, Id, Item, ItemEnum, ItemKind, ResolvedPath, StructKind, Type,
+    TypeBindingKind, VariantKind, WherePredicate,
+};
+
+/// One crate fed into [`merge_crates`].
+pub struct MergeInput {
+    pub crate_name: String,
+    pub crate_data: Crate,
+}
+
+/// How a `paths` entry that points outside its own crate was resolved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExternalResolution {
+    /// The referencing crate's `paths` entry matched an item actually
+    /// supplied to `merge_crates`; this is that item's rewritten `Id`.
+    Linked(Id),
+    /// No crate supplying this item was given; a docs URL synthesized from
+    /// `html_root_url` plus the item's path and kind.
+    DocsUrl(String),
+}
+
+/// The result of [`merge_crates`]: one [`Crate`] whose `index`/`paths` union
+/// every input crate's own (`Id`s rewritten into a collision-free namespace),
+/// plus how each originally cross-crate `paths` entry resolved.
+pub struct MergedCrate {
+    pub crate_data: Crate,
+    /// Each input crate's own root module, rewritten, keyed by crate name.
+    /// `crate_data.root` alone can't represent a multi-crate merge, since
+    /// [`Crate`] models a single root.
+    pub crate_roots: HashMap<String, Id>,
+    /// Keyed by the rewritten `Id` a cross-crate `paths` entry was found
+    /// under (namespaced the same way, so entries from different source
+    /// crates can't collide).
+    pub external_resolutions: HashMap<Id, ExternalResolution>,
+}
+
+/// Prefix `id` with `crate_name` so the same id minted independently by two
+/// different crates never collides once merged.
+fn rewrite_id(crate_name: &str, id: &Id) -> Id {
+    Id(format!("{crate_name}::{}", id.0))
+}
+
+/// Rewrite every `Id` an [`Item`] carries -- its own id, the `Id`s in
+/// whatever list contains its children, its doc `links`, and every `Id`
+/// embedded in a [`Type::ResolvedPath`] reachable from its generics, field
+/// types, bounds, or impl header -- into `crate_name`'s namespace. The
+/// container-list walk mirrors [`super::prune::prune_private_items`]; the
+/// `Type` walk is separate because a `ResolvedPath` can be nested arbitrarily
+/// deep (tuples, refs, generic args, dyn trait objects, ...), unlike the
+/// flat `Id` lists pruning only needs to keep consistent.
+fn rewrite_item_ids(crate_name: &str, item: &mut Item) {
+    if let Some(id) = &mut item.id {
+        *id = rewrite_id(crate_name, id);
+    }
+    for id in item.links.values_mut() {
+        *id = rewrite_id(crate_name, id);
+    }
+
+    let rewrite_list = |ids: &mut Vec<Id>| {
+        for id in ids.iter_mut() {
+            *id = rewrite_id(crate_name, id);
+        }
+    };
+    let rewrite_opt_list = |ids: &mut Vec<Option<Id>>| {
+        for id in ids.iter_mut().flatten() {
+            *id = rewrite_id(crate_name, id);
+        }
+    };
+
+    match &mut item.inner {
+        ItemEnum::Module { items, .. } => rewrite_list(items),
+        ItemEnum::Struct { kind, generics, .. } => {
+            match kind {
+                StructKind::Plain { fields, .. } => rewrite_list(fields),
+                StructKind::Tuple(fields) => rewrite_opt_list(fields),
+                StructKind::Unit => {}
+            }
+            rewrite_generics_ids(crate_name, generics);
+        }
+        ItemEnum::Union { fields, generics, .. } => {
+            rewrite_list(fields);
+            rewrite_generics_ids(crate_name, generics);
+        }
+        ItemEnum::Enum { variants, generics, .. } => {
+            rewrite_list(variants);
+            rewrite_generics_ids(crate_name, generics);
+        }
+        ItemEnum::Variant(variant) => match &mut variant.kind {
+            VariantKind::Struct { fields, .. } => rewrite_list(fields),
+            VariantKind::Tuple(fields) => rewrite_opt_list(fields),
+            VariantKind::Plain => {}
+        },
+        ItemEnum::Function { sig, generics, .. } => {
+            rewrite_function_signature_ids(crate_name, sig);
+            rewrite_generics_ids(crate_name, generics);
+        }
+        ItemEnum::Trait { generics, bounds, items, .. } => {
+            rewrite_generics_ids(crate_name, generics);
+            rewrite_bounds_ids(crate_name, bounds);
+            rewrite_list(items);
+        }
+        ItemEnum::Impl { generics, trait_, for_, items, blanket_impl, .. } => {
+            rewrite_generics_ids(crate_name, generics);
+            if let Some(trait_path) = trait_ {
+                rewrite_resolved_path_ids(crate_name, trait_path);
+            }
+            rewrite_type_ids(crate_name, for_);
+            rewrite_list(items);
+            if let Some(blanket) = blanket_impl {
+                rewrite_type_ids(crate_name, blanket);
+            }
+        }
+        ItemEnum::Use { id, .. } => {
+            if let Some(id) = id {
+                *id = rewrite_id(crate_name, id);
+            }
+        }
+        ItemEnum::TraitAlias { generics, params } => {
+            rewrite_generics_ids(crate_name, generics);
+            rewrite_bounds_ids(crate_name, params);
+        }
+        ItemEnum::TypeAlias { generics, type_ } => {
+            rewrite_generics_ids(crate_name, generics);
+            if let Some(ty) = type_ {
+                rewrite_type_ids(crate_name, ty);
+            }
+        }
+        ItemEnum::Constant { type_, .. }
+        | ItemEnum::Static { type_, .. }
+        | ItemEnum::StructField(type_)
+        | ItemEnum::AssocConst { type_, .. } => rewrite_type_ids(crate_name, type_),
+        ItemEnum::AssocType { generics, bounds, type_, .. } => {
+            rewrite_generics_ids(crate_name, generics);
+            rewrite_bounds_ids(crate_name, bounds);
+            if let Some(ty) = type_ {
+                rewrite_type_ids(crate_name, ty);
+            }
+        }
+        ItemEnum::ExternCrate { .. }
+        | ItemEnum::Macro(_)
+        | ItemEnum::ProcMacro { .. }
+        | ItemEnum::ExternType
+        | ItemEnum::Primitive { .. }
+        | ItemEnum::Keyword
+        | ItemEnum::Unknown { .. } => {}
+    }
+}
+
+fn rewrite_resolved_path_ids(crate_name: &str, path: &mut ResolvedPath) {
+    if let Some(id) = &mut path.id {
+        *id = rewrite_id(crate_name, id);
+    }
+    if let Some(args) = &mut path.args {
+        rewrite_generic_args_ids(crate_name, args);
+    }
+}
+
+fn rewrite_type_ids(crate_name: &str, ty: &mut Type) {
+    match ty {
+        Type::ResolvedPath(path) => rewrite_resolved_path_ids(crate_name, path),
+        Type::Tuple(types) => {
+            for ty in types {
+                rewrite_type_ids(crate_name, ty);
+            }
+        }
+        Type::Slice(inner) | Type::RawPointer { type_: inner, .. } | Type::BorrowedRef { type_: inner, .. } => {
+            rewrite_type_ids(crate_name, inner);
+        }
+        Type::Array { type_, .. } => rewrite_type_ids(crate_name, type_),
+        Type::FunctionPointer(fp) => {
+            rewrite_function_signature_ids(crate_name, &mut fp.sig);
+            rewrite_generic_params_ids(crate_name, &mut fp.generic_params);
+        }
+        Type::QualifiedPath { args, self_type, trait_, .. } => {
+            rewrite_type_ids(crate_name, self_type);
+            if let Some(args) = args {
+                rewrite_generic_args_ids(crate_name, args);
+            }
+            if let Some(trait_path) = trait_ {
+                rewrite_resolved_path_ids(crate_name, trait_path);
+            }
+        }
+        Type::ImplTrait(bounds) => rewrite_bounds_ids(crate_name, bounds),
+        Type::DynTrait(dyn_trait) => {
+            for poly_trait in &mut dyn_trait.traits {
+                rewrite_resolved_path_ids(crate_name, &mut poly_trait.trait_);
+                rewrite_generic_params_ids(crate_name, &mut poly_trait.generic_params);
+            }
+        }
+        Type::Primitive(_) | Type::Infer | Type::Generic(_) | Type::Unknown { .. } => {}
+    }
+}
+
+fn rewrite_generic_args_ids(crate_name: &str, args: &mut GenericArgs) {
+    match args {
+        GenericArgs::AngleBracketed { args, constraints } => {
+            for arg in args {
+                if let GenericArg::Type(ty) = arg {
+                    rewrite_type_ids(crate_name, ty);
+                }
+            }
+            for binding in constraints {
+                if let Some(binding_args) = &mut binding.args {
+                    rewrite_generic_args_ids(crate_name, binding_args);
+                }
+                match &mut binding.binding {
+                    TypeBindingKind::Equality(ty) => rewrite_type_ids(crate_name, ty),
+                    TypeBindingKind::Constraint(bounds) => rewrite_bounds_ids(crate_name, bounds),
+                    TypeBindingKind::Unknown => {}
+                }
+            }
+        }
+        GenericArgs::Parenthesized { inputs, output } => {
+            for ty in inputs {
+                rewrite_type_ids(crate_name, ty);
+            }
+            if let Some(ty) = output {
+                rewrite_type_ids(crate_name, ty);
+            }
+        }
+    }
+}
+
+fn rewrite_bounds_ids(crate_name: &str, bounds: &mut [GenericBound]) {
+    for bound in bounds {
+        if let GenericBound::TraitBound { trait_, generic_params, .. } = bound {
+            rewrite_resolved_path_ids(crate_name, trait_);
+            rewrite_generic_params_ids(crate_name, generic_params);
+        }
+    }
+}
+
+fn rewrite_generic_params_ids(crate_name: &str, params: &mut [GenericParamDef]) {
+    for param in params {
+        match &mut param.kind {
+            GenericParamDefKind::Lifetime { .. } | GenericParamDefKind::Unknown => {}
+            GenericParamDefKind::Type { bounds, default, .. } => {
+                rewrite_bounds_ids(crate_name, bounds);
+                if let Some(ty) = default {
+                    rewrite_type_ids(crate_name, ty);
+                }
+            }
+            GenericParamDefKind::Const { type_, .. } => rewrite_type_ids(crate_name, type_),
+        }
+    }
+}
+
+fn rewrite_generics_ids(crate_name: &str, generics: &mut Generics) {
+    rewrite_generic_params_ids(crate_name, &mut generics.params);
+    for predicate in &mut generics.where_predicates {
+        match predicate {
+            WherePredicate::BoundPredicate { type_, bounds, generic_params } => {
+                rewrite_type_ids(crate_name, type_);
+                rewrite_bounds_ids(crate_name, bounds);
+                rewrite_generic_params_ids(crate_name, generic_params);
+            }
+            WherePredicate::LifetimePredicate { .. } => {}
+            WherePredicate::EqPredicate { lhs, rhs } => {
+                rewrite_type_ids(crate_name, lhs);
+                rewrite_type_ids(crate_name, rhs);
+            }
+        }
+    }
+}
+
+fn rewrite_function_signature_ids(crate_name: &str, sig: &mut FunctionSignature) {
+    for (_, ty) in &mut sig.inputs {
+        rewrite_type_ids(crate_name, ty);
+    }
+    if let Some(output) = &mut sig.output {
+        rewrite_type_ids(crate_name, output);
+    }
+}
+
+/// The on-disk rustdoc HTML filename prefix for a kind of item, e.g.
+/// `struct.Vec.html`. Falls back to `"index"` for kinds rustdoc doesn't
+/// give their own page (keywords, associated items, ...).
+fn item_kind_url_fragment(kind: &ItemKind) -> &'static str {
+    match kind {
+        ItemKind::Module => "index",
+        ItemKind::Struct => "struct",
+        ItemKind::Union => "union",
+        ItemKind::Enum => "enum",
+        ItemKind::Trait | ItemKind::TraitAlias => "trait",
+        ItemKind::Function => "fn",
+        ItemKind::TypeAlias => "type",
+        ItemKind::Constant => "constant",
+        ItemKind::Static => "static",
+        ItemKind::Macro | ItemKind::ProcMacro | ItemKind::ProcAttribute | ItemKind::ProcDerive => {
+            "macro"
+        }
+        ItemKind::Primitive => "primitive",
+        _ => "index",
+    }
+}
+
+/// Build the docs.rs-style URL rustdoc would have generated for an item at
+/// `path` (its full dotted path, crate name included) of the given `kind`,
+/// rooted at `html_root_url`.
+fn docs_url(html_root_url: &str, path: &[String], kind: &ItemKind) -> String {
+    let base = html_root_url.trim_end_matches('/');
+    if *kind == ItemKind::Module && !path.is_empty() {
+        return format!("{base}/{}/index.html", path.join("/"));
+    }
+    match path.split_last() {
+        Some((name, modules)) if !modules.is_empty() => {
+            format!("{base}/{}/{}.{name}.html", modules.join("/"), item_kind_url_fragment(kind))
+        }
+        Some((name, _)) => format!("{base}/{}.{name}.html", item_kind_url_fragment(kind)),
+        None => base.to_string(),
+    }
+}
+
+/// Merge `inputs` into one unified [`Crate`]. See the module docs for the
+/// id-rewriting and cross-crate resolution rules.
+pub fn merge_crates(inputs: Vec<MergeInput>) -> MergedCrate {
+    let crate_roots: HashMap<String, Id> = inputs
+        .iter()
+        .map(|input| {
+            (
+                input.crate_name.clone(),
+                rewrite_id(&input.crate_name, &input.crate_data.root),
+            )
+        })
+        .collect();
+
+    // Every input's own `paths`, so a cross-crate reference from one crate
+    // can be matched against what another supplied crate says about itself.
+    let mut path_index: HashMap<(String, Vec<String>), Id> = HashMap::new();
+    for input in &inputs {
+        for (orig_id, summary) in &input.crate_data.paths {
+            path_index
+                .entry((input.crate_name.clone(), summary.path.clone()))
+                .or_insert_with(|| rewrite_id(&input.crate_name, &Id(orig_id.clone())));
+        }
+    }
+
+    let mut external_resolutions = HashMap::new();
+    let mut merged_index = HashMap::new();
+    let mut merged_paths = HashMap::new();
+    let mut merged_external_crates = HashMap::new();
+
+    for input in inputs {
+        let MergeInput {
+            crate_name,
+            mut crate_data,
+        } = input;
+
+        for (orig_id, summary) in &crate_data.paths {
+            if crate_data.index.contains_key(orig_id) {
+                continue; // defined locally -- not a cross-crate reference
+            }
+            let rewritten = rewrite_id(&crate_name, &Id(orig_id.clone()));
+            let Some(origin_crate) = summary.path.first() else {
+                continue;
+            };
+            let resolution = path_index
+                .get(&(origin_crate.clone(), summary.path.clone()))
+                .cloned()
+                .map(ExternalResolution::Linked)
+                .or_else(|| {
+                    crate_data
+                        .external_crates
+                        .values()
+                        .find(|ext| &ext.name == origin_crate)
+                        .and_then(|ext| ext.html_root_url.as_deref())
+                        .map(|root| ExternalResolution::DocsUrl(docs_url(root, &summary.path, &summary.kind)))
+                });
+            if let Some(resolution) = resolution {
+                external_resolutions.insert(rewritten, resolution);
+            }
+        }
+
+        for (orig_id, mut item) in std::mem::take(&mut crate_data.index) {
+            rewrite_item_ids(&crate_name, &mut item);
+            merged_index.insert(rewrite_id(&crate_name, &Id(orig_id)).0, item);
+        }
+        for (orig_id, summary) in std::mem::take(&mut crate_data.paths) {
+            merged_paths.insert(rewrite_id(&crate_name, &Id(orig_id)).0, summary);
+        }
+        merged_external_crates.extend(crate_data.external_crates);
+    }
+
+    let root = crate_roots
+        .values()
+        .next()
+        .cloned()
+        .unwrap_or_else(|| Id(String::new()));
+
+    MergedCrate {
+        crate_data: Crate {
+            root,
+            crate_version: None,
+            index: merged_index,
+            paths: merged_paths,
+            external_crates: merged_external_crates,
+            includes_private: false,
+            format_version: 0,
+        },
+        crate_roots,
+        external_resolutions,
+    }
+}
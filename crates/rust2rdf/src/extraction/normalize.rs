@@ -0,0 +1,191 @@
+//! Explicit format-version compatibility layer for rustdoc JSON.
+//!
+//! Field renames across rustdoc JSON format versions (see [`RENAMED_FIELDS`]
+//! for the full list this model knows about) are resolved *before*
+//! deserialization: [`rename_versioned_fields`] walks the raw
+//! [`serde_json::Value`] and renames `old_name` to `new_name` wherever it
+//! appears, but only when the detected `format_version` actually falls
+//! inside `old_name_versions`. Outside that range `old_name` is left alone
+//! instead of being folded into `new_name` -- if a later format version
+//! ever reuses `old_name` for something unrelated, this gate is what keeps
+//! that from being silently misparsed as the old field. [`normalize`] is
+//! the one remaining post-deserialize fixup that isn't a rename:
+//! backfilling [`Crate::format_version`] from whatever version was actually
+//! detected on the wire (in case the struct's own default ever diverges
+//! from it), and warning when that version is newer than
+//! [`MAX_MODELED_FORMAT_VERSION`].
+
+use std::ops::RangeInclusive;
+
+use super::rustdoc_loader::MAX_MODELED_FORMAT_VERSION;
+use super::rustdoc_model::Crate;
+
+/// One historically-renamed field: the `format_version` range over which
+/// rustdoc used `old_name`, and what this model calls it now. Consulted by
+/// [`rename_versioned_fields`], which is what actually carries out the
+/// rename (gated on `old_name_versions`) before `rustdoc_model` ever sees
+/// the JSON -- this table exists so the full rename history is visible and
+/// queryable in one place instead of scattered across doc comments.
+#[derive(Debug, Clone)]
+pub struct RenamedField {
+    pub struct_name: &'static str,
+    pub old_name: &'static str,
+    pub new_name: &'static str,
+    /// The `format_version` range over which `old_name` was used.
+    pub old_name_versions: RangeInclusive<u32>,
+}
+
+/// The renamed-field history this model accounts for, oldest rename first.
+pub const RENAMED_FIELDS: &[RenamedField] = &[
+    RenamedField {
+        struct_name: "Crate",
+        old_name: "version",
+        new_name: "crate_version",
+        old_name_versions: 0..=13,
+    },
+    RenamedField {
+        struct_name: "Item",
+        old_name: "source",
+        new_name: "span",
+        old_name_versions: 0..=16,
+    },
+    RenamedField {
+        struct_name: "Item",
+        old_name: "kind",
+        new_name: "inner",
+        old_name_versions: 0..=19,
+    },
+    RenamedField {
+        struct_name: "ResolvedPath",
+        old_name: "name",
+        new_name: "path",
+        old_name_versions: 0..=26,
+    },
+    RenamedField {
+        struct_name: "GenericArgs::AngleBracketed",
+        old_name: "bindings",
+        new_name: "constraints",
+        old_name_versions: 0..=26,
+    },
+    RenamedField {
+        struct_name: "StructKind::Plain / VariantKind::Struct / Union",
+        old_name: "fields_stripped",
+        new_name: "has_stripped_fields",
+        old_name_versions: 0..=29,
+    },
+    RenamedField {
+        struct_name: "ItemEnum::Trait",
+        old_name: "is_object_safe",
+        new_name: "is_dyn_compatible",
+        old_name_versions: 0..=31,
+    },
+];
+
+/// Rename every historically-renamed field (per [`RENAMED_FIELDS`]) from
+/// `old_name` to `new_name`, gated on `format_version` falling inside that
+/// field's `old_name_versions` range. Must run before `value` is
+/// deserialized into [`Crate`]. A field whose `old_name` shows up outside
+/// its recorded range is left untouched rather than renamed -- this model
+/// simply won't recognize it under either name, which is the safe failure
+/// mode if a later format version reuses the key for something else
+/// entirely.
+///
+/// Several of these old names (`kind`, `name`) are also used, unrelatedly,
+/// by other fields this model never renames (`StructKind`'s own `kind` tag,
+/// `Item::name`) -- so this can't just rename `old_name` wherever it's
+/// found in the document. Each rename below is scoped to the specific
+/// struct it came from, either by the `index` map it lives in (`Item`) or
+/// by the externally-tagged JSON key that wraps it (`resolved_path`,
+/// `angle_bracketed`, ...), matching [`RENAMED_FIELDS`]'s `struct_name`.
+pub fn rename_versioned_fields(value: &mut serde_json::Value, format_version: u32) {
+    rename_scoped(value, format_version, "version", "crate_version");
+
+    if let Some(index) = value.get_mut("index").and_then(serde_json::Value::as_object_mut) {
+        for item in index.values_mut() {
+            rename_scoped(item, format_version, "source", "span");
+            rename_scoped(item, format_version, "kind", "inner");
+        }
+    }
+
+    rename_tagged_fields(value, format_version);
+}
+
+/// Recurse through `value`, applying the renames whose `struct_name` is
+/// identified by an externally-tagged wrapper key rather than by position
+/// (`Item`/`Crate` are handled separately in [`rename_versioned_fields`]
+/// since they aren't tag-wrapped). The same tag can legitimately wrap more
+/// than one shape (`struct` tags both `ItemEnum::Struct` and
+/// `VariantKind::Struct`) -- that's fine, since a rename only ever fires
+/// when its `old_name` key is actually present on that particular payload.
+fn rename_tagged_fields(value: &mut serde_json::Value, format_version: u32) {
+    if let serde_json::Value::Object(map) = value {
+        if let Some(resolved_path) = map.get_mut("resolved_path") {
+            rename_scoped(resolved_path, format_version, "name", "path");
+        }
+        if let Some(angle_bracketed) = map.get_mut("angle_bracketed") {
+            rename_scoped(angle_bracketed, format_version, "bindings", "constraints");
+        }
+        for tag in ["plain", "struct", "union"] {
+            if let Some(payload) = map.get_mut(tag) {
+                rename_scoped(payload, format_version, "fields_stripped", "has_stripped_fields");
+            }
+        }
+        if let Some(trait_) = map.get_mut("trait") {
+            rename_scoped(trait_, format_version, "is_object_safe", "is_dyn_compatible");
+        }
+    }
+
+    match value {
+        serde_json::Value::Object(map) => {
+            for nested in map.values_mut() {
+                rename_tagged_fields(nested, format_version);
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for item in items {
+                rename_tagged_fields(item, format_version);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Rename `old_name` to `new_name` on `value` (an object) if `old_name` is
+/// present and `format_version` falls inside the range [`RENAMED_FIELDS`]
+/// records for that pair. If `new_name` is already present, it wins and
+/// `old_name` is just dropped, so a partially-migrated document can't
+/// clobber real data. No-op if `old_name` isn't present, or `value` isn't
+/// an object, or no matching, in-range entry exists.
+fn rename_scoped(value: &mut serde_json::Value, format_version: u32, old_name: &str, new_name: &str) {
+    let in_range = RENAMED_FIELDS
+        .iter()
+        .any(|f| f.old_name == old_name && f.new_name == new_name && f.old_name_versions.contains(&format_version));
+    if !in_range {
+        return;
+    }
+
+    if let serde_json::Value::Object(map) = value {
+        if let Some(renamed) = map.remove(old_name) {
+            map.entry(new_name.to_string()).or_insert(renamed);
+        }
+    }
+}
+
+/// Run post-deserialize normalization over `crate_data`, given the
+/// `format_version` actually detected on the wire (which may come from
+/// outside the deserialized JSON, e.g. [`super::rustdoc_loader`] peeks it
+/// before the full parse). Backfills [`Crate::format_version`] if it wasn't
+/// already set, and warns once if `format_version` is newer than anything
+/// [`RENAMED_FIELDS`] -- and this model generally -- was written against.
+pub fn normalize(crate_data: &mut Crate, format_version: u32) {
+    if crate_data.format_version == 0 {
+        crate_data.format_version = format_version;
+    }
+
+    if format_version > MAX_MODELED_FORMAT_VERSION {
+        eprintln!(
+            "warning: rustdoc JSON format_version {format_version} is newer than the {MAX_MODELED_FORMAT_VERSION} this model's rename history ({} entries) was written against; some fields may not be extracted",
+            RENAMED_FIELDS.len()
+        );
+    }
+}
@@ -0,0 +1,90 @@
+//! Prune a loaded [`Crate`] down to its public API surface.
+//!
+//! A `Crate` loaded via [`super::rustdoc_loader`] may carry rustdoc's full
+//! private index (`--document-private-items`). Some consumers -- diffing a
+//! crate's public API across releases, generating docs for external readers
+//! -- only want what's actually `pub`. [`prune_private_items`] produces that
+//! view by deleting every non-public [`Item`] and scrubbing the now-dangling
+//! [`Id`] references left behind in whatever referenced them.
+
+use std::collections::HashSet;
+
+use super::rustdoc_model::{Crate, ItemEnum, StructKind, VariantKind, Visibility};
+
+/// Whether `visibility` counts as public for [`prune_private_items`]: only
+/// [`Visibility::Public`] passes. `Default`/`Crate`/`Restricted` are all
+/// private -- none of them are reachable by a caller outside the crate.
+fn is_public(visibility: &Visibility) -> bool {
+    matches!(visibility, Visibility::Public)
+}
+
+/// Drop every non-public item from `crate_data.index`, then remove the
+/// `Id` references left dangling in `Module::items`, `Enum::variants`,
+/// `Struct`/`Union`/`Variant` fields, `Trait::items`, and `Impl::items`.
+///
+/// Tuple struct/variant fields (`StructKind::Tuple`/`VariantKind::Tuple`)
+/// are set to `None` in place rather than removed, since rustdoc already
+/// uses `None` to mark a stripped positional field and removing an entry
+/// outright would shift every later field's index. Every other list is
+/// simply filtered, since those don't carry positional meaning.
+///
+/// Leaves [`Crate::includes_private`] set to `false`.
+pub fn prune_private_items(crate_data: &mut Crate) {
+    let private_ids: HashSet<String> = crate_data
+        .index
+        .iter()
+        .filter(|(_, item)| !is_public(&item.visibility))
+        .map(|(id, _)| id.clone())
+        .collect();
+
+    crate_data.index.retain(|id, _| !private_ids.contains(id));
+
+    for item in crate_data.index.values_mut() {
+        match &mut item.inner {
+            ItemEnum::Module { items, .. } => {
+                items.retain(|id| !private_ids.contains(&id.0));
+            }
+            ItemEnum::Struct { kind, .. } => match kind {
+                StructKind::Plain { fields, .. } => {
+                    fields.retain(|id| !private_ids.contains(&id.0));
+                }
+                StructKind::Tuple(fields) => {
+                    for field in fields.iter_mut() {
+                        if field.as_ref().is_some_and(|id| private_ids.contains(&id.0)) {
+                            *field = None;
+                        }
+                    }
+                }
+                StructKind::Unit => {}
+            },
+            ItemEnum::Union { fields, .. } => {
+                fields.retain(|id| !private_ids.contains(&id.0));
+            }
+            ItemEnum::Enum { variants, .. } => {
+                variants.retain(|id| !private_ids.contains(&id.0));
+            }
+            ItemEnum::Variant(variant) => match &mut variant.kind {
+                VariantKind::Struct { fields, .. } => {
+                    fields.retain(|id| !private_ids.contains(&id.0));
+                }
+                VariantKind::Tuple(fields) => {
+                    for field in fields.iter_mut() {
+                        if field.as_ref().is_some_and(|id| private_ids.contains(&id.0)) {
+                            *field = None;
+                        }
+                    }
+                }
+                VariantKind::Plain => {}
+            },
+            ItemEnum::Trait { items, .. } => {
+                items.retain(|id| !private_ids.contains(&id.0));
+            }
+            ItemEnum::Impl { items, .. } => {
+                items.retain(|id| !private_ids.contains(&id.0));
+            }
+            _ => {}
+        }
+    }
+
+    crate_data.includes_private = false;
+}
@@ -1,9 +1,56 @@
 //! Load and deserialize rustdoc JSON output.
+//!
+//! rustdoc JSON's `format_version` drifts with every schema-affecting
+//! nightly change. [`Crate::load_with_options`] peeks `format_version` out
+//! of the raw JSON before the full parse, then hands it to
+//! [`normalize::rename_versioned_fields`], which renames every
+//! historically-renamed field back to its current name -- but only within
+//! the `format_version` range that rename actually applies to (see
+//! [`super::normalize::RENAMED_FIELDS`]) -- before `rustdoc_model` ever sees
+//! the document. [`Id`](super::rustdoc_model::Id) additionally has a custom
+//! deserializer accepting both its string and integer encodings, since that
+//! isn't a rename. The one post-deserialize fixup left
+//! ([`normalize::normalize`]: backfilling `format_version`, warning on an
+//! unrecognized-future version) also lives in [`super::normalize`].
+//! [`Crate::load`] only needs to guard the versions that range falls
+//! outside of.
 
+use std::io::Read;
+use std::ops::RangeInclusive;
 use std::path::Path;
 use std::process::Command;
+use super::normalize;
 use super::rustdoc_model::Crate;
 
+/// Lowest `format_version` [`Crate::load`] accepts by default. Below this,
+/// the schema has drifted far enough (beyond what the field renames in
+/// [`normalize::RENAMED_FIELDS`] can absorb) that deserializing would silently drop
+/// or misplace data rather than fail loudly.
+pub const MIN_SUPPORTED_FORMAT_VERSION: u32 = 12;
+
+/// Highest `format_version` this model was written against. Newer versions
+/// still deserialize -- unknown fields are ignored via `#[serde(default)]`
+/// -- but may carry schema changes we haven't modeled yet, so
+/// [`Crate::load`] warns rather than rejects.
+pub const MAX_MODELED_FORMAT_VERSION: u32 = 35;
+
+/// Policy controlling which rustdoc JSON `format_version`s [`Crate::load`]
+/// will accept.
+pub struct LoadOptions {
+    /// Lowest `format_version` to accept; anything below is rejected with
+    /// [`LoadError::UnsupportedFormatVersion`]. Defaults to
+    /// [`MIN_SUPPORTED_FORMAT_VERSION`].
+    pub min_format_version: u32,
+}
+
+impl Default for LoadOptions {
+    fn default() -> Self {
+        Self {
+            min_format_version: MIN_SUPPORTED_FORMAT_VERSION,
+        }
+    }
+}
+
 /// Errors that can occur during loading.
 #[derive(Debug)]
 pub enum LoadError {
@@ -12,6 +59,14 @@ pub enum LoadError {
     RustdocFailed(String),
     CrateNameNotFound,
     OutputNotFound(String),
+    /// The input's `format_version` falls outside the range this build
+    /// understands (below [`LoadOptions::min_format_version`]).
+    UnsupportedFormatVersion {
+        found: u32,
+        supported: RangeInclusive<u32>,
+    },
+    /// `Cargo.toml` isn't valid TOML.
+    TomlParse(toml::de::Error),
 }
 
 impl std::fmt::Display for LoadError {
@@ -22,6 +77,13 @@ impl std::fmt::Display for LoadError {
             LoadError::RustdocFailed(msg) => write!(f, "rustdoc failed: {msg}"),
             LoadError::CrateNameNotFound => write!(f, "could not determine crate name from Cargo.toml"),
             LoadError::OutputNotFound(path) => write!(f, "rustdoc JSON output not found at: {path}"),
+            LoadError::UnsupportedFormatVersion { found, supported } => write!(
+                f,
+                "rustdoc JSON format_version {found} is outside the supported range {}..={} -- pin a nightly toolchain that emits a format_version in that range",
+                supported.start(),
+                supported.end()
+            ),
+            LoadError::TomlParse(e) => write!(f, "Cargo.toml parse error: {e}"),
         }
     }
 }
@@ -36,20 +98,66 @@ impl From<serde_json::Error> for LoadError {
     fn from(e: serde_json::Error) -> Self { LoadError::Json(e) }
 }
 
+impl From<toml::de::Error> for LoadError {
+    fn from(e: toml::de::Error) -> Self { LoadError::TomlParse(e) }
+}
+
+impl Crate {
+    /// Load a rustdoc JSON document from `reader`, using
+    /// [`LoadOptions::default`]'s version floor. A single deserialize path
+    /// that succeeds on JSON emitted by a range of toolchains (format
+    /// versions [`MIN_SUPPORTED_FORMAT_VERSION`]..=[`MAX_MODELED_FORMAT_VERSION`]
+    /// and, with a warning, newer), rather than only the latest one.
+    pub fn load<R: Read>(reader: R) -> Result<Crate, LoadError> {
+        Self::load_with_options(reader, &LoadOptions::default())
+    }
+
+    /// Like [`Self::load`], but with an explicit [`LoadOptions`] (e.g. to
+    /// lower the version floor for a known-compatible older toolchain).
+    pub fn load_with_options<R: Read>(
+        mut reader: R,
+        options: &LoadOptions,
+    ) -> Result<Crate, LoadError> {
+        let mut content = String::new();
+        reader.read_to_string(&mut content)?;
+
+        let mut peeked: serde_json::Value = serde_json::from_str(&content)?;
+        let detected_version = peeked
+            .get("format_version")
+            .and_then(serde_json::Value::as_u64)
+            .map(|v| v as u32);
+
+        if let Some(version) = detected_version {
+            if version < options.min_format_version {
+                return Err(LoadError::UnsupportedFormatVersion {
+                    found: version,
+                    supported: options.min_format_version..=MAX_MODELED_FORMAT_VERSION,
+                });
+            }
+        }
+
+        // Rustdoc JSON without a `format_version` field predates its
+        // introduction, i.e. is older than every `old_name_versions` range
+        // starts -- so format_version 0 gates in every known rename.
+        normalize::rename_versioned_fields(&mut peeked, detected_version.unwrap_or(0));
+
+        let mut krate: Crate = serde_json::from_value(peeked)?;
+        let normalize_version = detected_version.unwrap_or(krate.format_version);
+        normalize::normalize(&mut krate, normalize_version);
+        Ok(krate)
+    }
+}
+
 /// Load a rustdoc JSON file from disk.
 pub fn load_json(path: &Path) -> Result<Crate, LoadError> {
-    let content = std::fs::read_to_string(path)?;
-    let krate: Crate = serde_json::from_str(&content)?;
-    Ok(krate)
+    let file = std::fs::File::open(path)?;
+    Crate::load(file)
 }
 
 /// Run `cargo +nightly rustdoc` on a crate directory and load the result.
 pub fn load_crate(crate_dir: &Path) -> Result<Crate, LoadError> {
-    // Determine crate name from Cargo.toml
-    let cargo_toml_path = crate_dir.join("Cargo.toml");
-    let cargo_toml = std::fs::read_to_string(&cargo_toml_path)?;
-    let crate_name = extract_crate_name(&cargo_toml)
-        .ok_or(LoadError::CrateNameNotFound)?;
+    let metadata = resolve_package_metadata(crate_dir)?;
+    let crate_name = metadata.name.ok_or(LoadError::CrateNameNotFound)?;
 
     // Run cargo rustdoc
     let output = Command::new("cargo")
@@ -62,8 +170,9 @@ pub fn load_crate(crate_dir: &Path) -> Result<Crate, LoadError> {
         return Err(LoadError::RustdocFailed(stderr.to_string()));
     }
 
-    // Find the JSON output
-    let json_name = crate_name.replace('-', "_");
+    // A renamed `[lib]` target produces `target/doc/<lib_name>.json`, not
+    // `target/doc/<package_name>.json`.
+    let json_name = metadata.lib_name.unwrap_or(crate_name).replace('-', "_");
     let json_path = crate_dir.join(format!("target/doc/{json_name}.json"));
 
     if !json_path.exists() {
@@ -73,39 +182,80 @@ pub fn load_crate(crate_dir: &Path) -> Result<Crate, LoadError> {
     load_json(&json_path)
 }
 
-/// Extract crate name from Cargo.toml content (simple parser).
-fn extract_crate_name(content: &str) -> Option<String> {
-    for line in content.lines() {
-        let line = line.trim();
-        if line.starts_with("name") {
-            if let Some(value) = line.split('=').nth(1) {
-                let name = value.trim().trim_matches('"').trim_matches('\'');
-                return Some(name.to_string());
-            }
-        }
+/// A crate's `[package]` name/version and `[lib]` target name, with
+/// `{ workspace = true }` inheritance on `name`/`version` already resolved.
+pub struct PackageMetadata {
+    pub name: Option<String>,
+    pub version: Option<String>,
+    pub lib_name: Option<String>,
+}
+
+/// Read and parse `<crate_dir>/Cargo.toml`, resolving `name`/`version`
+/// through workspace inheritance (see [`resolve_inheritable_field`]) and
+/// picking up an explicit `[lib].name` override.
+pub fn resolve_package_metadata(crate_dir: &Path) -> Result<PackageMetadata, LoadError> {
+    let cargo_toml_path = crate_dir.join("Cargo.toml");
+    let content = std::fs::read_to_string(&cargo_toml_path)?;
+    let document: toml::Value = content.parse::<toml::Value>().map_err(LoadError::TomlParse)?;
+
+    let package = document.get("package");
+    let name = resolve_inheritable_field(package, "name", crate_dir)?;
+    let version = resolve_inheritable_field(package, "version", crate_dir)?;
+    let lib_name = document
+        .get("lib")
+        .and_then(|lib| lib.get("name"))
+        .and_then(toml::Value::as_str)
+        .map(str::to_string);
+
+    Ok(PackageMetadata { name, version, lib_name })
+}
+
+/// Resolve `[package].<field>`: a plain string is returned as-is; `{ workspace
+/// = true }` is resolved by walking parent directories for the nearest
+/// `[workspace.package].<field>`, since that's the only place such a value
+/// can be defined.
+fn resolve_inheritable_field(
+    package: Option<&toml::Value>,
+    field: &str,
+    crate_dir: &Path,
+) -> Result<Option<String>, LoadError> {
+    let Some(value) = package.and_then(|package| package.get(field)) else {
+        return Ok(None);
+    };
+
+    if let Some(s) = value.as_str() {
+        return Ok(Some(s.to_string()));
+    }
+
+    let inherits_workspace = value
+        .get("workspace")
+        .and_then(toml::Value::as_bool)
+        .unwrap_or(false);
+    if !inherits_workspace {
+        return Ok(None);
     }
-    None
+
+    find_workspace_package_field(crate_dir, field)
 }
 
-/// Extract crate version from Cargo.toml content.
-pub fn extract_crate_version(content: &str) -> Option<String> {
-    let mut in_package = false;
-    for line in content.lines() {
-        let line = line.trim();
-        if line == "[package]" {
-            in_package = true;
-            continue;
-        }
-        if line.starts_with('[') && line != "[package]" {
-            in_package = false;
+/// Walk up from `start_dir`'s ancestors looking for a `Cargo.toml` carrying
+/// a `[workspace.package].<field>` table.
+fn find_workspace_package_field(start_dir: &Path, field: &str) -> Result<Option<String>, LoadError> {
+    for ancestor in start_dir.ancestors().skip(1) {
+        let candidate = ancestor.join("Cargo.toml");
+        if !candidate.is_file() {
             continue;
         }
-        if in_package && line.starts_with("version") {
-            if let Some(value) = line.split('=').nth(1) {
-                let version = value.trim().trim_matches('"').trim_matches('\'');
-                return Some(version.to_string());
-            }
+        let content = std::fs::read_to_string(&candidate)?;
+        let document: toml::Value = content.parse::<toml::Value>().map_err(LoadError::TomlParse)?;
+        if let Some(value) = document
+            .get("workspace")
+            .and_then(|workspace| workspace.get("package"))
+            .and_then(|package| package.get(field))
+            .and_then(toml::Value::as_str)
+        {
+            return Ok(Some(value.to_string()));
         }
     }
-    None
+    Ok(None)
 }
@@ -12,17 +12,31 @@
 //! - We do NOT use `#[serde(deny_unknown_fields)]` -- unknown fields are ignored.
 //! - The `Id` type accepts both string and integer JSON values for compatibility
 //!   across rustdoc format versions.
+//! - Every type also derives `Serialize` (with a hand-written impl for `Id`,
+//!   which always writes back out as a string) so a loaded `Crate` can be
+//!   pruned/transformed and re-emitted as a normalized rustdoc JSON document,
+//!   not just consumed.
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Newtype for rustdoc item IDs.
 ///
 /// Handles both string IDs (older format versions) and integer IDs (format
-/// version 35+) by using a custom deserializer.
+/// version 35+) by using a custom deserializer. Always serializes back out
+/// as a string, matching what we already accept on the way in.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Id(pub String);
 
+impl Serialize for Id {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.0)
+    }
+}
+
 impl<'de> Deserialize<'de> for Id {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -70,12 +84,31 @@ impl<'de> Deserialize<'de> for Id {
     }
 }
 
+/// Splits an externally-tagged JSON value (`{ "tag": payload }` or the bare
+/// string `"tag"` for a unit variant) into its tag name and payload, for
+/// enums whose `Unknown` fallback variant needs to keep both -- see
+/// [`ItemEnum`] and [`Type`].
+fn split_tagged_value(value: serde_json::Value) -> Result<(String, serde_json::Value), String> {
+    match value {
+        serde_json::Value::String(tag) => Ok((tag, serde_json::Value::Null)),
+        serde_json::Value::Object(map) if map.len() == 1 => {
+            let (tag, payload) = map.into_iter().next().expect("len == 1");
+            Ok((tag, payload))
+        }
+        other => Err(format!(
+            "expected an externally tagged string or single-key object, got {other}"
+        )),
+    }
+}
+
 /// Top-level rustdoc JSON output.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Crate {
     /// Root module item ID.
     pub root: Id,
     /// The crate version, if available.
+    /// Field is named `crate_version` in newer format versions, `version` in
+    /// older ones -- see [`super::normalize::RENAMED_FIELDS`].
     #[serde(default)]
     pub crate_version: Option<String>,
     /// All items indexed by ID.
@@ -87,13 +120,17 @@ pub struct Crate {
     /// External crate metadata.
     #[serde(default)]
     pub external_crates: HashMap<String, ExternalCrate>,
+    /// Whether `index` contains non-public items (rustdoc's `--document-private-items`).
+    /// `false` after [`crate::extraction::prune::prune_private_items`] runs.
+    #[serde(default)]
+    pub includes_private: bool,
     /// Format version of the JSON output.
     #[serde(default)]
     pub format_version: u32,
 }
 
 /// Summary of an item's path (used for external references).
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ItemSummary {
     /// The components of the item's path.
     #[serde(default)]
@@ -104,7 +141,7 @@ pub struct ItemSummary {
 }
 
 /// External crate metadata.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ExternalCrate {
     pub name: String,
     #[serde(default)]
@@ -112,7 +149,7 @@ pub struct ExternalCrate {
 }
 
 /// A single item in the rustdoc output.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Item {
     /// Item ID.
     #[serde(default)]
@@ -133,9 +170,13 @@ pub struct Item {
     #[serde(default)]
     pub docs: Option<String>,
     /// Source span.
+    /// Field is named `span` in newer format versions, `source` in older
+    /// ones -- see [`super::normalize::RENAMED_FIELDS`].
     #[serde(default)]
     pub span: Option<Span>,
     /// The item's inner content (what kind of item this is).
+    /// Field is named `inner` in newer format versions, `kind` in older
+    /// ones -- see [`super::normalize::RENAMED_FIELDS`].
     #[serde(default)]
     pub inner: ItemEnum,
     /// Links within documentation.
@@ -144,7 +185,7 @@ pub struct Item {
 }
 
 /// Source code span.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Span {
     #[serde(default)]
     pub filename: String,
@@ -155,7 +196,7 @@ pub struct Span {
 }
 
 /// Deprecation information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Deprecation {
     #[serde(default)]
     pub since: Option<String>,
@@ -164,7 +205,7 @@ pub struct Deprecation {
 }
 
 /// Visibility of an item.
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum Visibility {
     #[default]
@@ -175,14 +216,14 @@ pub enum Visibility {
 }
 
 /// Restricted visibility details.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VisibilityRestricted {
     pub parent: Id,
     pub path: String,
 }
 
 /// The kind of item (used in ItemSummary).
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum ItemKind {
     #[default]
@@ -215,9 +256,11 @@ pub enum ItemKind {
 /// The inner content of an Item -- determines what kind of item it is.
 ///
 /// Uses externally tagged representation (serde default): `{ "module": { ... } }`.
-/// The `Unknown` variant catches any unrecognized tag values for forward
-/// compatibility.
-#[derive(Debug, Deserialize, Default)]
+/// The `Unknown` variant catches any unrecognized tag, keeping both the tag
+/// name and its raw payload (see [`ItemEnum`]'s hand-written `Deserialize`
+/// impl below `ItemEnumKnown`) so a newer rustdoc nightly's item kinds
+/// survive deserialization instead of being silently dropped.
+#[derive(Debug, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ItemEnum {
     Module {
@@ -241,7 +284,7 @@ pub enum ItemEnum {
         generics: Generics,
         #[serde(default)]
         fields: Vec<Id>,
-        #[serde(default, alias = "fields_stripped")]
+        #[serde(default)]
         has_stripped_fields: bool,
         #[serde(default)]
         impls: Vec<Id>,
@@ -269,6 +312,10 @@ pub enum ItemEnum {
         has_body: bool,
         #[serde(default)]
         header: FunctionHeader,
+        /// Whether this item is declared `default` inside an impl block,
+        /// i.e. specializable by a more specific impl of the same trait.
+        #[serde(default)]
+        is_default: bool,
     },
 
     Trait {
@@ -284,7 +331,7 @@ pub enum ItemEnum {
         is_auto: bool,
         #[serde(default)]
         is_unsafe: bool,
-        #[serde(default, alias = "is_object_safe")]
+        #[serde(default)]
         is_dyn_compatible: bool,
     },
 
@@ -319,6 +366,20 @@ pub enum ItemEnum {
         is_glob: bool,
     },
 
+    ExternCrate {
+        #[serde(default)]
+        name: String,
+        #[serde(default)]
+        rename: Option<String>,
+    },
+
+    TraitAlias {
+        #[serde(default)]
+        generics: Generics,
+        #[serde(default)]
+        params: Vec<GenericBound>,
+    },
+
     TypeAlias {
         #[serde(default)]
         generics: Generics,
@@ -350,13 +411,234 @@ pub enum ItemEnum {
 
     Macro(String),
 
+    ProcMacro {
+        #[serde(default)]
+        kind: MacroKind,
+        #[serde(default)]
+        helpers: Vec<String>,
+    },
+
+    /// A foreign type declared by an `extern` block (`extern { type Foo; }`).
+    /// Carries no data of its own -- everything about it (name, visibility,
+    /// docs) lives on the enclosing [`Item`].
+    ExternType,
+
+    Primitive {
+        #[serde(default)]
+        name: String,
+        #[serde(default)]
+        impls: Vec<Id>,
+    },
+
+    /// A standard-library keyword doc page (e.g. `keyword.match`). Carries
+    /// no data of its own, same as [`ItemEnum::ExternType`].
+    Keyword,
+
     AssocConst {
         #[serde(rename = "type")]
         type_: Type,
         #[serde(default)]
         value: Option<String>,
+        /// Whether this item is declared `default` inside an impl block,
+        /// i.e. specializable by a more specific impl of the same trait.
+        #[serde(default)]
+        is_default: bool,
+    },
+
+    AssocType {
+        #[serde(default)]
+        generics: Generics,
+        #[serde(default)]
+        bounds: Vec<GenericBound>,
+        #[serde(rename = "type")]
+        #[serde(default)]
+        type_: Option<Type>,
+        /// Whether this item is declared `default` inside an impl block,
+        /// i.e. specializable by a more specific impl of the same trait.
+        #[serde(default)]
+        is_default: bool,
     },
 
+    /// Catch-all for unrecognized item kinds (forward compatibility). Keeps
+    /// the original tag name and its raw JSON payload rather than discarding
+    /// them -- see [`ItemEnum`]'s `Deserialize` impl.
+    Unknown {
+        tag: String,
+        #[serde(default)]
+        value: serde_json::Value,
+    },
+}
+
+impl Default for ItemEnum {
+    fn default() -> Self {
+        ItemEnum::Unknown {
+            tag: String::new(),
+            value: serde_json::Value::Null,
+        }
+    }
+}
+
+/// Mirrors [`ItemEnum`]'s known (modeled) variants for deserialization --
+/// see [`ItemEnum`]'s hand-written `Deserialize` impl just below.
+/// `#[serde(other)]` can only produce a unit fallback variant, which can't
+/// carry the tag name or an object-valued payload, so `ItemEnum` itself
+/// deserializes by first trying this type and falling back to
+/// [`ItemEnum::Unknown`] with the raw tag/value when it doesn't match.
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum ItemEnumKnown {
+    Module {
+        #[serde(default)]
+        items: Vec<Id>,
+        #[serde(default)]
+        is_stripped: bool,
+    },
+    Struct {
+        #[serde(default)]
+        kind: StructKind,
+        #[serde(default)]
+        generics: Generics,
+        #[serde(default)]
+        impls: Vec<Id>,
+    },
+    Union {
+        #[serde(default)]
+        generics: Generics,
+        #[serde(default)]
+        fields: Vec<Id>,
+        #[serde(default)]
+        has_stripped_fields: bool,
+        #[serde(default)]
+        impls: Vec<Id>,
+    },
+    Enum {
+        #[serde(default)]
+        generics: Generics,
+        #[serde(default)]
+        variants: Vec<Id>,
+        #[serde(default)]
+        variants_stripped: bool,
+        #[serde(default)]
+        impls: Vec<Id>,
+    },
+    Variant(VariantData),
+    Function {
+        #[serde(default)]
+        sig: FunctionSignature,
+        #[serde(default)]
+        generics: Generics,
+        #[serde(default)]
+        has_body: bool,
+        #[serde(default)]
+        header: FunctionHeader,
+        #[serde(default)]
+        is_default: bool,
+    },
+    Trait {
+        #[serde(default)]
+        generics: Generics,
+        #[serde(default)]
+        bounds: Vec<GenericBound>,
+        #[serde(default)]
+        items: Vec<Id>,
+        #[serde(default)]
+        implementations: Vec<Id>,
+        #[serde(default)]
+        is_auto: bool,
+        #[serde(default)]
+        is_unsafe: bool,
+        #[serde(default)]
+        is_dyn_compatible: bool,
+    },
+    Impl {
+        #[serde(default)]
+        generics: Generics,
+        #[serde(rename = "trait")]
+        #[serde(default)]
+        trait_: Option<ResolvedPath>,
+        #[serde(rename = "for")]
+        for_: Type,
+        #[serde(default)]
+        items: Vec<Id>,
+        #[serde(default)]
+        is_unsafe: bool,
+        #[serde(default)]
+        is_negative: bool,
+        #[serde(default)]
+        is_synthetic: bool,
+        #[serde(default)]
+        blanket_impl: Option<Type>,
+    },
+    Use {
+        #[serde(default)]
+        source: String,
+        #[serde(default)]
+        name: Option<String>,
+        #[serde(default)]
+        id: Option<Id>,
+        #[serde(default)]
+        is_glob: bool,
+    },
+    ExternCrate {
+        #[serde(default)]
+        name: String,
+        #[serde(default)]
+        rename: Option<String>,
+    },
+    TraitAlias {
+        #[serde(default)]
+        generics: Generics,
+        #[serde(default)]
+        params: Vec<GenericBound>,
+    },
+    TypeAlias {
+        #[serde(default)]
+        generics: Generics,
+        #[serde(rename = "type")]
+        #[serde(default)]
+        type_: Option<Type>,
+    },
+    Constant {
+        #[serde(rename = "type")]
+        type_: Type,
+        #[serde(rename = "const")]
+        #[serde(default)]
+        const_: Option<ConstExpr>,
+    },
+    Static {
+        #[serde(rename = "type")]
+        type_: Type,
+        #[serde(default)]
+        is_mutable: bool,
+        #[serde(default)]
+        is_unsafe: bool,
+        #[serde(default)]
+        expr: Option<String>,
+    },
+    StructField(Type),
+    Macro(String),
+    ProcMacro {
+        #[serde(default)]
+        kind: MacroKind,
+        #[serde(default)]
+        helpers: Vec<String>,
+    },
+    ExternType,
+    Primitive {
+        #[serde(default)]
+        name: String,
+        #[serde(default)]
+        impls: Vec<Id>,
+    },
+    Keyword,
+    AssocConst {
+        #[serde(rename = "type")]
+        type_: Type,
+        #[serde(default)]
+        value: Option<String>,
+        #[serde(default)]
+        is_default: bool,
+    },
     AssocType {
         #[serde(default)]
         generics: Generics,
@@ -365,16 +647,112 @@ pub enum ItemEnum {
         #[serde(rename = "type")]
         #[serde(default)]
         type_: Option<Type>,
+        #[serde(default)]
+        is_default: bool,
     },
+}
+
+impl From<ItemEnumKnown> for ItemEnum {
+    fn from(known: ItemEnumKnown) -> Self {
+        match known {
+            ItemEnumKnown::Module { items, is_stripped } => ItemEnum::Module { items, is_stripped },
+            ItemEnumKnown::Struct { kind, generics, impls } => ItemEnum::Struct { kind, generics, impls },
+            ItemEnumKnown::Union { generics, fields, has_stripped_fields, impls } => {
+                ItemEnum::Union { generics, fields, has_stripped_fields, impls }
+            }
+            ItemEnumKnown::Enum { generics, variants, variants_stripped, impls } => {
+                ItemEnum::Enum { generics, variants, variants_stripped, impls }
+            }
+            ItemEnumKnown::Variant(data) => ItemEnum::Variant(data),
+            ItemEnumKnown::Function { sig, generics, has_body, header, is_default } => {
+                ItemEnum::Function { sig, generics, has_body, header, is_default }
+            }
+            ItemEnumKnown::Trait {
+                generics,
+                bounds,
+                items,
+                implementations,
+                is_auto,
+                is_unsafe,
+                is_dyn_compatible,
+            } => ItemEnum::Trait {
+                generics,
+                bounds,
+                items,
+                implementations,
+                is_auto,
+                is_unsafe,
+                is_dyn_compatible,
+            },
+            ItemEnumKnown::Impl {
+                generics,
+                trait_,
+                for_,
+                items,
+                is_unsafe,
+                is_negative,
+                is_synthetic,
+                blanket_impl,
+            } => ItemEnum::Impl {
+                generics,
+                trait_,
+                for_,
+                items,
+                is_unsafe,
+                is_negative,
+                is_synthetic,
+                blanket_impl,
+            },
+            ItemEnumKnown::Use { source, name, id, is_glob } => ItemEnum::Use { source, name, id, is_glob },
+            ItemEnumKnown::ExternCrate { name, rename } => ItemEnum::ExternCrate { name, rename },
+            ItemEnumKnown::TraitAlias { generics, params } => ItemEnum::TraitAlias { generics, params },
+            ItemEnumKnown::TypeAlias { generics, type_ } => ItemEnum::TypeAlias { generics, type_ },
+            ItemEnumKnown::Constant { type_, const_ } => ItemEnum::Constant { type_, const_ },
+            ItemEnumKnown::Static { type_, is_mutable, is_unsafe, expr } => {
+                ItemEnum::Static { type_, is_mutable, is_unsafe, expr }
+            }
+            ItemEnumKnown::StructField(ty) => ItemEnum::StructField(ty),
+            ItemEnumKnown::Macro(s) => ItemEnum::Macro(s),
+            ItemEnumKnown::ProcMacro { kind, helpers } => ItemEnum::ProcMacro { kind, helpers },
+            ItemEnumKnown::ExternType => ItemEnum::ExternType,
+            ItemEnumKnown::Primitive { name, impls } => ItemEnum::Primitive { name, impls },
+            ItemEnumKnown::Keyword => ItemEnum::Keyword,
+            ItemEnumKnown::AssocConst { type_, value, is_default } => {
+                ItemEnum::AssocConst { type_, value, is_default }
+            }
+            ItemEnumKnown::AssocType { generics, bounds, type_, is_default } => {
+                ItemEnum::AssocType { generics, bounds, type_, is_default }
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ItemEnum {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        if let Ok(known) = serde_json::from_value::<ItemEnumKnown>(value.clone()) {
+            return Ok(known.into());
+        }
+        let (tag, value) = split_tagged_value(value).map_err(serde::de::Error::custom)?;
+        Ok(ItemEnum::Unknown { tag, value })
+    }
+}
 
-    /// Catch-all for unrecognized item kinds (forward compatibility).
+/// How a [`ItemEnum::ProcMacro`] is invoked.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum MacroKind {
     #[default]
-    #[serde(other)]
-    Unknown,
+    Bang,
+    Attr,
+    Derive,
 }
 
 /// A constant expression with value and type information.
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ConstExpr {
     #[serde(default)]
     pub expr: Option<String>,
@@ -390,7 +768,7 @@ pub struct ConstExpr {
 /// - `"unit"` (string)
 /// - `{ "tuple": [...] }` (externally tagged)
 /// - `{ "plain": { "fields": [...], "has_stripped_fields": false } }` (externally tagged)
-#[derive(Debug, Deserialize, Default)]
+#[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum StructKind {
     #[default]
@@ -398,13 +776,13 @@ pub enum StructKind {
     Tuple(Vec<Option<Id>>),
     Plain {
         fields: Vec<Id>,
-        #[serde(default, alias = "fields_stripped")]
+        #[serde(default)]
         has_stripped_fields: bool,
     },
 }
 
 /// Variant data wrapper (contains kind and optional discriminant).
-#[derive(Debug, Deserialize, Default)]
+#[derive(Debug, Serialize, Deserialize, Default)]
 pub struct VariantData {
     #[serde(default)]
     pub kind: VariantKind,
@@ -413,7 +791,7 @@ pub struct VariantData {
 }
 
 /// Discriminant value for enum variants.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Discriminant {
     #[serde(default)]
     pub expr: Option<String>,
@@ -427,7 +805,7 @@ pub struct Discriminant {
 /// - `"plain"` (string)
 /// - `{ "tuple": [...] }` (externally tagged)
 /// - `{ "struct": { "fields": [...], "has_stripped_fields": false } }` (externally tagged)
-#[derive(Debug, Deserialize, Default)]
+#[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum VariantKind {
     #[default]
@@ -435,17 +813,17 @@ pub enum VariantKind {
     Tuple(Vec<Option<Id>>),
     Struct {
         fields: Vec<Id>,
-        #[serde(default, alias = "fields_stripped")]
+        #[serde(default)]
         has_stripped_fields: bool,
     },
 }
 
 /// A resolved path reference to another item.
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ResolvedPath {
     /// The path string (e.g., "Vec", "std::io::Error").
-    /// Field is named `path` in newer format versions, `name` in older ones.
-    #[serde(alias = "name")]
+    /// Field is named `path` in newer format versions, `name` in older ones
+    /// -- see [`super::normalize::RENAMED_FIELDS`].
     pub path: String,
     #[serde(default)]
     pub id: Option<Id>,
@@ -456,8 +834,10 @@ pub struct ResolvedPath {
 /// A type reference in the rustdoc JSON.
 ///
 /// Uses externally tagged representation: `{ "primitive": "i32" }`.
-/// The `Unknown` variant catches any unrecognized type kinds.
-#[derive(Debug, Clone, Deserialize, Default)]
+/// The `Unknown` variant catches any unrecognized type kinds, keeping both
+/// the tag name and its raw payload -- see [`Type`]'s hand-written
+/// `Deserialize` impl, mirroring [`ItemEnum`]'s.
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Type {
     ResolvedPath(ResolvedPath),
@@ -510,14 +890,110 @@ pub enum Type {
 
     Generic(String),
 
-    /// Catch-all for unrecognized type kinds (forward compatibility).
-    #[default]
-    #[serde(other)]
-    Unknown,
+    /// Catch-all for unrecognized type kinds (forward compatibility). Keeps
+    /// the original tag name and its raw JSON payload rather than discarding
+    /// them -- see [`Type`]'s `Deserialize` impl.
+    Unknown {
+        tag: String,
+        #[serde(default)]
+        value: serde_json::Value,
+    },
+}
+
+impl Default for Type {
+    fn default() -> Self {
+        Type::Unknown {
+            tag: String::new(),
+            value: serde_json::Value::Null,
+        }
+    }
+}
+
+/// Mirrors [`Type`]'s known (modeled) variants for deserialization -- see
+/// [`ItemEnumKnown`] for the rationale (same `#[serde(other)]` limitation
+/// applies here).
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum TypeKnown {
+    ResolvedPath(ResolvedPath),
+    Primitive(String),
+    Tuple(Vec<Type>),
+    Slice(Box<Type>),
+    Array {
+        #[serde(rename = "type")]
+        type_: Box<Type>,
+        len: String,
+    },
+    RawPointer {
+        #[serde(default)]
+        is_mutable: bool,
+        #[serde(rename = "type")]
+        type_: Box<Type>,
+    },
+    BorrowedRef {
+        #[serde(default)]
+        lifetime: Option<String>,
+        #[serde(default)]
+        is_mutable: bool,
+        #[serde(rename = "type")]
+        type_: Box<Type>,
+    },
+    FunctionPointer(Box<FunctionPointer>),
+    QualifiedPath {
+        name: String,
+        #[serde(default)]
+        args: Option<Box<GenericArgs>>,
+        self_type: Box<Type>,
+        #[serde(rename = "trait")]
+        #[serde(default)]
+        trait_: Option<ResolvedPath>,
+    },
+    ImplTrait(Vec<GenericBound>),
+    DynTrait(DynTrait),
+    Infer,
+    Generic(String),
+}
+
+impl From<TypeKnown> for Type {
+    fn from(known: TypeKnown) -> Self {
+        match known {
+            TypeKnown::ResolvedPath(path) => Type::ResolvedPath(path),
+            TypeKnown::Primitive(name) => Type::Primitive(name),
+            TypeKnown::Tuple(types) => Type::Tuple(types),
+            TypeKnown::Slice(ty) => Type::Slice(ty),
+            TypeKnown::Array { type_, len } => Type::Array { type_, len },
+            TypeKnown::RawPointer { is_mutable, type_ } => Type::RawPointer { is_mutable, type_ },
+            TypeKnown::BorrowedRef { lifetime, is_mutable, type_ } => {
+                Type::BorrowedRef { lifetime, is_mutable, type_ }
+            }
+            TypeKnown::FunctionPointer(fp) => Type::FunctionPointer(fp),
+            TypeKnown::QualifiedPath { name, args, self_type, trait_ } => {
+                Type::QualifiedPath { name, args, self_type, trait_ }
+            }
+            TypeKnown::ImplTrait(bounds) => Type::ImplTrait(bounds),
+            TypeKnown::DynTrait(dyn_trait) => Type::DynTrait(dyn_trait),
+            TypeKnown::Infer => Type::Infer,
+            TypeKnown::Generic(name) => Type::Generic(name),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        if let Ok(known) = serde_json::from_value::<TypeKnown>(value.clone()) {
+            return Ok(known.into());
+        }
+        let (tag, value) = split_tagged_value(value).map_err(serde::de::Error::custom)?;
+        Ok(Type::Unknown { tag, value })
+    }
 }
 
 /// A function pointer type.
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct FunctionPointer {
     #[serde(default)]
     pub sig: FunctionSignature,
@@ -528,7 +1004,7 @@ pub struct FunctionPointer {
 }
 
 /// Function signature.
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct FunctionSignature {
     /// Parameter (name, type) pairs.
     #[serde(default)]
@@ -541,7 +1017,7 @@ pub struct FunctionSignature {
 }
 
 /// Function header qualifiers.
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct FunctionHeader {
     #[serde(default)]
     pub is_const: bool,
@@ -554,7 +1030,7 @@ pub struct FunctionHeader {
 }
 
 /// Generics information.
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Generics {
     #[serde(default)]
     pub params: Vec<GenericParamDef>,
@@ -563,7 +1039,7 @@ pub struct Generics {
 }
 
 /// A generic parameter definition.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GenericParamDef {
     pub name: String,
     #[serde(default)]
@@ -571,7 +1047,7 @@ pub struct GenericParamDef {
 }
 
 /// Kind of generic parameter.
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum GenericParamDefKind {
     Lifetime {
@@ -602,7 +1078,7 @@ pub enum GenericParamDefKind {
 }
 
 /// A generic bound on a type parameter.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum GenericBound {
     TraitBound {
@@ -620,7 +1096,7 @@ pub enum GenericBound {
 }
 
 /// Trait bound modifier.
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum TraitBoundModifier {
     #[default]
@@ -630,7 +1106,7 @@ pub enum TraitBoundModifier {
 }
 
 /// Where predicate.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum WherePredicate {
     BoundPredicate {
@@ -652,12 +1128,13 @@ pub enum WherePredicate {
 }
 
 /// Generic arguments.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum GenericArgs {
     AngleBracketed {
         #[serde(default)]
         args: Vec<GenericArg>,
+        /// Named `bindings` before format version 27.
         #[serde(default)]
         constraints: Vec<TypeBinding>,
     },
@@ -671,7 +1148,7 @@ pub enum GenericArgs {
 }
 
 /// A single generic argument.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum GenericArg {
     Lifetime(String),
@@ -684,7 +1161,7 @@ pub enum GenericArg {
 }
 
 /// A constant value.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConstantValue {
     #[serde(default)]
     pub value: Option<String>,
@@ -693,7 +1170,7 @@ pub struct ConstantValue {
 }
 
 /// Type binding (e.g., `Iterator<Item = T>`).
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TypeBinding {
     pub name: String,
     #[serde(default)]
@@ -703,7 +1180,7 @@ pub struct TypeBinding {
 }
 
 /// Kind of type binding.
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum TypeBindingKind {
     Equality(Type),
@@ -717,7 +1194,7 @@ pub enum TypeBindingKind {
 }
 
 /// Dynamic trait object.
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct DynTrait {
     #[serde(default)]
     pub traits: Vec<PolyTrait>,
@@ -726,7 +1203,7 @@ pub struct DynTrait {
 }
 
 /// A trait in a dyn trait object (may have higher-ranked lifetimes).
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PolyTrait {
     #[serde(rename = "trait")]
     pub trait_: ResolvedPath,
@@ -0,0 +1,510 @@
+//! Pre-emit validation of a loaded [`Crate`], modeled on rustdoc's own
+//! `jsondoclint`: every [`Id`] an item references while describing itself
+//! (module contents, struct/union fields, enum variants, impl targets,
+//! trait bounds, doc links, ...) should resolve to either an `index` entry
+//! or a `paths` entry, and -- where we know what the reference is *for* --
+//! resolve to a compatible [`ItemKind`]. Running this before extraction
+//! catches malformed or inconsistently-stripped rustdoc JSON before it
+//! turns into an RDF graph with dangling node IRIs.
+
+use super::rustdoc_model::{
+    Crate, GenericArg, GenericArgs, GenericBound, GenericParamDefKind, Generics, Id, Item,
+    ItemEnum, ItemKind, MacroKind, StructKind, Type, TypeBindingKind, VariantKind, Visibility,
+    WherePredicate,
+};
+
+/// How serious a [`ValidationIssue`] is. Lets a caller (the CLI, a test)
+/// decide whether to warn-and-continue or fail hard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// An unrecognized `ItemEnum`/`Type` tag (see [`ItemEnum::Unknown`] and
+    /// [`Type::Unknown`]) -- not a broken reference, just a newer rustdoc
+    /// format feature this model doesn't know about yet.
+    Info,
+    /// An `Id` that resolves to neither `index` nor `paths`. Whatever
+    /// referenced it just won't be linked -- the extractor already treats
+    /// missing index lookups as "skip", so this is recoverable.
+    Warning,
+    /// An `Id` that resolves, but to an [`ItemKind`] that can't appear in
+    /// the position it was referenced from -- the JSON is internally
+    /// inconsistent, not merely incomplete.
+    Error,
+}
+
+/// One broken or suspicious `Id` reference found by [`validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    pub severity: Severity,
+    /// The `Id` of the item whose inner data contains the bad reference.
+    pub owner: Id,
+    /// What the owning item was referencing it for, e.g. `"module items"`,
+    /// `"trait bound"`.
+    pub context: &'static str,
+    /// The referenced `Id` that failed to resolve or mismatched kind.
+    pub reference: Id,
+    pub message: String,
+}
+
+/// `ItemKind`s that [rustdoc considers][mod-item-kinds] valid direct
+/// children of a module.
+///
+/// [mod-item-kinds]: https://github.com/rust-lang/rust/blob/master/src/rustdoc-json-types/lib.rs
+const MOD_ITEM_KINDS: &[ItemKind] = &[
+    ItemKind::Module,
+    ItemKind::ExternCrate,
+    ItemKind::Use,
+    ItemKind::Struct,
+    ItemKind::Union,
+    ItemKind::Enum,
+    ItemKind::Function,
+    ItemKind::TypeAlias,
+    ItemKind::Constant,
+    ItemKind::Trait,
+    ItemKind::TraitAlias,
+    ItemKind::Impl,
+    ItemKind::Static,
+    ItemKind::ExternType,
+    ItemKind::Macro,
+    ItemKind::ProcMacro,
+    ItemKind::ProcAttribute,
+    ItemKind::ProcDerive,
+    ItemKind::Primitive,
+    ItemKind::Keyword,
+];
+
+/// `ItemKind`s valid as a trait's or impl's direct `items`.
+const ASSOC_ITEM_KINDS: &[ItemKind] = &[
+    ItemKind::Function,
+    ItemKind::Constant,
+    ItemKind::AssocConst,
+    ItemKind::AssocType,
+    ItemKind::TypeAlias,
+];
+
+/// What an `Id` resolved to, as far as validation cares.
+enum Resolved<'a> {
+    /// Resolved to `crate_data.index`; we know its exact [`ItemEnum`].
+    Local(&'a Item),
+    /// Resolved to `crate_data.paths` (an out-of-crate or stripped item);
+    /// we only know its declared [`ItemKind`].
+    External(&'a ItemKind),
+    /// Not found in either map.
+    Missing,
+}
+
+fn resolve<'a>(crate_data: &'a Crate, id: &Id) -> Resolved<'a> {
+    if let Some(item) = crate_data.index.get(&id.0) {
+        return Resolved::Local(item);
+    }
+    if let Some(summary) = crate_data.paths.get(&id.0) {
+        return Resolved::External(&summary.kind);
+    }
+    Resolved::Missing
+}
+
+/// The [`ItemKind`] an [`ItemEnum`] variant corresponds to -- used to check
+/// a locally-resolved `Id` against an expected-kind list, the same way a
+/// `paths`-resolved one is checked against its declared [`ItemKind`].
+fn item_enum_kind(inner: &ItemEnum) -> ItemKind {
+    match inner {
+        ItemEnum::Module { .. } => ItemKind::Module,
+        ItemEnum::Struct { .. } => ItemKind::Struct,
+        ItemEnum::Union { .. } => ItemKind::Union,
+        ItemEnum::Enum { .. } => ItemKind::Enum,
+        ItemEnum::Variant(_) => ItemKind::Variant,
+        ItemEnum::Function { .. } => ItemKind::Function,
+        ItemEnum::Trait { .. } => ItemKind::Trait,
+        ItemEnum::Impl { .. } => ItemKind::Impl,
+        ItemEnum::Use { .. } => ItemKind::Use,
+        ItemEnum::ExternCrate { .. } => ItemKind::ExternCrate,
+        ItemEnum::TraitAlias { .. } => ItemKind::TraitAlias,
+        ItemEnum::TypeAlias { .. } => ItemKind::TypeAlias,
+        ItemEnum::Constant { .. } => ItemKind::Constant,
+        ItemEnum::Static { .. } => ItemKind::Static,
+        ItemEnum::StructField(_) => ItemKind::StructField,
+        ItemEnum::Macro(_) => ItemKind::Macro,
+        ItemEnum::ProcMacro { kind, .. } => match kind {
+            MacroKind::Bang => ItemKind::ProcMacro,
+            MacroKind::Attr => ItemKind::ProcAttribute,
+            MacroKind::Derive => ItemKind::ProcDerive,
+        },
+        ItemEnum::ExternType => ItemKind::ExternType,
+        ItemEnum::Primitive { .. } => ItemKind::Primitive,
+        ItemEnum::Keyword => ItemKind::Keyword,
+        ItemEnum::AssocConst { .. } => ItemKind::AssocConst,
+        ItemEnum::AssocType { .. } => ItemKind::AssocType,
+        ItemEnum::Unknown { .. } => ItemKind::Module, // placeholder; never checked against
+    }
+}
+
+/// Validate every `Id` referenced from `crate_data`'s `index`, returning one
+/// [`ValidationIssue`] per broken or kind-mismatched reference. An empty
+/// result means every reference resolved cleanly.
+pub fn validate(crate_data: &Crate) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    for (id, item) in &crate_data.index {
+        let owner = Id(id.clone());
+        check_item(crate_data, &owner, item, &mut issues);
+    }
+    issues
+}
+
+/// Record an issue if `reference` doesn't resolve, or resolves to an
+/// `ItemKind` absent from `allowed` (an empty `allowed` slice skips the
+/// kind check, e.g. for references whose valid kinds aren't known here).
+fn check_ref(
+    crate_data: &Crate,
+    owner: &Id,
+    context: &'static str,
+    reference: &Id,
+    allowed: &[ItemKind],
+    issues: &mut Vec<ValidationIssue>,
+) {
+    match resolve(crate_data, reference) {
+        Resolved::Missing => issues.push(ValidationIssue {
+            severity: Severity::Warning,
+            owner: owner.clone(),
+            context,
+            reference: reference.clone(),
+            message: format!(
+                "{context}: Id {:?} is not present in `index` or `paths`",
+                reference.0
+            ),
+        }),
+        Resolved::Local(item) if !allowed.is_empty() && !matches!(item.inner, ItemEnum::Unknown { .. }) => {
+            let kind = item_enum_kind(&item.inner);
+            if !allowed.contains(&kind) {
+                issues.push(ValidationIssue {
+                    severity: Severity::Error,
+                    owner: owner.clone(),
+                    context,
+                    reference: reference.clone(),
+                    message: format!(
+                        "{context}: Id {:?} resolves to a {kind:?}, which can't appear there",
+                        reference.0
+                    ),
+                });
+            }
+        }
+        Resolved::External(kind) if !allowed.is_empty() => {
+            if !allowed.contains(kind) {
+                issues.push(ValidationIssue {
+                    severity: Severity::Error,
+                    owner: owner.clone(),
+                    context,
+                    reference: reference.clone(),
+                    message: format!(
+                        "{context}: Id {:?} resolves (via `paths`) to a {kind:?}, which can't appear there",
+                        reference.0
+                    ),
+                });
+            }
+        }
+        Resolved::Local(_) | Resolved::External(_) => {}
+    }
+}
+
+fn check_item(crate_data: &Crate, owner: &Id, item: &Item, issues: &mut Vec<ValidationIssue>) {
+    if let Visibility::Restricted(restricted) = &item.visibility {
+        check_ref(
+            crate_data,
+            owner,
+            "visibility restriction",
+            &restricted.parent,
+            &[ItemKind::Module],
+            issues,
+        );
+    }
+
+    for link_target in item.links.values() {
+        check_ref(crate_data, owner, "doc link", link_target, &[], issues);
+    }
+
+    match &item.inner {
+        ItemEnum::Module { items, .. } => {
+            for id in items {
+                check_ref(crate_data, owner, "module items", id, MOD_ITEM_KINDS, issues);
+            }
+        }
+        ItemEnum::Struct { kind, generics, impls } => {
+            check_struct_kind(crate_data, owner, kind, issues);
+            check_generics(crate_data, owner, generics, issues);
+            for id in impls {
+                check_ref(crate_data, owner, "struct impls", id, &[ItemKind::Impl], issues);
+            }
+        }
+        ItemEnum::Union { generics, fields, impls, .. } => {
+            check_generics(crate_data, owner, generics, issues);
+            for id in fields {
+                check_ref(crate_data, owner, "union fields", id, &[ItemKind::StructField], issues);
+            }
+            for id in impls {
+                check_ref(crate_data, owner, "union impls", id, &[ItemKind::Impl], issues);
+            }
+        }
+        ItemEnum::Enum { generics, variants, impls, .. } => {
+            check_generics(crate_data, owner, generics, issues);
+            for id in variants {
+                check_ref(crate_data, owner, "enum variants", id, &[ItemKind::Variant], issues);
+            }
+            for id in impls {
+                check_ref(crate_data, owner, "enum impls", id, &[ItemKind::Impl], issues);
+            }
+        }
+        ItemEnum::Variant(data) => match &data.kind {
+            VariantKind::Tuple(fields) => {
+                for id in fields.iter().flatten() {
+                    check_ref(crate_data, owner, "variant fields", id, &[ItemKind::StructField], issues);
+                }
+            }
+            VariantKind::Struct { fields, .. } => {
+                for id in fields {
+                    check_ref(crate_data, owner, "variant fields", id, &[ItemKind::StructField], issues);
+                }
+            }
+            VariantKind::Plain => {}
+        },
+        ItemEnum::Function { sig, generics, .. } => {
+            check_generics(crate_data, owner, generics, issues);
+            for (_, ty) in &sig.inputs {
+                check_type(crate_data, owner, "function parameter type", ty, issues);
+            }
+            if let Some(output) = &sig.output {
+                check_type(crate_data, owner, "function return type", output, issues);
+            }
+        }
+        ItemEnum::Trait { generics, bounds, items, implementations, .. } => {
+            check_generics(crate_data, owner, generics, issues);
+            check_bounds(crate_data, owner, "trait supertrait bound", bounds, issues);
+            for id in items {
+                check_ref(crate_data, owner, "trait items", id, ASSOC_ITEM_KINDS, issues);
+            }
+            for id in implementations {
+                check_ref(crate_data, owner, "trait implementations", id, &[ItemKind::Impl], issues);
+            }
+        }
+        ItemEnum::Impl { generics, trait_, for_, items, .. } => {
+            check_generics(crate_data, owner, generics, issues);
+            if let Some(trait_path) = trait_ {
+                if let Some(id) = &trait_path.id {
+                    check_ref(crate_data, owner, "impl trait target", id, &[ItemKind::Trait], issues);
+                }
+            }
+            check_type(crate_data, owner, "impl for-type", for_, issues);
+            for id in items {
+                check_ref(crate_data, owner, "impl items", id, ASSOC_ITEM_KINDS, issues);
+            }
+        }
+        ItemEnum::Use { id, .. } => {
+            if let Some(id) = id {
+                check_ref(crate_data, owner, "use target", id, &[], issues);
+            }
+        }
+        ItemEnum::TraitAlias { generics, params } => {
+            check_generics(crate_data, owner, generics, issues);
+            check_bounds(crate_data, owner, "trait alias bound", params, issues);
+        }
+        ItemEnum::Primitive { impls, .. } => {
+            for id in impls {
+                check_ref(crate_data, owner, "primitive impls", id, &[ItemKind::Impl], issues);
+            }
+        }
+        ItemEnum::TypeAlias { generics, type_ } => {
+            check_generics(crate_data, owner, generics, issues);
+            if let Some(ty) = type_ {
+                check_type(crate_data, owner, "type alias target", ty, issues);
+            }
+        }
+        ItemEnum::Constant { type_, .. } => {
+            check_type(crate_data, owner, "constant type", type_, issues);
+        }
+        ItemEnum::Static { type_, .. } => {
+            check_type(crate_data, owner, "static type", type_, issues);
+        }
+        ItemEnum::StructField(ty) => {
+            check_type(crate_data, owner, "struct field type", ty, issues);
+        }
+        ItemEnum::AssocConst { type_, .. } => {
+            check_type(crate_data, owner, "associated const type", type_, issues);
+        }
+        ItemEnum::AssocType { generics, bounds, type_, .. } => {
+            check_generics(crate_data, owner, generics, issues);
+            check_bounds(crate_data, owner, "associated type bound", bounds, issues);
+            if let Some(ty) = type_ {
+                check_type(crate_data, owner, "associated type default", ty, issues);
+            }
+        }
+        ItemEnum::ExternCrate { .. }
+        | ItemEnum::ProcMacro { .. }
+        | ItemEnum::ExternType
+        | ItemEnum::Keyword
+        | ItemEnum::Macro(_) => {}
+        ItemEnum::Unknown { tag, .. } => {
+            issues.push(ValidationIssue {
+                severity: Severity::Info,
+                owner: owner.clone(),
+                context: "item kind",
+                reference: owner.clone(),
+                message: format!("item has unrecognized `inner` tag {tag:?}; likely a newer rustdoc format"),
+            });
+        }
+    }
+}
+
+fn check_struct_kind(crate_data: &Crate, owner: &Id, kind: &StructKind, issues: &mut Vec<ValidationIssue>) {
+    match kind {
+        StructKind::Plain { fields, .. } => {
+            for id in fields {
+                check_ref(crate_data, owner, "struct fields", id, &[ItemKind::StructField], issues);
+            }
+        }
+        StructKind::Tuple(fields) => {
+            for id in fields.iter().flatten() {
+                check_ref(crate_data, owner, "struct fields", id, &[ItemKind::StructField], issues);
+            }
+        }
+        StructKind::Unit => {}
+    }
+}
+
+fn check_generics(crate_data: &Crate, owner: &Id, generics: &Generics, issues: &mut Vec<ValidationIssue>) {
+    for param in &generics.params {
+        match &param.kind {
+            GenericParamDefKind::Type { bounds, default, .. } => {
+                check_bounds(crate_data, owner, "generic param bound", bounds, issues);
+                if let Some(ty) = default {
+                    check_type(crate_data, owner, "generic param default", ty, issues);
+                }
+            }
+            GenericParamDefKind::Const { type_, .. } => {
+                check_type(crate_data, owner, "const generic param type", type_, issues);
+            }
+            GenericParamDefKind::Lifetime { .. } | GenericParamDefKind::Unknown => {}
+        }
+    }
+    for predicate in &generics.where_predicates {
+        match predicate {
+            WherePredicate::BoundPredicate { type_, bounds, .. } => {
+                check_type(crate_data, owner, "where-clause type", type_, issues);
+                check_bounds(crate_data, owner, "where-clause bound", bounds, issues);
+            }
+            WherePredicate::EqPredicate { lhs, rhs } => {
+                check_type(crate_data, owner, "where-clause equality lhs", lhs, issues);
+                check_type(crate_data, owner, "where-clause equality rhs", rhs, issues);
+            }
+            WherePredicate::LifetimePredicate { .. } => {}
+        }
+    }
+}
+
+fn check_bounds(
+    crate_data: &Crate,
+    owner: &Id,
+    context: &'static str,
+    bounds: &[GenericBound],
+    issues: &mut Vec<ValidationIssue>,
+) {
+    for bound in bounds {
+        if let GenericBound::TraitBound { trait_, .. } = bound {
+            if let Some(id) = &trait_.id {
+                check_ref(crate_data, owner, context, id, &[ItemKind::Trait], issues);
+            }
+        }
+    }
+}
+
+fn check_type(crate_data: &Crate, owner: &Id, context: &'static str, ty: &Type, issues: &mut Vec<ValidationIssue>) {
+    match ty {
+        Type::ResolvedPath(path) => {
+            if let Some(id) = &path.id {
+                check_ref(crate_data, owner, context, id, &[], issues);
+            }
+            if let Some(args) = &path.args {
+                check_generic_args(crate_data, owner, context, args, issues);
+            }
+        }
+        Type::Tuple(members) => {
+            for member in members {
+                check_type(crate_data, owner, context, member, issues);
+            }
+        }
+        Type::Slice(inner) | Type::RawPointer { type_: inner, .. } | Type::BorrowedRef { type_: inner, .. } => {
+            check_type(crate_data, owner, context, inner, issues);
+        }
+        Type::Array { type_, .. } => {
+            check_type(crate_data, owner, context, type_, issues);
+        }
+        Type::FunctionPointer(fp) => {
+            for (_, param_ty) in &fp.sig.inputs {
+                check_type(crate_data, owner, context, param_ty, issues);
+            }
+            if let Some(output) = &fp.sig.output {
+                check_type(crate_data, owner, context, output, issues);
+            }
+        }
+        Type::QualifiedPath { args, self_type, trait_, .. } => {
+            check_type(crate_data, owner, context, self_type, issues);
+            if let Some(args) = args {
+                check_generic_args(crate_data, owner, context, args, issues);
+            }
+            if let Some(trait_path) = trait_ {
+                if let Some(id) = &trait_path.id {
+                    check_ref(crate_data, owner, context, id, &[ItemKind::Trait], issues);
+                }
+            }
+        }
+        Type::ImplTrait(bounds) => check_bounds(crate_data, owner, context, bounds, issues),
+        Type::DynTrait(dyn_trait) => {
+            for poly_trait in &dyn_trait.traits {
+                if let Some(id) = &poly_trait.trait_.id {
+                    check_ref(crate_data, owner, context, id, &[ItemKind::Trait], issues);
+                }
+            }
+        }
+        Type::Primitive(_) | Type::Infer | Type::Generic(_) => {}
+        Type::Unknown { tag, .. } => {
+            issues.push(ValidationIssue {
+                severity: Severity::Info,
+                owner: owner.clone(),
+                context,
+                reference: owner.clone(),
+                message: format!("{context}: unrecognized type tag {tag:?}; likely a newer rustdoc format"),
+            });
+        }
+    }
+}
+
+fn check_generic_args(
+    crate_data: &Crate,
+    owner: &Id,
+    context: &'static str,
+    args: &GenericArgs,
+    issues: &mut Vec<ValidationIssue>,
+) {
+    match args {
+        GenericArgs::AngleBracketed { args, constraints } => {
+            for arg in args {
+                if let GenericArg::Type(ty) = arg {
+                    check_type(crate_data, owner, context, ty, issues);
+                }
+            }
+            for binding in constraints {
+                match &binding.binding {
+                    TypeBindingKind::Equality(ty) => check_type(crate_data, owner, context, ty, issues),
+                    TypeBindingKind::Constraint(bounds) => {
+                        check_bounds(crate_data, owner, context, bounds, issues)
+                    }
+                    TypeBindingKind::Unknown => {}
+                }
+            }
+        }
+        GenericArgs::Parenthesized { inputs, output } => {
+            for ty in inputs {
+                check_type(crate_data, owner, context, ty, issues);
+            }
+            if let Some(output) = output {
+                check_type(crate_data, owner, context, output, issues);
+            }
+        }
+    }
+}
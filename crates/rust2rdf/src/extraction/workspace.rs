@@ -0,0 +1,133 @@
+//! Load and merge every crate in a Cargo workspace into one ontology graph.
+//!
+//! [`load_workspace`] enumerates workspace members from the root
+//! `Cargo.toml`'s `[workspace]` table, runs [`rustdoc_loader::load_crate`]
+//! on each, and merges the results with [`super::merge::merge_crates`] so
+//! one member's `ResolvedPath` into another resolves to that member's real
+//! item rather than dangling. A member that fails to build (missing
+//! toolchain, compile error, ...) is recorded in
+//! [`WorkspaceLoadResult::failed_members`] rather than aborting the whole
+//! run -- a broken leaf crate shouldn't block documenting the rest of the
+//! workspace.
+
+use std::path::{Path, PathBuf};
+
+use super::merge::{merge_crates, MergeInput, MergedCrate};
+use super::rustdoc_loader::{self, LoadError};
+
+/// A workspace member that failed to load, and why.
+#[derive(Debug)]
+pub struct FailedMember {
+    pub crate_dir: PathBuf,
+    pub error: String,
+}
+
+/// The result of [`load_workspace`]: the merged graph of every member that
+/// loaded successfully, plus a record of the ones that didn't.
+pub struct WorkspaceLoadResult {
+    pub merged: MergedCrate,
+    pub failed_members: Vec<FailedMember>,
+}
+
+/// Load and merge every member of the workspace rooted at `workspace_dir`.
+/// Members that fail to load are skipped and recorded in
+/// [`WorkspaceLoadResult::failed_members`]; this only returns `Err` if the
+/// workspace root itself can't be read.
+pub fn load_workspace(workspace_dir: &Path) -> Result<WorkspaceLoadResult, LoadError> {
+    let member_dirs = enumerate_workspace_members(workspace_dir)?;
+
+    let mut inputs = Vec::new();
+    let mut failed_members = Vec::new();
+
+    for member_dir in member_dirs {
+        match load_member(&member_dir) {
+            Ok(input) => inputs.push(input),
+            Err(error) => failed_members.push(FailedMember {
+                crate_dir: member_dir,
+                error: error.to_string(),
+            }),
+        }
+    }
+
+    Ok(WorkspaceLoadResult {
+        merged: merge_crates(inputs),
+        failed_members,
+    })
+}
+
+fn load_member(member_dir: &Path) -> Result<MergeInput, LoadError> {
+    let metadata = rustdoc_loader::resolve_package_metadata(member_dir)?;
+    let crate_name = metadata.name.ok_or(LoadError::CrateNameNotFound)?;
+    let crate_data = rustdoc_loader::load_crate(member_dir)?;
+    Ok(MergeInput {
+        crate_name,
+        crate_data,
+    })
+}
+
+/// Resolve `workspace_dir`'s `[workspace].members`/`default-members` into
+/// concrete, de-duplicated member directories (each one confirmed to have
+/// its own `Cargo.toml`).
+pub fn enumerate_workspace_members(workspace_dir: &Path) -> Result<Vec<PathBuf>, LoadError> {
+    let cargo_toml_path = workspace_dir.join("Cargo.toml");
+    let content = std::fs::read_to_string(&cargo_toml_path)?;
+    let document: toml::Value = content.parse::<toml::Value>().map_err(LoadError::TomlParse)?;
+
+    let Some(workspace) = document.get("workspace") else {
+        return Ok(Vec::new());
+    };
+
+    let patterns = string_array(workspace, "members")
+        .into_iter()
+        .chain(string_array(workspace, "default-members"));
+    let exclude: Vec<PathBuf> = string_array(workspace, "exclude")
+        .into_iter()
+        .map(|excluded| workspace_dir.join(excluded))
+        .collect();
+
+    let mut members = Vec::new();
+    for pattern in patterns {
+        for dir in resolve_member_pattern(workspace_dir, &pattern)? {
+            if exclude.contains(&dir) || members.contains(&dir) {
+                continue;
+            }
+            if dir.join("Cargo.toml").is_file() {
+                members.push(dir);
+            }
+        }
+    }
+    Ok(members)
+}
+
+fn string_array(table: &toml::Value, field: &str) -> Vec<String> {
+    table
+        .get(field)
+        .and_then(toml::Value::as_array)
+        .map(|values| values.iter().filter_map(toml::Value::as_str).map(str::to_string).collect())
+        .unwrap_or_default()
+}
+
+/// Resolve one `members`/`default-members` entry into concrete directories.
+/// Supports exact paths and a single trailing `*` path segment (e.g.
+/// `crates/*`) -- the form real-world workspaces overwhelmingly use.
+/// Deeper glob forms (`**`, a `*` in the middle of a path) aren't supported.
+fn resolve_member_pattern(workspace_dir: &Path, pattern: &str) -> Result<Vec<PathBuf>, LoadError> {
+    let Some(prefix) = pattern.strip_suffix("/*") else {
+        return Ok(vec![workspace_dir.join(pattern)]);
+    };
+
+    let base = workspace_dir.join(prefix);
+    if !base.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut dirs = Vec::new();
+    for entry in std::fs::read_dir(&base)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            dirs.push(path);
+        }
+    }
+    dirs.sort();
+    Ok(dirs)
+}
@@ -0,0 +1,191 @@
+//! Self-contained interactive HTML export of a node/edge graph.
+//!
+//! Renders a [`Graph`] as a Plotly.js scatter/line diagram embedded in a
+//! single HTML file (Plotly loaded from its CDN) so the result can be
+//! opened directly in a browser with no separate asset files or server.
+//! Node labels and hover text are typically
+//! [`type_display_name`](crate::extraction::extractor) output, but this
+//! module has no dependency on the extractor -- it only knows about plain
+//! nodes and edges.
+
+use std::io::{self, Write};
+
+/// Client-side layout strategy for the exported diagram.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphLayout {
+    /// Nodes are placed on a golden-angle spiral in input order;
+    /// [`GraphNode::layer`] is ignored. No force simulation is run, either
+    /// here or in the generated page.
+    ForceDirected,
+    /// Nodes are placed in horizontal bands by [`GraphNode::layer`].
+    Hierarchical,
+}
+
+/// The kind of relationship an edge represents. Used only to pick the
+/// edge's color in the rendered diagram.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EdgeKind {
+    Reference,
+    Ownership,
+    Implementation,
+    Inheritance,
+}
+
+impl EdgeKind {
+    fn color(self) -> &'static str {
+        match self {
+            EdgeKind::Reference => "#4a90d9",
+            EdgeKind::Ownership => "#d94a4a",
+            EdgeKind::Implementation => "#4ad98a",
+            EdgeKind::Inheritance => "#d9a84a",
+        }
+    }
+}
+
+/// A single node in the exported graph.
+#[derive(Debug, Clone)]
+pub struct GraphNode {
+    pub id: String,
+    pub label: String,
+    pub hover_text: String,
+    /// Hierarchical layer (root = 0); only consulted under
+    /// [`GraphLayout::Hierarchical`].
+    pub layer: usize,
+}
+
+/// A directed edge between two [`GraphNode::id`]s.
+#[derive(Debug, Clone)]
+pub struct GraphEdge {
+    pub from: String,
+    pub to: String,
+    pub kind: EdgeKind,
+}
+
+/// The node/edge graph to render. Callers build this from whatever
+/// in-memory representation they have (e.g. a traversal over extracted
+/// triples) before handing it to [`export_html`].
+#[derive(Debug, Clone, Default)]
+pub struct Graph {
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<GraphEdge>,
+}
+
+impl Graph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Escape a string for safe embedding inside a double-quoted JS string
+/// literal in the generated HTML template.
+fn js_escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+        .replace('<', "\\u003C")
+}
+
+fn node_position(node: &GraphNode, index: usize, layout: GraphLayout) -> (f64, f64) {
+    match layout {
+        // Non-overlapping spiral positions; these are the final positions,
+        // since neither this code nor the generated page runs a simulation.
+        GraphLayout::ForceDirected => {
+            let angle = index as f64 * 2.399963229728653; // golden-angle spiral
+            let radius = (index as f64).sqrt();
+            (radius * angle.cos(), radius * angle.sin())
+        }
+        GraphLayout::Hierarchical => (index as f64, -(node.layer as f64)),
+    }
+}
+
+/// Write `graph` as a self-contained interactive HTML document to `writer`.
+pub fn export_html<W: Write>(graph: &Graph, layout: GraphLayout, writer: &mut W) -> io::Result<()> {
+    let node_index: std::collections::HashMap<&str, usize> = graph
+        .nodes
+        .iter()
+        .enumerate()
+        .map(|(i, n)| (n.id.as_str(), i))
+        .collect();
+
+    let mut node_x = Vec::with_capacity(graph.nodes.len());
+    let mut node_y = Vec::with_capacity(graph.nodes.len());
+    let mut node_labels = Vec::with_capacity(graph.nodes.len());
+    let mut node_hover = Vec::with_capacity(graph.nodes.len());
+    for (i, node) in graph.nodes.iter().enumerate() {
+        let (x, y) = node_position(node, i, layout);
+        node_x.push(x);
+        node_y.push(y);
+        node_labels.push(format!("\"{}\"", js_escape(&node.label)));
+        node_hover.push(format!("\"{}\"", js_escape(&node.hover_text)));
+    }
+
+    let mut edge_traces = String::new();
+    for edge in &graph.edges {
+        let from_i = match node_index.get(edge.from.as_str()) {
+            Some(&i) => i,
+            None => continue,
+        };
+        let to_i = match node_index.get(edge.to.as_str()) {
+            Some(&i) => i,
+            None => continue,
+        };
+        edge_traces.push_str(&format!(
+            "{{x:[{},{}],y:[{},{}],mode:\"lines\",type:\"scatter\",hoverinfo:\"skip\",showlegend:false,line:{{color:\"{}\"}}}},\n",
+            node_x[from_i], node_x[to_i], node_y[from_i], node_y[to_i], edge.kind.color()
+        ));
+    }
+
+    let layout_name = match layout {
+        GraphLayout::ForceDirected => "force-directed",
+        GraphLayout::Hierarchical => "hierarchical",
+    };
+
+    write!(
+        writer,
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>rust2rdf graph export ({layout_name})</title>
+<script src="https://cdn.plot.ly/plotly-latest.min.js"></script>
+</head>
+<body>
+<div id="graph" style="width:100%;height:100vh;"></div>
+<script>
+var nodeTrace = {{
+  x: [{node_x}],
+  y: [{node_y}],
+  text: [{node_labels}],
+  hovertext: [{node_hover}],
+  mode: "markers+text",
+  type: "scatter",
+  textposition: "top center",
+  marker: {{ size: 10 }}
+}};
+var traces = [{edge_traces}nodeTrace];
+Plotly.newPlot("graph", traces, {{
+  title: "rust2rdf graph ({layout_name} layout)",
+  showlegend: false,
+  hovermode: "closest"
+}});
+</script>
+</body>
+</html>
+"#,
+        layout_name = layout_name,
+        node_x = node_x
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join(","),
+        node_y = node_y
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join(","),
+        node_labels = node_labels.join(","),
+        node_hover = node_hover.join(","),
+        edge_traces = edge_traces,
+    )
+}
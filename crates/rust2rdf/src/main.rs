@@ -5,30 +5,45 @@ use std::process;
 
 use clap::Parser;
 
+use rust2rdf::config::Config;
+use rust2rdf::diff::diff_lines;
+use rust2rdf::emitter::canonical::CanonicalEmitter;
+use rust2rdf::emitter::jsonld::JsonLdEmitter;
+use rust2rdf::emitter::nquads::NQuadsEmitter;
 use rust2rdf::emitter::ntriples::NTriplesEmitter;
+use rust2rdf::emitter::trig::TriGEmitter;
 use rust2rdf::emitter::turtle::TurtleEmitter;
 use rust2rdf::emitter::TriplesEmitter;
-use rust2rdf::extraction::extractor::{CrateExtractor, ExtractionOptions};
+use rust2rdf::extraction::extractor::{CrateExtractor, ExtractionOptions, VisibilityMode};
 use rust2rdf::extraction::rustdoc_loader::{load_crate, load_json};
+use rust2rdf::extraction::rustdoc_model::Crate;
+use rust2rdf::extraction::validation::{self, Severity};
+use rust2rdf::extraction::workspace::load_workspace;
 
 /// Extract Rust crate type graphs to RDF format.
 #[derive(Parser)]
 #[command(name = "rust2rdf", version, about)]
 struct Cli {
-    /// Path to crate directory or rustdoc JSON file.
+    /// Path to crate directory or rustdoc JSON file. With `--workspace`,
+    /// the directory containing the workspace root `Cargo.toml` instead.
     input: PathBuf,
 
     /// Output file path [default: stdout].
     #[arg(short, long, value_name = "FILE")]
     output: Option<PathBuf>,
 
-    /// Output format: ntriples, turtle.
-    #[arg(short, long, value_name = "FORMAT", default_value = "ntriples")]
-    format: String,
+    /// Output format: ntriples, turtle, nquads, trig, jsonld. Overrides `format` in `--config`.
+    #[arg(short, long, value_name = "FORMAT")]
+    format: Option<String>,
+
+    /// Base URI for IRIs. Overrides `base_uri` in `--config`.
+    #[arg(short, long, value_name = "URI")]
+    base_uri: Option<String>,
 
-    /// Base URI for IRIs.
-    #[arg(short, long, value_name = "URI", default_value = "http://rust.example/")]
-    base_uri: String,
+    /// Path to a TOML config file providing defaults and path include/exclude
+    /// filters. CLI flags take precedence over values set here.
+    #[arg(long, value_name = "FILE")]
+    config: Option<PathBuf>,
 
     /// Exclude impl blocks.
     #[arg(long)]
@@ -38,6 +53,10 @@ struct Cli {
     #[arg(long)]
     exclude_attributes: bool,
 
+    /// Exclude source-location (`span`) triples.
+    #[arg(long)]
+    exclude_spans: bool,
+
     /// Don't extract Result<T,E> error types.
     #[arg(long)]
     no_error_types: bool,
@@ -46,10 +65,104 @@ struct Cli {
     #[arg(long)]
     no_derives: bool,
 
+    /// Don't extract stability/deprecation metadata.
+    #[arg(long)]
+    no_stability: bool,
+
+    /// Don't extract `tg:documentation`/`tg:summary` from doc comments.
+    #[arg(long)]
+    no_docs: bool,
+
+    /// Language tag for `tg:documentation`/`tg:summary` literals.
+    #[arg(long, default_value = "en")]
+    doc_language: String,
+
+    /// Don't describe primitive-type nodes with category/width/signedness.
+    #[arg(long)]
+    no_primitive_metadata: bool,
+
+    /// Don't emit synthetic `rt:DerivedImpl`/`rt:implementsTrait` edges for
+    /// `#[derive(...)]`-sourced impls (the `rt:derives` literal is kept
+    /// either way).
+    #[arg(long)]
+    no_derive_impls: bool,
+
+    /// Don't synthesize `rt:AutoTraitImpl` nodes for structurally-inferred
+    /// Send/Sync/Unpin/UnwindSafe impls.
+    #[arg(long)]
+    no_synthesize_auto_traits: bool,
+
+    /// Don't resolve `impl<T: Bound> Trait for T` blanket impls against
+    /// in-crate types, i.e. skip `rt:impliesImplFor` edges.
+    #[arg(long)]
+    exclude_blanket_impls: bool,
+
+    /// Which items to emit, by doc/public-API reachability from the crate
+    /// root: `all` (default), `doc-reachable`, or `public-api`.
+    #[arg(long, default_value = "all")]
+    visibility: String,
+
+    /// Don't analyze trait object-safety, i.e. skip `rt:objectSafe`/
+    /// `rt:objectSafetyViolation`/`rt:excludedFromObject`.
+    #[arg(long)]
+    no_object_safety: bool,
+
+    /// Don't extract specialization metadata, i.e. skip `rt:isDefaultImpl`/
+    /// `rt:isSpecializable`/`rt:specializes`.
+    #[arg(long)]
+    no_specialization: bool,
+
+    /// Link re-exported items to their rustdoc-canonical path with
+    /// `owl:sameAs`, deduplicating IRIs that a `pub use` facade would
+    /// otherwise mint twice.
+    #[arg(long)]
+    canonicalize_paths: bool,
+
+    /// Mint short `<shortname>-<hash>` IRIs for heavily-generic type names
+    /// (e.g. `HashMap<K, V>`) instead of percent-encoding the whole generic
+    /// signature, attaching the original name as an `rdfs:label`.
+    #[arg(long)]
+    hash_complex_iris: bool,
+
+    /// Number of worker threads for extraction. `1` (default) walks the
+    /// crate single-threaded; values above `1` split the root module's
+    /// children across a thread pool without changing output bytes.
+    #[arg(long, default_value_t = 1)]
+    jobs: usize,
+
     /// Input is a pre-generated rustdoc JSON file.
     #[arg(long)]
     json: bool,
 
+    /// Treat `input` as a Cargo workspace root: load every member listed in
+    /// its `[workspace]` table and merge them into one unified graph before
+    /// extraction. Members that fail to load are reported and skipped
+    /// rather than aborting the whole run. Incompatible with `--json` and
+    /// `--diff-against`.
+    #[arg(long, conflicts_with_all = ["json", "diff_against"])]
+    workspace: bool,
+
+    /// Skip pre-emit validation of the loaded crate (dangling `Id`
+    /// references, `ItemKind` mismatches). Validation only warns by
+    /// default, so this just saves the pass.
+    #[arg(long)]
+    no_validate: bool,
+
+    /// Treat pre-emit validation errors (not warnings) as fatal instead of
+    /// warning and continuing with extraction.
+    #[arg(long)]
+    strict_validate: bool,
+
+    /// Diff mode: path to the "old" rustdoc JSON to compare `input` (the "new"
+    /// version) against. Emits added/removed triples instead of the full graph.
+    #[arg(long, value_name = "FILE")]
+    diff_against: Option<PathBuf>,
+
+    /// Produce byte-stable output: triples are sorted lexicographically by
+    /// (subject, predicate, object) so runs over the same input can be diffed.
+    #[arg(long)]
+    canonical: bool,
+
     /// Verbose output.
     #[arg(short, long)]
     verbose: bool,
@@ -59,42 +172,212 @@ struct Cli {
     quiet: bool,
 }
 
-fn run(cli: Cli) -> Result<(), Box<dyn std::error::Error>> {
-    // Load the crate data
-    if cli.verbose {
-        eprintln!("Loading input from: {}", cli.input.display());
-    }
+/// Resolved settings after merging `--config` with CLI flags (CLI wins).
+struct Settings {
+    format: String,
+    options: ExtractionOptions,
+}
 
-    let crate_data = if cli.json {
-        load_json(&cli.input)?
-    } else {
-        load_crate(&cli.input)?
+/// Merge an optional `--config` file with CLI flags into final settings.
+/// CLI-supplied values always take precedence over the config file.
+fn resolve_settings(cli: &Cli) -> Result<Settings, Box<dyn std::error::Error>> {
+    let config = match &cli.config {
+        Some(path) => Config::load(path)?,
+        None => Config::default(),
     };
 
-    // Determine crate name and version from the loaded data
-    let crate_name = crate_data
-        .index
-        .get(&crate_data.root.0)
-        .and_then(|item| item.name.clone())
-        .unwrap_or_else(|| "unknown".to_string());
+    let format = cli
+        .format
+        .clone()
+        .or(config.format.clone())
+        .unwrap_or_else(|| "ntriples".to_string())
+        .to_lowercase();
 
-    let crate_version = crate_data
-        .crate_version
+    let base_uri = cli
+        .base_uri
         .clone()
-        .unwrap_or_else(|| "0.0.0".to_string());
+        .or(config.base_uri.clone())
+        .unwrap_or_else(|| "http://rust.example/".to_string());
 
-    if cli.verbose {
-        eprintln!("Crate: {crate_name} v{crate_version}");
-    }
+    let visibility = match cli.visibility.to_lowercase().as_str() {
+        "all" => VisibilityMode::All,
+        "doc-reachable" | "doc_reachable" => VisibilityMode::DocReachable,
+        "public-api" | "public_api" => VisibilityMode::PublicApi,
+        other => {
+            return Err(format!(
+                "Unknown visibility mode: {other}. Use 'all', 'doc-reachable', or 'public-api'."
+            )
+            .into())
+        }
+    };
 
-    // Build extraction options
     let options = ExtractionOptions {
-        base_uri: cli.base_uri.clone(),
+        base_uri,
         include_impls: !cli.exclude_impls,
         include_attributes: !cli.exclude_attributes,
+        include_spans: !cli.exclude_spans,
         extract_error_types: !cli.no_error_types,
         extract_derives: !cli.no_derives,
+        extract_stability: !cli.no_stability,
+        extract_docs: !cli.no_docs,
+        doc_language: cli.doc_language.clone(),
+        extract_primitive_metadata: !cli.no_primitive_metadata,
+        extract_derive_impls: !cli.no_derive_impls,
+        extra_derive_traits: config.derive_traits.clone(),
+        synthesize_auto_traits: !cli.no_synthesize_auto_traits,
+        include_blanket_impls: !cli.exclude_blanket_impls,
+        analyze_object_safety: !cli.no_object_safety,
+        extract_specialization: !cli.no_specialization,
+        visibility,
+        path_filter: config.path_filter(),
+        extra_prefixes: config.prefixes.clone(),
+        canonicalize_paths: cli.canonicalize_paths,
+        hash_complex_iris: cli.hash_complex_iris,
+        jobs: cli.jobs,
+    };
+
+    Ok(Settings { format, options })
+}
+
+/// Render a fully extracted, canonicalized graph as a flat list of lines in
+/// the given format (`ntriples`/`nt`, `turtle`/`ttl`, `nquads`/`nq`,
+/// `trig`, or `jsonld`/`json-ld`). Used by both normal output and
+/// `--diff-against`, which needs two directly-comparable line sets.
+fn render_canonical_lines(
+    crate_data: &Crate,
+    options: ExtractionOptions,
+    format: &str,
+) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let bytes = match format {
+        "ntriples" | "nt" => {
+            let mut emitter = CanonicalEmitter::new(NTriplesEmitter::new(Vec::new()));
+            let mut extractor = CrateExtractor::new(&mut emitter, crate_data, options);
+            extractor.extract()?;
+            emitter.flush()?;
+            emitter.into_inner().into_inner()
+        }
+        "turtle" | "ttl" => {
+            let mut emitter = CanonicalEmitter::new(TurtleEmitter::new(Vec::new()));
+            let mut extractor = CrateExtractor::new(&mut emitter, crate_data, options);
+            extractor.extract()?;
+            emitter.flush()?;
+            emitter.into_inner().into_inner()
+        }
+        "nquads" | "nq" => {
+            let mut emitter = CanonicalEmitter::new(NQuadsEmitter::new(Vec::new()));
+            let mut extractor = CrateExtractor::new(&mut emitter, crate_data, options);
+            extractor.extract()?;
+            emitter.flush()?;
+            emitter.into_inner().into_inner()
+        }
+        "trig" => {
+            let mut emitter = CanonicalEmitter::new(TriGEmitter::new(Vec::new()));
+            let mut extractor = CrateExtractor::new(&mut emitter, crate_data, options);
+            extractor.extract()?;
+            emitter.flush()?;
+            emitter.into_inner().into_inner()
+        }
+        "jsonld" | "json-ld" => {
+            let mut emitter = CanonicalEmitter::new(JsonLdEmitter::new(Vec::new()));
+            let mut extractor = CrateExtractor::new(&mut emitter, crate_data, options);
+            extractor.extract()?;
+            emitter.flush()?;
+            emitter.into_inner().into_inner()
+        }
+        _ => {
+            return Err(format!(
+                "Unknown format: {format}. Use 'ntriples', 'turtle', 'nquads', 'trig', or 'jsonld'."
+            )
+            .into());
+        }
+    };
+    let text = String::from_utf8(bytes)?;
+    Ok(text.lines().map(str::to_string).collect())
+}
+
+/// Load a rustdoc JSON/crate input the same way the main extraction path does.
+fn load_input(path: &std::path::Path, is_json: bool) -> Result<Crate, Box<dyn std::error::Error>> {
+    if is_json {
+        Ok(load_json(path)?)
+    } else {
+        Ok(load_crate(path)?)
+    }
+}
+
+/// `--diff-against`: extract both the old and new inputs to canonical lines
+/// and report what was added/removed between them.
+fn run_diff(cli: &Cli, old_path: &std::path::Path) -> Result<(), Box<dyn std::error::Error>> {
+    let old_data = load_input(old_path, cli.json)?;
+    let new_data = load_input(&cli.input, cli.json)?;
+
+    let Settings { format, options } = resolve_settings(cli)?;
+    let old_lines = render_canonical_lines(&old_data, options.clone(), &format)?;
+    let new_lines = render_canonical_lines(&new_data, options, &format)?;
+    let delta = diff_lines(&old_lines, &new_lines);
+
+    let output_writer: Box<dyn Write> = match &cli.output {
+        Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+        None => Box::new(BufWriter::new(io::stdout().lock())),
     };
+    let mut w = output_writer;
+    writeln!(w, "# Removed ({})", delta.removed.len())?;
+    for line in &delta.removed {
+        writeln!(w, "- {line}")?;
+    }
+    writeln!(w, "# Added ({})", delta.added.len())?;
+    for line in &delta.added {
+        writeln!(w, "+ {line}")?;
+    }
+    w.flush()?;
+
+    if !cli.quiet {
+        eprintln!(
+            "{} removed, {} added triples between {} and {}",
+            delta.removed.len(),
+            delta.added.len(),
+            old_path.display(),
+            cli.input.display()
+        );
+    }
+
+    Ok(())
+}
+
+/// Run pre-emit validation on `crate_data`, printing issues unless
+/// `--quiet`, and aborting if `--strict-validate` and any `Severity::Error`
+/// is present. Shared by the single-crate and `--workspace` paths.
+fn validate_crate(cli: &Cli, crate_data: &Crate) -> Result<(), Box<dyn std::error::Error>> {
+    if cli.no_validate {
+        return Ok(());
+    }
+
+    let issues = validation::validate(crate_data);
+    let error_count = issues.iter().filter(|i| i.severity == Severity::Error).count();
+    if !issues.is_empty() && !cli.quiet {
+        for issue in &issues {
+            eprintln!(
+                "{:?}: {} (owner {:?})",
+                issue.severity, issue.message, issue.owner.0
+            );
+        }
+    }
+    if cli.strict_validate && error_count > 0 {
+        return Err(format!(
+            "{error_count} validation error(s) found; aborting (see warnings above)"
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Resolve settings, open the output writer, and run extraction against
+/// `crate_data`, returning the number of triples emitted. Shared by the
+/// single-crate and `--workspace` paths, which differ only in how
+/// `crate_data` and the summary's crate name/version are derived.
+fn emit_triples(cli: &Cli, crate_data: &Crate) -> Result<u64, Box<dyn std::error::Error>> {
+    // Resolve extraction options (config file, then CLI overrides)
+    let Settings { format, options } = resolve_settings(cli)?;
 
     // Determine output writer
     let output_writer: Box<dyn Write> = match &cli.output {
@@ -103,37 +386,184 @@ fn run(cli: Cli) -> Result<(), Box<dyn std::error::Error>> {
     };
 
     // Create emitter and run extraction
-    let format = cli.format.to_lowercase();
-    let triple_count = match format.as_str() {
-        "ntriples" | "nt" => {
+    let triple_count = match (format.as_str(), cli.canonical) {
+        ("ntriples" | "nt", false) => {
             let mut emitter = NTriplesEmitter::new(output_writer);
-            let mut extractor = CrateExtractor::new(&mut emitter, &crate_data, options);
-            extractor.extract();
+            let mut extractor = CrateExtractor::new(&mut emitter, crate_data, options);
+            extractor.extract()?;
             emitter.flush()?;
             emitter.triple_count()
         }
-        "turtle" | "ttl" => {
+        ("ntriples" | "nt", true) => {
+            let mut emitter = CanonicalEmitter::new(NTriplesEmitter::new(output_writer));
+            let mut extractor = CrateExtractor::new(&mut emitter, crate_data, options);
+            extractor.extract()?;
+            emitter.flush()?;
+            emitter.triple_count()
+        }
+        ("turtle" | "ttl", false) => {
             let mut emitter = TurtleEmitter::new(output_writer);
-            let mut extractor = CrateExtractor::new(&mut emitter, &crate_data, options);
-            extractor.extract();
+            let mut extractor = CrateExtractor::new(&mut emitter, crate_data, options);
+            extractor.extract()?;
+            emitter.flush()?;
+            emitter.triple_count()
+        }
+        ("turtle" | "ttl", true) => {
+            let mut emitter = CanonicalEmitter::new(TurtleEmitter::new(output_writer));
+            let mut extractor = CrateExtractor::new(&mut emitter, crate_data, options);
+            extractor.extract()?;
+            emitter.flush()?;
+            emitter.triple_count()
+        }
+        ("nquads" | "nq", false) => {
+            let mut emitter = NQuadsEmitter::new(output_writer);
+            let mut extractor = CrateExtractor::new(&mut emitter, crate_data, options);
+            extractor.extract()?;
+            emitter.flush()?;
+            emitter.triple_count()
+        }
+        ("nquads" | "nq", true) => {
+            let mut emitter = CanonicalEmitter::new(NQuadsEmitter::new(output_writer));
+            let mut extractor = CrateExtractor::new(&mut emitter, crate_data, options);
+            extractor.extract()?;
+            emitter.flush()?;
+            emitter.triple_count()
+        }
+        ("trig", false) => {
+            let mut emitter = TriGEmitter::new(output_writer);
+            let mut extractor = CrateExtractor::new(&mut emitter, crate_data, options);
+            extractor.extract()?;
+            emitter.flush()?;
+            emitter.triple_count()
+        }
+        ("trig", true) => {
+            let mut emitter = CanonicalEmitter::new(TriGEmitter::new(output_writer));
+            let mut extractor = CrateExtractor::new(&mut emitter, crate_data, options);
+            extractor.extract()?;
+            emitter.flush()?;
+            emitter.triple_count()
+        }
+        ("jsonld" | "json-ld", false) => {
+            let mut emitter = JsonLdEmitter::new(output_writer);
+            let mut extractor = CrateExtractor::new(&mut emitter, crate_data, options);
+            extractor.extract()?;
+            emitter.flush()?;
+            emitter.triple_count()
+        }
+        ("jsonld" | "json-ld", true) => {
+            let mut emitter = CanonicalEmitter::new(JsonLdEmitter::new(output_writer));
+            let mut extractor = CrateExtractor::new(&mut emitter, crate_data, options);
+            extractor.extract()?;
             emitter.flush()?;
             emitter.triple_count()
         }
         _ => {
-            return Err(format!("Unknown format: {format}. Use 'ntriples' or 'turtle'.").into());
+            return Err(format!(
+                "Unknown format: {format}. Use 'ntriples', 'turtle', 'nquads', 'trig', or 'jsonld'."
+            )
+            .into());
         }
     };
 
-    // Print summary to stderr (unless quiet)
+    Ok(triple_count)
+}
+
+/// Normal, single-crate path: load one rustdoc JSON file or crate
+/// directory, validate it, and extract it.
+fn run_single_crate(cli: &Cli) -> Result<(), Box<dyn std::error::Error>> {
+    if cli.verbose {
+        eprintln!("Loading input from: {}", cli.input.display());
+    }
+
+    let crate_data = if cli.json {
+        load_json(&cli.input)?
+    } else {
+        load_crate(&cli.input)?
+    };
+
+    // Determine crate name and version from the loaded data
+    let crate_name = crate_data
+        .index
+        .get(&crate_data.root.0)
+        .and_then(|item| item.name.clone())
+        .unwrap_or_else(|| "unknown".to_string());
+
+    let crate_version = crate_data
+        .crate_version
+        .clone()
+        .unwrap_or_else(|| "0.0.0".to_string());
+
+    if cli.verbose {
+        eprintln!("Crate: {crate_name} v{crate_version}");
+    }
+
+    validate_crate(cli, &crate_data)?;
+    let triple_count = emit_triples(cli, &crate_data)?;
+
     if !cli.quiet {
-        eprintln!(
-            "Extracted {triple_count} triples from {crate_name} v{crate_version}"
-        );
+        eprintln!("Extracted {triple_count} triples from {crate_name} v{crate_version}");
+    }
+
+    Ok(())
+}
+
+/// `--workspace`: load every member of the workspace rooted at `input` and
+/// merge them into one unified graph, then extract that graph as if it
+/// were a single crate. Members that fail to load are reported on stderr
+/// (unless `--quiet`) and skipped, matching `load_workspace`'s own
+/// "a broken leaf crate shouldn't block documenting the rest" behavior.
+fn run_workspace(cli: &Cli) -> Result<(), Box<dyn std::error::Error>> {
+    if cli.verbose {
+        eprintln!("Loading workspace from: {}", cli.input.display());
+    }
+
+    let result = load_workspace(&cli.input)?;
+
+    if !result.failed_members.is_empty() && !cli.quiet {
+        for failed in &result.failed_members {
+            eprintln!(
+                "Warning: skipping workspace member {}: {}",
+                failed.crate_dir.display(),
+                failed.error
+            );
+        }
+    }
+
+    let crate_data = result.merged.crate_data;
+    let mut member_names: Vec<&String> = result.merged.crate_roots.keys().collect();
+    member_names.sort();
+
+    if cli.verbose {
+        eprintln!("Workspace members merged: {}", member_names.len());
+    }
+
+    validate_crate(cli, &crate_data)?;
+    let triple_count = emit_triples(cli, &crate_data)?;
+
+    if !cli.quiet {
+        let names = member_names
+            .iter()
+            .map(|s| s.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        eprintln!("Extracted {triple_count} triples from workspace members: {names}");
     }
 
     Ok(())
 }
 
+fn run(cli: Cli) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(ref old_path) = cli.diff_against {
+        return run_diff(&cli, old_path);
+    }
+
+    if cli.workspace {
+        return run_workspace(&cli);
+    }
+
+    run_single_crate(&cli)
+}
+
 fn main() {
     let cli = Cli::parse();
     if let Err(e) = run(cli) {
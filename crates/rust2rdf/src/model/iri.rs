@@ -34,18 +34,52 @@ const IRI_ENCODE_SET: &AsciiSet = &CONTROLS
     .add(b'|')
     .add(b'}');
 
-/// Generates consistent IRIs for Rust symbols in RDF graphs.
+/// Escaped-segment byte length above which [`IriMinter::type_iri`] switches
+/// to a hashed IRI when [`IriMinter::set_hash_complex_iris`] is enabled.
+const HASH_IRI_THRESHOLD: usize = 60;
+
+/// Stable, platform-independent 64-bit FNV-1a digest, used by
+/// [`IriMinter::type_iri`]'s hashed-IRI mode so the same full path always
+/// hashes to the same IRI across runs and machines.
+fn fnv1a_64(data: &str) -> u64 {
+    const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+    let mut hash = FNV_OFFSET_BASIS;
+    for byte in data.as_bytes() {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
+
+/// Generates consistent IRIs for Rust symbols in RDF graphs. Stateless aside
+/// from `base_uri`/`hash_complex_iris`, so it's cheap to `clone()` for
+/// sharing into a [`CrateExtractor`](crate::extraction::extractor::CrateExtractor)
+/// worker thread (see [`CrateExtractor::walk_root_parallel`](crate::extraction::extractor::CrateExtractor::walk_root_parallel)).
+#[derive(Clone)]
 pub struct IriMinter {
     base_uri: String,
+    /// When set, [`Self::type_iri`] mints `<shortname>-<hash>` IRIs instead
+    /// of percent-encoding the full path, for names that are long or contain
+    /// generic angle brackets (e.g. `HashMap<K, V>`). See
+    /// [`ExtractionOptions::hash_complex_iris`](crate::extraction::extractor::ExtractionOptions::hash_complex_iris).
+    hash_complex_iris: bool,
 }
 
 impl IriMinter {
     pub fn new(base_uri: &str) -> Self {
         Self {
             base_uri: base_uri.trim_end_matches('/').to_string(),
+            hash_complex_iris: false,
         }
     }
 
+    /// Enable or disable hashed IRIs for complex type names (see
+    /// [`Self::type_iri`]).
+    pub fn set_hash_complex_iris(&mut self, enabled: bool) {
+        self.hash_complex_iris = enabled;
+    }
+
     pub fn base_uri(&self) -> &str {
         &self.base_uri
     }
@@ -55,6 +89,24 @@ impl IriMinter {
         utf8_percent_encode(value, IRI_ENCODE_SET).to_string()
     }
 
+    /// Whether `full_path` would trigger [`Self::type_iri`]'s hashed-IRI
+    /// mode: it contains generic angle brackets, or its escaped form is
+    /// long enough to make an unwieldy, store-unfriendly IRI segment.
+    pub fn is_complex_path(full_path: &str) -> bool {
+        full_path.contains('<') || Self::escape(full_path).len() > HASH_IRI_THRESHOLD
+    }
+
+    /// The leading, non-generic type name of `full_path` (e.g. `Foo` for
+    /// `some::module::Foo<Bar<Baz>>`), used as the human-readable prefix of
+    /// a hashed IRI.
+    fn short_type_name(full_path: &str) -> &str {
+        let before_generics = full_path.split('<').next().unwrap_or(full_path);
+        before_generics
+            .rsplit("::")
+            .next()
+            .unwrap_or(before_generics)
+    }
+
     /// IRI for a crate (maps to tg:Assembly / rt:Crate).
     pub fn crate_iri(&self, name: &str, version: &str) -> String {
         format!(
@@ -77,7 +129,24 @@ impl IriMinter {
     }
 
     /// IRI for a type (struct, enum, trait, union, type alias).
+    ///
+    /// When [`Self::set_hash_complex_iris`] is enabled and `full_path` is
+    /// [`Self::is_complex_path`] (long, or carrying generic arguments like
+    /// `HashMap<K, V>`), mints `.../type/<crate>/<version>/<shortname>-<hash>`
+    /// instead of percent-encoding the whole path -- callers should pair
+    /// this with an `rdfs:label` triple carrying `full_path` verbatim, since
+    /// the hash alone isn't human-readable.
     pub fn type_iri(&self, crate_name: &str, version: &str, full_path: &str) -> String {
+        if self.hash_complex_iris && Self::is_complex_path(full_path) {
+            return format!(
+                "{}/type/{}/{}/{}-{:016x}",
+                self.base_uri,
+                Self::escape(crate_name),
+                Self::escape(version),
+                Self::escape(Self::short_type_name(full_path)),
+                fnv1a_64(full_path)
+            );
+        }
         format!(
             "{}/type/{}/{}/{}",
             self.base_uri,
@@ -127,6 +196,13 @@ impl IriMinter {
         )
     }
 
+    /// IRI for a compiler-synthesized impl (e.g. an inferred auto trait),
+    /// anchored on the implementing type rather than a real rustdoc impl
+    /// item id.
+    pub fn synthesized_impl_iri(&self, type_iri: &str, trait_name: &str) -> String {
+        format!("{}/auto-impl/{}", type_iri, Self::escape(trait_name))
+    }
+
     /// IRI for a lifetime parameter.
     pub fn lifetime_iri(&self, owner_iri: &str, name: &str) -> String {
         // Strip leading ' from lifetime name
@@ -184,4 +260,70 @@ impl IriMinter {
             Self::escape(target_type_name)
         )
     }
+
+    /// IRI for a `dyn Trait` / `dyn Trait + Auto` trait-object type, keyed
+    /// by its sorted trait paths (plus any lifetime bound) so that
+    /// syntactically-identical trait objects share one node.
+    pub fn dyn_trait_type_iri(
+        &self,
+        sorted_trait_paths: &[String],
+        lifetime: Option<&str>,
+    ) -> String {
+        let joined = sorted_trait_paths.join("+");
+        match lifetime {
+            Some(lt) => format!(
+                "{}/type/_dyn_/{}/{}",
+                self.base_uri,
+                Self::escape(&joined),
+                Self::escape(lt.strip_prefix('\'').unwrap_or(lt))
+            ),
+            None => format!("{}/type/_dyn_/{}", self.base_uri, Self::escape(&joined)),
+        }
+    }
+
+    /// IRI for an `impl Trait` opaque type, keyed by its sorted bound trait
+    /// paths so that syntactically-identical `impl Trait` positions share
+    /// one node.
+    pub fn impl_trait_type_iri(&self, sorted_trait_paths: &[String]) -> String {
+        format!(
+            "{}/type/_impl_/{}",
+            self.base_uri,
+            Self::escape(&sorted_trait_paths.join("+"))
+        )
+    }
+
+    /// IRI for an associated-type projection (`<Self as Trait>::Name`),
+    /// keyed by its self type, trait name, and associated item name.
+    pub fn projection_iri(&self, self_type_iri: &str, trait_name: &str, name: &str) -> String {
+        format!(
+            "{}/projection/{}/{}",
+            self_type_iri,
+            Self::escape(trait_name),
+            Self::escape(name)
+        )
+    }
+
+    /// IRI for an associated-type-equality binding (`Trait<Item = T>`),
+    /// keyed by the bounded type, the trait it's bound through, and the
+    /// associated item name.
+    pub fn assoc_binding_iri(&self, bounded_iri: &str, trait_name: &str, name: &str) -> String {
+        format!(
+            "{}/assoc-binding/{}/{}",
+            bounded_iri,
+            Self::escape(trait_name),
+            Self::escape(name)
+        )
+    }
+
+    /// IRI for a `cfg` predicate node, keyed by its canonical string so that
+    /// identical predicates across items share one node.
+    pub fn cfg_iri(&self, canonical: &str) -> String {
+        format!("{}/cfg/{}", self.base_uri, Self::escape(canonical))
+    }
+
+    /// IRI for a re-export edge record, keyed by the re-exporting module and
+    /// the public alias it introduces.
+    pub fn reexport_iri(&self, module_iri: &str, alias: &str) -> String {
+        format!("{}/reexport/{}", module_iri, Self::escape(alias))
+    }
 }
@@ -3,6 +3,7 @@
 //! - `tg:` prefix (http://typegraph.example/ontology/) -- shared cross-language predicates
 //! - `rt:` prefix (http://rust.example/ontology/) -- Rust-specific extensions
 //! - `dt:` prefix (http://dotnet.example/ontology/) -- .NET-specific extensions
+//! - `owl:` prefix (http://www.w3.org/2002/07/owl#) -- `owl:sameAs` re-export aliasing
 
 /// Standard RDF/RDFS/XSD namespace URIs
 pub mod standard {
@@ -11,10 +12,29 @@ pub mod standard {
     pub const XSD: &str = "http://www.w3.org/2001/XMLSchema#";
     pub const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
     pub const RDFS_LABEL: &str = "http://www.w3.org/2000/01/rdf-schema#label";
+    pub const RDFS_COMMENT: &str = "http://www.w3.org/2000/01/rdf-schema#comment";
     pub const RDFS_SUBCLASS_OF: &str = "http://www.w3.org/2000/01/rdf-schema#subClassOf";
     pub const XSD_STRING: &str = "http://www.w3.org/2001/XMLSchema#string";
     pub const XSD_BOOLEAN: &str = "http://www.w3.org/2001/XMLSchema#boolean";
     pub const XSD_INTEGER: &str = "http://www.w3.org/2001/XMLSchema#integer";
+    pub const RDF_FIRST: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#first";
+    pub const RDF_REST: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#rest";
+    pub const RDF_NIL: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#nil";
+    pub const RDF_PROPERTY: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#Property";
+    pub const RDFS_CLASS: &str = "http://www.w3.org/2000/01/rdf-schema#Class";
+    pub const RDFS_DOMAIN: &str = "http://www.w3.org/2000/01/rdf-schema#domain";
+    pub const RDFS_RANGE: &str = "http://www.w3.org/2000/01/rdf-schema#range";
+}
+
+/// OWL namespace (`owl:` prefix) -- used to link canonicalized re-export
+/// aliases back to their primary IRI, see
+/// [`CrateExtractor::resolve_canonical_paths`](crate::extraction::extractor::CrateExtractor::resolve_canonical_paths).
+pub mod owl {
+    pub const PREFIX: &str = "owl";
+    pub const NS: &str = "http://www.w3.org/2002/07/owl#";
+    pub const SAME_AS: &str = "http://www.w3.org/2002/07/owl#sameAs";
+    /// Used by [`vocabulary::emit_schema`] to declare a predicate's inverse.
+    pub const INVERSE_OF: &str = "http://www.w3.org/2002/07/owl#inverseOf";
 }
 
 /// Shared type-graph ontology (`tg:` prefix) -- used by both .NET and Rust tools
@@ -56,6 +76,7 @@ pub mod tg {
 
     // Type relationships
     pub const DEFINED_IN_ASSEMBLY: &str = "http://typegraph.example/ontology/definedInAssembly";
+    pub const DEFINED_IN_FILE: &str = "http://typegraph.example/ontology/definedInFile";
     pub const IN_NAMESPACE: &str = "http://typegraph.example/ontology/inNamespace";
     pub const INHERITS: &str = "http://typegraph.example/ontology/inherits";
     pub const IMPLEMENTS: &str = "http://typegraph.example/ontology/implements";
@@ -82,6 +103,8 @@ pub mod tg {
     pub const PROPERTY_TYPE: &str = "http://typegraph.example/ontology/propertyType";
     pub const FIELD_TYPE: &str = "http://typegraph.example/ontology/fieldType";
     pub const EVENT_TYPE: &str = "http://typegraph.example/ontology/eventType";
+    pub const CONST_EXPR: &str = "http://typegraph.example/ontology/constExpr";
+    pub const CONST_VALUE: &str = "http://typegraph.example/ontology/constValue";
     pub const HAS_PARAMETER: &str = "http://typegraph.example/ontology/hasParameter";
     pub const OVERRIDES_METHOD: &str = "http://typegraph.example/ontology/overridesMethod";
 
@@ -111,6 +134,28 @@ pub mod tg {
 
     // Language tag
     pub const LANGUAGE: &str = "http://typegraph.example/ontology/language";
+
+    // Conditional compilation, mirrored here so cross-language queries don't
+    // need to know which language-specific namespace emitted an item (see
+    // `rt::CFG_CONDITION` for the Rust-specific structured predicate tree).
+    pub const CFG: &str = "http://typegraph.example/ontology/cfg";
+
+    // Stability and deprecation, mirrored here so cross-language queries
+    // don't need to know which language-specific namespace emitted an item
+    // (see `rt::STABILITY_LEVEL`/`rt::DEPRECATED` and friends for the
+    // Rust-specific predicates).
+    pub const STABILITY: &str = "http://typegraph.example/ontology/stability";
+    pub const STABLE_SINCE: &str = "http://typegraph.example/ontology/stableSince";
+    pub const UNSTABLE_FEATURE: &str = "http://typegraph.example/ontology/unstableFeature";
+    pub const DEPRECATED: &str = "http://typegraph.example/ontology/deprecated";
+    pub const DEPRECATED_SINCE: &str = "http://typegraph.example/ontology/deprecatedSince";
+    pub const DEPRECATION_NOTE: &str = "http://typegraph.example/ontology/deprecationNote";
+
+    // Documentation text, mirrored here (rather than `rdfs:comment`) so
+    // cross-language queries can ask for full-text docs vs. just the
+    // summary without parsing the comment for a paragraph break themselves.
+    pub const DOCUMENTATION: &str = "http://typegraph.example/ontology/documentation";
+    pub const SUMMARY: &str = "http://typegraph.example/ontology/summary";
 }
 
 /// Rust-specific extensions (`rt:` prefix)
@@ -132,6 +177,17 @@ pub mod rt {
     pub const MACRO: &str = "http://rust.example/ontology/Macro";
     pub const STATIC: &str = "http://rust.example/ontology/Static";
     pub const CONSTANT: &str = "http://rust.example/ontology/Constant";
+    pub const PRIMITIVE_TYPE: &str = "http://rust.example/ontology/PrimitiveType";
+    pub const DERIVED_IMPL: &str = "http://rust.example/ontology/DerivedImpl";
+    pub const EXTERN_CRATE: &str = "http://rust.example/ontology/ExternCrate";
+    pub const TRAIT_ALIAS: &str = "http://rust.example/ontology/TraitAlias";
+    pub const PROC_MACRO: &str = "http://rust.example/ontology/ProcMacro";
+    pub const EXTERN_TYPE: &str = "http://rust.example/ontology/ExternType";
+    pub const KEYWORD: &str = "http://rust.example/ontology/Keyword";
+
+    // Procedural macros
+    pub const MACRO_KIND: &str = "http://rust.example/ontology/macroKind";
+    pub const DERIVE_HELPER: &str = "http://rust.example/ontology/deriveHelper";
 
     // Predicates
     pub const DEPENDS_ON: &str = "http://rust.example/ontology/dependsOn";
@@ -146,8 +202,447 @@ pub mod rt {
     pub const LIFETIME_BOUND: &str = "http://rust.example/ontology/lifetimeBound";
     pub const IS_UNSAFE: &str = "http://rust.example/ontology/isUnsafe";
     pub const IS_MUTABLE: &str = "http://rust.example/ontology/isMutable";
+    // Function header qualifiers (calling convention, `const`/`async`)
+    pub const ABI: &str = "http://rust.example/ontology/abi";
+    pub const IS_EXTERN: &str = "http://rust.example/ontology/isExtern";
+    pub const IS_ASYNC: &str = "http://rust.example/ontology/isAsync";
+    pub const IS_CONST_FN: &str = "http://rust.example/ontology/isConstFn";
+
+    // Primitive type metadata
+    pub const PRIMITIVE_CATEGORY: &str = "http://rust.example/ontology/primitiveCategory";
+    pub const BIT_WIDTH: &str = "http://rust.example/ontology/bitWidth";
+    pub const IS_SIGNED: &str = "http://rust.example/ontology/isSigned";
+    pub const IS_POINTER_SIZED: &str = "http://rust.example/ontology/isPointerSized";
+
+    // Derive-macro-sourced trait impls
+    pub const IMPLEMENTS_TRAIT: &str = "http://rust.example/ontology/implementsTrait";
+    pub const IMPL_SOURCE: &str = "http://rust.example/ontology/implSource";
     pub const IS_EXHAUSTIVE: &str = "http://rust.example/ontology/isExhaustive";
     pub const ERROR_TYPE: &str = "http://rust.example/ontology/errorType";
     pub const DERIVES: &str = "http://rust.example/ontology/derives";
     pub const TRAIT_BOUND: &str = "http://rust.example/ontology/traitBound";
+
+    // Conditional compilation (`#[cfg(...)]`)
+    pub const CFG_CONDITION: &str = "http://rust.example/ontology/cfgCondition";
+    pub const HAS_CFG_NODE: &str = "http://rust.example/ontology/hasCfgNode";
+    pub const CFG_OPERATOR: &str = "http://rust.example/ontology/cfgOperator";
+    pub const CFG_FLAG: &str = "http://rust.example/ontology/cfgFlag";
+    pub const CFG_KEY: &str = "http://rust.example/ontology/cfgKey";
+    pub const CFG_VALUE: &str = "http://rust.example/ontology/cfgValue";
+    pub const CFG_OPERAND: &str = "http://rust.example/ontology/cfgOperand";
+    /// `rdf:type` of an `All` condition node (see [`HAS_CFG_NODE`]).
+    pub const CFG_ALL: &str = "http://rust.example/ontology/CfgAll";
+    /// `rdf:type` of an `Any` condition node.
+    pub const CFG_ANY: &str = "http://rust.example/ontology/CfgAny";
+    /// `rdf:type` of a `Not` condition node.
+    pub const CFG_NOT: &str = "http://rust.example/ontology/CfgNot";
+    /// `rdf:type` of a leaf condition node (`Flag` or `NameValue`).
+    pub const CFG_OPTION: &str = "http://rust.example/ontology/CfgOption";
+    /// A `feature = "..."` name referenced anywhere in an item's effective
+    /// `cfg` -- lets a consumer find everything gated on a given feature
+    /// without re-parsing [`CFG_CONDITION`].
+    pub const REQUIRES_FEATURE: &str = "http://rust.example/ontology/requiresFeature";
+    /// A non-feature target predicate (`unix`, `target_os = "windows"`, ...)
+    /// referenced anywhere in an item's effective `cfg`.
+    pub const TARGET_ONLY: &str = "http://rust.example/ontology/targetOnly";
+    /// Whether an item's effective `cfg` is anything other than
+    /// unconditionally-`true` -- emitted alongside [`CFG_CONDITION`] so a
+    /// consumer can filter the graph without parsing the condition string.
+    pub const IS_CFG_GATED: &str = "http://rust.example/ontology/isCfgGated";
+
+    // Re-exports (`use` / `extern crate` aliasing)
+    pub const RE_EXPORT: &str = "http://rust.example/ontology/ReExport";
+    pub const RE_EXPORTS: &str = "http://rust.example/ontology/reExports";
+    pub const HAS_RE_EXPORT: &str = "http://rust.example/ontology/hasReExport";
+    pub const RE_EXPORT_ALIAS: &str = "http://rust.example/ontology/reExportAlias";
+    pub const RE_EXPORT_TARGET: &str = "http://rust.example/ontology/reExportTarget";
+
+    /// The `format_version` of the rustdoc JSON a crate was extracted from
+    /// -- lets a consumer tell which schema-era fields to expect.
+    pub const RUSTDOC_FORMAT_VERSION: &str = "http://rust.example/ontology/rustdocFormatVersion";
+
+    // Source location (`span`)
+    pub const LINE_START: &str = "http://rust.example/ontology/lineStart";
+    pub const LINE_END: &str = "http://rust.example/ontology/lineEnd";
+
+    // Stability and deprecation
+    pub const DEPRECATED: &str = "http://rust.example/ontology/deprecated";
+    pub const DEPRECATED_SINCE: &str = "http://rust.example/ontology/deprecatedSince";
+    pub const DEPRECATION_NOTE: &str = "http://rust.example/ontology/deprecationNote";
+    pub const STABILITY_LEVEL: &str = "http://rust.example/ontology/stabilityLevel";
+    pub const FEATURE_GATE: &str = "http://rust.example/ontology/featureGate";
+    pub const STABLE_SINCE: &str = "http://rust.example/ontology/stableSince";
+
+    // Auto-trait inference (Send / Sync / Unpin / UnwindSafe)
+    pub const SYNTHESIZED_IMPL: &str = "http://rust.example/ontology/synthesizedImpl";
+    pub const AUTO_TRAIT_IMPL: &str = "http://rust.example/ontology/AutoTraitImpl";
+    pub const IMPLEMENTS_AUTO: &str = "http://rust.example/ontology/implementsAuto";
+    pub const AUTO_BOUND: &str = "http://rust.example/ontology/autoBound";
+
+    // Intra-doc links
+    pub const DOC_LINK: &str = "http://rust.example/ontology/docLink";
+
+    // Blanket impls (`impl<T: Bound> Trait for T`)
+    pub const BLANKET_IMPL: &str = "http://rust.example/ontology/BlanketImpl";
+    pub const BLANKET_SOURCE_TYPE: &str = "http://rust.example/ontology/blanketSourceType";
+    pub const IMPLIES_IMPL_FOR: &str = "http://rust.example/ontology/impliesImplFor";
+
+    // Trait objects, `impl Trait`, and associated-type projections
+    pub const DYN_TRAIT_BOUND: &str = "http://rust.example/ontology/dynTraitBound";
+    pub const IMPL_TRAIT_BOUND: &str = "http://rust.example/ontology/implTraitBound";
+    pub const PROJECTION_BASE: &str = "http://rust.example/ontology/projectionBase";
+    pub const PROJECTION_TRAIT: &str = "http://rust.example/ontology/projectionTrait";
+
+    // Where-clause predicates and associated-type-equality bounds
+    pub const ASSOC_TYPE_BINDING: &str = "http://rust.example/ontology/AssocTypeBinding";
+    pub const HAS_ASSOC_BINDING: &str = "http://rust.example/ontology/hasAssocBinding";
+    pub const ASSOC_BINDING_TYPE: &str = "http://rust.example/ontology/assocBindingType";
+    pub const ASSOC_TYPE_EQUALS: &str = "http://rust.example/ontology/assocTypeEquals";
+    pub const OUTLIVES: &str = "http://rust.example/ontology/outlives";
+    /// Links a type parameter (`T` in `T = Default`) to its default type
+    /// node, as opposed to `tg:defaultValue` which carries a const
+    /// parameter's default as a literal.
+    pub const DEFAULT_TYPE: &str = "http://rust.example/ontology/defaultType";
+
+    // Negative impls (`impl !Trait for Type`)
+    pub const IS_NEGATIVE: &str = "http://rust.example/ontology/isNegative";
+
+    /// Links a method defined inside a trait impl to the method of the same
+    /// name declared by the trait, so a consumer can see which required
+    /// trait methods a type actually provides without re-matching names.
+    pub const IMPLEMENTS_TRAIT_METHOD: &str =
+        "http://rust.example/ontology/implementsTraitMethod";
+
+    // Object safety
+    pub const OBJECT_SAFE: &str = "http://rust.example/ontology/objectSafe";
+    pub const OBJECT_SAFETY_VIOLATION: &str =
+        "http://rust.example/ontology/objectSafetyViolation";
+    pub const EXCLUDED_FROM_OBJECT: &str = "http://rust.example/ontology/excludedFromObject";
+
+    // Specialization (`default impl` / `default fn`)
+    pub const IS_DEFAULT_IMPL: &str = "http://rust.example/ontology/isDefaultImpl";
+    pub const IS_SPECIALIZABLE: &str = "http://rust.example/ontology/isSpecializable";
+    pub const SPECIALIZES: &str = "http://rust.example/ontology/specializes";
+}
+
+/// .NET-specific extensions (`dt:` prefix) -- CLR facts that don't fit the
+/// shared `tg:` vocabulary (no Rust equivalent) without forcing every
+/// consumer of the cross-language graph to special-case them.
+pub mod dt {
+    pub const PREFIX: &str = "dt";
+    pub const NS: &str = "http://dotnet.example/ontology/";
+
+    // Classes
+    pub const STRUCT_LAYOUT: &str = "http://dotnet.example/ontology/StructLayout";
+
+    // Explicit struct layout (`[StructLayout(...)]`)
+    pub const LAYOUT_KIND: &str = "http://dotnet.example/ontology/layoutKind";
+    pub const PACK_SIZE: &str = "http://dotnet.example/ontology/packSize";
+    pub const HAS_STRUCT_LAYOUT: &str = "http://dotnet.example/ontology/hasStructLayout";
+    pub const EXPLICIT_OFFSET: &str = "http://dotnet.example/ontology/explicitOffset";
+
+    // Extension methods (`this` parameter sugar)
+    pub const IS_EXTENSION_METHOD: &str = "http://dotnet.example/ontology/isExtensionMethod";
+    pub const EXTENDS_TYPE: &str = "http://dotnet.example/ontology/extendsType";
+
+    // Explicit interface implementations (`void IFoo.Bar()`)
+    pub const IS_EXPLICIT_INTERFACE_IMPL: &str =
+        "http://dotnet.example/ontology/isExplicitInterfaceImpl";
+    pub const EXPLICIT_INTERFACE_MEMBER: &str =
+        "http://dotnet.example/ontology/explicitInterfaceMember";
+
+    // Partial types/methods
+    pub const IS_PARTIAL: &str = "http://dotnet.example/ontology/isPartial";
+    pub const PARTIAL_DECLARATION_FILE: &str =
+        "http://dotnet.example/ontology/partialDeclarationFile";
+
+    // Nullable reference types (`#nullable` context, `Type?`)
+    pub const NULLABLE_CONTEXT: &str = "http://dotnet.example/ontology/nullableContext";
+    pub const IS_NULLABLE_REFERENCE: &str = "http://dotnet.example/ontology/isNullableReference";
+
+    // Events/delegates refinements
+    pub const DELEGATE_TYPE: &str = "http://dotnet.example/ontology/delegateType";
+    pub const ADD_ACCESSOR: &str = "http://dotnet.example/ontology/addAccessor";
+    pub const REMOVE_ACCESSOR: &str = "http://dotnet.example/ontology/removeAccessor";
+    pub const IS_MULTICAST_DELEGATE: &str = "http://dotnet.example/ontology/isMulticastDelegate";
+}
+
+/// A typed layer over the bare `&str` constants in [`tg`]/[`rt`]/[`dt`]: a
+/// small registry recording each vocabulary predicate's domain/range class
+/// and declared inverse (and each class's `rdfs:subClassOf` parent), plus
+/// [`vocabulary::emit_schema`] to emit the ontology's own RDFS description
+/// into the same graph it describes. Not every constant above has an entry
+/// here -- only the relationships worth validating or auto-inverting are
+/// registered; an unregistered predicate/class is simply not checked by
+/// [`vocabulary::matches_domain_range`], not rejected.
+pub mod vocabulary {
+    use super::{dt, owl, rt, standard, tg};
+    use crate::emitter::TriplesEmitter;
+
+    /// One RDFS class this vocabulary defines.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Class {
+        pub iri: &'static str,
+        pub label: &'static str,
+        pub sub_class_of: Option<&'static str>,
+    }
+
+    /// One RDF property this vocabulary defines, with enough metadata to
+    /// validate a triple against it and to generate its inverse edge.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Predicate {
+        pub iri: &'static str,
+        pub label: &'static str,
+        pub domain: Option<&'static str>,
+        pub range: Option<&'static str>,
+        /// The predicate's declared inverse, if the relationship is
+        /// mirrored by another predicate in the vocabulary (e.g.
+        /// `tg:memberOf` <-> `tg:hasMember`).
+        pub inverse: Option<&'static str>,
+    }
+
+    const CLASSES: &[Class] = &[
+        Class {
+            iri: tg::TYPE,
+            label: "Type",
+            sub_class_of: None,
+        },
+        Class {
+            iri: tg::CLASS,
+            label: "Class",
+            sub_class_of: Some(tg::TYPE),
+        },
+        Class {
+            iri: tg::STRUCT,
+            label: "Struct",
+            sub_class_of: Some(tg::TYPE),
+        },
+        Class {
+            iri: tg::INTERFACE,
+            label: "Interface",
+            sub_class_of: Some(tg::TYPE),
+        },
+        Class {
+            iri: tg::ENUM,
+            label: "Enum",
+            sub_class_of: Some(tg::TYPE),
+        },
+        Class {
+            iri: tg::MEMBER,
+            label: "Member",
+            sub_class_of: None,
+        },
+        Class {
+            iri: tg::METHOD,
+            label: "Method",
+            sub_class_of: Some(tg::MEMBER),
+        },
+        Class {
+            iri: tg::PROPERTY,
+            label: "Property",
+            sub_class_of: Some(tg::MEMBER),
+        },
+        Class {
+            iri: tg::FIELD,
+            label: "Field",
+            sub_class_of: Some(tg::MEMBER),
+        },
+        Class {
+            iri: tg::NAMESPACE,
+            label: "Namespace",
+            sub_class_of: None,
+        },
+        Class {
+            iri: rt::TRAIT,
+            label: "Trait",
+            sub_class_of: Some(tg::INTERFACE),
+        },
+        Class {
+            iri: rt::MODULE,
+            label: "Module",
+            sub_class_of: Some(tg::NAMESPACE),
+        },
+        Class {
+            iri: dt::STRUCT_LAYOUT,
+            label: "StructLayout",
+            sub_class_of: None,
+        },
+    ];
+
+    const PREDICATES: &[Predicate] = &[
+        Predicate {
+            iri: tg::MEMBER_OF,
+            label: "memberOf",
+            domain: Some(tg::MEMBER),
+            range: Some(tg::TYPE),
+            inverse: Some(tg::HAS_MEMBER),
+        },
+        Predicate {
+            iri: tg::HAS_MEMBER,
+            label: "hasMember",
+            domain: Some(tg::TYPE),
+            range: Some(tg::MEMBER),
+            inverse: Some(tg::MEMBER_OF),
+        },
+        Predicate {
+            iri: tg::PARAMETER_OF,
+            label: "parameterOf",
+            domain: Some(tg::PARAMETER),
+            range: None,
+            inverse: Some(tg::HAS_PARAMETER),
+        },
+        Predicate {
+            iri: tg::HAS_PARAMETER,
+            label: "hasParameter",
+            domain: None,
+            range: Some(tg::PARAMETER),
+            inverse: Some(tg::PARAMETER_OF),
+        },
+        Predicate {
+            iri: tg::TYPE_PARAMETER_OF,
+            label: "typeParameterOf",
+            domain: Some(tg::TYPE_PARAMETER),
+            range: None,
+            inverse: Some(tg::HAS_TYPE_PARAMETER),
+        },
+        Predicate {
+            iri: tg::HAS_TYPE_PARAMETER,
+            label: "hasTypeParameter",
+            domain: None,
+            range: Some(tg::TYPE_PARAMETER),
+            inverse: Some(tg::TYPE_PARAMETER_OF),
+        },
+        Predicate {
+            iri: tg::ATTRIBUTE_OF,
+            label: "attributeOf",
+            domain: Some(tg::ATTRIBUTE),
+            range: None,
+            inverse: Some(tg::HAS_ATTRIBUTE),
+        },
+        Predicate {
+            iri: tg::HAS_ATTRIBUTE,
+            label: "hasAttribute",
+            domain: None,
+            range: Some(tg::ATTRIBUTE),
+            inverse: Some(tg::ATTRIBUTE_OF),
+        },
+        Predicate {
+            iri: tg::PARENT_NAMESPACE,
+            label: "parentNamespace",
+            domain: Some(tg::NAMESPACE),
+            range: Some(tg::NAMESPACE),
+            inverse: None,
+        },
+        Predicate {
+            iri: tg::CONTAINS_TYPE,
+            label: "containsType",
+            domain: Some(tg::NAMESPACE),
+            range: Some(tg::TYPE),
+            inverse: None,
+        },
+        Predicate {
+            iri: tg::INHERITS,
+            label: "inherits",
+            domain: Some(tg::TYPE),
+            range: Some(tg::TYPE),
+            inverse: None,
+        },
+        Predicate {
+            iri: tg::IMPLEMENTS,
+            label: "implements",
+            domain: Some(tg::TYPE),
+            range: Some(tg::INTERFACE),
+            inverse: None,
+        },
+        Predicate {
+            iri: rt::IMPL_FOR,
+            label: "implFor",
+            domain: Some(rt::TRAIT_IMPL),
+            range: Some(tg::TYPE),
+            inverse: Some(rt::HAS_IMPL),
+        },
+        Predicate {
+            iri: rt::HAS_IMPL,
+            label: "hasImpl",
+            domain: Some(tg::TYPE),
+            range: Some(rt::TRAIT_IMPL),
+            inverse: Some(rt::IMPL_FOR),
+        },
+        Predicate {
+            iri: dt::EXTENDS_TYPE,
+            label: "extendsType",
+            domain: Some(tg::METHOD),
+            range: Some(tg::TYPE),
+            inverse: None,
+        },
+        Predicate {
+            iri: dt::HAS_STRUCT_LAYOUT,
+            label: "hasStructLayout",
+            domain: Some(tg::STRUCT),
+            range: Some(dt::STRUCT_LAYOUT),
+            inverse: None,
+        },
+    ];
+
+    /// Look up a class's declared metadata by IRI.
+    pub fn class(iri: &str) -> Option<&'static Class> {
+        CLASSES.iter().find(|c| c.iri == iri)
+    }
+
+    /// Look up a predicate's declared metadata by IRI.
+    pub fn predicate(iri: &str) -> Option<&'static Predicate> {
+        PREDICATES.iter().find(|p| p.iri == iri)
+    }
+
+    /// Whether `subject_class`/`object_class` satisfy `predicate_iri`'s
+    /// declared domain/range. A predicate with no declared domain/range (or
+    /// not registered in the vocabulary at all) always passes -- this only
+    /// catches a *known* mismatch, it isn't a closed-world check over every
+    /// constant in [`tg`]/[`rt`]/[`dt`].
+    pub fn matches_domain_range(predicate_iri: &str, subject_class: &str, object_class: &str) -> bool {
+        match predicate(predicate_iri) {
+            Some(pred) => {
+                pred.domain.is_none_or(|d| d == subject_class)
+                    && pred.range.is_none_or(|r| r == object_class)
+            }
+            None => true,
+        }
+    }
+
+    /// The inverse edge a triple using `predicate_iri` implies, if the
+    /// vocabulary declares one (e.g. a `tg:memberOf` triple implies the
+    /// corresponding `tg:hasMember` triple in the opposite direction).
+    pub fn inverse_of(predicate_iri: &str) -> Option<&'static str> {
+        predicate(predicate_iri).and_then(|p| p.inverse)
+    }
+
+    /// Emit this vocabulary's own RDFS schema -- every registered [`Class`]
+    /// as `rdf:type rdfs:Class` (plus `rdfs:subClassOf` when declared) and
+    /// every registered [`Predicate`] as `rdf:type rdf:Property` (plus
+    /// `rdfs:domain`/`rdfs:range`/`owl:inverseOf` when declared) -- so the
+    /// ontology describes itself in the same graph consumers already query,
+    /// instead of that metadata only living in this source file.
+    pub fn emit_schema<E: TriplesEmitter>(emitter: &mut E) -> std::io::Result<()> {
+        for class in CLASSES {
+            emitter.emit_iri(class.iri, standard::RDF_TYPE, standard::RDFS_CLASS)?;
+            emitter.emit_literal(class.iri, standard::RDFS_LABEL, class.label)?;
+            if let Some(parent) = class.sub_class_of {
+                emitter.emit_iri(class.iri, standard::RDFS_SUBCLASS_OF, parent)?;
+            }
+        }
+        for pred in PREDICATES {
+            emitter.emit_iri(pred.iri, standard::RDF_TYPE, standard::RDF_PROPERTY)?;
+            emitter.emit_literal(pred.iri, standard::RDFS_LABEL, pred.label)?;
+            if let Some(domain) = pred.domain {
+                emitter.emit_iri(pred.iri, standard::RDFS_DOMAIN, domain)?;
+            }
+            if let Some(range) = pred.range {
+                emitter.emit_iri(pred.iri, standard::RDFS_RANGE, range)?;
+            }
+            if let Some(inverse) = pred.inverse {
+                emitter.emit_iri(pred.iri, owl::INVERSE_OF, inverse)?;
+            }
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,283 @@
+//! Structural pattern queries over an in-memory triple graph.
+//!
+//! The extractor only ever streams triples straight out to an emitter (see
+//! [`crate::emitter`]), so there's nowhere to ask "find every type that
+//! implements `Iterator` and has a method returning `?t`" without
+//! post-processing serialized output by hand. [`TripleIndex`] holds a graph
+//! of [`Triple`]s with a subject index and an object ("incoming edge")
+//! index, and [`query`] runs an ordered list of [`TriplePattern`]s --
+//! triples with [`PatternTerm::Var`] holes -- through a backtracking join,
+//! returning one [`Bindings`] map per satisfying assignment. Mirrors the
+//! pattern-matching ergonomics of structural search-and-replace tooling,
+//! just scoped to this crate's RDF graph instead of source code.
+
+use std::collections::HashMap;
+
+/// An RDF object term: either an IRI or a literal value. Subjects and
+/// predicates are always IRIs, so only the object side needs this
+/// distinction.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Node {
+    Iri(String),
+    Literal(String),
+}
+
+impl Node {
+    pub fn iri(value: impl Into<String>) -> Self {
+        Node::Iri(value.into())
+    }
+
+    pub fn literal(value: impl Into<String>) -> Self {
+        Node::Literal(value.into())
+    }
+}
+
+/// One triple in the graph: `subject`/`predicate` are IRIs, `object` is a
+/// [`Node`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Triple {
+    pub subject: String,
+    pub predicate: String,
+    pub object: Node,
+}
+
+impl Triple {
+    pub fn new(subject: impl Into<String>, predicate: impl Into<String>, object: Node) -> Self {
+        Self {
+            subject: subject.into(),
+            predicate: predicate.into(),
+            object,
+        }
+    }
+}
+
+/// One slot of a [`TriplePattern`]: either a fixed term the matching triple
+/// must carry, or a named variable that binds to whatever it matches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PatternTerm {
+    Iri(String),
+    Literal(String),
+    Var(String),
+}
+
+impl PatternTerm {
+    pub fn iri(value: impl Into<String>) -> Self {
+        PatternTerm::Iri(value.into())
+    }
+
+    pub fn literal(value: impl Into<String>) -> Self {
+        PatternTerm::Literal(value.into())
+    }
+
+    pub fn var(name: impl Into<String>) -> Self {
+        PatternTerm::Var(name.into())
+    }
+
+    /// The fixed [`Node`] this term matches, or `None` if it's a variable.
+    fn fixed_node(&self) -> Option<Node> {
+        match self {
+            PatternTerm::Iri(v) => Some(Node::Iri(v.clone())),
+            PatternTerm::Literal(v) => Some(Node::Literal(v.clone())),
+            PatternTerm::Var(_) => None,
+        }
+    }
+}
+
+/// A triple pattern: each of `subject`/`predicate`/`object` is either a
+/// fixed term or a [`PatternTerm::Var`]. The same variable name used in two
+/// patterns (or twice in one pattern) must bind to the same [`Node`] --
+/// that's how [`query`] performs its joins.
+#[derive(Debug, Clone)]
+pub struct TriplePattern {
+    pub subject: PatternTerm,
+    pub predicate: PatternTerm,
+    pub object: PatternTerm,
+}
+
+impl TriplePattern {
+    pub fn new(subject: PatternTerm, predicate: PatternTerm, object: PatternTerm) -> Self {
+        Self {
+            subject,
+            predicate,
+            object,
+        }
+    }
+}
+
+/// One satisfying assignment from [`query`]: variable name to the [`Node`]
+/// it bound to.
+pub type Bindings = HashMap<String, Node>;
+
+/// An in-memory triple graph, indexed both forward (by subject, for "what
+/// does this node point at") and backward (by object, for "what points at
+/// this node" -- the common reverse-relationship lookup).
+#[derive(Debug, Default)]
+pub struct TripleIndex {
+    triples: Vec<Triple>,
+    by_subject: HashMap<String, Vec<usize>>,
+    by_object: HashMap<Node, Vec<usize>>,
+}
+
+impl TripleIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build an index over `triples`, deduplicating identical entries (the
+    /// extractor's own `begin_definition`/`end_definition` dedup scoping
+    /// means the same triple can legitimately be offered twice).
+    pub fn from_triples(triples: impl IntoIterator<Item = Triple>) -> Self {
+        let mut index = Self::new();
+        for triple in triples {
+            index.insert(triple);
+        }
+        index
+    }
+
+    /// Add one triple to the index, skipping it if already present.
+    pub fn insert(&mut self, triple: Triple) {
+        if self.triples.contains(&triple) {
+            return;
+        }
+        let id = self.triples.len();
+        self.by_subject
+            .entry(triple.subject.clone())
+            .or_default()
+            .push(id);
+        self.by_object
+            .entry(triple.object.clone())
+            .or_default()
+            .push(id);
+        self.triples.push(triple);
+    }
+
+    pub fn triples(&self) -> &[Triple] {
+        &self.triples
+    }
+
+    /// Every triple with `subject` as its subject, optionally narrowed to a
+    /// single `predicate`.
+    pub fn outgoing<'a>(&'a self, subject: &str, predicate: Option<&str>) -> Vec<&'a Triple> {
+        self.by_subject
+            .get(subject)
+            .into_iter()
+            .flatten()
+            .map(|&id| &self.triples[id])
+            .filter(|t| predicate.is_none_or(|p| t.predicate == p))
+            .collect()
+    }
+
+    /// Every triple with `object` as its object, optionally narrowed to a
+    /// single `predicate` -- the reverse/incoming-edge lookup (e.g. "who
+    /// implements this trait", "who calls into this namespace").
+    pub fn incoming<'a>(&'a self, object: &Node, predicate: Option<&str>) -> Vec<&'a Triple> {
+        self.by_object
+            .get(object)
+            .into_iter()
+            .flatten()
+            .map(|&id| &self.triples[id])
+            .filter(|t| predicate.is_none_or(|p| t.predicate == p))
+            .collect()
+    }
+}
+
+/// Run `patterns` against `index` as a conjunctive join, returning one
+/// [`Bindings`] per satisfying assignment. Patterns are evaluated in order
+/// via backtracking: each pattern narrows to the index scan its bound terms
+/// allow (subject-indexed if the subject is fixed/already bound,
+/// object-indexed if only the object is, a full scan otherwise), and a
+/// variable seen in an earlier pattern must agree with its binding in every
+/// later one.
+pub fn query(index: &TripleIndex, patterns: &[TriplePattern]) -> Vec<Bindings> {
+    let mut results = Vec::new();
+    backtrack(index, patterns, 0, Bindings::new(), &mut results);
+    results
+}
+
+fn backtrack(
+    index: &TripleIndex,
+    patterns: &[TriplePattern],
+    pos: usize,
+    bindings: Bindings,
+    results: &mut Vec<Bindings>,
+) {
+    let Some(pattern) = patterns.get(pos) else {
+        results.push(bindings);
+        return;
+    };
+
+    for triple in candidate_triples(index, pattern, &bindings) {
+        if let Some(extended) = try_bind(pattern, triple, bindings.clone()) {
+            backtrack(index, patterns, pos + 1, extended, results);
+        }
+    }
+}
+
+/// Narrow the scan to whichever index a pattern's bound terms allow,
+/// falling back to a full scan only when neither the subject nor the object
+/// is fixed or already bound.
+fn candidate_triples<'a>(
+    index: &'a TripleIndex,
+    pattern: &TriplePattern,
+    bindings: &Bindings,
+) -> Vec<&'a Triple> {
+    if let Some(subject) = resolved_subject(&pattern.subject, bindings) {
+        return index.outgoing(&subject, None);
+    }
+    if let Some(object) = resolved_object(&pattern.object, bindings) {
+        return index.incoming(&object, None);
+    }
+    index.triples().iter().collect()
+}
+
+fn resolved_subject(term: &PatternTerm, bindings: &Bindings) -> Option<String> {
+    match term {
+        PatternTerm::Iri(v) => Some(v.clone()),
+        PatternTerm::Literal(_) => None, // a subject can never be a literal
+        PatternTerm::Var(name) => match bindings.get(name) {
+            Some(Node::Iri(v)) => Some(v.clone()),
+            _ => None,
+        },
+    }
+}
+
+fn resolved_object(term: &PatternTerm, bindings: &Bindings) -> Option<Node> {
+    match term.fixed_node() {
+        Some(node) => Some(node),
+        None => {
+            let PatternTerm::Var(name) = term else {
+                unreachable!("fixed_node only returns None for Var");
+            };
+            bindings.get(name).cloned()
+        }
+    }
+}
+
+/// Check `triple` against `pattern`, extending `bindings` with any
+/// previously-unbound variables it introduces. Returns `None` if the triple
+/// doesn't match (a fixed term differs, or a variable's existing binding
+/// conflicts).
+fn try_bind(pattern: &TriplePattern, triple: &Triple, mut bindings: Bindings) -> Option<Bindings> {
+    match_term(&pattern.subject, &Node::Iri(triple.subject.clone()), &mut bindings)?;
+    match_term(
+        &pattern.predicate,
+        &Node::Iri(triple.predicate.clone()),
+        &mut bindings,
+    )?;
+    match_term(&pattern.object, &triple.object, &mut bindings)?;
+    Some(bindings)
+}
+
+fn match_term(term: &PatternTerm, actual: &Node, bindings: &mut Bindings) -> Option<()> {
+    match term {
+        PatternTerm::Iri(v) => (actual == &Node::Iri(v.clone())).then_some(()),
+        PatternTerm::Literal(v) => (actual == &Node::Literal(v.clone())).then_some(()),
+        PatternTerm::Var(name) => match bindings.get(name) {
+            Some(bound) => (bound == actual).then_some(()),
+            None => {
+                bindings.insert(name.clone(), actual.clone());
+                Some(())
+            }
+        },
+    }
+}
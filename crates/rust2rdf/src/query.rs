@@ -0,0 +1,120 @@
+//! Multi-hop traversal over a [`crate::html_export::Graph`].
+//!
+//! Gives callers a "nodes N where X -> N" query layer instead of requiring
+//! them to walk `graph.edges` by hand: direct successors/predecessors, and
+//! a breadth-first `paths` search bounded by hop count, both filterable by
+//! [`EdgeKind`]. Every returned node carries its own label/hover text (set
+//! from `type_display_name` when the graph was built), so callers can
+//! render a traversal result directly.
+
+use std::collections::VecDeque;
+
+use crate::html_export::{EdgeKind, Graph, GraphNode};
+
+/// One hop in a [`Path`]: the node reached, and the edge kind that led to
+/// it (`None` only for the path's starting node).
+#[derive(Debug, Clone, Copy)]
+pub struct PathStep<'a> {
+    pub node: &'a GraphNode,
+    pub via: Option<EdgeKind>,
+}
+
+/// An ordered sequence of hops from a path's start node to its end node.
+pub type Path<'a> = Vec<PathStep<'a>>;
+
+fn node_by_id<'a>(graph: &'a Graph, id: &str) -> Option<&'a GraphNode> {
+    graph.nodes.iter().find(|n| n.id == id)
+}
+
+/// Nodes directly reachable from `node_id` via an outgoing edge, optionally
+/// restricted to a single `kind`.
+pub fn successors<'a>(
+    graph: &'a Graph,
+    node_id: &str,
+    kind: Option<EdgeKind>,
+) -> Vec<&'a GraphNode> {
+    graph
+        .edges
+        .iter()
+        .filter(|e| e.from == node_id && kind.is_none_or(|k| e.kind == k))
+        .filter_map(|e| node_by_id(graph, &e.to))
+        .collect()
+}
+
+/// Nodes with a direct outgoing edge into `node_id`, optionally restricted
+/// to a single `kind`.
+pub fn predecessors<'a>(
+    graph: &'a Graph,
+    node_id: &str,
+    kind: Option<EdgeKind>,
+) -> Vec<&'a GraphNode> {
+    graph
+        .edges
+        .iter()
+        .filter(|e| e.to == node_id && kind.is_none_or(|k| e.kind == k))
+        .filter_map(|e| node_by_id(graph, &e.from))
+        .collect()
+}
+
+/// Breadth-first search for every simple path from `from` to `to` of at
+/// most `max_hops` edges, optionally restricted to a single edge `kind`.
+/// Each returned [`Path`] starts with `from` (`via: None`) and ends at `to`.
+pub fn paths<'a>(
+    graph: &'a Graph,
+    from: &str,
+    to: &str,
+    max_hops: usize,
+    kind: Option<EdgeKind>,
+) -> Vec<Path<'a>> {
+    let start = match node_by_id(graph, from) {
+        Some(n) => n,
+        None => return Vec::new(),
+    };
+    if node_by_id(graph, to).is_none() {
+        return Vec::new();
+    }
+
+    let mut found = Vec::new();
+    let mut queue: VecDeque<Path<'a>> = VecDeque::new();
+    queue.push_back(vec![PathStep {
+        node: start,
+        via: None,
+    }]);
+
+    while let Some(path) = queue.pop_front() {
+        let current = path.last().expect("path always has a starting step").node;
+        if current.id == to && path.len() > 1 {
+            found.push(path);
+            continue;
+        }
+        if path.len() - 1 >= max_hops {
+            continue;
+        }
+        for edge in &graph.edges {
+            if edge.from != current.id {
+                continue;
+            }
+            if let Some(k) = kind {
+                if edge.kind != k {
+                    continue;
+                }
+            }
+            // Avoid revisiting a node already on this path (simple paths only).
+            if path.iter().any(|step| step.node.id == edge.to) {
+                continue;
+            }
+            let next_node = match node_by_id(graph, &edge.to) {
+                Some(n) => n,
+                None => continue,
+            };
+            let mut extended = path.clone();
+            extended.push(PathStep {
+                node: next_node,
+                via: Some(edge.kind),
+            });
+            queue.push_back(extended);
+        }
+    }
+
+    found
+}
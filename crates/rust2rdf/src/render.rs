@@ -0,0 +1,268 @@
+//! ANSI-styled rendering of [`Type`] trees for terminal output, as a
+//! colorized sibling to [`type_display_name`](crate::extraction::extractor).
+//!
+//! Each styled segment is wrapped in its own SGR-set/reset pair (rather than
+//! one reset at the very end) so that recursing into a nested type can never
+//! leak an outer segment's color into an inner one.
+
+use crate::extraction::rustdoc_model::{GenericArg, GenericArgs, GenericBound, Type};
+
+const RESET: &str = "\x1b[0m";
+
+/// An ANSI SGR color code (the `3x`/`9x` foreground set).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnsiColor {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl AnsiColor {
+    fn code(self) -> u8 {
+        match self {
+            AnsiColor::Black => 30,
+            AnsiColor::Red => 31,
+            AnsiColor::Green => 32,
+            AnsiColor::Yellow => 33,
+            AnsiColor::Blue => 34,
+            AnsiColor::Magenta => 35,
+            AnsiColor::Cyan => 36,
+            AnsiColor::White => 37,
+        }
+    }
+}
+
+/// Per-category color/bold attributes for [`type_display_styled`].
+#[derive(Debug, Clone, Copy)]
+pub struct Palette {
+    pub pointer_color: Option<AnsiColor>,
+    pub pointer_bold: bool,
+    pub mut_keyword_color: Option<AnsiColor>,
+    pub mut_keyword_bold: bool,
+    pub type_name_color: Option<AnsiColor>,
+    pub type_name_bold: bool,
+}
+
+impl Palette {
+    /// No colors or bold attributes at all; every segment is emitted as
+    /// plain text. Use this for non-TTY sinks (piped output, log files).
+    pub fn plain() -> Self {
+        Self {
+            pointer_color: None,
+            pointer_bold: false,
+            mut_keyword_color: None,
+            mut_keyword_bold: false,
+            type_name_color: None,
+            type_name_bold: false,
+        }
+    }
+
+    /// A readable default color scheme for interactive terminal output:
+    /// cyan pointers/references, bold yellow mutability keywords, and
+    /// green type names.
+    pub fn ansi() -> Self {
+        Self {
+            pointer_color: Some(AnsiColor::Cyan),
+            pointer_bold: false,
+            mut_keyword_color: Some(AnsiColor::Yellow),
+            mut_keyword_bold: true,
+            type_name_color: Some(AnsiColor::Green),
+            type_name_bold: false,
+        }
+    }
+
+    fn style(&self, text: &str, color: Option<AnsiColor>, bold: bool) -> String {
+        if color.is_none() && !bold {
+            return text.to_string();
+        }
+        let mut sgr = String::new();
+        if bold {
+            sgr.push_str("1;");
+        }
+        match color {
+            Some(c) => sgr.push_str(&c.code().to_string()),
+            None => {
+                sgr.pop();
+            }
+        }
+        format!("\x1b[{sgr}m{text}{RESET}")
+    }
+
+    fn pointer(&self, text: &str) -> String {
+        self.style(text, self.pointer_color, self.pointer_bold)
+    }
+
+    fn mut_keyword(&self, text: &str) -> String {
+        self.style(text, self.mut_keyword_color, self.mut_keyword_bold)
+    }
+
+    fn type_name(&self, text: &str) -> String {
+        self.style(text, self.type_name_color, self.type_name_bold)
+    }
+}
+
+impl Default for Palette {
+    /// Plain/no-color, matching the safe default for non-TTY sinks.
+    fn default() -> Self {
+        Self::plain()
+    }
+}
+
+/// Render `ty` as it would appear in source, with references, pointers,
+/// mutability keywords, and leaf type names wrapped in ANSI SGR escapes per
+/// `palette`. Mirrors the structure of
+/// [`type_display_name`](crate::extraction::extractor) but each call site
+/// below applies its own color/reset pair instead of building a single flat
+/// string.
+pub fn type_display_styled(ty: &Type, palette: &Palette) -> String {
+    match ty {
+        Type::Primitive(name) => palette.type_name(name),
+        Type::ResolvedPath(path) => {
+            let mut name = palette.type_name(&path.path);
+            if let Some(ref args) = path.args {
+                name.push_str(&generic_args_styled(args, palette));
+            }
+            name
+        }
+        Type::Generic(name) => palette.type_name(name),
+        Type::Tuple(types) => {
+            let parts: Vec<String> = types
+                .iter()
+                .map(|t| type_display_styled(t, palette))
+                .collect();
+            format!("({})", parts.join(","))
+        }
+        Type::Slice(inner) => format!("[{}]", type_display_styled(inner, palette)),
+        Type::Array { type_, len, .. } => {
+            format!("[{};{}]", type_display_styled(type_, palette), len)
+        }
+        Type::BorrowedRef {
+            is_mutable, type_, ..
+        } => {
+            if *is_mutable {
+                format!(
+                    "{}{} {}",
+                    palette.pointer("&"),
+                    palette.mut_keyword("mut"),
+                    type_display_styled(type_, palette)
+                )
+            } else {
+                format!("{}{}", palette.pointer("&"), type_display_styled(type_, palette))
+            }
+        }
+        Type::RawPointer {
+            is_mutable, type_, ..
+        } => {
+            if *is_mutable {
+                format!(
+                    "{}{} {}",
+                    palette.pointer("*"),
+                    palette.mut_keyword("mut"),
+                    type_display_styled(type_, palette)
+                )
+            } else {
+                format!(
+                    "{} {}",
+                    palette.pointer("*const"),
+                    type_display_styled(type_, palette)
+                )
+            }
+        }
+        Type::FunctionPointer(fp) => {
+            let params: Vec<String> = fp
+                .sig
+                .inputs
+                .iter()
+                .map(|(_, param_ty)| type_display_styled(param_ty, palette))
+                .collect();
+            match fp.sig.output {
+                Some(ref ret) => format!(
+                    "fn({}) -> {}",
+                    params.join(","),
+                    type_display_styled(ret, palette)
+                ),
+                None => format!("fn({})", params.join(",")),
+            }
+        }
+        Type::DynTrait(dyn_trait) => {
+            let parts: Vec<String> = dyn_trait
+                .traits
+                .iter()
+                .map(|poly| palette.type_name(&poly.trait_.path))
+                .collect();
+            format!("dyn {}", parts.join(" + "))
+        }
+        Type::ImplTrait(bounds) => {
+            let parts: Vec<String> = bounds
+                .iter()
+                .filter_map(|bound| match bound {
+                    GenericBound::TraitBound { ref trait_, .. } => {
+                        Some(palette.type_name(&trait_.path))
+                    }
+                    GenericBound::Outlives(ref lifetime) => Some(lifetime.clone()),
+                    GenericBound::Use(_) => None,
+                })
+                .collect();
+            format!("impl {}", parts.join(" + "))
+        }
+        Type::QualifiedPath {
+            name,
+            self_type,
+            trait_,
+            ..
+        } => {
+            let self_name = type_display_styled(self_type, palette);
+            match trait_ {
+                Some(ref trait_path) => {
+                    format!("<{} as {}>::{}", self_name, trait_path.path, name)
+                }
+                None => format!("{self_name}::{name}"),
+            }
+        }
+        Type::Infer => "_".to_string(),
+        Type::Unknown { .. } => "unknown".to_string(),
+    }
+}
+
+fn generic_args_styled(args: &GenericArgs, palette: &Palette) -> String {
+    match args {
+        GenericArgs::AngleBracketed { args, .. } => {
+            if args.is_empty() {
+                return String::new();
+            }
+            let parts: Vec<String> = args
+                .iter()
+                .map(|arg| generic_arg_styled(arg, palette))
+                .collect();
+            format!("<{}>", parts.join(","))
+        }
+        GenericArgs::Parenthesized { inputs, output } => {
+            let parts: Vec<String> = inputs
+                .iter()
+                .map(|t| type_display_styled(t, palette))
+                .collect();
+            match output {
+                Some(ref ret) => format!(
+                    "({}) -> {}",
+                    parts.join(","),
+                    type_display_styled(ret, palette)
+                ),
+                None => format!("({})", parts.join(",")),
+            }
+        }
+    }
+}
+
+fn generic_arg_styled(arg: &GenericArg, palette: &Palette) -> String {
+    match arg {
+        GenericArg::Lifetime(lifetime) => lifetime.clone(),
+        GenericArg::Type(ty) => type_display_styled(ty, palette),
+        GenericArg::Const(value) => value.value.clone().unwrap_or_default(),
+        GenericArg::Infer => "_".to_string(),
+    }
+}
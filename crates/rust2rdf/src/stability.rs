@@ -0,0 +1,90 @@
+//! Stability and deprecation attribute modeling.
+//!
+//! Like `cfg`, rustdoc JSON carries `#[stable(...)]`/`#[unstable(...)]` as raw
+//! strings in an item's `attrs` list rather than as a structured field. This
+//! module extracts the stability level and feature-gate name (when present)
+//! from those strings. `deprecation` is already structured on [`Item`] and
+//! needs no parsing.
+
+use crate::extraction::rustdoc_model::Item;
+
+/// An item's parsed stability attribute.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stability {
+    /// `"stable"` or `"unstable"`.
+    pub level: String,
+    /// The feature-gate name, present on `#[unstable(feature = "...")]` and
+    /// occasionally on `#[stable(feature = "...")]` too.
+    pub feature: Option<String>,
+    /// The `since = "..."` version, present on `#[stable(since = "...")]`.
+    pub since: Option<String>,
+}
+
+/// Parse the first `#[stable(...)]`/`#[unstable(...)]` attribute found in an
+/// item's raw `attrs`. Returns `None` if the item carries neither (true of
+/// most crates outside the standard library).
+pub fn parse_item_stability(item: &Item) -> Option<Stability> {
+    item.attrs
+        .iter()
+        .filter_map(|attr| attr.as_str())
+        .find_map(parse_stability_attr)
+}
+
+fn parse_stability_attr(attr: &str) -> Option<Stability> {
+    let inner = attr.trim().strip_prefix("#[")?.strip_suffix(']')?;
+    if let Some(rest) = strip_call(inner, "stable") {
+        return Some(Stability {
+            level: "stable".to_string(),
+            feature: find_key(rest, "feature"),
+            since: find_key(rest, "since"),
+        });
+    }
+    if let Some(rest) = strip_call(inner, "unstable") {
+        return Some(Stability {
+            level: "unstable".to_string(),
+            feature: find_key(rest, "feature"),
+            since: find_key(rest, "since"),
+        });
+    }
+    None
+}
+
+fn strip_call<'a>(text: &'a str, name: &str) -> Option<&'a str> {
+    let rest = text.strip_prefix(name)?.strip_prefix('(')?;
+    rest.strip_suffix(')')
+}
+
+/// Find `key = "value"` among comma-separated `name = "value"` pairs.
+fn find_key(text: &str, key: &str) -> Option<String> {
+    for part in split_top_level(text, ',') {
+        let part = part.trim();
+        if let Some(rest) = part.strip_prefix(key) {
+            if let Some(value) = rest.trim_start().strip_prefix('=') {
+                return Some(value.trim().trim_matches('"').to_string());
+            }
+        }
+    }
+    None
+}
+
+/// Split on `sep` outside double-quoted strings.
+fn split_top_level(text: &str, sep: char) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut in_quotes = false;
+    let mut start = 0;
+    for (i, c) in text.char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            c if c == sep && !in_quotes => {
+                parts.push(text[start..i].trim().to_string());
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    let tail = text[start..].trim();
+    if !tail.is_empty() {
+        parts.push(tail.to_string());
+    }
+    parts
+}
@@ -0,0 +1,228 @@
+//! Integration tests for synthesized auto-trait impls (Send/Sync/Unpin/
+//! UnwindSafe): structural inference from field types, conditional impls
+//! carrying `rt:autoBound` edges to the relevant type parameter, negative
+//! impls for structurally-blocked fields, and manual impls overriding the
+//! synthesized result. Uses a small hand-written rustdoc JSON crate, since
+//! these shapes are easiest to pin down explicitly.
+
+use rust2rdf::emitter::ntriples::NTriplesEmitter;
+use rust2rdf::emitter::TriplesEmitter;
+use rust2rdf::extraction::extractor::{CrateExtractor, ExtractionOptions};
+use rust2rdf::extraction::rustdoc_model::Crate;
+
+const CRATE_JSON: &str = r#"
+{
+  "root": "0",
+  "crate_version": "0.1.0",
+  "format_version": 35,
+  "external_crates": {},
+  "paths": {},
+  "index": {
+    "0": {
+      "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+      "inner": { "module": { "items": ["1", "2", "3", "4", "5", "6", "7", "8"] } }
+    },
+    "1": {
+      "id": "1", "name": "Plain", "visibility": "public", "attrs": [],
+      "inner": { "struct": {
+        "kind": { "plain": { "fields": ["11"], "has_stripped_fields": false } },
+        "generics": { "params": [], "where_predicates": [] },
+        "impls": []
+      } }
+    },
+    "11": {
+      "id": "11", "name": "value", "visibility": "public", "attrs": [],
+      "inner": { "struct_field": { "primitive": "i32" } }
+    },
+    "2": {
+      "id": "2", "name": "HasRawPointer", "visibility": "public", "attrs": [],
+      "inner": { "struct": {
+        "kind": { "plain": { "fields": ["21"], "has_stripped_fields": false } },
+        "generics": { "params": [], "where_predicates": [] },
+        "impls": []
+      } }
+    },
+    "21": {
+      "id": "21", "name": "ptr", "visibility": "public", "attrs": [],
+      "inner": { "struct_field": { "raw_pointer": { "is_mutable": false, "type": { "primitive": "u8" } } } }
+    },
+    "3": {
+      "id": "3", "name": "Wrapper", "visibility": "public", "attrs": [],
+      "inner": { "struct": {
+        "kind": { "plain": { "fields": ["31"], "has_stripped_fields": false } },
+        "generics": {
+          "params": [
+            { "name": "T", "kind": { "type": { "bounds": [], "default": null, "is_synthetic": false } } }
+          ],
+          "where_predicates": []
+        },
+        "impls": []
+      } }
+    },
+    "31": {
+      "id": "31", "name": "value", "visibility": "public", "attrs": [],
+      "inner": { "struct_field": { "generic": "T" } }
+    },
+    "4": {
+      "id": "4", "name": "ManuallySend", "visibility": "public", "attrs": [],
+      "inner": { "struct": {
+        "kind": { "plain": { "fields": ["41"], "has_stripped_fields": false } },
+        "generics": { "params": [], "where_predicates": [] },
+        "impls": ["42"]
+      } }
+    },
+    "41": {
+      "id": "41", "name": "ptr", "visibility": "public", "attrs": [],
+      "inner": { "struct_field": { "raw_pointer": { "is_mutable": false, "type": { "primitive": "u8" } } } }
+    },
+    "42": {
+      "id": "42", "name": null, "visibility": "default", "attrs": [],
+      "inner": { "impl": {
+        "generics": { "params": [], "where_predicates": [] },
+        "trait": { "path": "Send", "id": null },
+        "for": { "resolved_path": { "path": "ManuallySend", "id": "4" } },
+        "items": [],
+        "is_unsafe": true, "is_negative": false, "is_synthetic": false
+      } }
+    },
+    "5": {
+      "id": "5", "name": "AlreadyNegative", "visibility": "public", "attrs": [],
+      "inner": { "struct": {
+        "kind": { "plain": { "fields": [], "has_stripped_fields": false } },
+        "generics": { "params": [], "where_predicates": [] },
+        "impls": ["51"]
+      } }
+    },
+    "51": {
+      "id": "51", "name": null, "visibility": "default", "attrs": [],
+      "inner": { "impl": {
+        "generics": { "params": [], "where_predicates": [] },
+        "trait": { "path": "Sync", "id": null },
+        "for": { "resolved_path": { "path": "AlreadyNegative", "id": "5" } },
+        "items": [],
+        "is_unsafe": false, "is_negative": true, "is_synthetic": false
+      } }
+    }
+  }
+}
+"#;
+
+fn load() -> Crate {
+    serde_json::from_str(CRATE_JSON).expect("fixture JSON should parse")
+}
+
+fn extract(opts: ExtractionOptions) -> String {
+    let krate = load();
+    let mut buf = Vec::new();
+    {
+        let mut emitter = NTriplesEmitter::new(&mut buf);
+        let mut extractor = CrateExtractor::new(&mut emitter, &krate, opts);
+        extractor.extract();
+        emitter.flush().unwrap();
+    }
+    String::from_utf8(buf).expect("Invalid UTF-8 in NTriples output")
+}
+
+// Local types' full path is "mycrate::<Name>" (the module path, which for a
+// root item is just the crate name, joined to the item name) -- and
+// IriMinter::type_iri percent-encodes the whole thing, so `::` becomes
+// `%3A%3A` rather than surviving as a literal path separator.
+const PLAIN_IRI: &str = "http://rust.example/type/mycrate/0.1.0/mycrate%3A%3APlain";
+const HAS_RAW_POINTER_IRI: &str = "http://rust.example/type/mycrate/0.1.0/mycrate%3A%3AHasRawPointer";
+const WRAPPER_IRI: &str = "http://rust.example/type/mycrate/0.1.0/mycrate%3A%3AWrapper";
+const MANUALLY_SEND_IRI: &str = "http://rust.example/type/mycrate/0.1.0/mycrate%3A%3AManuallySend";
+const ALREADY_NEGATIVE_IRI: &str = "http://rust.example/type/mycrate/0.1.0/mycrate%3A%3AAlreadyNegative";
+const SEND_IRI: &str = "http://rust.example/type/mycrate/0.1.0/core%3A%3Amarker%3A%3ASend";
+const SYNC_IRI: &str = "http://rust.example/type/mycrate/0.1.0/core%3A%3Amarker%3A%3ASync";
+// `trait: { "path": "Send", "id": null }` (the unqualified name as written,
+// with no `Id` rustdoc could resolve) falls back to minting an IRI from the
+// written path rather than the fully-qualified one `AUTO_TRAITS` uses.
+const UNRESOLVED_SEND_IRI: &str = "http://rust.example/type/mycrate/0.1.0/Send";
+
+fn auto_trait_impl_iri(type_iri: &str, trait_name: &str) -> String {
+    format!("{type_iri}/auto-impl/{trait_name}")
+}
+
+#[test]
+fn all_primitive_fields_synthesize_positive_impl() {
+    let out = extract(ExtractionOptions::default());
+    let impl_iri = auto_trait_impl_iri(PLAIN_IRI, "Send");
+
+    assert!(out.contains(&format!(
+        "<{impl_iri}> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://rust.example/ontology/AutoTraitImpl> ."
+    )));
+    assert!(out.contains(&format!(
+        "<{impl_iri}> <http://rust.example/ontology/implFor> <{PLAIN_IRI}> ."
+    )));
+    assert!(out.contains(&format!(
+        "<{impl_iri}> <http://rust.example/ontology/implementsAuto> <{SEND_IRI}> ."
+    )));
+    assert!(out.contains(&format!(
+        "<{impl_iri}> <http://rust.example/ontology/isNegative> \"false\"^^<http://www.w3.org/2001/XMLSchema#boolean> ."
+    )));
+    assert!(out.contains(&format!(
+        "<{PLAIN_IRI}> <http://typegraph.example/ontology/implements> <{SEND_IRI}> ."
+    )));
+}
+
+#[test]
+fn raw_pointer_field_synthesizes_negative_impl() {
+    let out = extract(ExtractionOptions::default());
+    let impl_iri = auto_trait_impl_iri(HAS_RAW_POINTER_IRI, "Send");
+
+    assert!(out.contains(&format!(
+        "<{impl_iri}> <http://rust.example/ontology/isNegative> \"true\"^^<http://www.w3.org/2001/XMLSchema#boolean> ."
+    )));
+    assert!(!out.contains(&format!(
+        "<{HAS_RAW_POINTER_IRI}> <http://typegraph.example/ontology/implements> <{SEND_IRI}> ."
+    )));
+}
+
+#[test]
+fn generic_field_synthesizes_conditional_impl_bound_to_type_parameter() {
+    let out = extract(ExtractionOptions::default());
+    let impl_iri = auto_trait_impl_iri(WRAPPER_IRI, "Send");
+    let tp_iri = format!("{WRAPPER_IRI}/typeparam/0");
+
+    assert!(out.contains(&format!(
+        "<{impl_iri}> <http://rust.example/ontology/autoBound> <{tp_iri}> ."
+    )));
+    // Conditional on T, so not unconditionally asserted.
+    assert!(!out.contains(&format!(
+        "<{WRAPPER_IRI}> <http://typegraph.example/ontology/implements> <{SEND_IRI}> ."
+    )));
+}
+
+#[test]
+fn manual_impl_overrides_synthesized_result() {
+    let out = extract(ExtractionOptions::default());
+    let impl_iri = auto_trait_impl_iri(MANUALLY_SEND_IRI, "Send");
+
+    // No AutoTraitImpl node synthesized -- the manual `unsafe impl Send` wins.
+    assert!(!out.contains(&impl_iri));
+    assert!(out.contains(&format!(
+        "<{MANUALLY_SEND_IRI}> <http://typegraph.example/ontology/implements> <{UNRESOLVED_SEND_IRI}> ."
+    )));
+}
+
+#[test]
+fn manual_negative_impl_is_not_duplicated_by_synthesis() {
+    let out = extract(ExtractionOptions::default());
+    let impl_iri = auto_trait_impl_iri(ALREADY_NEGATIVE_IRI, "Sync");
+
+    assert!(!out.contains(&impl_iri));
+}
+
+#[test]
+fn synthesize_auto_traits_false_suppresses_all_synthesized_impls() {
+    let opts = ExtractionOptions {
+        synthesize_auto_traits: false,
+        ..ExtractionOptions::default()
+    };
+    let out = extract(opts);
+
+    assert!(!out.contains("http://rust.example/ontology/AutoTraitImpl"));
+    assert!(!out.contains(&format!(
+        "<{PLAIN_IRI}> <http://typegraph.example/ontology/implements> <{SYNC_IRI}> ."
+    )));
+}
@@ -0,0 +1,191 @@
+//! Integration tests for blanket-impl resolution: `impl<T: Bound> Trait for
+//! T` defined by the crate itself (as opposed to the `blanket_impl` field on
+//! a per-concrete-type impl, which reports a *derived realization* of
+//! someone else's blanket impl and is covered by `impl_block_tests.rs`).
+//! Uses a small hand-written rustdoc JSON crate so the bound-satisfaction
+//! matching can be pinned down precisely.
+
+use rust2rdf::emitter::ntriples::NTriplesEmitter;
+use rust2rdf::emitter::TriplesEmitter;
+use rust2rdf::extraction::extractor::{CrateExtractor, ExtractionOptions};
+use rust2rdf::extraction::rustdoc_model::Crate;
+
+const CRATE_JSON: &str = r#"
+{
+  "root": "0",
+  "crate_version": "0.1.0",
+  "format_version": 35,
+  "external_crates": {},
+  "paths": {},
+  "index": {
+    "0": {
+      "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+      "inner": { "module": { "items": ["1", "2", "3", "4"] } }
+    },
+    "1": {
+      "id": "1", "name": "MyTrait", "visibility": "public", "attrs": [],
+      "inner": { "trait": {
+        "generics": { "params": [], "where_predicates": [] },
+        "bounds": [], "items": [], "is_auto": false, "is_unsafe": false,
+        "is_object_safe": true
+      } }
+    },
+    "2": {
+      "id": "2", "name": "HasClone", "visibility": "public", "attrs": [],
+      "inner": { "struct": { "kind": "unit", "generics": { "params": [], "where_predicates": [] }, "impls": ["21"] } }
+    },
+    "21": {
+      "id": "21", "name": null, "visibility": "default", "attrs": [],
+      "inner": { "impl": {
+        "generics": { "params": [], "where_predicates": [] },
+        "trait": { "path": "Clone", "id": null },
+        "for": { "resolved_path": { "path": "HasClone", "id": "2" } },
+        "items": [],
+        "is_unsafe": false, "is_negative": false, "is_synthetic": false
+      } }
+    },
+    "3": {
+      "id": "3", "name": "NoClone", "visibility": "public", "attrs": [],
+      "inner": { "struct": { "kind": "unit", "generics": { "params": [], "where_predicates": [] }, "impls": [] } }
+    },
+    "4": {
+      "id": "4", "name": null, "visibility": "default", "attrs": [],
+      "inner": { "impl": {
+        "generics": {
+          "params": [
+            { "name": "T", "kind": { "type": { "bounds": [
+              { "trait_bound": { "trait": { "path": "Clone", "id": null } } }
+            ], "default": null, "is_synthetic": false } } }
+          ],
+          "where_predicates": []
+        },
+        "trait": { "path": "MyTrait", "id": "1" },
+        "for": { "generic": "T" },
+        "items": [],
+        "is_unsafe": false, "is_negative": false, "is_synthetic": false
+      } }
+    }
+  }
+}
+"#;
+
+fn load() -> Crate {
+    serde_json::from_str(CRATE_JSON).expect("fixture JSON should parse")
+}
+
+fn extract(opts: ExtractionOptions) -> String {
+    let krate = load();
+    let mut buf = Vec::new();
+    {
+        let mut emitter = NTriplesEmitter::new(&mut buf);
+        let mut extractor = CrateExtractor::new(&mut emitter, &krate, opts);
+        extractor.extract();
+        emitter.flush().unwrap();
+    }
+    String::from_utf8(buf).expect("Invalid UTF-8 in NTriples output")
+}
+
+fn has_iri_triple(output: &str, subject: &str, predicate: &str, object: &str) -> bool {
+    let expected = format!("<{subject}> <{predicate}> <{object}> .");
+    output.lines().any(|line| line.trim() == expected)
+}
+
+const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+const BASE: &str = "http://rust.example";
+
+fn rt(local: &str) -> String {
+    format!("http://rust.example/ontology/{local}")
+}
+
+// For a trait reference with no `Id` at all (`Clone` here),
+// `resolve_path_to_iri`'s fallback mints the IRI from the bare path as
+// written, not the type's fully-qualified `mycrate::<Name>` form -- see
+// `auto_trait_tests.rs`'s `UNRESOLVED_SEND_IRI` for the same fallback.
+fn type_iri(path: &str) -> String {
+    format!("{BASE}/type/mycrate/0.1.0/{path}")
+}
+
+// A locally-resolved item (trait or type, referenced by `Id`), by contrast,
+// is minted from its full module-qualified path (`mycrate::<Name>`),
+// percent-encoded whole by `IriMinter::type_iri`.
+fn concrete_type_iri(name: &str) -> String {
+    format!("{BASE}/type/mycrate/0.1.0/mycrate%3A%3A{name}")
+}
+
+fn impl_iri(id: &str) -> String {
+    format!("{BASE}/impl/mycrate/0.1.0/{id}")
+}
+
+#[test]
+fn blanket_impl_is_reported_as_a_node_with_its_generics() {
+    let out = extract(ExtractionOptions::default());
+    let blanket_impl = impl_iri("4");
+    let my_trait = concrete_type_iri("MyTrait");
+    let type_param = format!("{blanket_impl}/typeparam/0");
+    let clone_trait = type_iri("Clone");
+
+    assert!(has_iri_triple(
+        &out,
+        &blanket_impl,
+        RDF_TYPE,
+        &rt("BlanketImpl")
+    ));
+    assert!(has_iri_triple(
+        &out,
+        &blanket_impl,
+        &rt("implTrait"),
+        &my_trait
+    ));
+    assert!(has_iri_triple(
+        &out,
+        &blanket_impl,
+        "http://typegraph.example/ontology/hasTypeParameter",
+        &type_param
+    ));
+    assert!(has_iri_triple(
+        &out,
+        &type_param,
+        &rt("traitBound"),
+        &clone_trait
+    ));
+}
+
+#[test]
+fn blanket_impl_implies_impl_for_a_type_satisfying_its_bound() {
+    let out = extract(ExtractionOptions::default());
+    let blanket_impl = impl_iri("4");
+    let has_clone = concrete_type_iri("HasClone");
+
+    assert!(has_iri_triple(
+        &out,
+        &blanket_impl,
+        &rt("impliesImplFor"),
+        &has_clone
+    ));
+}
+
+#[test]
+fn blanket_impl_does_not_imply_impl_for_a_type_missing_its_bound() {
+    let out = extract(ExtractionOptions::default());
+    let blanket_impl = impl_iri("4");
+    let no_clone = concrete_type_iri("NoClone");
+
+    assert!(!has_iri_triple(
+        &out,
+        &blanket_impl,
+        &rt("impliesImplFor"),
+        &no_clone
+    ));
+}
+
+#[test]
+fn exclude_blanket_impls_suppresses_all_blanket_impl_output() {
+    let opts = ExtractionOptions {
+        include_blanket_impls: false,
+        ..ExtractionOptions::default()
+    };
+    let out = extract(opts);
+
+    assert!(!out.contains(&rt("BlanketImpl")));
+    assert!(!out.contains(&rt("impliesImplFor")));
+}
@@ -0,0 +1,139 @@
+use rust2rdf::extraction::cache::{fingerprint, CrateCache};
+use rust2rdf::extraction::rustdoc_model::Crate;
+use std::path::PathBuf;
+
+/// Scratch directory under the OS temp dir, unique per test process + call
+/// site, so parallel test runs don't collide.
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("rust2rdf-cache-test-{}-{name}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).expect("create scratch dir");
+    dir
+}
+
+fn write_crate_source(crate_dir: &std::path::Path, lib_rs_contents: &str) {
+    std::fs::create_dir_all(crate_dir.join("src")).expect("create src dir");
+    std::fs::write(crate_dir.join("src/lib.rs"), lib_rs_contents).expect("write lib.rs");
+    std::fs::write(
+        crate_dir.join("Cargo.toml"),
+        "[package]\nname = \"scratch-crate\"\nversion = \"0.1.0\"\n",
+    )
+    .expect("write Cargo.toml");
+}
+
+#[test]
+fn fingerprint_is_stable_for_an_unchanged_tree() {
+    let dir = scratch_dir("stable");
+    write_crate_source(&dir, "pub fn hello() {}\n");
+
+    let first = fingerprint(&dir, Some("0.1.0")).expect("fingerprint should compute");
+    let second = fingerprint(&dir, Some("0.1.0")).expect("fingerprint should compute");
+    assert_eq!(first, second);
+}
+
+#[test]
+fn fingerprint_changes_when_crate_version_changes() {
+    let dir = scratch_dir("version-bump");
+    write_crate_source(&dir, "pub fn hello() {}\n");
+
+    let before = fingerprint(&dir, Some("0.1.0")).expect("fingerprint should compute");
+    let after = fingerprint(&dir, Some("0.2.0")).expect("fingerprint should compute");
+    assert_ne!(before, after);
+}
+
+#[test]
+fn fingerprint_changes_when_a_source_file_is_added() {
+    let dir = scratch_dir("file-added");
+    write_crate_source(&dir, "pub fn hello() {}\n");
+
+    let before = fingerprint(&dir, Some("0.1.0")).expect("fingerprint should compute");
+    std::fs::write(dir.join("src/extra.rs"), "pub fn extra() {}\n").expect("write extra.rs");
+    let after = fingerprint(&dir, Some("0.1.0")).expect("fingerprint should compute");
+
+    assert_ne!(before, after);
+}
+
+#[test]
+fn fingerprint_is_none_without_a_src_directory() {
+    let dir = scratch_dir("no-src");
+    assert!(fingerprint(&dir, Some("0.1.0")).is_none());
+}
+
+fn sample_crate() -> Crate {
+    let json = r#"
+    {
+      "root": "0",
+      "format_version": 30,
+      "index": {
+        "0": { "id": "0", "name": "scratch_crate", "visibility": "public", "attrs": [], "inner": { "module": { "items": [] } } }
+      }
+    }
+    "#;
+    Crate::load(json.as_bytes()).expect("sample crate should load")
+}
+
+#[test]
+fn cache_round_trips_a_crate_by_fingerprint() {
+    let cache_dir = scratch_dir("round-trip-cache");
+    let cache = CrateCache::new(&cache_dir).expect("create cache");
+    let crate_dir = scratch_dir("round-trip-crate");
+    write_crate_source(&crate_dir, "pub fn hello() {}\n");
+    let fp = fingerprint(&crate_dir, Some("0.1.0")).expect("fingerprint");
+
+    assert!(cache.get(fp).is_none());
+    cache
+        .put(fp, &sample_crate())
+        .expect("write cache entry");
+
+    let reloaded = cache.get(fp).expect("cache entry should round-trip");
+    assert_eq!(reloaded.format_version, 30);
+    assert!(reloaded.index.contains_key("0"));
+}
+
+#[test]
+fn invalidate_all_clears_every_entry() {
+    let cache_dir = scratch_dir("invalidate-all");
+    let cache = CrateCache::new(&cache_dir).expect("create cache");
+    let crate_dir = scratch_dir("invalidate-all-crate");
+    write_crate_source(&crate_dir, "pub fn hello() {}\n");
+    let fp = fingerprint(&crate_dir, Some("0.1.0")).expect("fingerprint");
+    cache
+        .put(fp, &sample_crate())
+        .expect("write cache entry");
+
+    cache.invalidate_all().expect("invalidate_all should succeed");
+
+    assert!(cache.get(fp).is_none());
+}
+
+#[test]
+fn invalidate_removes_only_the_named_crates_current_entry() {
+    let cache_dir = scratch_dir("invalidate-one");
+    let cache = CrateCache::new(&cache_dir).expect("create cache");
+
+    let kept_dir = scratch_dir("invalidate-one-kept");
+    write_crate_source(&kept_dir, "pub fn kept() {}\n");
+    let kept_fp = fingerprint(&kept_dir, Some("0.1.0")).expect("fingerprint");
+    cache
+        .put(kept_fp, &sample_crate())
+        .expect("write kept entry");
+
+    let removed_dir = scratch_dir("invalidate-one-removed");
+    write_crate_source(&removed_dir, "pub fn removed() {}\n");
+    let removed_fp = fingerprint(&removed_dir, Some("0.1.0")).expect("fingerprint");
+    cache
+        .put(removed_fp, &sample_crate())
+        .expect("write removed entry");
+
+    cache.invalidate(&removed_dir).expect("invalidate should succeed");
+
+    assert!(cache.get(kept_fp).is_some());
+    assert!(cache.get(removed_fp).is_none());
+}
+
+#[test]
+fn cache_dir_reports_the_configured_location() {
+    let cache_dir = scratch_dir("location");
+    let cache = CrateCache::new(&cache_dir).expect("create cache");
+    assert_eq!(cache.cache_dir(), cache_dir);
+}
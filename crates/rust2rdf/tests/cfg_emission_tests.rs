@@ -0,0 +1,171 @@
+//! Integration tests for the `cfg` condition-node subgraph emitted by
+//! `CrateExtractor` (as opposed to the pure parsing tested in `cfg_tests.rs`).
+//!
+//! Uses a small hand-written rustdoc JSON crate so `all`/`any`/`not`/leaf
+//! combinations and shared guards can be pinned down explicitly.
+
+use rust2rdf::emitter::ntriples::NTriplesEmitter;
+use rust2rdf::emitter::TriplesEmitter;
+use rust2rdf::extraction::extractor::{CrateExtractor, ExtractionOptions};
+use rust2rdf::extraction::rustdoc_model::Crate;
+
+const CRATE_JSON: &str = r##"
+{
+  "root": "0",
+  "crate_version": "0.1.0",
+  "format_version": 35,
+  "external_crates": {},
+  "paths": {},
+  "index": {
+    "0": {
+      "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+      "inner": { "module": { "items": ["1", "2"] } }
+    },
+    "1": {
+      "id": "1", "name": "UnixOnly", "visibility": "public",
+      "attrs": ["#[cfg(unix)]"],
+      "inner": { "struct": {} }
+    },
+    "2": {
+      "id": "2", "name": "AlsoUnixOnly", "visibility": "public",
+      "attrs": ["#[cfg(unix)]"],
+      "inner": { "struct": {} }
+    }
+  }
+}
+"##;
+
+fn load() -> Crate {
+    serde_json::from_str(CRATE_JSON).expect("fixture JSON should parse")
+}
+
+fn extract() -> String {
+    let krate = load();
+    let mut buf = Vec::new();
+    {
+        let mut emitter = NTriplesEmitter::new(&mut buf);
+        let mut extractor =
+            CrateExtractor::new(&mut emitter, &krate, ExtractionOptions::default());
+        extractor.extract();
+        emitter.flush().unwrap();
+    }
+    String::from_utf8(buf).expect("Invalid UTF-8 in NTriples output")
+}
+
+fn count_lines(output: &str, needle: &str) -> usize {
+    output.lines().filter(|line| line.contains(needle)).count()
+}
+
+#[test]
+fn leaf_cfg_node_is_typed_cfg_option() {
+    let out = extract();
+    assert!(out.contains(&format!(
+        "<http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://rust.example/ontology/CfgOption>"
+    )));
+}
+
+#[test]
+fn identical_cfg_guards_share_one_node() {
+    let out = extract();
+    // Both `UnixOnly` and `AlsoUnixOnly` carry the same `unix` guard, so the
+    // leaf condition node's type/operator/flag triples should appear exactly
+    // once even though two items reference it.
+    assert_eq!(
+        count_lines(&out, "http://rust.example/ontology/CfgOption"),
+        1,
+        "shared cfg guard should only be emitted once:\n{out}"
+    );
+    assert_eq!(
+        count_lines(&out, "http://rust.example/ontology/cfgFlag"),
+        1,
+        "shared cfg guard should only be emitted once:\n{out}"
+    );
+}
+
+const ALL_CRATE_JSON: &str = r##"
+{
+  "root": "0",
+  "crate_version": "0.1.0",
+  "format_version": 35,
+  "external_crates": {},
+  "paths": {},
+  "index": {
+    "0": {
+      "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+      "inner": { "module": { "items": ["1"] } }
+    },
+    "1": {
+      "id": "1", "name": "UnixFeatureX", "visibility": "public",
+      "attrs": ["#[cfg(all(unix, feature = \"x\"))]"],
+      "inner": { "struct": {} }
+    }
+  }
+}
+"##;
+
+fn extract_all_crate() -> String {
+    let krate: Crate = serde_json::from_str(ALL_CRATE_JSON).expect("fixture JSON should parse");
+    let mut buf = Vec::new();
+    {
+        let mut emitter = NTriplesEmitter::new(&mut buf);
+        let mut extractor =
+            CrateExtractor::new(&mut emitter, &krate, ExtractionOptions::default());
+        extractor.extract();
+        emitter.flush().unwrap();
+    }
+    String::from_utf8(buf).expect("Invalid UTF-8 in NTriples output")
+}
+
+#[test]
+fn all_node_links_operands_through_an_ordered_collection() {
+    let out = extract_all_crate();
+    // The `CfgAll` node's `cfgOperand` object must be a blank-node list head,
+    // not an operand IRI directly -- order matters here (it mirrors the
+    // source `all(...)` operand order), so it's spelled out as an
+    // `rdf:first`/`rdf:rest` chain rather than a repeated flat predicate.
+    let operand_line = out
+        .lines()
+        .find(|l| l.contains("http://rust.example/ontology/cfgOperand"))
+        .expect("expected a cfgOperand triple");
+    assert!(
+        operand_line.contains(" _:"),
+        "cfgOperand should point at a blank list node: {operand_line}"
+    );
+    assert!(out.contains("http://rust.example/ontology/CfgAll"));
+    assert!(out.contains("http://rust.example/ontology/cfgFlag"));
+    assert!(out.contains("http://rust.example/ontology/cfgKey"));
+    assert!(out.contains("http://rust.example/ontology/cfgValue"));
+}
+
+#[test]
+fn gated_item_gets_is_cfg_gated_requires_feature_and_target_only() {
+    let out = extract_all_crate();
+
+    assert!(
+        out.lines().any(|l| l.contains("http://rust.example/ontology/isCfgGated")
+            && l.contains("\"true\"")),
+        "expected an isCfgGated=true triple:\n{out}"
+    );
+    assert!(
+        out.lines()
+            .any(|l| l.contains("http://rust.example/ontology/requiresFeature") && l.contains("\"x\"")),
+        "expected a requiresFeature \"x\" triple:\n{out}"
+    );
+    assert!(
+        out.lines()
+            .any(|l| l.contains("http://rust.example/ontology/targetOnly") && l.contains("\"unix\"")),
+        "expected a targetOnly \"unix\" triple:\n{out}"
+    );
+}
+
+#[test]
+fn cfg_gating_predicates_are_only_emitted_per_gated_item_not_deduplicated() {
+    // `extract()`'s fixture has two `#[cfg(unix)]` items and no `feature`
+    // predicate anywhere -- unlike the shared condition node (which is
+    // deduplicated), `isCfgGated`/`targetOnly` are per-item triples, so both
+    // should show up twice, and `requiresFeature` not at all.
+    let out = extract();
+    assert_eq!(count_lines(&out, "http://rust.example/ontology/isCfgGated"), 2);
+    assert_eq!(count_lines(&out, "http://rust.example/ontology/targetOnly"), 2);
+    assert_eq!(count_lines(&out, "http://rust.example/ontology/requiresFeature"), 0);
+}
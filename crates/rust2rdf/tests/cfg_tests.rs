@@ -0,0 +1,128 @@
+use rust2rdf::cfg::{
+    canonical_string, conjoin, parse_item_cfg, referenced_features, simplify, target_only_predicates, Cfg,
+};
+use serde_json::Value;
+
+fn attr(s: &str) -> Value {
+    Value::String(s.to_string())
+}
+
+#[test]
+fn bare_flag_parses_as_flag() {
+    let cfg = parse_item_cfg(&[attr("#[cfg(unix)]")]);
+    assert_eq!(cfg, Cfg::Flag("unix".to_string()));
+}
+
+#[test]
+fn name_value_parses_key_and_value() {
+    let cfg = parse_item_cfg(&[attr(r#"#[cfg(feature = "fancy")]"#)]);
+    assert_eq!(
+        cfg,
+        Cfg::NameValue("feature".to_string(), "fancy".to_string())
+    );
+}
+
+#[test]
+fn cfg_attr_predicate_is_extracted() {
+    let cfg = parse_item_cfg(&[attr(r#"#[cfg_attr(windows, allow(dead_code))]"#)]);
+    assert_eq!(cfg, Cfg::Flag("windows".to_string()));
+}
+
+#[test]
+fn multiple_cfg_attrs_are_conjoined() {
+    let cfg = parse_item_cfg(&[attr("#[cfg(unix)]"), attr("#[cfg(feature = \"x\")]")]);
+    assert_eq!(canonical_string(&cfg), r#"all(feature = "x", unix)"#);
+}
+
+#[test]
+fn nested_all_flattens() {
+    let cfg = simplify(Cfg::All(vec![
+        Cfg::Flag("a".to_string()),
+        Cfg::All(vec![Cfg::Flag("b".to_string()), Cfg::Flag("c".to_string())]),
+    ]));
+    assert_eq!(canonical_string(&cfg), "all(a, b, c)");
+}
+
+#[test]
+fn duplicate_children_are_deduplicated() {
+    let cfg = simplify(Cfg::All(vec![
+        Cfg::Flag("unix".to_string()),
+        Cfg::Flag("unix".to_string()),
+    ]));
+    assert_eq!(cfg, Cfg::Flag("unix".to_string()));
+}
+
+#[test]
+fn false_short_circuits_all() {
+    let cfg = simplify(Cfg::All(vec![Cfg::Flag("unix".to_string()), Cfg::False]));
+    assert_eq!(cfg, Cfg::False);
+}
+
+#[test]
+fn true_short_circuits_any() {
+    let cfg = simplify(Cfg::Any(vec![Cfg::Flag("unix".to_string()), Cfg::True]));
+    assert_eq!(cfg, Cfg::True);
+}
+
+#[test]
+fn single_child_all_collapses_to_child() {
+    let cfg = simplify(Cfg::All(vec![Cfg::Flag("unix".to_string())]));
+    assert_eq!(cfg, Cfg::Flag("unix".to_string()));
+}
+
+#[test]
+fn not_of_all_pushes_through_de_morgan() {
+    let cfg = simplify(Cfg::Not(Box::new(Cfg::All(vec![
+        Cfg::Flag("a".to_string()),
+        Cfg::Flag("b".to_string()),
+    ]))));
+    assert_eq!(canonical_string(&cfg), "any(not(a), not(b))");
+}
+
+#[test]
+fn double_negation_cancels() {
+    let cfg = simplify(Cfg::Not(Box::new(Cfg::Not(Box::new(Cfg::Flag(
+        "unix".to_string(),
+    ))))));
+    assert_eq!(cfg, Cfg::Flag("unix".to_string()));
+}
+
+#[test]
+fn no_cfg_attrs_means_unconditionally_true() {
+    let cfg = parse_item_cfg(&[attr("#[doc(hidden)]")]);
+    assert_eq!(cfg, Cfg::True);
+}
+
+#[test]
+fn conjoin_combines_ambient_and_own_cfg() {
+    let ambient = Cfg::Flag("windows".to_string());
+    let own = Cfg::Flag("feature_x".to_string());
+    let combined = conjoin(&ambient, own);
+    assert_eq!(canonical_string(&combined), "all(feature_x, windows)");
+}
+
+#[test]
+fn referenced_features_collects_feature_name_values_only() {
+    let cfg = parse_item_cfg(&[attr(
+        r#"#[cfg(all(unix, feature = "x", not(feature = "y")))]"#,
+    )]);
+    assert_eq!(referenced_features(&cfg), vec!["x".to_string(), "y".to_string()]);
+    assert!(target_only_predicates(&cfg)
+        .iter()
+        .all(|p| !p.contains("feature")));
+}
+
+#[test]
+fn target_only_predicates_collects_flags_and_non_feature_name_values() {
+    let cfg = parse_item_cfg(&[attr(r#"#[cfg(any(unix, target_os = "windows"))]"#)]);
+    assert_eq!(
+        target_only_predicates(&cfg),
+        vec!["target_os = \"windows\"".to_string(), "unix".to_string()]
+    );
+}
+
+#[test]
+fn referenced_features_and_target_only_predicates_are_deduplicated() {
+    let cfg = parse_item_cfg(&[attr(r#"#[cfg(any(feature = "x", feature = "x"))]"#)]);
+    assert_eq!(referenced_features(&cfg), vec!["x".to_string()]);
+}
@@ -106,6 +106,34 @@ fn turtle_output_has_prefixes() {
     );
 }
 
+#[test]
+fn json_ld_alias_produces_a_valid_jsonld_document() {
+    let output = Command::new(binary_path())
+        .args([
+            "--json",
+            fixture_path().to_str().unwrap(),
+            "--format",
+            "json-ld",
+            "-q",
+        ])
+        .output()
+        .expect("failed to execute binary");
+
+    assert!(
+        output.status.success(),
+        "rust2rdf failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let stdout = String::from_utf8(output.stdout).expect("invalid UTF-8");
+    let doc: serde_json::Value = serde_json::from_str(&stdout).expect("output is not valid JSON");
+    assert!(doc["@context"].is_object(), "expected a @context object");
+    assert!(
+        doc["@graph"].as_array().is_some_and(|g| !g.is_empty()),
+        "expected a non-empty @graph array"
+    );
+}
+
 #[test]
 fn base_uri_changes_output_iris() {
     let custom_base = "http://custom.example/test";
@@ -226,6 +254,46 @@ fn verbose_prints_summary_to_stderr() {
     );
 }
 
+#[test]
+fn workspace_conflicts_with_json() {
+    let output = Command::new(binary_path())
+        .args(["--workspace", "--json", fixture_path().to_str().unwrap()])
+        .output()
+        .expect("failed to execute binary");
+
+    assert!(
+        !output.status.success(),
+        "--workspace and --json should be rejected together"
+    );
+    let stderr = String::from_utf8(output.stderr).expect("invalid UTF-8");
+    assert!(
+        stderr.contains("cannot be used with"),
+        "clap should report the --workspace/--json conflict, got: {stderr}"
+    );
+}
+
+#[test]
+fn workspace_mode_reports_a_missing_cargo_toml() {
+    let dir = std::env::temp_dir().join(format!(
+        "rust2rdf-cli-workspace-test-{}",
+        std::process::id()
+    ));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).expect("create scratch dir");
+
+    let output = Command::new(binary_path())
+        .args(["--workspace", dir.to_str().unwrap(), "-q"])
+        .output()
+        .expect("failed to execute binary");
+
+    std::fs::remove_dir_all(&dir).ok();
+
+    assert!(
+        !output.status.success(),
+        "a workspace root without a Cargo.toml should fail"
+    );
+}
+
 #[test]
 fn quiet_suppresses_stderr() {
     let output = Command::new(binary_path())
@@ -0,0 +1,67 @@
+use rust2rdf::config::{Config, PathFilter};
+
+#[test]
+fn parses_base_uri_and_format() {
+    let toml = r#"
+base_uri = "http://example.org/custom"
+format = "turtle"
+"#;
+    let config: Config = toml::from_str(toml).expect("should parse");
+    assert_eq!(config.base_uri.as_deref(), Some("http://example.org/custom"));
+    assert_eq!(config.format.as_deref(), Some("turtle"));
+}
+
+#[test]
+fn parses_prefixes_table() {
+    let toml = r#"
+[prefixes]
+ex = "http://example.org/"
+"#;
+    let config: Config = toml::from_str(toml).expect("should parse");
+    assert_eq!(
+        config.prefixes.get("ex").map(String::as_str),
+        Some("http://example.org/")
+    );
+}
+
+#[test]
+fn missing_fields_default_to_empty() {
+    let config: Config = toml::from_str("").expect("should parse");
+    assert!(config.base_uri.is_none());
+    assert!(config.include.is_empty());
+    assert!(config.exclude.is_empty());
+}
+
+// --- PathFilter ---
+
+#[test]
+fn empty_filter_allows_everything() {
+    let filter = PathFilter::allow_all();
+    assert!(filter.allows("mycrate::module::Thing"));
+}
+
+#[test]
+fn prefix_include_matches_nested_paths() {
+    let filter = PathFilter::new(vec!["mycrate::public".to_string()], vec![]);
+    assert!(filter.allows("mycrate::public::Thing"));
+    assert!(filter.allows("mycrate::public"));
+    assert!(!filter.allows("mycrate::internal::Thing"));
+}
+
+#[test]
+fn exclude_wins_over_include() {
+    let filter = PathFilter::new(
+        vec!["mycrate".to_string()],
+        vec!["mycrate::tests".to_string()],
+    );
+    assert!(filter.allows("mycrate::Thing"));
+    assert!(!filter.allows("mycrate::tests::helper"));
+}
+
+#[test]
+fn glob_pattern_matches_any_segment() {
+    let filter = PathFilter::new(vec![], vec!["mycrate::*::tests".to_string()]);
+    assert!(!filter.allows("mycrate::foo::tests"));
+    assert!(!filter.allows("mycrate::bar::tests"));
+    assert!(filter.allows("mycrate::bar::tests::nested"));
+}
@@ -0,0 +1,127 @@
+//! Integration tests for constant/static value extraction and const-generic
+//! defaults.
+
+use rust2rdf::emitter::ntriples::NTriplesEmitter;
+use rust2rdf::emitter::TriplesEmitter;
+use rust2rdf::extraction::extractor::{CrateExtractor, ExtractionOptions};
+use rust2rdf::extraction::rustdoc_model::Crate;
+
+const CRATE_JSON: &str = r#"
+{
+  "root": "0",
+  "crate_version": "0.1.0",
+  "format_version": 35,
+  "external_crates": {},
+  "paths": {},
+  "index": {
+    "0": {
+      "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+      "inner": { "module": { "items": ["1", "2", "3"] } }
+    },
+    "1": {
+      "id": "1", "name": "MAX_SIZE", "visibility": "public", "attrs": [],
+      "inner": { "constant": {
+        "type": { "primitive": "usize" },
+        "const": { "expr": "4 * 1024", "value": "4096", "is_literal": false }
+      } }
+    },
+    "2": {
+      "id": "2", "name": "COUNTER", "visibility": "public", "attrs": [],
+      "inner": { "static": {
+        "type": { "primitive": "i32" },
+        "is_mutable": true,
+        "is_unsafe": false,
+        "expr": "0"
+      } }
+    },
+    "3": {
+      "id": "3", "name": "make_array", "visibility": "public", "attrs": [],
+      "inner": { "function": {
+        "sig": { "inputs": [], "output": null },
+        "generics": { "params": [
+          { "name": "N", "kind": { "const": { "type": { "primitive": "usize" }, "default": "4" } } }
+        ], "where_predicates": [] },
+        "header": { "is_const": false, "is_unsafe": false, "is_async": false }
+      } }
+    }
+  }
+}
+"#;
+
+fn load() -> Crate {
+    serde_json::from_str(CRATE_JSON).expect("fixture JSON should parse")
+}
+
+fn extract() -> String {
+    let krate = load();
+    let mut buf = Vec::new();
+    {
+        let mut emitter = NTriplesEmitter::new(&mut buf);
+        let mut extractor =
+            CrateExtractor::new(&mut emitter, &krate, ExtractionOptions::default());
+        extractor.extract();
+        emitter.flush().unwrap();
+    }
+    String::from_utf8(buf).expect("Invalid UTF-8 in NTriples output")
+}
+
+fn has_literal_triple(output: &str, subject: &str, predicate: &str, value: &str) -> bool {
+    let expected = format!("<{subject}> <{predicate}> \"{value}\" .");
+    output.lines().any(|line| line.trim() == expected)
+}
+
+const BASE: &str = "http://rust.example";
+
+fn module_iri() -> String {
+    format!("{BASE}/module/mycrate/0.1.0/mycrate")
+}
+
+fn member_iri(name: &str) -> String {
+    format!("{}/member/{name}", module_iri())
+}
+
+#[test]
+fn constant_gets_its_source_expression_and_evaluated_value() {
+    let out = extract();
+    let max_size = member_iri("MAX_SIZE");
+
+    assert!(has_literal_triple(
+        &out,
+        &max_size,
+        "http://typegraph.example/ontology/constExpr",
+        "4 * 1024"
+    ));
+    assert!(has_literal_triple(
+        &out,
+        &max_size,
+        "http://typegraph.example/ontology/constValue",
+        "4096"
+    ));
+}
+
+#[test]
+fn static_gets_its_source_expression() {
+    let out = extract();
+    let counter = member_iri("COUNTER");
+
+    assert!(has_literal_triple(
+        &out,
+        &counter,
+        "http://typegraph.example/ontology/constExpr",
+        "0"
+    ));
+}
+
+#[test]
+fn const_generic_parameter_default_is_emitted() {
+    let out = extract();
+    let fn_iri = member_iri("make_array");
+    let n_param = format!("{fn_iri}/typeparam/0");
+
+    assert!(has_literal_triple(
+        &out,
+        &n_param,
+        "http://typegraph.example/ontology/defaultValue",
+        "4"
+    ));
+}
@@ -0,0 +1,110 @@
+//! Integration tests for the synthetic `rt:DerivedImpl`/`rt:implementsTrait`
+//! edges emitted alongside the existing `rt:derives` literal.
+
+use rust2rdf::emitter::ntriples::NTriplesEmitter;
+use rust2rdf::emitter::TriplesEmitter;
+use rust2rdf::extraction::extractor::{CrateExtractor, ExtractionOptions};
+use rust2rdf::extraction::rustdoc_model::Crate;
+
+const CRATE_JSON: &str = r#"
+{
+  "root": "0",
+  "crate_version": "0.1.0",
+  "format_version": 35,
+  "external_crates": {},
+  "paths": {},
+  "index": {
+    "0": {
+      "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+      "inner": { "module": { "items": ["1", "2"] } }
+    },
+    "1": {
+      "id": "1", "name": "Widget", "visibility": "public", "attrs": [],
+      "inner": { "struct": { "impls": ["2"] } }
+    },
+    "2": {
+      "id": "2", "name": null, "visibility": "default",
+      "attrs": ["automatically_derived"],
+      "inner": { "impl": {
+        "generics": { "params": [], "where_predicates": [] },
+        "trait": { "path": "Clone", "id": null },
+        "for": { "resolved_path": { "path": "Widget", "id": "1" } },
+        "items": []
+      } }
+    }
+  }
+}
+"#;
+
+fn load() -> Crate {
+    serde_json::from_str(CRATE_JSON).expect("fixture JSON should parse")
+}
+
+fn extract(opts: ExtractionOptions) -> String {
+    let krate = load();
+    let mut buf = Vec::new();
+    {
+        let mut emitter = NTriplesEmitter::new(&mut buf);
+        let mut extractor = CrateExtractor::new(&mut emitter, &krate, opts);
+        extractor.extract();
+        emitter.flush().unwrap();
+    }
+    String::from_utf8(buf).expect("Invalid UTF-8 in NTriples output")
+}
+
+const WIDGET_IRI: &str = "http://rust.example/type/mycrate/0.1.0/mycrate%3A%3AWidget";
+const CLONE_IRI: &str = "http://rust.example/type/mycrate/0.1.0/core%3A%3Aclone%3A%3AClone";
+
+#[test]
+fn derived_impl_resolves_registry_trait_when_unindexed() {
+    let out = extract(ExtractionOptions::default());
+
+    assert!(out.contains(&format!(
+        "<{WIDGET_IRI}> <http://rust.example/ontology/implementsTrait> <{CLONE_IRI}> ."
+    )));
+    // The rt:derives literal is kept for backward compatibility.
+    assert!(out.contains(&format!(
+        "<{WIDGET_IRI}> <http://rust.example/ontology/derives> \"Clone\" ."
+    )));
+}
+
+#[test]
+fn derived_impl_node_is_typed_and_carries_impl_source() {
+    let out = extract(ExtractionOptions::default());
+
+    assert!(out.contains("http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://rust.example/ontology/DerivedImpl>"));
+    assert!(out.contains(&format!(
+        "<http://rust.example/ontology/implSource> \"derive\" ."
+    )));
+}
+
+#[test]
+fn user_registered_derive_trait_overrides_builtin_registry() {
+    let mut extra = std::collections::HashMap::new();
+    extra.insert("Clone".to_string(), "my_crate::CustomClone".to_string());
+    let opts = ExtractionOptions {
+        extra_derive_traits: extra,
+        ..ExtractionOptions::default()
+    };
+    let out = extract(opts);
+    let custom_iri = "http://rust.example/type/mycrate/0.1.0/my_crate%3A%3ACustomClone";
+
+    assert!(out.contains(&format!(
+        "<{WIDGET_IRI}> <http://rust.example/ontology/implementsTrait> <{custom_iri}> ."
+    )));
+}
+
+#[test]
+fn extract_derive_impls_false_suppresses_synthetic_edges_but_keeps_derives() {
+    let opts = ExtractionOptions {
+        extract_derive_impls: false,
+        ..ExtractionOptions::default()
+    };
+    let out = extract(opts);
+
+    assert!(!out.contains("http://rust.example/ontology/implementsTrait"));
+    assert!(!out.contains("http://rust.example/ontology/DerivedImpl"));
+    assert!(out.contains(&format!(
+        "<{WIDGET_IRI}> <http://rust.example/ontology/derives> \"Clone\" ."
+    )));
+}
@@ -0,0 +1,46 @@
+use rust2rdf::diff::diff_lines;
+
+fn lines(v: &[&str]) -> Vec<String> {
+    v.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn identical_inputs_produce_no_delta() {
+    let a = lines(&["<a> <p> <o> .", "<b> <p> <o> ."]);
+    let delta = diff_lines(&a, &a);
+    assert!(delta.is_empty());
+}
+
+#[test]
+fn detects_added_and_removed_lines() {
+    let old = lines(&["<a> <p> <o> .", "<b> <p> <o> ."]);
+    let new = lines(&["<a> <p> <o> .", "<c> <p> <o> ."]);
+    let delta = diff_lines(&old, &new);
+    assert_eq!(delta.removed, lines(&["<b> <p> <o> ."]));
+    assert_eq!(delta.added, lines(&["<c> <p> <o> ."]));
+}
+
+#[test]
+fn empty_old_reports_everything_as_added() {
+    let new = lines(&["<a> <p> <o> .", "<b> <p> <o> ."]);
+    let delta = diff_lines(&[], &new);
+    assert_eq!(delta.added, new);
+    assert!(delta.removed.is_empty());
+}
+
+#[test]
+fn empty_new_reports_everything_as_removed() {
+    let old = lines(&["<a> <p> <o> .", "<b> <p> <o> ."]);
+    let delta = diff_lines(&old, &[]);
+    assert_eq!(delta.removed, old);
+    assert!(delta.added.is_empty());
+}
+
+#[test]
+fn trailing_entries_past_the_shorter_list_are_handled() {
+    let old = lines(&["<a> <p> <o> ."]);
+    let new = lines(&["<a> <p> <o> .", "<z> <p> <o> ."]);
+    let delta = diff_lines(&old, &new);
+    assert_eq!(delta.added, lines(&["<z> <p> <o> ."]));
+    assert!(delta.removed.is_empty());
+}
@@ -0,0 +1,170 @@
+//! Integration tests for `discriminant`: assigning concrete enum
+//! discriminant values and filling in literal constant values.
+
+use rust2rdf::discriminant::{evaluate_const_values, evaluate_enum_discriminants, parse_int_literal};
+use rust2rdf::extraction::rustdoc_model::{Crate, ItemEnum};
+
+fn load(json: &str) -> Crate {
+    serde_json::from_str(json).expect("fixture JSON should parse")
+}
+
+#[test]
+fn parse_int_literal_handles_prefixes_suffixes_and_underscores() {
+    assert_eq!(parse_int_literal("42"), Some(42));
+    assert_eq!(parse_int_literal("-7"), Some(-7));
+    assert_eq!(parse_int_literal("0x2A"), Some(42));
+    assert_eq!(parse_int_literal("0o52"), Some(42));
+    assert_eq!(parse_int_literal("0b101010"), Some(42));
+    assert_eq!(parse_int_literal("1_000_000"), Some(1_000_000));
+    assert_eq!(parse_int_literal("42i32"), Some(42));
+    assert_eq!(parse_int_literal("-1i8"), Some(-1));
+    assert_eq!(parse_int_literal("FOO + 1"), None);
+}
+
+const ENUM_JSON: &str = r##"
+{
+  "root": "0",
+  "format_version": 35,
+  "index": {
+    "0": {
+      "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+      "inner": { "module": { "items": ["1"] } }
+    },
+    "1": {
+      "id": "1", "name": "Color", "visibility": "public",
+      "attrs": ["#[repr(u8)]"],
+      "inner": { "enum": {
+        "generics": { "params": [], "where_predicates": [] },
+        "variants": ["10", "11", "12", "13"],
+        "variants_stripped": false,
+        "impls": []
+      } }
+    },
+    "10": { "id": "10", "name": "Red", "visibility": "public", "attrs": [], "inner": { "variant": { "kind": "plain" } } },
+    "11": { "id": "11", "name": "Green", "visibility": "public", "attrs": [], "inner": { "variant": { "kind": "plain" } } },
+    "12": {
+      "id": "12", "name": "Blue", "visibility": "public", "attrs": [],
+      "inner": { "variant": { "kind": "plain", "discriminant": { "expr": "10", "value": null } } }
+    },
+    "13": { "id": "13", "name": "Alpha", "visibility": "public", "attrs": [], "inner": { "variant": { "kind": "plain" } } }
+  }
+}
+"##;
+
+fn variant_value(krate: &Crate, id: &str) -> String {
+    let ItemEnum::Variant(variant) = &krate.index[id].inner else {
+        panic!("expected a variant");
+    };
+    variant
+        .discriminant
+        .as_ref()
+        .and_then(|d| d.value.clone())
+        .expect("discriminant value should be set")
+}
+
+#[test]
+fn implicit_variants_count_up_from_zero() {
+    let mut krate = load(ENUM_JSON);
+    evaluate_enum_discriminants(&mut krate);
+
+    assert_eq!(variant_value(&krate, "10"), "0");
+    assert_eq!(variant_value(&krate, "11"), "1");
+}
+
+#[test]
+fn explicit_discriminant_resets_the_counter() {
+    let mut krate = load(ENUM_JSON);
+    evaluate_enum_discriminants(&mut krate);
+
+    assert_eq!(variant_value(&krate, "12"), "10");
+    assert_eq!(variant_value(&krate, "13"), "11");
+}
+
+#[test]
+fn discriminants_wrap_per_repr_width() {
+    let json = r##"
+    {
+      "root": "0",
+      "format_version": 35,
+      "index": {
+        "0": {
+          "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+          "inner": { "module": { "items": ["1"] } }
+        },
+        "1": {
+          "id": "1", "name": "Small", "visibility": "public",
+          "attrs": ["#[repr(u8)]"],
+          "inner": { "enum": {
+            "generics": { "params": [], "where_predicates": [] },
+            "variants": ["10", "11"],
+            "variants_stripped": false,
+            "impls": []
+          } }
+        },
+        "10": {
+          "id": "10", "name": "Max", "visibility": "public", "attrs": [],
+          "inner": { "variant": { "kind": "plain", "discriminant": { "expr": "255", "value": null } } }
+        },
+        "11": { "id": "11", "name": "Wraps", "visibility": "public", "attrs": [], "inner": { "variant": { "kind": "plain" } } }
+      }
+    }
+    "##;
+    let mut krate = load(json);
+    evaluate_enum_discriminants(&mut krate);
+
+    assert_eq!(variant_value(&krate, "10"), "255");
+    assert_eq!(variant_value(&krate, "11"), "0");
+}
+
+#[test]
+fn const_value_is_filled_in_from_a_literal_expr() {
+    let json = r#"
+    {
+      "root": "0",
+      "format_version": 35,
+      "index": {
+        "0": {
+          "id": "0", "name": "MAX", "visibility": "public", "attrs": [],
+          "inner": { "constant": {
+            "type": { "primitive": "i32" },
+            "const": { "expr": "100_000", "value": null, "is_literal": true }
+          } }
+        }
+      }
+    }
+    "#;
+    let mut krate = load(json);
+    evaluate_const_values(&mut krate);
+
+    let ItemEnum::Constant { const_: Some(const_expr), .. } = &krate.index["0"].inner else {
+        panic!("expected a constant");
+    };
+    assert_eq!(const_expr.value.as_deref(), Some("100000"));
+}
+
+#[test]
+fn assoc_const_literal_value_is_normalized() {
+    let json = r#"
+    {
+      "root": "0",
+      "format_version": 35,
+      "index": {
+        "0": {
+          "id": "0", "name": "MAX", "visibility": "public", "attrs": [],
+          "inner": { "assoc_const": {
+            "type": { "primitive": "u8" },
+            "value": "0xFF",
+            "is_default": false
+          } }
+        }
+      }
+    }
+    "#;
+    let mut krate = load(json);
+    evaluate_const_values(&mut krate);
+
+    let ItemEnum::AssocConst { value, .. } = &krate.index["0"].inner else {
+        panic!("expected an associated constant");
+    };
+    assert_eq!(value.as_deref(), Some("255"));
+}
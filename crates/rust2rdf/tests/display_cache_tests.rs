@@ -0,0 +1,76 @@
+use std::rc::Rc;
+
+use rust2rdf::display_cache::{DisplayCache, GreenType};
+use rust2rdf::extraction::rustdoc_model::Type;
+
+fn green(ty: Type) -> GreenType {
+    Rc::new(ty)
+}
+
+#[test]
+fn display_renders_the_correct_string() {
+    let mut cache = DisplayCache::new();
+    let node = green(Type::Primitive("u8".to_string()));
+
+    assert_eq!(&*cache.display(&node), "u8");
+}
+
+#[test]
+fn repeated_display_of_the_same_node_reuses_one_cache_entry() {
+    let mut cache = DisplayCache::new();
+    let node = green(Type::Primitive("u8".to_string()));
+
+    let first = cache.display(&node);
+    let second = cache.display(&node.clone());
+
+    assert_eq!(cache.len(), 1);
+    assert!(Rc::ptr_eq(&first, &second));
+}
+
+#[test]
+fn structurally_equal_but_distinct_nodes_are_cached_separately() {
+    let mut cache = DisplayCache::new();
+    let a = green(Type::Primitive("u8".to_string()));
+    let b = green(Type::Primitive("u8".to_string()));
+
+    cache.display(&a);
+    cache.display(&b);
+
+    assert_eq!(cache.len(), 2);
+}
+
+#[test]
+fn invalidate_forces_recomputation_without_touching_other_entries() {
+    let mut cache = DisplayCache::new();
+    let a = green(Type::Primitive("u8".to_string()));
+    let b = green(Type::Primitive("u16".to_string()));
+
+    cache.display(&a);
+    cache.display(&b);
+    assert_eq!(cache.len(), 2);
+
+    cache.invalidate(&a);
+    assert_eq!(cache.len(), 1);
+
+    let rendered = cache.display(&a);
+    assert_eq!(&*rendered, "u8");
+    assert_eq!(cache.len(), 2);
+}
+
+#[test]
+fn invalidate_path_clears_exactly_the_given_spine() {
+    let mut cache = DisplayCache::new();
+    let root = green(Type::Primitive("Root".to_string()));
+    let child = green(Type::Primitive("Child".to_string()));
+    let unrelated = green(Type::Primitive("Unrelated".to_string()));
+
+    cache.display(&root);
+    cache.display(&child);
+    cache.display(&unrelated);
+    assert_eq!(cache.len(), 3);
+
+    cache.invalidate_path(&[root.clone(), child.clone()]);
+
+    assert_eq!(cache.len(), 1);
+    assert!(!cache.is_empty());
+}
@@ -0,0 +1,122 @@
+//! Integration tests for `tg:documentation`/`tg:summary` doc-comment extraction.
+
+use rust2rdf::emitter::ntriples::NTriplesEmitter;
+use rust2rdf::emitter::TriplesEmitter;
+use rust2rdf::extraction::extractor::{CrateExtractor, ExtractionOptions};
+use rust2rdf::extraction::rustdoc_model::Crate;
+
+fn crate_json(docs: &str) -> String {
+    format!(
+        r#"
+{{
+  "root": "0",
+  "crate_version": "0.1.0",
+  "format_version": 35,
+  "external_crates": {{}},
+  "paths": {{}},
+  "index": {{
+    "0": {{
+      "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+      "inner": {{ "module": {{ "items": ["1"] }} }}
+    }},
+    "1": {{
+      "id": "1", "name": "Documented", "visibility": "public", "attrs": [],
+      "docs": {docs},
+      "inner": {{ "struct": {{}} }}
+    }}
+  }}
+}}
+"#
+    )
+}
+
+fn extract_with(docs: &str, opts: ExtractionOptions) -> String {
+    let json = crate_json(docs);
+    let krate: Crate = serde_json::from_str(&json).expect("fixture JSON should parse");
+    let mut buf = Vec::new();
+    {
+        let mut emitter = NTriplesEmitter::new(&mut buf);
+        let mut extractor = CrateExtractor::new(&mut emitter, &krate, opts);
+        extractor.extract();
+        emitter.flush().unwrap();
+    }
+    String::from_utf8(buf).expect("Invalid UTF-8 in NTriples output")
+}
+
+fn type_iri() -> String {
+    // `Documented`'s full path is "mycrate::Documented" -- `IriMinter::type_iri`
+    // percent-encodes the whole thing, so `::` becomes `%3A%3A`.
+    "http://rust.example/type/mycrate/0.1.0/mycrate%3A%3ADocumented".to_string()
+}
+
+#[test]
+fn documentation_is_emitted_with_language_tag() {
+    let out = extract_with(
+        r#""First paragraph.\n\nSecond paragraph.""#,
+        ExtractionOptions::default(),
+    );
+    let subject = type_iri();
+
+    assert!(out.contains(&format!(
+        "<{subject}> <http://typegraph.example/ontology/documentation> \"First paragraph.\\n\\nSecond paragraph.\"@en ."
+    )));
+}
+
+#[test]
+fn summary_is_only_the_first_paragraph() {
+    let out = extract_with(
+        r#""First paragraph.\n\nSecond paragraph.""#,
+        ExtractionOptions::default(),
+    );
+    let subject = type_iri();
+
+    assert!(out.contains(&format!(
+        "<{subject}> <http://typegraph.example/ontology/summary> \"First paragraph.\"@en ."
+    )));
+    assert!(!out.contains("Second paragraph.\"@en"));
+}
+
+#[test]
+fn common_indentation_is_stripped() {
+    let out = extract_with(r#""  indented line\n  another line""#, ExtractionOptions::default());
+    let subject = type_iri();
+
+    assert!(out.contains(&format!(
+        "<{subject}> <http://typegraph.example/ontology/documentation> \"indented line\\nanother line\"@en ."
+    )));
+}
+
+#[test]
+fn custom_doc_language_is_honored() {
+    let opts = ExtractionOptions {
+        doc_language: "fr".to_string(),
+        ..ExtractionOptions::default()
+    };
+    let out = extract_with(r#""Bonjour.""#, opts);
+    let subject = type_iri();
+
+    assert!(out.contains(&format!(
+        "<{subject}> <http://typegraph.example/ontology/documentation> \"Bonjour.\"@fr ."
+    )));
+}
+
+#[test]
+fn extract_docs_false_suppresses_documentation_and_summary() {
+    let opts = ExtractionOptions {
+        extract_docs: false,
+        ..ExtractionOptions::default()
+    };
+    let out = extract_with(r#""Some docs.""#, opts);
+    let subject = type_iri();
+
+    assert!(!out.contains(&format!(
+        "<{subject}> <http://typegraph.example/ontology/documentation>"
+    )));
+    assert!(!out.contains(&format!(
+        "<{subject}> <http://typegraph.example/ontology/summary>"
+    )));
+    // rdfs:comment is unaffected by the flag.
+    assert!(out.contains(&format!(
+        "<{subject}> <http://www.w3.org/2000/01/rdf-schema#comment> \"Some docs.\" ."
+    )));
+}
@@ -1,6 +1,10 @@
+use rust2rdf::emitter::canonical::CanonicalEmitter;
+use rust2rdf::emitter::jsonld::JsonLdEmitter;
+use rust2rdf::emitter::nquads::NQuadsEmitter;
 use rust2rdf::emitter::ntriples::NTriplesEmitter;
+use rust2rdf::emitter::trig::TriGEmitter;
 use rust2rdf::emitter::turtle::TurtleEmitter;
-use rust2rdf::emitter::TriplesEmitter;
+use rust2rdf::emitter::{ObjectTerm, TriplesEmitter};
 
 // ---------------------------------------------------------------------------
 // NTriples tests
@@ -14,7 +18,7 @@ fn nt_basic_iri_triple() {
         "http://example.org/s",
         "http://example.org/p",
         "http://example.org/o",
-    );
+    ).unwrap();
     let out = String::from_utf8(buf).unwrap();
     assert_eq!(
         out,
@@ -30,7 +34,7 @@ fn nt_literal_triple() {
         "http://example.org/s",
         "http://example.org/name",
         "hello world",
-    );
+    ).unwrap();
     let out = String::from_utf8(buf).unwrap();
     assert_eq!(
         out,
@@ -47,7 +51,7 @@ fn nt_typed_literal() {
         "http://example.org/p",
         "42",
         "http://www.w3.org/2001/XMLSchema#integer",
-    );
+    ).unwrap();
     let out = String::from_utf8(buf).unwrap();
     assert_eq!(
         out,
@@ -59,7 +63,7 @@ fn nt_typed_literal() {
 fn nt_bool_true() {
     let mut buf = Vec::new();
     let mut em = NTriplesEmitter::new(&mut buf);
-    em.emit_bool("http://example.org/s", "http://example.org/flag", true);
+    em.emit_bool("http://example.org/s", "http://example.org/flag", true).unwrap();
     let out = String::from_utf8(buf).unwrap();
     assert!(out.contains("\"true\"^^<http://www.w3.org/2001/XMLSchema#boolean>"));
 }
@@ -68,7 +72,7 @@ fn nt_bool_true() {
 fn nt_bool_false() {
     let mut buf = Vec::new();
     let mut em = NTriplesEmitter::new(&mut buf);
-    em.emit_bool("http://example.org/s", "http://example.org/flag", false);
+    em.emit_bool("http://example.org/s", "http://example.org/flag", false).unwrap();
     let out = String::from_utf8(buf).unwrap();
     assert!(out.contains("\"false\"^^<http://www.w3.org/2001/XMLSchema#boolean>"));
 }
@@ -77,7 +81,7 @@ fn nt_bool_false() {
 fn nt_int() {
     let mut buf = Vec::new();
     let mut em = NTriplesEmitter::new(&mut buf);
-    em.emit_int("http://example.org/s", "http://example.org/count", -7);
+    em.emit_int("http://example.org/s", "http://example.org/count", -7).unwrap();
     let out = String::from_utf8(buf).unwrap();
     assert!(out.contains("\"-7\"^^<http://www.w3.org/2001/XMLSchema#integer>"));
 }
@@ -90,7 +94,7 @@ fn nt_escape_special_chars() {
         "http://example.org/s",
         "http://example.org/p",
         "line1\nline2\ttab\\slash\"quote",
-    );
+    ).unwrap();
     let out = String::from_utf8(buf).unwrap();
     assert!(out.contains(r#"\"#));
     assert!(out.contains("\\n"));
@@ -108,7 +112,7 @@ fn nt_escape_control_chars() {
         "http://example.org/s",
         "http://example.org/p",
         "a\x01b",
-    );
+    ).unwrap();
     let out = String::from_utf8(buf).unwrap();
     assert!(out.contains("\\u0001"), "Expected \\u0001 in: {out}");
 }
@@ -122,7 +126,7 @@ fn nt_escape_unicode_passthrough() {
         "http://example.org/s",
         "http://example.org/p",
         "cafe\u{0301}",
-    );
+    ).unwrap();
     let out = String::from_utf8(buf).unwrap();
     assert!(
         out.contains("cafe\u{0301}"),
@@ -130,11 +134,50 @@ fn nt_escape_unicode_passthrough() {
     );
 }
 
+#[test]
+fn nt_lang_literal() {
+    let mut buf = Vec::new();
+    let mut em = NTriplesEmitter::new(&mut buf);
+    em.emit_lang_literal(
+        "http://example.org/s",
+        "http://www.w3.org/2000/01/rdf-schema#comment",
+        "doc comment",
+        "en",
+    ).unwrap();
+    let out = String::from_utf8(buf).unwrap();
+    assert_eq!(
+        out,
+        "<http://example.org/s> <http://www.w3.org/2000/01/rdf-schema#comment> \"doc comment\"@en .\n"
+    );
+}
+
+#[test]
+fn nt_lang_literal_with_subtag() {
+    let mut buf = Vec::new();
+    let mut em = NTriplesEmitter::new(&mut buf);
+    em.emit_lang_literal("http://example.org/s", "http://example.org/p", "hi", "zh-Hans").unwrap();
+    let out = String::from_utf8(buf).unwrap();
+    assert!(out.contains("\"hi\"@zh-Hans ."), "Expected tagged literal: {out}");
+}
+
+#[test]
+fn nt_lang_literal_invalid_tag_falls_back_to_plain() {
+    let mut buf = Vec::new();
+    let mut em = NTriplesEmitter::new(&mut buf);
+    em.emit_lang_literal("http://example.org/s", "http://example.org/p", "hi", "EN").unwrap();
+    let out = String::from_utf8(buf).unwrap();
+    assert_eq!(
+        out,
+        "<http://example.org/s> <http://example.org/p> \"hi\" .\n",
+        "Invalid tag should fall back to a plain literal: {out}"
+    );
+}
+
 #[test]
 fn nt_prefix_as_comment() {
     let mut buf = Vec::new();
     let mut em = NTriplesEmitter::new(&mut buf);
-    em.add_prefix("ex", "http://example.org/");
+    em.add_prefix("ex", "http://example.org/").unwrap();
     let out = String::from_utf8(buf).unwrap();
     assert_eq!(out, "# @prefix ex: <http://example.org/> .\n");
 }
@@ -148,13 +191,13 @@ fn nt_triple_count() {
         "http://example.org/s",
         "http://example.org/p",
         "http://example.org/o",
-    );
+    ).unwrap();
     assert_eq!(em.triple_count(), 1);
-    em.emit_literal("http://example.org/s", "http://example.org/p", "val");
+    em.emit_literal("http://example.org/s", "http://example.org/p", "val").unwrap();
     assert_eq!(em.triple_count(), 2);
-    em.emit_bool("http://example.org/s", "http://example.org/p", true);
+    em.emit_bool("http://example.org/s", "http://example.org/p", true).unwrap();
     assert_eq!(em.triple_count(), 3);
-    em.emit_int("http://example.org/s", "http://example.org/p", 10);
+    em.emit_int("http://example.org/s", "http://example.org/p", 10).unwrap();
     assert_eq!(em.triple_count(), 4);
 }
 
@@ -166,7 +209,7 @@ fn nt_flush() {
         "http://example.org/s",
         "http://example.org/p",
         "http://example.org/o",
-    );
+    ).unwrap();
     assert!(em.flush().is_ok());
 }
 
@@ -178,12 +221,13 @@ fn nt_flush() {
 fn turtle_basic_iri_with_prefix() {
     let mut buf = Vec::new();
     let mut em = TurtleEmitter::new(&mut buf);
-    em.add_prefix("ex", "http://example.org/");
+    em.add_prefix("ex", "http://example.org/").unwrap();
     em.emit_iri(
         "http://example.org/s",
         "http://example.org/p",
         "http://example.org/o",
-    );
+    ).unwrap();
+    em.flush().unwrap();
     let out = String::from_utf8(buf).unwrap();
     assert!(out.contains("@prefix ex: <http://example.org/> ."));
     assert!(out.contains("ex:s ex:p ex:o ."));
@@ -193,8 +237,9 @@ fn turtle_basic_iri_with_prefix() {
 fn turtle_literal_with_prefix() {
     let mut buf = Vec::new();
     let mut em = TurtleEmitter::new(&mut buf);
-    em.add_prefix("ex", "http://example.org/");
-    em.emit_literal("http://example.org/s", "http://example.org/name", "Alice");
+    em.add_prefix("ex", "http://example.org/").unwrap();
+    em.emit_literal("http://example.org/s", "http://example.org/name", "Alice").unwrap();
+    em.flush().unwrap();
     let out = String::from_utf8(buf).unwrap();
     assert!(
         out.contains("ex:s ex:name \"Alice\" ."),
@@ -206,14 +251,15 @@ fn turtle_literal_with_prefix() {
 fn turtle_typed_literal_with_prefix() {
     let mut buf = Vec::new();
     let mut em = TurtleEmitter::new(&mut buf);
-    em.add_prefix("xsd", "http://www.w3.org/2001/XMLSchema#");
-    em.add_prefix("ex", "http://example.org/");
+    em.add_prefix("xsd", "http://www.w3.org/2001/XMLSchema#").unwrap();
+    em.add_prefix("ex", "http://example.org/").unwrap();
     em.emit_typed_literal(
         "http://example.org/s",
         "http://example.org/p",
         "42",
         "http://www.w3.org/2001/XMLSchema#integer",
-    );
+    ).unwrap();
+    em.flush().unwrap();
     let out = String::from_utf8(buf).unwrap();
     assert!(
         out.contains("ex:s ex:p \"42\"^^xsd:integer ."),
@@ -225,8 +271,9 @@ fn turtle_typed_literal_with_prefix() {
 fn turtle_bool() {
     let mut buf = Vec::new();
     let mut em = TurtleEmitter::new(&mut buf);
-    em.add_prefix("xsd", "http://www.w3.org/2001/XMLSchema#");
-    em.emit_bool("http://example.org/s", "http://example.org/p", true);
+    em.add_prefix("xsd", "http://www.w3.org/2001/XMLSchema#").unwrap();
+    em.emit_bool("http://example.org/s", "http://example.org/p", true).unwrap();
+    em.flush().unwrap();
     let out = String::from_utf8(buf).unwrap();
     assert!(
         out.contains("\"true\"^^xsd:boolean"),
@@ -238,8 +285,9 @@ fn turtle_bool() {
 fn turtle_int() {
     let mut buf = Vec::new();
     let mut em = TurtleEmitter::new(&mut buf);
-    em.add_prefix("xsd", "http://www.w3.org/2001/XMLSchema#");
-    em.emit_int("http://example.org/s", "http://example.org/p", 99);
+    em.add_prefix("xsd", "http://www.w3.org/2001/XMLSchema#").unwrap();
+    em.emit_int("http://example.org/s", "http://example.org/p", 99).unwrap();
+    em.flush().unwrap();
     let out = String::from_utf8(buf).unwrap();
     assert!(
         out.contains("\"99\"^^xsd:integer"),
@@ -247,14 +295,49 @@ fn turtle_int() {
     );
 }
 
+#[test]
+fn turtle_lang_literal_with_prefix() {
+    let mut buf = Vec::new();
+    let mut em = TurtleEmitter::new(&mut buf);
+    em.add_prefix("rdfs", "http://www.w3.org/2000/01/rdf-schema#").unwrap();
+    em.add_prefix("ex", "http://example.org/").unwrap();
+    em.emit_lang_literal(
+        "http://example.org/s",
+        "http://www.w3.org/2000/01/rdf-schema#comment",
+        "doc comment",
+        "en",
+    ).unwrap();
+    em.flush().unwrap();
+    let out = String::from_utf8(buf).unwrap();
+    assert!(
+        out.contains("ex:s rdfs:comment \"doc comment\"@en ."),
+        "Expected compacted language-tagged literal: {out}"
+    );
+}
+
+#[test]
+fn turtle_lang_literal_invalid_tag_falls_back_to_plain() {
+    let mut buf = Vec::new();
+    let mut em = TurtleEmitter::new(&mut buf);
+    em.add_prefix("ex", "http://example.org/").unwrap();
+    em.emit_lang_literal("http://example.org/s", "http://example.org/p", "hi", "not a tag").unwrap();
+    em.flush().unwrap();
+    let out = String::from_utf8(buf).unwrap();
+    assert!(
+        out.contains("ex:s ex:p \"hi\" .") && !out.contains("\"hi\"@"),
+        "Invalid tag should fall back to a plain literal: {out}"
+    );
+}
+
 #[test]
 fn turtle_prefix_declaration_sorted() {
     let mut buf = Vec::new();
     let mut em = TurtleEmitter::new(&mut buf);
-    em.add_prefix("z", "http://z.org/");
-    em.add_prefix("a", "http://a.org/");
-    em.add_prefix("m", "http://m.org/");
-    em.emit_iri("http://a.org/s", "http://m.org/p", "http://z.org/o");
+    em.add_prefix("z", "http://z.org/").unwrap();
+    em.add_prefix("a", "http://a.org/").unwrap();
+    em.add_prefix("m", "http://m.org/").unwrap();
+    em.emit_iri("http://a.org/s", "http://m.org/p", "http://z.org/o").unwrap();
+    em.flush().unwrap();
     let out = String::from_utf8(buf).unwrap();
     let a_pos = out.find("@prefix a:").expect("missing @prefix a:");
     let m_pos = out.find("@prefix m:").expect("missing @prefix m:");
@@ -273,7 +356,8 @@ fn turtle_escape_special_chars() {
         "http://example.org/s",
         "http://example.org/p",
         "line\n\"end\\",
-    );
+    ).unwrap();
+    em.flush().unwrap();
     let out = String::from_utf8(buf).unwrap();
     assert!(out.contains("\\n"), "Expected escaped newline: {out}");
     assert!(out.contains("\\\""), "Expected escaped quote: {out}");
@@ -284,13 +368,14 @@ fn turtle_escape_special_chars() {
 fn turtle_non_compactable_iri() {
     let mut buf = Vec::new();
     let mut em = TurtleEmitter::new(&mut buf);
-    em.add_prefix("ex", "http://example.org/");
+    em.add_prefix("ex", "http://example.org/").unwrap();
     // IRI that doesn't match any prefix
     em.emit_iri(
         "http://other.org/s",
         "http://example.org/p",
         "http://other.org/o",
-    );
+    ).unwrap();
+    em.flush().unwrap();
     let out = String::from_utf8(buf).unwrap();
     assert!(
         out.contains("<http://other.org/s>"),
@@ -308,10 +393,10 @@ fn turtle_triple_count() {
         "http://example.org/s",
         "http://example.org/p",
         "http://example.org/o",
-    );
-    em.emit_literal("http://example.org/s", "http://example.org/p", "v");
-    em.emit_bool("http://example.org/s", "http://example.org/p", false);
-    em.emit_int("http://example.org/s", "http://example.org/p", 1);
+    ).unwrap();
+    em.emit_literal("http://example.org/s", "http://example.org/p", "v").unwrap();
+    em.emit_bool("http://example.org/s", "http://example.org/p", false).unwrap();
+    em.emit_int("http://example.org/s", "http://example.org/p", 1).unwrap();
     assert_eq!(em.triple_count(), 4);
 }
 
@@ -324,7 +409,8 @@ fn turtle_no_prefix_uses_full_iri() {
         "http://example.org/s",
         "http://example.org/p",
         "http://example.org/o",
-    );
+    ).unwrap();
+    em.flush().unwrap();
     let out = String::from_utf8(buf).unwrap();
     assert_eq!(
         out,
@@ -333,23 +419,129 @@ fn turtle_no_prefix_uses_full_iri() {
 }
 
 #[test]
-fn turtle_local_name_with_special_chars_not_compacted() {
+fn turtle_local_name_with_dot_compacts_with_escape() {
     let mut buf = Vec::new();
     let mut em = TurtleEmitter::new(&mut buf);
-    em.add_prefix("ex", "http://example.org/");
-    // IRI whose local part has a dot — should NOT compact
+    em.add_prefix("ex", "http://example.org/").unwrap();
+    // A '.' in the local part is a legal PN_LOCAL_ESC, so this should
+    // compact rather than fall back to a full IRI.
     em.emit_iri(
         "http://example.org/foo.bar",
         "http://example.org/p",
         "http://example.org/o",
+    ).unwrap();
+    em.flush().unwrap();
+    let out = String::from_utf8(buf).unwrap();
+    assert!(
+        out.contains(r"ex:foo\.bar"),
+        "IRI with '.' should compact with an escaped dot: {out}"
     );
+}
+
+#[test]
+fn turtle_local_name_with_whitespace_not_compacted() {
+    let mut buf = Vec::new();
+    let mut em = TurtleEmitter::new(&mut buf);
+    em.add_prefix("ex", "http://example.org/").unwrap();
+    // Whitespace has no PN_LOCAL_ESC form, so this should fall back to a
+    // full IRI rather than compact.
+    em.emit_iri(
+        "http://example.org/foo bar",
+        "http://example.org/p",
+        "http://example.org/o",
+    ).unwrap();
+    em.flush().unwrap();
+    let out = String::from_utf8(buf).unwrap();
+    assert!(
+        out.contains("<http://example.org/foo bar>"),
+        "IRI with whitespace should not compact: {out}"
+    );
+}
+
+#[test]
+fn turtle_local_name_with_percent_sequence_passes_through() {
+    let mut buf = Vec::new();
+    let mut em = TurtleEmitter::new(&mut buf);
+    em.add_prefix("ex", "http://example.org/").unwrap();
+    em.emit_iri(
+        "http://example.org/foo%20bar",
+        "http://example.org/p",
+        "http://example.org/o",
+    ).unwrap();
+    em.flush().unwrap();
     let out = String::from_utf8(buf).unwrap();
     assert!(
-        out.contains("<http://example.org/foo.bar>"),
-        "IRI with '.' should not compact: {out}"
+        out.contains("ex:foo%20bar"),
+        "Percent-encoded sequence should pass through unescaped: {out}"
+    );
+}
+
+#[test]
+fn turtle_groups_distinct_predicates_for_the_same_subject_with_semicolons() {
+    let mut buf = Vec::new();
+    let mut em = TurtleEmitter::new(&mut buf);
+    em.add_prefix("ex", "http://example.org/").unwrap();
+    em.emit_iri("http://example.org/s", "http://example.org/p", "http://example.org/o").unwrap();
+    em.emit_literal("http://example.org/s", "http://example.org/name", "Alice").unwrap();
+    em.flush().unwrap();
+    let out = String::from_utf8(buf).unwrap();
+    assert_eq!(out, "@prefix ex: <http://example.org/> .\n\nex:s ex:p ex:o ;\n    ex:name \"Alice\" .\n");
+}
+
+#[test]
+fn turtle_groups_repeated_predicate_objects_for_the_same_subject_with_commas() {
+    let mut buf = Vec::new();
+    let mut em = TurtleEmitter::new(&mut buf);
+    em.add_prefix("ex", "http://example.org/").unwrap();
+    em.emit_iri("http://example.org/s", "http://example.org/p", "http://example.org/a").unwrap();
+    em.emit_iri("http://example.org/s", "http://example.org/p", "http://example.org/b").unwrap();
+    em.flush().unwrap();
+    let out = String::from_utf8(buf).unwrap();
+    assert_eq!(out, "@prefix ex: <http://example.org/> .\n\nex:s ex:p ex:a, ex:b .\n");
+}
+
+#[test]
+fn turtle_groups_an_interleaved_predicate_into_one_comma_list() {
+    let mut buf = Vec::new();
+    let mut em = TurtleEmitter::new(&mut buf);
+    em.add_prefix("ex", "http://example.org/").unwrap();
+    em.emit_iri("http://example.org/s", "http://example.org/p", "http://example.org/a").unwrap();
+    em.emit_literal("http://example.org/s", "http://example.org/name", "Alice").unwrap();
+    em.emit_iri("http://example.org/s", "http://example.org/p", "http://example.org/b").unwrap();
+    em.flush().unwrap();
+    let out = String::from_utf8(buf).unwrap();
+    assert_eq!(
+        out,
+        "@prefix ex: <http://example.org/> .\n\nex:s ex:p ex:a, ex:b ;\n    ex:name \"Alice\" .\n"
+    );
+}
+
+#[test]
+fn turtle_a_new_subject_closes_the_previous_statement() {
+    let mut buf = Vec::new();
+    let mut em = TurtleEmitter::new(&mut buf);
+    em.add_prefix("ex", "http://example.org/").unwrap();
+    em.emit_iri("http://example.org/s1", "http://example.org/p", "http://example.org/o1").unwrap();
+    em.emit_iri("http://example.org/s2", "http://example.org/p", "http://example.org/o2").unwrap();
+    em.flush().unwrap();
+    let out = String::from_utf8(buf).unwrap();
+    assert_eq!(
+        out,
+        "@prefix ex: <http://example.org/> .\n\nex:s1 ex:p ex:o1 .\nex:s2 ex:p ex:o2 .\n"
     );
 }
 
+#[test]
+fn turtle_grouping_does_not_inflate_the_triple_count() {
+    let mut buf = Vec::new();
+    let mut em = TurtleEmitter::new(&mut buf);
+    em.emit_iri("http://example.org/s", "http://example.org/p", "http://example.org/a").unwrap();
+    em.emit_iri("http://example.org/s", "http://example.org/p", "http://example.org/b").unwrap();
+    assert_eq!(em.triple_count(), 2);
+    em.flush().unwrap();
+    assert_eq!(em.triple_count(), 2);
+}
+
 #[test]
 fn turtle_flush() {
     let mut buf = Vec::new();
@@ -358,6 +550,378 @@ fn turtle_flush() {
         "http://example.org/s",
         "http://example.org/p",
         "http://example.org/o",
-    );
+    ).unwrap();
     assert!(em.flush().is_ok());
 }
+
+// ---------------------------------------------------------------------------
+// Canonical emitter tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn canonical_sorts_by_subject_then_predicate_then_object() {
+    let mut buf = Vec::new();
+    let mut em = CanonicalEmitter::new(NTriplesEmitter::new(&mut buf));
+    em.emit_iri("http://example.org/b", "http://example.org/p", "http://example.org/o").unwrap();
+    em.emit_iri("http://example.org/a", "http://example.org/q", "http://example.org/o").unwrap();
+    em.emit_iri("http://example.org/a", "http://example.org/p", "http://example.org/o").unwrap();
+    em.flush().unwrap();
+    drop(em);
+    let out = String::from_utf8(buf).unwrap();
+    let lines: Vec<&str> = out.lines().collect();
+    assert_eq!(
+        lines,
+        vec![
+            "<http://example.org/a> <http://example.org/p> <http://example.org/o> .",
+            "<http://example.org/a> <http://example.org/q> <http://example.org/o> .",
+            "<http://example.org/b> <http://example.org/p> <http://example.org/o> .",
+        ]
+    );
+}
+
+#[test]
+fn canonical_output_is_stable_across_emission_order() {
+    let run = |order: &[usize]| {
+        let mut buf = Vec::new();
+        let mut em = CanonicalEmitter::new(NTriplesEmitter::new(&mut buf));
+        let triples = [
+            ("http://example.org/s1", "http://example.org/p", "http://example.org/o1"),
+            ("http://example.org/s2", "http://example.org/p", "http://example.org/o2"),
+            ("http://example.org/s1", "http://example.org/q", "http://example.org/o3"),
+        ];
+        for &i in order {
+            em.emit_iri(triples[i].0, triples[i].1, triples[i].2).unwrap();
+        }
+        em.flush().unwrap();
+        String::from_utf8(buf).unwrap()
+    };
+    assert_eq!(run(&[0, 1, 2]), run(&[2, 1, 0]));
+}
+
+#[test]
+fn canonical_forwards_prefixes_immediately() {
+    let mut buf = Vec::new();
+    let mut em = CanonicalEmitter::new(TurtleEmitter::new(&mut buf));
+    em.add_prefix("ex", "http://example.org/").unwrap();
+    em.emit_iri(
+        "http://example.org/s",
+        "http://example.org/p",
+        "http://example.org/o",
+    ).unwrap();
+    em.flush().unwrap();
+    drop(em);
+    let out = String::from_utf8(buf).unwrap();
+    assert!(out.starts_with("@prefix ex: <http://example.org/> .\n"));
+    assert!(out.contains("ex:s ex:p ex:o ."));
+}
+
+#[test]
+fn canonical_triple_count_includes_buffered_triples() {
+    let buf = Vec::new();
+    let mut em = CanonicalEmitter::new(NTriplesEmitter::new(buf));
+    em.emit_iri("http://example.org/s", "http://example.org/p", "http://example.org/o").unwrap();
+    em.emit_iri("http://example.org/s", "http://example.org/p", "http://example.org/o2").unwrap();
+    assert_eq!(em.triple_count(), 2);
+    em.flush().unwrap();
+    assert_eq!(em.triple_count(), 2);
+}
+
+// ---------------------------------------------------------------------------
+// N-Quads tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn nq_default_graph_looks_like_ntriples() {
+    let mut buf = Vec::new();
+    let mut em = NQuadsEmitter::new(&mut buf);
+    em.emit_iri(
+        "http://example.org/s",
+        "http://example.org/p",
+        "http://example.org/o",
+    ).unwrap();
+    let out = String::from_utf8(buf).unwrap();
+    assert_eq!(
+        out,
+        "<http://example.org/s> <http://example.org/p> <http://example.org/o> .\n"
+    );
+}
+
+#[test]
+fn nq_named_graph_adds_fourth_term() {
+    let mut buf = Vec::new();
+    let mut em = NQuadsEmitter::new(&mut buf);
+    em.set_graph(Some("http://example.org/g"));
+    em.emit_literal("http://example.org/s", "http://example.org/p", "hello").unwrap();
+    let out = String::from_utf8(buf).unwrap();
+    assert_eq!(
+        out,
+        "<http://example.org/s> <http://example.org/p> \"hello\" <http://example.org/g> .\n"
+    );
+}
+
+#[test]
+fn nq_clearing_the_graph_returns_to_the_default_graph() {
+    let mut buf = Vec::new();
+    let mut em = NQuadsEmitter::new(&mut buf);
+    em.set_graph(Some("http://example.org/g"));
+    em.emit_iri("http://example.org/s", "http://example.org/p", "http://example.org/o1").unwrap();
+    em.set_graph(None);
+    em.emit_iri("http://example.org/s", "http://example.org/p", "http://example.org/o2").unwrap();
+    let out = String::from_utf8(buf).unwrap();
+    let lines: Vec<&str> = out.lines().collect();
+    assert_eq!(
+        lines,
+        vec![
+            "<http://example.org/s> <http://example.org/p> <http://example.org/o1> <http://example.org/g> .",
+            "<http://example.org/s> <http://example.org/p> <http://example.org/o2> .",
+        ]
+    );
+}
+
+#[test]
+fn nq_lang_literal_invalid_tag_falls_back_to_plain() {
+    let mut buf = Vec::new();
+    let mut em = NQuadsEmitter::new(&mut buf);
+    em.emit_lang_literal("http://example.org/s", "http://example.org/p", "hi", "EN").unwrap();
+    let out = String::from_utf8(buf).unwrap();
+    assert_eq!(
+        out,
+        "<http://example.org/s> <http://example.org/p> \"hi\" .\n"
+    );
+}
+
+#[test]
+fn nq_triple_count() {
+    let mut buf = Vec::new();
+    let mut em = NQuadsEmitter::new(&mut buf);
+    em.emit_iri("http://example.org/s", "http://example.org/p", "http://example.org/o").unwrap();
+    em.set_graph(Some("http://example.org/g"));
+    em.emit_iri("http://example.org/s", "http://example.org/p", "http://example.org/o2").unwrap();
+    assert_eq!(em.triple_count(), 2);
+}
+
+// ---------------------------------------------------------------------------
+// TriG tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn trig_default_graph_looks_like_turtle() {
+    let mut buf = Vec::new();
+    let mut em = TriGEmitter::new(&mut buf);
+    em.add_prefix("ex", "http://example.org/").unwrap();
+    em.emit_iri(
+        "http://example.org/s",
+        "http://example.org/p",
+        "http://example.org/o",
+    ).unwrap();
+    em.flush().unwrap();
+    drop(em);
+    let out = String::from_utf8(buf).unwrap();
+    assert_eq!(out, "@prefix ex: <http://example.org/> .\n\nex:s ex:p ex:o .\n");
+}
+
+#[test]
+fn trig_named_graph_opens_a_block() {
+    let mut buf = Vec::new();
+    let mut em = TriGEmitter::new(&mut buf);
+    em.add_prefix("ex", "http://example.org/").unwrap();
+    em.set_graph(Some("http://example.org/g"));
+    em.emit_iri("http://example.org/s", "http://example.org/p", "http://example.org/o").unwrap();
+    em.flush().unwrap();
+    drop(em);
+    let out = String::from_utf8(buf).unwrap();
+    assert_eq!(
+        out,
+        "@prefix ex: <http://example.org/> .\n\nex:g {\nex:s ex:p ex:o .\n}\n"
+    );
+}
+
+#[test]
+fn trig_switching_graphs_closes_and_reopens_blocks() {
+    let mut buf = Vec::new();
+    let mut em = TriGEmitter::new(&mut buf);
+    em.add_prefix("ex", "http://example.org/").unwrap();
+    em.emit_iri("http://example.org/s", "http://example.org/p", "http://example.org/a").unwrap();
+    em.set_graph(Some("http://example.org/g"));
+    em.emit_iri("http://example.org/s", "http://example.org/p", "http://example.org/b").unwrap();
+    em.set_graph(None);
+    em.emit_iri("http://example.org/s", "http://example.org/p", "http://example.org/c").unwrap();
+    em.flush().unwrap();
+    drop(em);
+    let out = String::from_utf8(buf).unwrap();
+    assert_eq!(
+        out,
+        "@prefix ex: <http://example.org/> .\n\nex:s ex:p ex:a .\nex:g {\nex:s ex:p ex:b .\n}\nex:s ex:p ex:c .\n"
+    );
+}
+
+#[test]
+fn trig_triple_count() {
+    let mut buf = Vec::new();
+    let mut em = TriGEmitter::new(&mut buf);
+    em.set_graph(Some("http://example.org/g"));
+    em.emit_iri("http://example.org/s", "http://example.org/p", "http://example.org/o").unwrap();
+    em.emit_iri("http://example.org/s", "http://example.org/p", "http://example.org/o2").unwrap();
+    assert_eq!(em.triple_count(), 2);
+}
+
+// ---------------------------------------------------------------------------
+// JSON-LD tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn jsonld_groups_triples_into_one_node_per_subject() {
+    let mut buf = Vec::new();
+    let mut em = JsonLdEmitter::new(&mut buf);
+    em.add_prefix("ex", "http://example.org/").unwrap();
+    em.emit_iri("http://example.org/s", "http://example.org/p", "http://example.org/o").unwrap();
+    em.emit_literal("http://example.org/s", "http://example.org/name", "hello").unwrap();
+    em.flush().unwrap();
+    drop(em);
+    let doc: serde_json::Value = serde_json::from_slice(&buf).unwrap();
+    let graph = doc["@graph"].as_array().unwrap();
+    assert_eq!(graph.len(), 1);
+    let node = &graph[0];
+    assert_eq!(node["@id"], "ex:s");
+    assert_eq!(node["ex:p"][0]["@id"], "ex:o");
+    assert_eq!(node["ex:name"][0]["@value"], "hello");
+}
+
+#[test]
+fn jsonld_context_maps_registered_prefixes() {
+    let mut buf = Vec::new();
+    let mut em = JsonLdEmitter::new(&mut buf);
+    em.add_prefix("ex", "http://example.org/").unwrap();
+    em.emit_literal("http://example.org/s", "http://example.org/name", "hello").unwrap();
+    em.flush().unwrap();
+    drop(em);
+    let doc: serde_json::Value = serde_json::from_slice(&buf).unwrap();
+    assert_eq!(doc["@context"]["ex"], "http://example.org/");
+}
+
+#[test]
+fn jsonld_typed_and_lang_literals_carry_type_and_language() {
+    let mut buf = Vec::new();
+    let mut em = JsonLdEmitter::new(&mut buf);
+    em.add_prefix("ex", "http://example.org/").unwrap();
+    em.add_prefix("xsd", "http://www.w3.org/2001/XMLSchema#").unwrap();
+    em.emit_int("http://example.org/s", "http://example.org/count", 7).unwrap();
+    em.emit_lang_literal("http://example.org/s", "http://example.org/label", "hello", "en").unwrap();
+    em.flush().unwrap();
+    drop(em);
+    let doc: serde_json::Value = serde_json::from_slice(&buf).unwrap();
+    let node = &doc["@graph"][0];
+    assert_eq!(node["ex:count"][0]["@value"], "7");
+    assert_eq!(node["ex:count"][0]["@type"], "xsd:integer");
+    assert_eq!(node["ex:label"][0]["@value"], "hello");
+    assert_eq!(node["ex:label"][0]["@language"], "en");
+}
+
+#[test]
+fn jsonld_multiple_objects_for_same_predicate_fold_into_an_array() {
+    let mut buf = Vec::new();
+    let mut em = JsonLdEmitter::new(&mut buf);
+    em.add_prefix("ex", "http://example.org/").unwrap();
+    em.emit_iri("http://example.org/s", "http://example.org/p", "http://example.org/a").unwrap();
+    em.emit_iri("http://example.org/s", "http://example.org/p", "http://example.org/b").unwrap();
+    em.flush().unwrap();
+    drop(em);
+    let doc: serde_json::Value = serde_json::from_slice(&buf).unwrap();
+    let values = doc["@graph"][0]["ex:p"].as_array().unwrap();
+    assert_eq!(values.len(), 2);
+}
+
+#[test]
+fn jsonld_triple_count() {
+    let mut buf = Vec::new();
+    let mut em = JsonLdEmitter::new(&mut buf);
+    em.emit_iri("http://example.org/s", "http://example.org/p", "http://example.org/o").unwrap();
+    em.emit_iri("http://example.org/s", "http://example.org/p", "http://example.org/o2").unwrap();
+    assert_eq!(em.triple_count(), 2);
+}
+
+// ---------------------------------------------------------------------------
+// Blank-node and collection tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn fresh_blank_node_yields_distinct_sequential_labels() {
+    let mut buf = Vec::new();
+    let mut em = NTriplesEmitter::new(&mut buf);
+    assert_eq!(em.fresh_blank_node(), "_:b0");
+    assert_eq!(em.fresh_blank_node(), "_:b1");
+    assert_eq!(em.fresh_blank_node(), "_:b2");
+}
+
+#[test]
+fn nt_emit_collection_expands_to_rdf_first_rest_nil_chain() {
+    let mut buf = Vec::new();
+    let mut em = NTriplesEmitter::new(&mut buf);
+    em.emit_collection(
+        "http://example.org/s",
+        "http://example.org/items",
+        &[ObjectTerm::Literal("a".into()), ObjectTerm::Literal("b".into())],
+    )
+    .unwrap();
+    assert_eq!(em.triple_count(), 5);
+    let out = String::from_utf8(buf).unwrap();
+    assert_eq!(
+        out,
+        "<http://example.org/s> <http://example.org/items> _:b0 .\n\
+         _:b0 <http://www.w3.org/1999/02/22-rdf-syntax-ns#first> \"a\" .\n\
+         _:b0 <http://www.w3.org/1999/02/22-rdf-syntax-ns#rest> _:b1 .\n\
+         _:b1 <http://www.w3.org/1999/02/22-rdf-syntax-ns#first> \"b\" .\n\
+         _:b1 <http://www.w3.org/1999/02/22-rdf-syntax-ns#rest> <http://www.w3.org/1999/02/22-rdf-syntax-ns#nil> .\n"
+    );
+}
+
+#[test]
+fn nt_emit_collection_of_empty_members_points_straight_at_rdf_nil() {
+    let mut buf = Vec::new();
+    let mut em = NTriplesEmitter::new(&mut buf);
+    em.emit_collection("http://example.org/s", "http://example.org/items", &[]).unwrap();
+    assert_eq!(em.triple_count(), 1);
+    let out = String::from_utf8(buf).unwrap();
+    assert_eq!(
+        out,
+        "<http://example.org/s> <http://example.org/items> <http://www.w3.org/1999/02/22-rdf-syntax-ns#nil> .\n"
+    );
+}
+
+#[test]
+fn turtle_emit_collection_uses_native_round_bracket_syntax() {
+    let mut buf = Vec::new();
+    let mut em = TurtleEmitter::new(&mut buf);
+    em.add_prefix("ex", "http://example.org/").unwrap();
+    em.emit_collection(
+        "http://example.org/s",
+        "http://example.org/items",
+        &[ObjectTerm::Int(1), ObjectTerm::Int(2), ObjectTerm::Int(3)],
+    )
+    .unwrap();
+    em.flush().unwrap();
+    assert_eq!(em.triple_count(), 7);
+    let out = String::from_utf8(buf).unwrap();
+    assert!(out.contains("ex:s ex:items ( 1 2 3 ) .\n"), "got: {out}");
+}
+
+#[test]
+fn jsonld_emit_collection_links_a_subject_node_to_a_blank_list_node() {
+    let mut buf = Vec::new();
+    let mut em = JsonLdEmitter::new(&mut buf);
+    em.add_prefix("ex", "http://example.org/").unwrap();
+    em.emit_collection(
+        "http://example.org/s",
+        "http://example.org/items",
+        &[ObjectTerm::Literal("a".into())],
+    )
+    .unwrap();
+    em.flush().unwrap();
+    drop(em);
+    let doc: serde_json::Value = serde_json::from_slice(&buf).unwrap();
+    let graph = doc["@graph"].as_array().unwrap();
+    assert_eq!(graph.len(), 2);
+    assert_eq!(graph[0]["@id"], "ex:s");
+    let list_node_id = graph[0]["ex:items"][0]["@id"].as_str().unwrap();
+    assert!(list_node_id.starts_with("_:b"), "got: {list_node_id}");
+}
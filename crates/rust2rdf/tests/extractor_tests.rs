@@ -676,6 +676,46 @@ fn unsafe_function_marked() {
     );
 }
 
+#[test]
+fn plain_function_gets_rust_abi_and_no_qualifier_flags() {
+    let out = extract_default();
+    let root_mod = module_iri("fixture_crate");
+    let simple_add = format!("{root_mod}/member/simple_add");
+
+    assert!(has_literal_triple(&out, &simple_add, &rt("abi"), "Rust"));
+    assert!(!out.contains(&format!("<{simple_add}> <{}>", rt("isExtern"))));
+    assert!(!out.contains(&format!("<{simple_add}> <{}>", rt("isAsync"))));
+    assert!(!out.contains(&format!("<{simple_add}> <{}>", rt("isConstFn"))));
+}
+
+#[test]
+fn async_function_marked_is_async() {
+    let out = extract_default();
+    let root_mod = module_iri("fixture_crate");
+    let async_fn = format!("{root_mod}/member/async_fn");
+
+    assert!(has_bool_triple(&out, &async_fn, &rt("isAsync"), true));
+}
+
+#[test]
+fn const_function_marked_is_const_fn() {
+    let out = extract_default();
+    let root_mod = module_iri("fixture_crate");
+    let const_fn = format!("{root_mod}/member/const_fn");
+
+    assert!(has_bool_triple(&out, &const_fn, &rt("isConstFn"), true));
+}
+
+#[test]
+fn extern_c_function_gets_c_abi_and_is_extern() {
+    let out = extract_default();
+    let root_mod = module_iri("fixture_crate");
+    let extern_c_fn = format!("{root_mod}/member/extern_c_fn");
+
+    assert!(has_literal_triple(&out, &extern_c_fn, &rt("abi"), "C"));
+    assert!(has_bool_triple(&out, &extern_c_fn, &rt("isExtern"), true));
+}
+
 // ===========================================================================
 // Tests: Result error type extraction
 // ===========================================================================
@@ -60,6 +60,17 @@ pub struct Derived {
 /// An unsafe function.
 pub unsafe fn unsafe_fn() {}
 
+/// An async function.
+pub async fn async_fn() {}
+
+/// A const function.
+pub const fn const_fn(x: i32) -> i32 {
+    x
+}
+
+/// An `extern "C"` function.
+pub extern "C" fn extern_c_fn() {}
+
 /// A nested module.
 pub mod nested {
     /// An inner struct.
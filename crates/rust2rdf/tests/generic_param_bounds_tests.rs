@@ -0,0 +1,115 @@
+//! Integration tests for bounds declared directly in a generic parameter
+//! list (as opposed to a `where`-clause, covered by `where_clause_tests.rs`):
+//! a type parameter's default type (`T = Default`) and a lifetime
+//! parameter's inline outlives bound (`'a: 'b`). Uses a small hand-written
+//! rustdoc JSON crate, since these shapes are easiest to pin down explicitly
+//! rather than via the `fixture_crate.json` golden.
+
+use rust2rdf::emitter::ntriples::NTriplesEmitter;
+use rust2rdf::emitter::TriplesEmitter;
+use rust2rdf::extraction::extractor::{CrateExtractor, ExtractionOptions};
+use rust2rdf::extraction::rustdoc_model::Crate;
+
+const CRATE_JSON: &str = r#"
+{
+  "root": "0",
+  "crate_version": "0.1.0",
+  "format_version": 35,
+  "external_crates": {},
+  "paths": {},
+  "index": {
+    "0": {
+      "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+      "inner": { "module": { "items": ["1"] } }
+    },
+    "1": {
+      "id": "1", "name": "Container", "visibility": "public", "attrs": [],
+      "inner": { "struct": {
+        "kind": { "plain": { "fields": [], "has_stricter_visibility": false } },
+        "generics": {
+          "params": [
+            { "name": "'a", "kind": { "lifetime": { "outlives": ["'b"] } } },
+            { "name": "'b", "kind": { "lifetime": { "outlives": [] } } },
+            { "name": "T", "kind": { "type": { "bounds": [], "default": { "primitive": "i32" }, "is_synthetic": false } } }
+          ],
+          "where_predicates": []
+        },
+        "impls": []
+      } }
+    }
+  }
+}
+"#;
+
+fn load() -> Crate {
+    serde_json::from_str(CRATE_JSON).expect("fixture JSON should parse")
+}
+
+fn extract() -> String {
+    let krate = load();
+    let mut buf = Vec::new();
+    {
+        let mut emitter = NTriplesEmitter::new(&mut buf);
+        let mut extractor =
+            CrateExtractor::new(&mut emitter, &krate, ExtractionOptions::default());
+        extractor.extract();
+        emitter.flush().unwrap();
+    }
+    String::from_utf8(buf).expect("Invalid UTF-8 in NTriples output")
+}
+
+fn has_iri_triple(output: &str, subject: &str, predicate: &str, object: &str) -> bool {
+    let expected = format!("<{subject}> <{predicate}> <{object}> .");
+    output.lines().any(|line| line.trim() == expected)
+}
+
+const BASE: &str = "http://rust.example";
+
+fn rt(local: &str) -> String {
+    format!("http://rust.example/ontology/{local}")
+}
+
+// `path` is always a root-level item here, so its full path is
+// "mycrate::<path>" -- `IriMinter::type_iri` percent-encodes the whole
+// thing, turning `::` into `%3A%3A`.
+fn type_iri(path: &str) -> String {
+    format!("{BASE}/type/mycrate/0.1.0/mycrate%3A%3A{path}")
+}
+
+fn container_iri() -> String {
+    type_iri("Container")
+}
+
+fn type_param_iri(ordinal: usize) -> String {
+    format!("{}/typeparam/{ordinal}", container_iri())
+}
+
+fn lifetime_iri(name: &str) -> String {
+    format!("{}/lifetime/{name}", container_iri())
+}
+
+#[test]
+fn type_parameter_default_links_to_the_default_type() {
+    let out = extract();
+    let t = type_param_iri(2);
+    let i32_type = format!("{BASE}/type/_primitive_/i32");
+
+    assert!(has_iri_triple(&out, &t, &rt("defaultType"), &i32_type));
+}
+
+#[test]
+fn lifetime_without_inline_outlives_emits_no_outlives_triple() {
+    let out = extract();
+    let b = lifetime_iri("b");
+
+    assert!(!out.lines().any(|l| l.contains(&format!("<{b}> <{}>", rt("outlives")))));
+}
+
+#[test]
+fn inline_lifetime_outlives_links_the_two_lifetimes() {
+    let out = extract();
+    let a = lifetime_iri("a");
+    let b = lifetime_iri("b");
+
+    assert!(has_iri_triple(&out, &a, &rt("outlives"), &b));
+}
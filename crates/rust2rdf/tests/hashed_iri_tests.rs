@@ -0,0 +1,126 @@
+//! Integration tests for `ExtractionOptions::hash_complex_iris`: hashed
+//! `<shortname>-<hash>` IRIs (plus an `rdfs:label`) for heavily-generic type
+//! references, in place of percent-encoding the whole generic signature.
+//! Uses a small hand-written rustdoc JSON crate, mirroring the style of
+//! `type_resolution_tests.rs`.
+
+use rust2rdf::emitter::ntriples::NTriplesEmitter;
+use rust2rdf::emitter::TriplesEmitter;
+use rust2rdf::extraction::extractor::{CrateExtractor, ExtractionOptions};
+use rust2rdf::extraction::rustdoc_model::Crate;
+use rust2rdf::model::iri::IriMinter;
+
+const CRATE_JSON: &str = r#"
+{
+  "root": "0",
+  "crate_version": "0.1.0",
+  "format_version": 35,
+  "external_crates": {},
+  "paths": {},
+  "index": {
+    "0": {
+      "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+      "inner": { "module": { "items": ["1"] } }
+    },
+    "1": {
+      "id": "1", "name": "take_map", "visibility": "public", "attrs": [],
+      "inner": { "function": {
+        "sig": {
+          "inputs": [["x", { "resolved_path": { "path": "HashMap<K, V>", "id": null } }]],
+          "output": null
+        },
+        "generics": { "params": [], "where_predicates": [] },
+        "header": { "is_const": false, "is_unsafe": false, "is_async": false }
+      } }
+    }
+  }
+}
+"#;
+
+fn load() -> Crate {
+    serde_json::from_str(CRATE_JSON).expect("fixture JSON should parse")
+}
+
+fn extract(options: ExtractionOptions) -> String {
+    let krate = load();
+    let mut buf = Vec::new();
+    {
+        let mut emitter = NTriplesEmitter::new(&mut buf);
+        let mut extractor = CrateExtractor::new(&mut emitter, &krate, options);
+        extractor.extract();
+        emitter.flush().unwrap();
+    }
+    String::from_utf8(buf).expect("Invalid UTF-8 in NTriples output")
+}
+
+fn has_iri_triple(output: &str, subject: &str, predicate: &str, object: &str) -> bool {
+    let expected = format!("<{subject}> <{predicate}> <{object}> .");
+    output.lines().any(|line| line.trim() == expected)
+}
+
+const BASE: &str = "http://rust.example";
+const PARAMETER_TYPE: &str = "http://typegraph.example/ontology/parameterType";
+const RDFS_LABEL: &str = "http://www.w3.org/2000/01/rdf-schema#label";
+
+fn param_iri() -> String {
+    format!("{BASE}/module/mycrate/0.1.0/mycrate/member/take_map/param/0")
+}
+
+#[test]
+fn off_by_default_percent_encodes_the_full_generic_signature() {
+    let out = extract(ExtractionOptions::default());
+    let hash_map_iri = format!("{BASE}/type/mycrate/0.1.0/HashMap%3CK%2C%20V%3E");
+
+    assert!(has_iri_triple(&out, &param_iri(), PARAMETER_TYPE, &hash_map_iri));
+    assert!(!out.contains(RDFS_LABEL));
+}
+
+#[test]
+fn enabled_mints_a_shortname_hash_iri_with_a_label() {
+    let out = extract(ExtractionOptions {
+        hash_complex_iris: true,
+        ..ExtractionOptions::default()
+    });
+    let param = param_iri();
+
+    let type_line = out
+        .lines()
+        .find(|l| l.trim().starts_with(&format!("<{param}> <{PARAMETER_TYPE}>")))
+        .expect("expected a parameterType triple");
+    let hash_map_iri = type_line
+        .split_whitespace()
+        .nth(2)
+        .expect("object term")
+        .trim_matches(['<', '>']);
+
+    assert!(
+        hash_map_iri.starts_with(&format!("{BASE}/type/mycrate/0.1.0/HashMap-")),
+        "unexpected object IRI: {hash_map_iri}"
+    );
+    assert!(!hash_map_iri.contains('<'));
+    assert!(!hash_map_iri.contains(' '));
+
+    let expected_label = format!("<{hash_map_iri}> <{RDFS_LABEL}> \"HashMap<K, V>\" .");
+    assert!(
+        out.lines().any(|l| l.trim() == expected_label),
+        "expected rdfs:label carrying the original generic signature:\n{out}"
+    );
+}
+
+#[test]
+fn hashed_iri_is_deterministic_across_extraction_runs() {
+    let first = extract(ExtractionOptions {
+        hash_complex_iris: true,
+        ..ExtractionOptions::default()
+    });
+    let second = extract(ExtractionOptions {
+        hash_complex_iris: true,
+        ..ExtractionOptions::default()
+    });
+    assert_eq!(first, second);
+}
+
+#[test]
+fn is_complex_path_matches_what_triggers_hashing() {
+    assert!(IriMinter::is_complex_path("HashMap<K, V>"));
+}
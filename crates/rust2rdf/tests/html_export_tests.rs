@@ -0,0 +1,73 @@
+use rust2rdf::html_export::{export_html, EdgeKind, Graph, GraphEdge, GraphLayout, GraphNode};
+
+fn sample_graph() -> Graph {
+    Graph {
+        nodes: vec![
+            GraphNode {
+                id: "a".to_string(),
+                label: "Vec<T>".to_string(),
+                hover_text: "struct Vec<T>".to_string(),
+                layer: 0,
+            },
+            GraphNode {
+                id: "b".to_string(),
+                label: "T".to_string(),
+                hover_text: "generic parameter T".to_string(),
+                layer: 1,
+            },
+        ],
+        edges: vec![GraphEdge {
+            from: "a".to_string(),
+            to: "b".to_string(),
+            kind: EdgeKind::Ownership,
+        }],
+    }
+}
+
+fn render(layout: GraphLayout) -> String {
+    let graph = sample_graph();
+    let mut buf = Vec::new();
+    export_html(&graph, layout, &mut buf).unwrap();
+    String::from_utf8(buf).unwrap()
+}
+
+#[test]
+fn exported_html_is_a_self_contained_document_with_plotly() {
+    let html = render(GraphLayout::ForceDirected);
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("cdn.plot.ly"));
+    assert!(html.contains("Plotly.newPlot"));
+}
+
+#[test]
+fn node_labels_and_hover_text_are_embedded() {
+    let html = render(GraphLayout::ForceDirected);
+    // '<' is escaped as a unicode sequence so a label can never close out
+    // of the surrounding <script> tag.
+    assert!(html.contains(r#""Vec\u003CT>""#));
+    assert!(html.contains(r#""struct Vec\u003CT>""#));
+}
+
+#[test]
+fn edge_is_colored_by_its_relationship_kind() {
+    let html = render(GraphLayout::ForceDirected);
+    assert!(html.contains("#d94a4a"));
+}
+
+#[test]
+fn hierarchical_layout_places_nodes_by_layer() {
+    let html = render(GraphLayout::Hierarchical);
+    assert!(html.contains("hierarchical"));
+}
+
+#[test]
+fn unresolvable_edge_endpoints_are_skipped_without_panicking() {
+    let mut graph = sample_graph();
+    graph.edges.push(GraphEdge {
+        from: "a".to_string(),
+        to: "missing".to_string(),
+        kind: EdgeKind::Reference,
+    });
+    let mut buf = Vec::new();
+    export_html(&graph, GraphLayout::ForceDirected, &mut buf).unwrap();
+}
@@ -0,0 +1,183 @@
+//! Integration tests for impl-block extraction: generic parameters/bounds on
+//! the impl itself, linking a trait-impl method back to the trait's declared
+//! method of the same name, and negative impls (`impl !Trait for Type`).
+//! Uses a small hand-written rustdoc JSON crate for precise control over the
+//! impl shapes involved.
+
+use rust2rdf::emitter::ntriples::NTriplesEmitter;
+use rust2rdf::emitter::TriplesEmitter;
+use rust2rdf::extraction::extractor::{CrateExtractor, ExtractionOptions};
+use rust2rdf::extraction::rustdoc_model::Crate;
+
+const CRATE_JSON: &str = r#"
+{
+  "root": "0",
+  "crate_version": "0.1.0",
+  "format_version": 35,
+  "external_crates": {},
+  "paths": {},
+  "index": {
+    "0": {
+      "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+      "inner": { "module": { "items": ["1", "2", "3", "4"] } }
+    },
+    "1": {
+      "id": "1", "name": "Greet", "visibility": "public", "attrs": [],
+      "inner": { "trait": {
+        "generics": { "params": [], "where_predicates": [] },
+        "bounds": [], "items": ["5"], "is_auto": false, "is_unsafe": false,
+        "is_object_safe": true
+      } }
+    },
+    "2": {
+      "id": "2", "name": "Widget", "visibility": "public", "attrs": [],
+      "inner": { "struct": { "kind": "unit", "generics": { "params": [], "where_predicates": [] }, "impls": ["3", "4"] } }
+    },
+    "3": {
+      "id": "3", "name": null, "visibility": "default", "attrs": [],
+      "inner": { "impl": {
+        "generics": {
+          "params": [
+            { "name": "T", "kind": { "type": { "bounds": [
+              { "trait_bound": { "trait": { "path": "Clone", "id": null } } }
+            ], "default": null, "is_synthetic": false } } }
+          ],
+          "where_predicates": []
+        },
+        "trait": { "path": "Greet", "id": "1" },
+        "for": { "resolved_path": { "path": "Widget", "id": "2" } },
+        "items": ["6"],
+        "is_unsafe": false, "is_negative": false, "is_synthetic": false
+      } }
+    },
+    "4": {
+      "id": "4", "name": null, "visibility": "default", "attrs": [],
+      "inner": { "impl": {
+        "generics": { "params": [], "where_predicates": [] },
+        "trait": { "path": "Send", "id": null },
+        "for": { "resolved_path": { "path": "Widget", "id": "2" } },
+        "items": [],
+        "is_unsafe": false, "is_negative": true, "is_synthetic": false
+      } }
+    },
+    "5": {
+      "id": "5", "name": "greet", "visibility": "default", "attrs": [],
+      "inner": { "function": {
+        "sig": { "inputs": [], "output": null },
+        "generics": { "params": [], "where_predicates": [] },
+        "header": { "is_const": false, "is_unsafe": false, "is_async": false },
+        "has_body": false
+      } }
+    },
+    "6": {
+      "id": "6", "name": "greet", "visibility": "default", "attrs": [],
+      "inner": { "function": {
+        "sig": { "inputs": [], "output": null },
+        "generics": { "params": [], "where_predicates": [] },
+        "header": { "is_const": false, "is_unsafe": false, "is_async": false },
+        "has_body": true
+      } }
+    }
+  }
+}
+"#;
+
+fn load() -> Crate {
+    serde_json::from_str(CRATE_JSON).expect("fixture JSON should parse")
+}
+
+fn extract() -> String {
+    let krate = load();
+    let mut buf = Vec::new();
+    {
+        let mut emitter = NTriplesEmitter::new(&mut buf);
+        let mut extractor =
+            CrateExtractor::new(&mut emitter, &krate, ExtractionOptions::default());
+        extractor.extract();
+        emitter.flush().unwrap();
+    }
+    String::from_utf8(buf).expect("Invalid UTF-8 in NTriples output")
+}
+
+fn has_iri_triple(output: &str, subject: &str, predicate: &str, object: &str) -> bool {
+    let expected = format!("<{subject}> <{predicate}> <{object}> .");
+    output.lines().any(|line| line.trim() == expected)
+}
+
+fn has_bool_triple(output: &str, subject: &str, predicate: &str, value: bool) -> bool {
+    let expected = format!(
+        "<{subject}> <{predicate}> \"{value}\"^^<http://www.w3.org/2001/XMLSchema#boolean> ."
+    );
+    output.lines().any(|line| line.trim() == expected)
+}
+
+const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+const BASE: &str = "http://rust.example";
+
+fn rt(local: &str) -> String {
+    format!("http://rust.example/ontology/{local}")
+}
+
+// For a trait reference with no `Id` at all (`Clone`, `Send` here),
+// `resolve_path_to_iri`'s fallback mints the IRI from the bare path as
+// written, not the type's fully-qualified `mycrate::<Name>` form.
+fn type_iri(path: &str) -> String {
+    format!("{BASE}/type/mycrate/0.1.0/{path}")
+}
+
+// A local item resolved through an `Id` (`Greet`, `Widget` here), by
+// contrast, is minted from its full module-qualified path
+// (`mycrate::<Name>`), percent-encoded whole by `IriMinter::type_iri`.
+fn concrete_type_iri(name: &str) -> String {
+    format!("{BASE}/type/mycrate/0.1.0/mycrate%3A%3A{name}")
+}
+
+fn impl_iri(id: &str) -> String {
+    format!("{BASE}/impl/mycrate/0.1.0/{id}")
+}
+
+#[test]
+fn generic_parameters_and_bounds_on_the_impl_itself_are_extracted() {
+    let out = extract();
+    let impl_node = impl_iri("3");
+    let type_param = format!("{impl_node}/typeparam/0");
+    let clone_trait = type_iri("Clone");
+
+    assert!(has_iri_triple(
+        &out,
+        &impl_node,
+        "http://typegraph.example/ontology/hasTypeParameter",
+        &type_param
+    ));
+    assert!(has_iri_triple(&out, &type_param, &rt("traitBound"), &clone_trait));
+}
+
+#[test]
+fn trait_impl_method_links_back_to_the_trait_declared_method() {
+    let out = extract();
+    let greet_trait = concrete_type_iri("Greet");
+    let declared_method = format!("{greet_trait}/member/greet");
+    let impl_node = impl_iri("3");
+    let provided_method = format!("{impl_node}/member/greet");
+
+    assert!(has_iri_triple(
+        &out,
+        &provided_method,
+        &rt("implementsTraitMethod"),
+        &declared_method
+    ));
+}
+
+#[test]
+fn negative_impl_is_reported_as_a_node_flagged_is_negative() {
+    let out = extract();
+    let widget = concrete_type_iri("Widget");
+    let impl_node = impl_iri("4");
+    let send_trait = type_iri("Send");
+
+    assert!(has_bool_triple(&out, &impl_node, &rt("isNegative"), true));
+    assert!(has_iri_triple(&out, &impl_node, &rt("implFor"), &widget));
+    assert!(has_iri_triple(&out, &impl_node, &rt("implTrait"), &send_trait));
+    // Negative impls have no body — no RDF_TYPE (TraitImpl/InherentImpl) node kind is asserted.
+    assert!(!has_iri_triple(&out, &impl_node, RDF_TYPE, &rt("TraitImpl")));
+}
@@ -88,6 +88,65 @@ fn type_iri_special_characters() {
     assert!(!iri.contains('>'));
 }
 
+// --- Type IRI: hash_complex_iris ---
+
+#[test]
+fn is_complex_path_flags_generics_and_long_names() {
+    assert!(IriMinter::is_complex_path("HashMap<K, V>"));
+    assert!(!IriMinter::is_complex_path("Deserializer"));
+    assert!(IriMinter::is_complex_path(
+        "some::deeply::nested::module::path::ReallyLongTypeNameThatsNotGeneric"
+    ));
+}
+
+#[test]
+fn hash_complex_iris_off_by_default() {
+    let m = minter();
+    let iri = m.type_iri("std", "1.78.0", "HashMap<K, V>");
+    assert!(iri.contains("HashMap%3CK%2C%20V%3E"));
+}
+
+#[test]
+fn hash_complex_iris_mints_shortname_hash_iri_for_generics() {
+    let mut m = minter();
+    m.set_hash_complex_iris(true);
+    let iri = m.type_iri("std", "1.78.0", "HashMap<K, V>");
+    assert!(
+        iri.starts_with("http://example.org/rust/type/std/1.78.0/HashMap-"),
+        "unexpected hashed IRI: {iri}"
+    );
+    assert!(!iri.contains('<'));
+    assert!(!iri.contains(' '));
+}
+
+#[test]
+fn hash_complex_iris_leaves_simple_names_alone() {
+    let mut m = minter();
+    m.set_hash_complex_iris(true);
+    assert_eq!(
+        m.type_iri("serde", "1.0.0", "Deserializer"),
+        "http://example.org/rust/type/serde/1.0.0/Deserializer"
+    );
+}
+
+#[test]
+fn hash_complex_iris_is_stable_and_deterministic() {
+    let mut m = minter();
+    m.set_hash_complex_iris(true);
+    let first = m.type_iri("std", "1.78.0", "HashMap<K, V>");
+    let second = m.type_iri("std", "1.78.0", "HashMap<K, V>");
+    assert_eq!(first, second);
+}
+
+#[test]
+fn hash_complex_iris_differs_for_differing_generic_args() {
+    let mut m = minter();
+    m.set_hash_complex_iris(true);
+    let a = m.type_iri("std", "1.78.0", "HashMap<K, V>");
+    let b = m.type_iri("std", "1.78.0", "HashMap<K, W>");
+    assert_ne!(a, b);
+}
+
 // --- Member IRI ---
 
 #[test]
@@ -1,5 +1,6 @@
-use rust2rdf::extraction::rustdoc_loader;
-use std::path::Path;
+use rust2rdf::extraction::rustdoc_loader::{self, LoadError, LoadOptions};
+use rust2rdf::extraction::rustdoc_model::Crate;
+use std::path::{Path, PathBuf};
 
 #[test]
 fn load_fixture_json() {
@@ -35,30 +36,165 @@ fn fixture_has_expected_items() {
 }
 
 #[test]
-fn extract_crate_version_works() {
-    let toml = r#"
+fn load_nonexistent_file_gives_error() {
+    let result = rustdoc_loader::load_json(Path::new("/nonexistent/file.json"));
+    assert!(result.is_err());
+}
+
+#[test]
+fn unsupported_format_version_error_names_the_supported_range() {
+    let err = Crate::load_with_options(
+        MINIMAL_CRATE_JSON.as_bytes(),
+        &LoadOptions {
+            min_format_version: 27,
+        },
+    )
+    .expect_err("format_version 12 should be rejected below a floor of 27");
+
+    let message = err.to_string();
+    assert!(message.contains("12"), "message should name the version found: {message}");
+    assert!(message.contains("27"), "message should name the supported range: {message}");
+}
+
+/// Scratch directory under the OS temp dir, unique per test process +
+/// call site, so parallel test runs don't collide.
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("rust2rdf-loader-test-{}-{name}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).expect("create scratch dir");
+    dir
+}
+
+#[test]
+fn resolve_package_metadata_reads_a_plain_package() {
+    let dir = scratch_dir("plain");
+    std::fs::write(
+        dir.join("Cargo.toml"),
+        r#"
 [package]
 name = "my-crate"
 version = "1.2.3"
-edition = "2021"
-"#;
-    assert_eq!(
-        rustdoc_loader::extract_crate_version(toml),
-        Some("1.2.3".to_string())
-    );
+"#,
+    )
+    .expect("write Cargo.toml");
+
+    let metadata = rustdoc_loader::resolve_package_metadata(&dir).expect("resolve metadata");
+    assert_eq!(metadata.name, Some("my-crate".to_string()));
+    assert_eq!(metadata.version, Some("1.2.3".to_string()));
+    assert_eq!(metadata.lib_name, None);
 }
 
 #[test]
-fn extract_crate_version_missing() {
-    let toml = r#"
-[dependencies]
-serde = "1"
+fn resolve_package_metadata_follows_workspace_inheritance() {
+    let workspace_dir = scratch_dir("workspace-root");
+    std::fs::write(
+        workspace_dir.join("Cargo.toml"),
+        r#"
+[workspace]
+members = ["member"]
+
+[workspace.package]
+version = "9.9.9"
+"#,
+    )
+    .expect("write workspace Cargo.toml");
+
+    let member_dir = workspace_dir.join("member");
+    std::fs::create_dir_all(&member_dir).expect("create member dir");
+    std::fs::write(
+        member_dir.join("Cargo.toml"),
+        r#"
+[package]
+name = "member-crate"
+version.workspace = true
+
+[lib]
+name = "member_lib"
+"#,
+    )
+    .expect("write member Cargo.toml");
+
+    let metadata = rustdoc_loader::resolve_package_metadata(&member_dir).expect("resolve metadata");
+    assert_eq!(metadata.name, Some("member-crate".to_string()));
+    assert_eq!(metadata.version, Some("9.9.9".to_string()));
+    assert_eq!(metadata.lib_name, Some("member_lib".to_string()));
+}
+
+const MINIMAL_CRATE_JSON: &str = r#"
+{
+  "root": "0",
+  "format_version": 12,
+  "index": {
+    "0": { "id": "0", "name": "mycrate", "visibility": "public", "attrs": [], "inner": { "module": { "items": [] } } }
+  }
+}
 "#;
-    assert_eq!(rustdoc_loader::extract_crate_version(toml), None);
+
+#[test]
+fn crate_load_accepts_the_default_minimum_version() {
+    let krate = Crate::load(MINIMAL_CRATE_JSON.as_bytes()).expect("format_version 12 should load");
+    assert_eq!(krate.format_version, 12);
 }
 
 #[test]
-fn load_nonexistent_file_gives_error() {
-    let result = rustdoc_loader::load_json(Path::new("/nonexistent/file.json"));
-    assert!(result.is_err());
+fn crate_load_rejects_versions_below_the_configured_floor() {
+    let err = Crate::load_with_options(
+        MINIMAL_CRATE_JSON.as_bytes(),
+        &LoadOptions {
+            min_format_version: 27,
+        },
+    )
+    .expect_err("format_version 12 should be rejected below a floor of 27");
+
+    match err {
+        LoadError::UnsupportedFormatVersion { found, supported } => {
+            assert_eq!(found, 12);
+            assert_eq!(*supported.start(), 27);
+        }
+        other => panic!("expected UnsupportedFormatVersion, got {other:?}"),
+    }
+}
+
+#[test]
+fn angle_bracketed_generic_args_accept_the_old_bindings_field_name() {
+    let json = r#"
+    {
+      "root": "0",
+      "format_version": 26,
+      "index": {
+        "0": {
+          "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+          "inner": { "function": {
+            "sig": {
+              "inputs": [["x", { "resolved_path": {
+                "path": "Iterator",
+                "args": { "angle_bracketed": { "args": [], "bindings": [
+                  { "name": "Item", "binding": { "equality": { "primitive": "i32" } } }
+                ] } }
+              } }]],
+              "output": null
+            },
+            "generics": { "params": [], "where_predicates": [] },
+            "header": { "is_const": false, "is_unsafe": false, "is_async": false }
+          } }
+        }
+      }
+    }
+    "#;
+
+    let krate = Crate::load(json.as_bytes()).expect("old `bindings` field name should parse");
+    let item = krate.index.get("0").expect("item 0");
+    let rust2rdf::extraction::rustdoc_model::ItemEnum::Function { sig, .. } = &item.inner else {
+        panic!("expected a function item");
+    };
+    let rust2rdf::extraction::rustdoc_model::Type::ResolvedPath(path) = &sig.inputs[0].1 else {
+        panic!("expected a resolved-path parameter type");
+    };
+    let args = path.args.as_deref().expect("generic args");
+    let rust2rdf::extraction::rustdoc_model::GenericArgs::AngleBracketed { constraints, .. } = args
+    else {
+        panic!("expected angle-bracketed generic args");
+    };
+    assert_eq!(constraints.len(), 1);
+    assert_eq!(constraints[0].name, "Item");
 }
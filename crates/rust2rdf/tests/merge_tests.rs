@@ -0,0 +1,141 @@
+//! Integration tests for `extraction::merge::merge_crates`: namespacing ids
+//! across crates and resolving cross-crate `paths` entries.
+
+use rust2rdf::extraction::merge::{merge_crates, ExternalResolution, MergeInput};
+use rust2rdf::extraction::rustdoc_model::{Crate, Id, ItemEnum, Type};
+
+fn parse(json: &str) -> Crate {
+    serde_json::from_str(json).expect("fixture JSON should parse")
+}
+
+const MYCRATE_JSON: &str = r#"
+{
+  "root": "0",
+  "format_version": 35,
+  "index": {
+    "0": {
+      "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+      "inner": { "module": { "items": ["1"] } }
+    },
+    "1": {
+      "id": "1", "name": "Wrapper", "visibility": "public", "attrs": [],
+      "inner": { "struct": {
+        "kind": { "plain": { "fields": ["2"], "has_stripped_fields": false } },
+        "generics": { "params": [], "where_predicates": [] },
+        "impls": []
+      } }
+    },
+    "2": {
+      "id": "2", "name": "helper", "visibility": "public", "attrs": [],
+      "inner": { "struct_field": { "resolved_path": { "path": "Helper", "id": "100" } } }
+    }
+  },
+  "paths": {
+    "0": { "path": ["mycrate"], "kind": "module" },
+    "1": { "path": ["mycrate", "Wrapper"], "kind": "struct" },
+    "100": { "path": ["othercrate", "Helper"], "kind": "struct" }
+  },
+  "external_crates": {
+    "5": { "name": "othercrate", "html_root_url": "https://docs.rs/othercrate/1.0.0" }
+  }
+}
+"#;
+
+const OTHERCRATE_JSON: &str = r#"
+{
+  "root": "0",
+  "format_version": 35,
+  "index": {
+    "0": {
+      "id": "0", "name": "othercrate", "visibility": "public", "attrs": [],
+      "inner": { "module": { "items": ["1"] } }
+    },
+    "1": {
+      "id": "1", "name": "Helper", "visibility": "public", "attrs": [],
+      "inner": { "struct": {
+        "kind": { "plain": { "fields": [], "has_stripped_fields": false } },
+        "generics": { "params": [], "where_predicates": [] },
+        "impls": []
+      } }
+    }
+  },
+  "paths": {
+    "0": { "path": ["othercrate"], "kind": "module" },
+    "1": { "path": ["othercrate", "Helper"], "kind": "struct" }
+  }
+}
+"#;
+
+#[test]
+fn ids_are_rewritten_into_a_per_crate_namespace() {
+    let merged = merge_crates(vec![MergeInput {
+        crate_name: "mycrate".to_string(),
+        crate_data: parse(MYCRATE_JSON),
+    }]);
+
+    assert!(merged.crate_data.index.contains_key("mycrate::1"));
+    assert_eq!(merged.crate_roots["mycrate"], Id("mycrate::0".to_string()));
+
+    let ItemEnum::Module { items, .. } = &merged.crate_data.index["mycrate::0"].inner else {
+        panic!("expected a module");
+    };
+    assert_eq!(items, &vec![Id("mycrate::1".to_string())]);
+}
+
+#[test]
+fn a_resolved_path_id_embedded_in_a_field_type_is_also_rewritten() {
+    let merged = merge_crates(vec![MergeInput {
+        crate_name: "mycrate".to_string(),
+        crate_data: parse(MYCRATE_JSON),
+    }]);
+
+    let ItemEnum::StructField(Type::ResolvedPath(path)) = &merged.crate_data.index["mycrate::2"].inner
+    else {
+        panic!("expected a struct field holding a resolved path");
+    };
+    assert_eq!(path.id, Some(Id("mycrate::100".to_string())));
+}
+
+#[test]
+fn cross_crate_reference_resolves_to_the_supplied_crate_when_present() {
+    let merged = merge_crates(vec![
+        MergeInput {
+            crate_name: "mycrate".to_string(),
+            crate_data: parse(MYCRATE_JSON),
+        },
+        MergeInput {
+            crate_name: "othercrate".to_string(),
+            crate_data: parse(OTHERCRATE_JSON),
+        },
+    ]);
+
+    let resolution = merged
+        .external_resolutions
+        .get(&Id("mycrate::100".to_string()))
+        .expect("mycrate's reference to othercrate::Helper should resolve");
+
+    assert_eq!(
+        resolution,
+        &ExternalResolution::Linked(Id("othercrate::1".to_string()))
+    );
+}
+
+#[test]
+fn cross_crate_reference_falls_back_to_a_docs_url_when_the_crate_is_absent() {
+    let merged = merge_crates(vec![MergeInput {
+        crate_name: "mycrate".to_string(),
+        crate_data: parse(MYCRATE_JSON),
+    }]);
+
+    let resolution = merged
+        .external_resolutions
+        .get(&Id("mycrate::100".to_string()))
+        .expect("mycrate's reference to othercrate::Helper should resolve");
+
+    assert_eq!(
+        resolution,
+        &ExternalResolution::DocsUrl(
+            "https://docs.rs/othercrate/1.0.0/othercrate/struct.Helper.html".to_string()
+        )
+    );
+}
@@ -0,0 +1,184 @@
+//! Integration tests for `extraction::normalize`: version-gated field
+//! renames and the post-deserialize `normalize` pass.
+
+use rust2rdf::extraction::normalize::{normalize, rename_versioned_fields, RENAMED_FIELDS};
+use rust2rdf::extraction::rustdoc_model::{Crate, ItemEnum, Type};
+
+#[test]
+fn crate_version_is_read_from_the_old_version_field_name() {
+    let json = r#"
+    {
+      "root": "0",
+      "format_version": 13,
+      "version": "0.3.1",
+      "index": {
+        "0": { "id": "0", "name": "old", "visibility": "public", "attrs": [], "inner": { "module": { "items": [] } } }
+      }
+    }
+    "#;
+    let krate = Crate::load(json.as_bytes()).expect("should load");
+    assert_eq!(krate.crate_version.as_deref(), Some("0.3.1"));
+}
+
+#[test]
+fn item_span_is_read_from_the_old_source_field_name() {
+    let json = r#"
+    {
+      "root": "0",
+      "format_version": 16,
+      "index": {
+        "0": {
+          "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+          "source": { "filename": "src/lib.rs", "begin": [1, 0], "end": [1, 10] },
+          "inner": { "module": { "items": [] } }
+        }
+      }
+    }
+    "#;
+    let krate = Crate::load(json.as_bytes()).expect("should load");
+    let root = krate.index.get("0").expect("root item");
+    assert_eq!(root.span.as_ref().expect("span").filename, "src/lib.rs");
+}
+
+#[test]
+fn item_inner_is_read_from_the_old_kind_field_name() {
+    let json = r#"
+    {
+      "root": "0",
+      "format_version": 19,
+      "index": {
+        "0": {
+          "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+          "kind": { "module": { "items": ["1"] } }
+        },
+        "1": {
+          "id": "1", "name": "Thing", "visibility": "public", "attrs": [],
+          "kind": { "struct": {
+            "kind": "unit",
+            "generics": { "params": [], "where_predicates": [] },
+            "impls": []
+          } }
+        }
+      }
+    }
+    "#;
+    let krate = Crate::load(json.as_bytes()).expect("should load");
+    let root = krate.index.get("0").expect("root item");
+    assert!(matches!(
+        root.inner,
+        rust2rdf::extraction::rustdoc_model::ItemEnum::Module { .. }
+    ));
+}
+
+#[test]
+fn normalize_backfills_a_missing_format_version() {
+    // format_version 20 only picks a value `Crate::load` accepts; the
+    // backfill scenario under test is simulated below by resetting
+    // `format_version` to 0 (its "unset" sentinel) before calling
+    // `normalize` directly, same as a struct default would look before any
+    // version was ever detected on the wire.
+    let mut krate = Crate::load(
+        r#"{ "root": "0", "format_version": 20, "index": {} }"#.as_bytes(),
+    )
+    .expect("should load");
+    krate.format_version = 0;
+    normalize(&mut krate, 27);
+    assert_eq!(krate.format_version, 27);
+}
+
+#[test]
+fn normalize_does_not_override_an_already_known_format_version() {
+    let mut krate = Crate::load(
+        r#"{ "root": "0", "format_version": 35, "index": {} }"#.as_bytes(),
+    )
+    .expect("should load");
+    normalize(&mut krate, 12);
+    assert_eq!(krate.format_version, 35);
+}
+
+#[test]
+fn renamed_fields_table_covers_the_resolved_path_alias() {
+    let entry = RENAMED_FIELDS
+        .iter()
+        .find(|f| f.struct_name == "ResolvedPath")
+        .expect("ResolvedPath rename should be documented");
+    assert_eq!(entry.old_name, "name");
+    assert_eq!(entry.new_name, "path");
+}
+
+#[test]
+fn a_rename_outside_its_format_version_range_does_not_fire() {
+    // "version" is only renamed to "crate_version" for format_version 0..=13.
+    // At 14 it's out of range, so the key should be left alone -- and since
+    // `Crate::crate_version` no longer has an alias either, it stays unset.
+    let krate = Crate::load(
+        r#"
+        {
+          "root": "0",
+          "format_version": 14,
+          "version": "9.9.9",
+          "index": {}
+        }
+        "#
+        .as_bytes(),
+    )
+    .expect("should load");
+    assert_eq!(krate.crate_version, None);
+}
+
+#[test]
+fn a_resolved_path_name_is_read_from_the_old_field_name() {
+    let json = r#"
+    {
+      "root": "0",
+      "format_version": 20,
+      "index": {
+        "0": {
+          "id": "0", "name": "f", "visibility": "public", "attrs": [],
+          "inner": { "struct_field": { "resolved_path": { "name": "Vec", "id": "1" } } }
+        }
+      }
+    }
+    "#;
+    let krate = Crate::load(json.as_bytes()).expect("should load");
+    let ItemEnum::StructField(Type::ResolvedPath(path)) = &krate.index["0"].inner else {
+        panic!("expected a struct field holding a resolved path");
+    };
+    assert_eq!(path.path, "Vec");
+}
+
+#[test]
+fn the_struct_shape_tag_fields_stripped_rename_does_not_clobber_structkind_own_kind_tag() {
+    // StructKind::Plain's own externally-tagged `kind` (e.g. `"kind": "unit"`)
+    // shares a key name with `Item::kind` (renamed to `inner` before format
+    // version 20), but is a different field on a different struct entirely
+    // and must never be touched by that rename.
+    let mut value: serde_json::Value = serde_json::from_str(
+        r#"
+        {
+          "root": "0",
+          "format_version": 19,
+          "index": {
+            "0": {
+              "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+              "kind": { "module": { "items": ["1"] } }
+            },
+            "1": {
+              "id": "1", "name": "Thing", "visibility": "public", "attrs": [],
+              "kind": { "struct": {
+                "kind": "unit",
+                "generics": { "params": [], "where_predicates": [] },
+                "impls": []
+              } }
+            }
+          }
+        }
+        "#,
+    )
+    .expect("fixture JSON should parse");
+
+    rename_versioned_fields(&mut value, 19);
+
+    let thing_inner = &value["index"]["1"]["inner"];
+    assert_eq!(thing_inner["struct"]["kind"], serde_json::json!("unit"));
+}
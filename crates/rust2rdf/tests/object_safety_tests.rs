@@ -0,0 +1,275 @@
+//! Integration tests for `ExtractionOptions::analyze_object_safety`: the
+//! `objectSafe`/`objectSafetyViolation`/`excludedFromObject` triples computed
+//! from the standard object-safety rules. Uses a small hand-written rustdoc
+//! JSON crate so each violation kind can be pinned down precisely.
+
+use rust2rdf::emitter::ntriples::NTriplesEmitter;
+use rust2rdf::emitter::TriplesEmitter;
+use rust2rdf::extraction::extractor::{CrateExtractor, ExtractionOptions};
+use rust2rdf::extraction::rustdoc_model::Crate;
+
+const CRATE_JSON: &str = r#"
+{
+  "root": "0",
+  "crate_version": "0.1.0",
+  "format_version": 35,
+  "external_crates": {},
+  "paths": {},
+  "index": {
+    "0": {
+      "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+      "inner": { "module": { "items": ["1", "2", "3", "10", "11", "12", "13", "20", "21"] } }
+    },
+    "1": {
+      "id": "1", "name": "Safe", "visibility": "public", "attrs": [],
+      "inner": { "trait": {
+        "generics": { "params": [], "where_predicates": [] },
+        "bounds": [], "items": ["2"], "is_auto": false, "is_unsafe": false
+      } }
+    },
+    "2": {
+      "id": "2", "name": "borrow", "visibility": "default", "attrs": [],
+      "inner": { "function": {
+        "sig": {
+          "inputs": [["self", { "borrowed_ref": { "is_mutable": false, "type": { "generic": "Self" } } }]],
+          "output": null
+        },
+        "generics": { "params": [], "where_predicates": [] },
+        "header": { "is_const": false, "is_unsafe": false, "is_async": false }
+      } }
+    },
+    "3": {
+      "id": "3", "name": "NoSelf", "visibility": "public", "attrs": [],
+      "inner": { "trait": {
+        "generics": { "params": [], "where_predicates": [] },
+        "bounds": [], "items": ["10"], "is_auto": false, "is_unsafe": false
+      } }
+    },
+    "10": {
+      "id": "10", "name": "make", "visibility": "default", "attrs": [],
+      "inner": { "function": {
+        "sig": { "inputs": [], "output": { "generic": "Self" } },
+        "generics": { "params": [], "where_predicates": [] },
+        "header": { "is_const": false, "is_unsafe": false, "is_async": false }
+      } }
+    },
+    "11": {
+      "id": "11", "name": "ReturnsSelf", "visibility": "public", "attrs": [],
+      "inner": { "trait": {
+        "generics": { "params": [], "where_predicates": [] },
+        "bounds": [], "items": ["12"], "is_auto": false, "is_unsafe": false
+      } }
+    },
+    "12": {
+      "id": "12", "name": "clone_self", "visibility": "default", "attrs": [],
+      "inner": { "function": {
+        "sig": {
+          "inputs": [["self", { "borrowed_ref": { "is_mutable": false, "type": { "generic": "Self" } } }]],
+          "output": { "generic": "Self" }
+        },
+        "generics": { "params": [], "where_predicates": [] },
+        "header": { "is_const": false, "is_unsafe": false, "is_async": false }
+      } }
+    },
+    "13": {
+      "id": "13", "name": "ByValue", "visibility": "public", "attrs": [],
+      "inner": { "trait": {
+        "generics": { "params": [], "where_predicates": [] },
+        "bounds": [], "items": ["14"], "is_auto": false, "is_unsafe": false
+      } }
+    },
+    "14": {
+      "id": "14", "name": "merge", "visibility": "default", "attrs": [],
+      "inner": { "function": {
+        "sig": {
+          "inputs": [
+            ["self", { "borrowed_ref": { "is_mutable": false, "type": { "generic": "Self" } } }],
+            ["other", { "generic": "Self" }]
+          ],
+          "output": null
+        },
+        "generics": { "params": [], "where_predicates": [] },
+        "header": { "is_const": false, "is_unsafe": false, "is_async": false }
+      } }
+    },
+    "20": {
+      "id": "20", "name": "Generic", "visibility": "public", "attrs": [],
+      "inner": { "trait": {
+        "generics": { "params": [], "where_predicates": [] },
+        "bounds": [], "items": ["22"], "is_auto": false, "is_unsafe": false
+      } }
+    },
+    "22": {
+      "id": "22", "name": "convert", "visibility": "default", "attrs": [],
+      "inner": { "function": {
+        "sig": {
+          "inputs": [["self", { "borrowed_ref": { "is_mutable": false, "type": { "generic": "Self" } } }]],
+          "output": null
+        },
+        "generics": {
+          "params": [
+            { "name": "T", "kind": { "type": { "bounds": [], "default": null, "is_synthetic": false } } }
+          ],
+          "where_predicates": []
+        },
+        "header": { "is_const": false, "is_unsafe": false, "is_async": false }
+      } }
+    },
+    "21": {
+      "id": "21", "name": "ExcludedViaSized", "visibility": "public", "attrs": [],
+      "inner": { "trait": {
+        "generics": { "params": [], "where_predicates": [] },
+        "bounds": [], "items": ["2", "23"], "is_auto": false, "is_unsafe": false
+      } }
+    },
+    "23": {
+      "id": "23", "name": "make_boxed", "visibility": "default", "attrs": [],
+      "inner": { "function": {
+        "sig": { "inputs": [], "output": { "generic": "Self" } },
+        "generics": {
+          "params": [],
+          "where_predicates": [
+            { "bound_predicate": {
+              "type": { "generic": "Self" },
+              "bounds": [{ "trait_bound": { "trait": { "path": "Sized", "id": null } } }]
+            } }
+          ]
+        },
+        "header": { "is_const": false, "is_unsafe": false, "is_async": false }
+      } }
+    }
+  }
+}
+"#;
+
+fn load() -> Crate {
+    serde_json::from_str(CRATE_JSON).expect("fixture JSON should parse")
+}
+
+fn extract(analyze_object_safety: bool) -> String {
+    let krate = load();
+    let mut buf = Vec::new();
+    {
+        let mut emitter = NTriplesEmitter::new(&mut buf);
+        let options = ExtractionOptions {
+            analyze_object_safety,
+            ..ExtractionOptions::default()
+        };
+        let mut extractor = CrateExtractor::new(&mut emitter, &krate, options);
+        extractor.extract();
+        emitter.flush().unwrap();
+    }
+    String::from_utf8(buf).expect("Invalid UTF-8 in NTriples output")
+}
+
+const RT_NS: &str = "http://rust.example/ontology/";
+const BASE: &str = "http://rust.example";
+
+fn rt(local: &str) -> String {
+    format!("{RT_NS}{local}")
+}
+
+fn type_iri(name: &str) -> String {
+    format!("{BASE}/type/mycrate/0.1.0/mycrate%3A%3A{name}")
+}
+
+fn has_bool_triple(output: &str, subject: &str, predicate: &str, value: bool) -> bool {
+    let val = if value { "true" } else { "false" };
+    let expected = format!(
+        "<{subject}> <{predicate}> \"{val}\"^^<http://www.w3.org/2001/XMLSchema#boolean> ."
+    );
+    output.lines().any(|line| line.trim() == expected)
+}
+
+fn has_literal_triple_containing(output: &str, subject: &str, predicate: &str, needle: &str) -> bool {
+    let prefix = format!("<{subject}> <{predicate}> \"");
+    output
+        .lines()
+        .any(|line| line.trim().starts_with(&prefix) && line.contains(needle))
+}
+
+#[test]
+fn trait_with_only_self_receiving_methods_is_object_safe() {
+    let out = extract(true);
+    let safe = type_iri("Safe");
+    assert!(has_bool_triple(&out, &safe, &rt("objectSafe"), true));
+}
+
+#[test]
+fn associated_function_without_self_is_a_violation() {
+    let out = extract(true);
+    let no_self = type_iri("NoSelf");
+    assert!(has_bool_triple(&out, &no_self, &rt("objectSafe"), false));
+    assert!(has_literal_triple_containing(
+        &out,
+        &no_self,
+        &rt("objectSafetyViolation"),
+        "no `self` receiver"
+    ));
+}
+
+#[test]
+fn method_returning_self_is_a_violation() {
+    let out = extract(true);
+    let returns_self = type_iri("ReturnsSelf");
+    assert!(has_bool_triple(&out, &returns_self, &rt("objectSafe"), false));
+    assert!(has_literal_triple_containing(
+        &out,
+        &returns_self,
+        &rt("objectSafetyViolation"),
+        "returns `Self`"
+    ));
+}
+
+#[test]
+fn method_taking_self_by_value_outside_receiver_is_a_violation() {
+    let out = extract(true);
+    let by_value = type_iri("ByValue");
+    assert!(has_bool_triple(&out, &by_value, &rt("objectSafe"), false));
+    assert!(has_literal_triple_containing(
+        &out,
+        &by_value,
+        &rt("objectSafetyViolation"),
+        "by value"
+    ));
+}
+
+#[test]
+fn generic_method_is_a_violation() {
+    let out = extract(true);
+    let generic = type_iri("Generic");
+    assert!(has_bool_triple(&out, &generic, &rt("objectSafe"), false));
+    assert!(has_literal_triple_containing(
+        &out,
+        &generic,
+        &rt("objectSafetyViolation"),
+        "generic type parameters"
+    ));
+}
+
+#[test]
+fn method_with_where_self_sized_is_excluded_not_a_violation() {
+    let out = extract(true);
+    let excluded = type_iri("ExcludedViaSized");
+    // The only non-excluded method is the plain `borrow(&self)`, so the
+    // trait as a whole remains object safe.
+    assert!(has_bool_triple(&out, &excluded, &rt("objectSafe"), true));
+
+    let make_boxed_iri = format!("{excluded}/member/make_boxed");
+    assert!(has_bool_triple(
+        &out,
+        &make_boxed_iri,
+        &rt("excludedFromObject"),
+        true
+    ));
+}
+
+#[test]
+fn disabling_analyze_object_safety_suppresses_the_triples() {
+    let out = extract(false);
+    let safe = type_iri("Safe");
+    assert!(!out.contains(&rt("objectSafe")));
+    assert!(!out.contains(&rt("objectSafetyViolation")));
+    assert!(!out.contains(&rt("excludedFromObject")));
+    let _ = safe;
+}
@@ -0,0 +1,117 @@
+//! Tests for the typed vocabulary layer in `model::ontology::vocabulary`:
+//! domain/range lookups, inverse-predicate lookups, and the RDFS schema
+//! `emit_schema` generates for the ontology itself.
+
+use rust2rdf::emitter::ntriples::NTriplesEmitter;
+use rust2rdf::emitter::TriplesEmitter;
+use rust2rdf::model::ontology::{dt, rt, tg, vocabulary};
+
+#[test]
+fn registered_predicate_declares_its_domain_range_and_inverse() {
+    let pred = vocabulary::predicate(tg::MEMBER_OF).expect("tg:memberOf should be registered");
+    assert_eq!(pred.domain, Some(tg::MEMBER));
+    assert_eq!(pred.range, Some(tg::TYPE));
+    assert_eq!(pred.inverse, Some(tg::HAS_MEMBER));
+}
+
+#[test]
+fn inverse_of_is_symmetric() {
+    assert_eq!(vocabulary::inverse_of(tg::MEMBER_OF), Some(tg::HAS_MEMBER));
+    assert_eq!(vocabulary::inverse_of(tg::HAS_MEMBER), Some(tg::MEMBER_OF));
+}
+
+#[test]
+fn unregistered_predicate_has_no_metadata() {
+    assert_eq!(vocabulary::predicate("http://example/not-a-real-predicate"), None);
+    assert_eq!(vocabulary::inverse_of("http://example/not-a-real-predicate"), None);
+}
+
+#[test]
+fn matches_domain_range_accepts_a_correct_triple() {
+    assert!(vocabulary::matches_domain_range(
+        tg::HAS_MEMBER,
+        tg::TYPE,
+        tg::MEMBER
+    ));
+}
+
+#[test]
+fn matches_domain_range_rejects_a_known_mismatch() {
+    assert!(!vocabulary::matches_domain_range(
+        tg::HAS_MEMBER,
+        tg::MEMBER, // swapped: hasMember's domain is tg:Type, not tg:Member
+        tg::TYPE
+    ));
+}
+
+#[test]
+fn matches_domain_range_is_permissive_for_unregistered_predicates() {
+    // Not every constant in `tg`/`rt`/`dt` is registered -- an unregistered
+    // predicate shouldn't be rejected just because it isn't in the table.
+    assert!(vocabulary::matches_domain_range(
+        "http://example/not-a-real-predicate",
+        "http://example/AnyClass",
+        "http://example/AnyOtherClass"
+    ));
+}
+
+#[test]
+fn dt_struct_layout_class_is_registered() {
+    let class = vocabulary::class(dt::STRUCT_LAYOUT).expect("dt:StructLayout should be registered");
+    assert_eq!(class.label, "StructLayout");
+}
+
+#[test]
+fn rt_trait_is_registered_as_a_subclass_of_tg_interface() {
+    let class = vocabulary::class(rt::TRAIT).expect("rt:Trait should be registered");
+    assert_eq!(class.sub_class_of, Some(tg::INTERFACE));
+}
+
+fn emit_schema_output() -> String {
+    let mut buf = Vec::new();
+    {
+        let mut emitter = NTriplesEmitter::new(&mut buf);
+        vocabulary::emit_schema(&mut emitter).expect("emit_schema should not fail");
+        emitter.flush().unwrap();
+    }
+    String::from_utf8(buf).expect("Invalid UTF-8 in NTriples output")
+}
+
+#[test]
+fn emit_schema_types_every_registered_class_as_rdfs_class() {
+    let out = emit_schema_output();
+    assert!(out.contains(&format!(
+        "<{}> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://www.w3.org/2000/01/rdf-schema#Class>",
+        tg::TYPE
+    )));
+}
+
+#[test]
+fn emit_schema_declares_subclass_of_for_classes_with_a_parent() {
+    let out = emit_schema_output();
+    assert!(out.contains(&format!(
+        "<{}> <http://www.w3.org/2000/01/rdf-schema#subClassOf> <{}>",
+        tg::CLASS,
+        tg::TYPE
+    )));
+}
+
+#[test]
+fn emit_schema_declares_domain_range_and_inverse_for_predicates() {
+    let out = emit_schema_output();
+    assert!(out.contains(&format!(
+        "<{}> <http://www.w3.org/2000/01/rdf-schema#domain> <{}>",
+        tg::MEMBER_OF,
+        tg::MEMBER
+    )));
+    assert!(out.contains(&format!(
+        "<{}> <http://www.w3.org/2000/01/rdf-schema#range> <{}>",
+        tg::MEMBER_OF,
+        tg::TYPE
+    )));
+    assert!(out.contains(&format!(
+        "<{}> <http://www.w3.org/2002/07/owl#inverseOf> <{}>",
+        tg::MEMBER_OF,
+        tg::HAS_MEMBER
+    )));
+}
@@ -0,0 +1,129 @@
+//! Integration tests for [`ExtractionOptions::jobs`]: running extraction
+//! with more than one worker thread must produce byte-identical N-Triples
+//! output to the single-threaded walk, including when multiple top-level
+//! items -- and therefore multiple workers -- reference the same shared
+//! node (here, the `i32` primitive-type node), which exercises the
+//! `begin_definition`/`end_definition` dedup path in
+//! `CrateExtractor::walk_root_parallel`.
+
+use rust2rdf::emitter::ntriples::NTriplesEmitter;
+use rust2rdf::emitter::TriplesEmitter;
+use rust2rdf::extraction::extractor::{CrateExtractor, ExtractionOptions};
+use rust2rdf::extraction::rustdoc_model::Crate;
+
+const CRATE_JSON: &str = r#"
+{
+  "root": "0",
+  "crate_version": "0.1.0",
+  "format_version": 35,
+  "external_crates": {},
+  "paths": {},
+  "index": {
+    "0": {
+      "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+      "inner": { "module": { "items": ["1", "2", "3", "4"] } }
+    },
+    "1": {
+      "id": "1", "name": "first", "visibility": "public", "attrs": [],
+      "inner": { "function": {
+        "sig": {
+          "inputs": [["x", { "primitive": "i32" }]],
+          "output": null
+        },
+        "generics": { "params": [], "where_predicates": [] },
+        "header": { "is_const": false, "is_unsafe": false, "is_async": false }
+      } }
+    },
+    "2": {
+      "id": "2", "name": "second", "visibility": "public", "attrs": [],
+      "inner": { "function": {
+        "sig": {
+          "inputs": [["y", { "primitive": "i32" }]],
+          "output": null
+        },
+        "generics": { "params": [], "where_predicates": [] },
+        "header": { "is_const": false, "is_unsafe": false, "is_async": false }
+      } }
+    },
+    "3": {
+      "id": "3", "name": "third", "visibility": "public", "attrs": [],
+      "inner": { "function": {
+        "sig": {
+          "inputs": [["z", { "primitive": "bool" }]],
+          "output": null
+        },
+        "generics": { "params": [], "where_predicates": [] },
+        "header": { "is_const": false, "is_unsafe": false, "is_async": false }
+      } }
+    },
+    "4": {
+      "id": "4", "name": "fourth", "visibility": "public", "attrs": [],
+      "inner": { "function": {
+        "sig": {
+          "inputs": [["w", { "primitive": "i32" }]],
+          "output": null
+        },
+        "generics": { "params": [], "where_predicates": [] },
+        "header": { "is_const": false, "is_unsafe": false, "is_async": false }
+      } }
+    }
+  }
+}
+"#;
+
+fn load() -> Crate {
+    serde_json::from_str(CRATE_JSON).expect("fixture JSON should parse")
+}
+
+fn extract(jobs: usize) -> (String, u64) {
+    let krate = load();
+    let mut buf = Vec::new();
+    let count;
+    {
+        let mut emitter = NTriplesEmitter::new(&mut buf);
+        let mut extractor = CrateExtractor::new(
+            &mut emitter,
+            &krate,
+            ExtractionOptions {
+                jobs,
+                ..ExtractionOptions::default()
+            },
+        );
+        extractor.extract().unwrap();
+        emitter.flush().unwrap();
+        count = emitter.triple_count();
+    }
+    (String::from_utf8(buf).expect("Invalid UTF-8 in NTriples output"), count)
+}
+
+#[test]
+fn two_jobs_matches_serial_output() {
+    let (serial, serial_count) = extract(1);
+    let (parallel, parallel_count) = extract(2);
+    assert_eq!(serial_count, parallel_count);
+    assert_eq!(serial, parallel);
+}
+
+#[test]
+fn four_jobs_matches_serial_output() {
+    let (serial, serial_count) = extract(1);
+    let (parallel, parallel_count) = extract(4);
+    assert_eq!(serial_count, parallel_count);
+    assert_eq!(serial, parallel);
+}
+
+#[test]
+fn shared_primitive_type_is_emitted_exactly_once_across_workers() {
+    let (out, _) = extract(4);
+    let i32_type = "http://rust.example/type/_primitive_/i32";
+    let rdf_type = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+    let occurrences = out
+        .lines()
+        .filter(|l| l.contains(&format!("<{i32_type}> <{rdf_type}>")))
+        .count();
+
+    assert_eq!(
+        occurrences, 1,
+        "expected the shared i32 primitive node to be described exactly once:\n{out}"
+    );
+}
@@ -0,0 +1,129 @@
+use rust2rdf::pattern_query::{query, Node, PatternTerm, Triple, TripleIndex, TriplePattern};
+
+const IMPLEMENTS: &str = "tg:implements";
+const NAME: &str = "tg:name";
+const HAS_METHOD: &str = "tg:hasMethod";
+const RETURN_TYPE: &str = "tg:returnType";
+
+fn sample_index() -> TripleIndex {
+    // Two types, `MyIter`/`OtherType`, both implementing a trait `:Iterator`
+    // (itself named "Iterator"); `MyIter` has a method returning `:MyItem`.
+    TripleIndex::from_triples(vec![
+        Triple::new(":MyIter", IMPLEMENTS, Node::iri(":Iterator")),
+        Triple::new(":OtherType", IMPLEMENTS, Node::iri(":Iterator")),
+        Triple::new(":Iterator", NAME, Node::literal("Iterator")),
+        Triple::new(":MyIter", HAS_METHOD, Node::iri(":MyIter::next")),
+        Triple::new(":MyIter::next", RETURN_TYPE, Node::iri(":MyItem")),
+    ])
+}
+
+#[test]
+fn single_pattern_binds_the_matching_subject() {
+    let index = sample_index();
+    let patterns = vec![TriplePattern::new(
+        PatternTerm::var("type"),
+        PatternTerm::iri(IMPLEMENTS),
+        PatternTerm::iri(":Iterator"),
+    )];
+
+    let mut types: Vec<String> = query(&index, &patterns)
+        .into_iter()
+        .map(|b| match &b["type"] {
+            Node::Iri(iri) => iri.clone(),
+            Node::Literal(_) => panic!("expected an IRI binding"),
+        })
+        .collect();
+    types.sort();
+
+    assert_eq!(types, vec![":MyIter".to_string(), ":OtherType".to_string()]);
+}
+
+#[test]
+fn joined_patterns_filter_down_to_types_implementing_a_named_trait_with_a_method() {
+    let index = sample_index();
+    let patterns = vec![
+        TriplePattern::new(
+            PatternTerm::var("type"),
+            PatternTerm::iri(IMPLEMENTS),
+            PatternTerm::var("trait"),
+        ),
+        TriplePattern::new(
+            PatternTerm::var("trait"),
+            PatternTerm::iri(NAME),
+            PatternTerm::literal("Iterator"),
+        ),
+        TriplePattern::new(
+            PatternTerm::var("type"),
+            PatternTerm::iri(HAS_METHOD),
+            PatternTerm::var("method"),
+        ),
+        TriplePattern::new(
+            PatternTerm::var("method"),
+            PatternTerm::iri(RETURN_TYPE),
+            PatternTerm::var("return_type"),
+        ),
+    ];
+
+    let results = query(&index, &patterns);
+    assert_eq!(results.len(), 1, "only MyIter has a method: {results:?}");
+
+    let binding = &results[0];
+    assert_eq!(binding["type"], Node::iri(":MyIter"));
+    assert_eq!(binding["trait"], Node::iri(":Iterator"));
+    assert_eq!(binding["return_type"], Node::iri(":MyItem"));
+}
+
+#[test]
+fn repeated_variable_must_agree_across_patterns() {
+    let index = sample_index();
+    // `x` is forced to both implement `:Iterator` and be named "Iterator" --
+    // no type satisfies both, so the join should come up empty.
+    let patterns = vec![
+        TriplePattern::new(
+            PatternTerm::var("x"),
+            PatternTerm::iri(IMPLEMENTS),
+            PatternTerm::iri(":Iterator"),
+        ),
+        TriplePattern::new(
+            PatternTerm::var("x"),
+            PatternTerm::iri(NAME),
+            PatternTerm::literal("Iterator"),
+        ),
+    ];
+
+    assert!(query(&index, &patterns).is_empty());
+}
+
+#[test]
+fn incoming_finds_every_subject_pointing_at_a_shared_object() {
+    let index = sample_index();
+    let mut subjects: Vec<&str> = index
+        .incoming(&Node::iri(":Iterator"), Some(IMPLEMENTS))
+        .into_iter()
+        .map(|t| t.subject.as_str())
+        .collect();
+    subjects.sort();
+
+    assert_eq!(subjects, vec![":MyIter", ":OtherType"]);
+}
+
+#[test]
+fn outgoing_can_be_narrowed_to_a_single_predicate() {
+    let index = sample_index();
+    let triples = index.outgoing(":MyIter", Some(NAME));
+    assert!(triples.is_empty(), ":MyIter has no tg:name triple of its own");
+
+    let triples = index.outgoing(":MyIter", Some(IMPLEMENTS));
+    assert_eq!(triples.len(), 1);
+    assert_eq!(triples[0].object, Node::iri(":Iterator"));
+}
+
+#[test]
+fn duplicate_triples_are_not_stored_twice() {
+    let mut index = TripleIndex::new();
+    let triple = Triple::new(":a", IMPLEMENTS, Node::iri(":b"));
+    index.insert(triple.clone());
+    index.insert(triple);
+
+    assert_eq!(index.triples().len(), 1);
+}
@@ -0,0 +1,127 @@
+//! Integration tests for the primitive-type metadata subsystem: every
+//! referenced primitive (`i32`, `usize`, ...) should get a described
+//! `rt:PrimitiveType` node instead of a bare, undescribed IRI.
+
+use rust2rdf::emitter::ntriples::NTriplesEmitter;
+use rust2rdf::emitter::TriplesEmitter;
+use rust2rdf::extraction::extractor::{CrateExtractor, ExtractionOptions};
+use rust2rdf::extraction::rustdoc_model::Crate;
+
+const CRATE_JSON: &str = r#"
+{
+  "root": "0",
+  "crate_version": "0.1.0",
+  "format_version": 35,
+  "external_crates": {},
+  "paths": {},
+  "index": {
+    "0": {
+      "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+      "inner": { "module": { "items": ["1", "2", "3"] } }
+    },
+    "1": {
+      "id": "1", "name": "takes_i32", "visibility": "public", "attrs": [],
+      "inner": { "function": {
+        "sig": {
+          "inputs": [["x", { "primitive": "i32" }]],
+          "output": null
+        },
+        "generics": { "params": [], "where_predicates": [] },
+        "header": { "is_const": false, "is_unsafe": false, "is_async": false }
+      } }
+    },
+    "2": {
+      "id": "2", "name": "takes_usize", "visibility": "public", "attrs": [],
+      "inner": { "function": {
+        "sig": {
+          "inputs": [["x", { "primitive": "usize" }]],
+          "output": null
+        },
+        "generics": { "params": [], "where_predicates": [] },
+        "header": { "is_const": false, "is_unsafe": false, "is_async": false }
+      } }
+    },
+    "3": {
+      "id": "3", "name": "also_takes_i32", "visibility": "public", "attrs": [],
+      "inner": { "function": {
+        "sig": {
+          "inputs": [["y", { "primitive": "i32" }]],
+          "output": null
+        },
+        "generics": { "params": [], "where_predicates": [] },
+        "header": { "is_const": false, "is_unsafe": false, "is_async": false }
+      } }
+    }
+  }
+}
+"#;
+
+fn load() -> Crate {
+    serde_json::from_str(CRATE_JSON).expect("fixture JSON should parse")
+}
+
+fn extract(opts: ExtractionOptions) -> String {
+    let krate = load();
+    let mut buf = Vec::new();
+    {
+        let mut emitter = NTriplesEmitter::new(&mut buf);
+        let mut extractor = CrateExtractor::new(&mut emitter, &krate, opts);
+        extractor.extract();
+        emitter.flush().unwrap();
+    }
+    String::from_utf8(buf).expect("Invalid UTF-8 in NTriples output")
+}
+
+const I32_IRI: &str = "http://rust.example/type/_primitive_/i32";
+const USIZE_IRI: &str = "http://rust.example/type/_primitive_/usize";
+
+#[test]
+fn signed_integer_gets_category_and_bit_width() {
+    let out = extract(ExtractionOptions::default());
+
+    assert!(out.contains(&format!(
+        "<{I32_IRI}> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://rust.example/ontology/PrimitiveType> ."
+    )));
+    assert!(out.contains(&format!(
+        "<{I32_IRI}> <http://rust.example/ontology/primitiveCategory> \"signed-integer\" ."
+    )));
+    assert!(out.contains(&format!(
+        "<{I32_IRI}> <http://rust.example/ontology/bitWidth> \"32\"^^<http://www.w3.org/2001/XMLSchema#integer> ."
+    )));
+    assert!(out.contains(&format!(
+        "<{I32_IRI}> <http://rust.example/ontology/isSigned> \"true\"^^<http://www.w3.org/2001/XMLSchema#boolean> ."
+    )));
+}
+
+#[test]
+fn pointer_sized_integer_omits_bit_width() {
+    let out = extract(ExtractionOptions::default());
+
+    assert!(out.contains(&format!(
+        "<{USIZE_IRI}> <http://rust.example/ontology/isPointerSized> \"true\"^^<http://www.w3.org/2001/XMLSchema#boolean> ."
+    )));
+    assert!(!out.contains(&format!("<{USIZE_IRI}> <http://rust.example/ontology/bitWidth>")));
+}
+
+#[test]
+fn shared_primitive_is_only_described_once() {
+    let out = extract(ExtractionOptions::default());
+    let count = out
+        .lines()
+        .filter(|line| line.contains("http://rust.example/ontology/primitiveCategory"))
+        .filter(|line| line.starts_with(&format!("<{I32_IRI}>")))
+        .count();
+    assert_eq!(count, 1, "i32 is used by two functions but should be described once:\n{out}");
+}
+
+#[test]
+fn extract_primitive_metadata_false_suppresses_description() {
+    let opts = ExtractionOptions {
+        extract_primitive_metadata: false,
+        ..ExtractionOptions::default()
+    };
+    let out = extract(opts);
+
+    assert!(!out.contains("http://rust.example/ontology/PrimitiveType"));
+    assert!(!out.contains("http://rust.example/ontology/primitiveCategory"));
+}
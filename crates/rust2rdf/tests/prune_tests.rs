@@ -0,0 +1,166 @@
+//! Integration tests for `extraction::prune::prune_private_items`: dropping
+//! non-public items from a loaded `Crate` and scrubbing the dangling `Id`
+//! references left behind.
+
+use rust2rdf::extraction::prune::prune_private_items;
+use rust2rdf::extraction::rustdoc_model::{Crate, ItemEnum, StructKind};
+
+const CRATE_JSON: &str = r#"
+{
+  "root": "0",
+  "format_version": 35,
+  "includes_private": true,
+  "index": {
+    "0": {
+      "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+      "inner": { "module": { "items": ["1", "2", "3"] } }
+    },
+    "1": {
+      "id": "1", "name": "PublicStruct", "visibility": "public", "attrs": [],
+      "inner": { "struct": {
+        "kind": { "plain": { "fields": ["10", "11"], "has_stripped_fields": false } },
+        "generics": { "params": [], "where_predicates": [] },
+        "impls": []
+      } }
+    },
+    "10": {
+      "id": "10", "name": "pub_field", "visibility": "public", "attrs": [],
+      "inner": { "struct_field": { "primitive": "i32" } }
+    },
+    "11": {
+      "id": "11", "name": "priv_field", "visibility": "default", "attrs": [],
+      "inner": { "struct_field": { "primitive": "i32" } }
+    },
+    "2": {
+      "id": "2", "name": "PrivateHelper", "visibility": "default", "attrs": [],
+      "inner": { "function": {
+        "sig": { "inputs": [], "output": null },
+        "generics": { "params": [], "where_predicates": [] },
+        "header": { "is_const": false, "is_unsafe": false, "is_async": false }
+      } }
+    },
+    "3": {
+      "id": "3", "name": "Choice", "visibility": "public", "attrs": [],
+      "inner": { "enum": {
+        "generics": { "params": [], "where_predicates": [] },
+        "variants": ["20", "21"],
+        "variants_stripped": false,
+        "impls": []
+      } }
+    },
+    "20": {
+      "id": "20", "name": "A", "visibility": "public", "attrs": [],
+      "inner": { "variant": { "kind": "plain" } }
+    },
+    "21": {
+      "id": "21", "name": "B", "visibility": "default", "attrs": [],
+      "inner": { "variant": { "kind": "plain" } }
+    }
+  }
+}
+"#;
+
+fn load() -> Crate {
+    serde_json::from_str(CRATE_JSON).expect("fixture JSON should parse")
+}
+
+#[test]
+fn private_items_are_removed_from_the_index() {
+    let mut krate = load();
+    prune_private_items(&mut krate);
+
+    assert!(krate.index.contains_key("1"), "public struct stays");
+    assert!(!krate.index.contains_key("2"), "private function is dropped");
+    assert!(!krate.index.contains_key("21"), "private variant is dropped");
+}
+
+#[test]
+fn dangling_module_item_references_are_scrubbed() {
+    let mut krate = load();
+    prune_private_items(&mut krate);
+
+    let ItemEnum::Module { items, .. } = &krate.index["0"].inner else {
+        panic!("expected a module");
+    };
+    assert_eq!(items.iter().map(|id| id.0.as_str()).collect::<Vec<_>>(), vec!["1", "3"]);
+}
+
+#[test]
+fn dangling_struct_field_references_are_scrubbed() {
+    let mut krate = load();
+    prune_private_items(&mut krate);
+
+    let ItemEnum::Struct { kind, .. } = &krate.index["1"].inner else {
+        panic!("expected a struct");
+    };
+    let StructKind::Plain { fields, .. } = kind else {
+        panic!("expected plain fields");
+    };
+    assert_eq!(fields.iter().map(|id| id.0.as_str()).collect::<Vec<_>>(), vec!["10"]);
+}
+
+#[test]
+fn dangling_enum_variant_references_are_scrubbed() {
+    let mut krate = load();
+    prune_private_items(&mut krate);
+
+    let ItemEnum::Enum { variants, .. } = &krate.index["3"].inner else {
+        panic!("expected an enum");
+    };
+    assert_eq!(variants.iter().map(|id| id.0.as_str()).collect::<Vec<_>>(), vec!["20"]);
+}
+
+#[test]
+fn tuple_fields_are_nulled_not_removed_to_preserve_arity() {
+    let mut krate: Crate = serde_json::from_str(
+        r#"
+        {
+          "root": "0",
+          "format_version": 35,
+          "index": {
+            "0": {
+              "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+              "inner": { "module": { "items": ["1"] } }
+            },
+            "1": {
+              "id": "1", "name": "Pair", "visibility": "public", "attrs": [],
+              "inner": { "struct": {
+                "kind": { "tuple": ["10", "11"] },
+                "generics": { "params": [], "where_predicates": [] },
+                "impls": []
+              } }
+            },
+            "10": {
+              "id": "10", "visibility": "public", "attrs": [],
+              "inner": { "struct_field": { "primitive": "i32" } }
+            },
+            "11": {
+              "id": "11", "visibility": "default", "attrs": [],
+              "inner": { "struct_field": { "primitive": "bool" } }
+            }
+          }
+        }
+        "#,
+    )
+    .expect("fixture JSON should parse");
+
+    prune_private_items(&mut krate);
+
+    let ItemEnum::Struct { kind, .. } = &krate.index["1"].inner else {
+        panic!("expected a struct");
+    };
+    let StructKind::Tuple(fields) = kind else {
+        panic!("expected tuple fields");
+    };
+    assert_eq!(fields.len(), 2, "arity must be preserved");
+    assert_eq!(fields[0].as_ref().map(|id| id.0.as_str()), Some("10"));
+    assert_eq!(fields[1], None);
+}
+
+#[test]
+fn includes_private_is_cleared() {
+    let mut krate = load();
+    assert!(krate.includes_private);
+    prune_private_items(&mut krate);
+    assert!(!krate.includes_private);
+}
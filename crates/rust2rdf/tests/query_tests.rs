@@ -0,0 +1,106 @@
+use rust2rdf::html_export::{EdgeKind, Graph, GraphEdge, GraphNode};
+use rust2rdf::query::{paths, predecessors, successors};
+
+fn node(id: &str) -> GraphNode {
+    GraphNode {
+        id: id.to_string(),
+        label: id.to_string(),
+        hover_text: format!("node {id}"),
+        layer: 0,
+    }
+}
+
+fn edge(from: &str, to: &str, kind: EdgeKind) -> GraphEdge {
+    GraphEdge {
+        from: from.to_string(),
+        to: to.to_string(),
+        kind,
+    }
+}
+
+// a --Ownership--> b --Reference--> c
+// a --Reference--> c  (a direct shortcut edge)
+fn sample_graph() -> Graph {
+    Graph {
+        nodes: vec![node("a"), node("b"), node("c")],
+        edges: vec![
+            edge("a", "b", EdgeKind::Ownership),
+            edge("b", "c", EdgeKind::Reference),
+            edge("a", "c", EdgeKind::Reference),
+        ],
+    }
+}
+
+#[test]
+fn successors_returns_direct_outgoing_neighbors() {
+    let graph = sample_graph();
+    let mut ids: Vec<&str> = successors(&graph, "a", None)
+        .into_iter()
+        .map(|n| n.id.as_str())
+        .collect();
+    ids.sort();
+    assert_eq!(ids, vec!["b", "c"]);
+}
+
+#[test]
+fn successors_can_be_filtered_by_edge_kind() {
+    let graph = sample_graph();
+    let ids: Vec<&str> = successors(&graph, "a", Some(EdgeKind::Ownership))
+        .into_iter()
+        .map(|n| n.id.as_str())
+        .collect();
+    assert_eq!(ids, vec!["b"]);
+}
+
+#[test]
+fn predecessors_returns_direct_incoming_neighbors() {
+    let graph = sample_graph();
+    let ids: Vec<&str> = predecessors(&graph, "c", None)
+        .into_iter()
+        .map(|n| n.id.as_str())
+        .collect();
+    let mut ids = ids;
+    ids.sort();
+    assert_eq!(ids, vec!["a", "b"]);
+}
+
+#[test]
+fn paths_finds_both_the_direct_and_multi_hop_route() {
+    let graph = sample_graph();
+    let found = paths(&graph, "a", "c", 2, None);
+    assert_eq!(found.len(), 2);
+
+    let mut lengths: Vec<usize> = found.iter().map(|p| p.len()).collect();
+    lengths.sort();
+    assert_eq!(lengths, vec![2, 3]);
+
+    for path in &found {
+        assert_eq!(path.first().unwrap().node.id, "a");
+        assert!(path.first().unwrap().via.is_none());
+        assert_eq!(path.last().unwrap().node.id, "c");
+    }
+}
+
+#[test]
+fn paths_respects_max_hops() {
+    let graph = sample_graph();
+    let found = paths(&graph, "a", "c", 1, None);
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].len(), 2);
+}
+
+#[test]
+fn paths_can_be_filtered_to_a_single_edge_kind() {
+    let graph = sample_graph();
+    let found = paths(&graph, "a", "c", 2, Some(EdgeKind::Reference));
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].len(), 2);
+    assert_eq!(found[0][1].via, Some(EdgeKind::Reference));
+}
+
+#[test]
+fn paths_between_unknown_nodes_returns_nothing() {
+    let graph = sample_graph();
+    assert!(paths(&graph, "a", "nope", 3, None).is_empty());
+    assert!(paths(&graph, "nope", "a", 3, None).is_empty());
+}
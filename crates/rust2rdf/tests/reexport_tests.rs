@@ -0,0 +1,236 @@
+//! Integration tests for `use`-item re-export extraction, including glob
+//! imports and aliasing. Uses a small hand-written rustdoc JSON crate rather
+//! than the `fixture_crate.json` golden, since the scenarios here (aliasing,
+//! glob expansion, unresolved `id`) are easiest to pin down explicitly.
+
+use rust2rdf::emitter::ntriples::NTriplesEmitter;
+use rust2rdf::emitter::TriplesEmitter;
+use rust2rdf::extraction::extractor::{CrateExtractor, ExtractionOptions};
+use rust2rdf::extraction::rustdoc_model::Crate;
+
+const CRATE_JSON: &str = r#"
+{
+  "root": "0",
+  "crate_version": "0.1.0",
+  "format_version": 35,
+  "external_crates": {},
+  "paths": {
+    "10": { "path": ["std", "collections", "HashMap"], "kind": "struct" },
+    "21": { "path": ["other", "PubFn"], "kind": "function" }
+  },
+  "index": {
+    "0": {
+      "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+      "inner": { "module": { "items": ["1", "2", "3", "4"] } }
+    },
+    "1": {
+      "id": "1", "name": null, "visibility": "public", "attrs": [],
+      "inner": { "use": { "source": "std::collections::HashMap", "name": null, "id": "10", "is_glob": false } }
+    },
+    "2": {
+      "id": "2", "name": null, "visibility": "public", "attrs": [],
+      "inner": { "use": { "source": "inner::Widget", "name": "MyWidget", "id": "11", "is_glob": false } }
+    },
+    "11": {
+      "id": "11", "name": "Widget", "visibility": "public", "attrs": [],
+      "inner": { "struct": {} }
+    },
+    "3": {
+      "id": "3", "name": null, "visibility": "public", "attrs": [],
+      "inner": { "use": { "source": "other::*", "name": null, "id": "20", "is_glob": true } }
+    },
+    "20": {
+      "id": "20", "name": "other", "visibility": "public", "attrs": [],
+      "inner": { "module": { "items": ["21", "22"] } }
+    },
+    "21": {
+      "id": "21", "name": "PubFn", "visibility": "public", "attrs": [],
+      "inner": { "struct": {} }
+    },
+    "22": {
+      "id": "22", "name": "Hidden", "visibility": "crate", "attrs": [],
+      "inner": { "struct": {} }
+    },
+    "4": {
+      "id": "4", "name": null, "visibility": "public", "attrs": [],
+      "inner": { "use": { "source": "qux::Thing", "name": null, "id": null, "is_glob": false } }
+    }
+  }
+}
+"#;
+
+fn load() -> Crate {
+    serde_json::from_str(CRATE_JSON).expect("fixture JSON should parse")
+}
+
+fn extract() -> String {
+    let krate = load();
+    let mut buf = Vec::new();
+    {
+        let mut emitter = NTriplesEmitter::new(&mut buf);
+        let mut extractor = CrateExtractor::new(&mut emitter, &krate, ExtractionOptions::default());
+        extractor.extract();
+        emitter.flush().unwrap();
+    }
+    String::from_utf8(buf).expect("Invalid UTF-8 in NTriples output")
+}
+
+fn has_iri_triple(output: &str, subject: &str, predicate: &str, object: &str) -> bool {
+    let expected = format!("<{subject}> <{predicate}> <{object}> .");
+    output.lines().any(|line| line.trim() == expected)
+}
+
+fn has_literal_triple(output: &str, subject: &str, predicate: &str, value: &str) -> bool {
+    let expected = format!("<{subject}> <{predicate}> \"{value}\" .");
+    output.lines().any(|line| line.trim() == expected)
+}
+
+const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+const BASE: &str = "http://rust.example";
+
+fn rt(local: &str) -> String {
+    format!("http://rust.example/ontology/{local}")
+}
+
+fn module_iri() -> String {
+    format!("{BASE}/module/mycrate/0.1.0/mycrate")
+}
+
+fn type_iri(path: &str) -> String {
+    format!("{BASE}/type/mycrate/0.1.0/{path}")
+}
+
+fn reexport_iri(alias: &str) -> String {
+    format!("{}/reexport/{alias}", module_iri())
+}
+
+#[test]
+fn plain_use_reexports_externally_resolved_target() {
+    let out = extract();
+    let module = module_iri();
+    let hashmap = type_iri("std%3A%3Acollections%3A%3AHashMap");
+
+    assert!(has_iri_triple(&out, &module, &rt("reExports"), &hashmap));
+    assert!(has_iri_triple(&out, &hashmap, RDF_TYPE, "http://typegraph.example/ontology/Type"));
+    assert!(has_literal_triple(&out, &hashmap, "http://typegraph.example/ontology/name", "HashMap"));
+
+    let reexport = reexport_iri("HashMap");
+    assert!(has_iri_triple(&out, &reexport, RDF_TYPE, &rt("ReExport")));
+    assert!(has_literal_triple(&out, &reexport, &rt("reExportAlias"), "HashMap"));
+    assert!(has_iri_triple(&out, &reexport, &rt("reExportTarget"), &hashmap));
+    assert!(has_iri_triple(&out, &module, &rt("hasReExport"), &reexport));
+}
+
+#[test]
+fn aliased_use_keeps_public_alias_but_resolves_original_target() {
+    let out = extract();
+    let module = module_iri();
+    let widget = type_iri("Widget");
+
+    assert!(has_iri_triple(&out, &module, &rt("reExports"), &widget));
+    assert!(has_literal_triple(&out, &widget, "http://typegraph.example/ontology/name", "Widget"));
+
+    let reexport = reexport_iri("MyWidget");
+    assert!(has_literal_triple(&out, &reexport, &rt("reExportAlias"), "MyWidget"));
+    assert!(has_iri_triple(&out, &reexport, &rt("reExportTarget"), &widget));
+}
+
+#[test]
+fn glob_use_expands_to_one_reexport_per_public_child() {
+    let out = extract();
+    let module = module_iri();
+    let pub_fn = type_iri("other%3A%3APubFn");
+
+    assert!(has_iri_triple(&out, &module, &rt("reExports"), &pub_fn));
+    let reexport = reexport_iri("PubFn");
+    assert!(has_literal_triple(&out, &reexport, &rt("reExportAlias"), "PubFn"));
+}
+
+#[test]
+fn glob_use_skips_non_public_children() {
+    let out = extract();
+    let hidden = type_iri("Hidden");
+
+    assert!(!out.contains(&hidden));
+    assert!(!out.contains("Hidden"));
+}
+
+#[test]
+fn unresolved_id_falls_back_to_textual_use_source() {
+    let out = extract();
+    let module = module_iri();
+    let thing = type_iri("qux%3A%3AThing");
+
+    assert!(has_iri_triple(&out, &module, &rt("reExports"), &thing));
+    let reexport = reexport_iri("Thing");
+    assert!(has_literal_triple(&out, &reexport, &rt("reExportAlias"), "Thing"));
+    assert!(has_iri_triple(&out, &reexport, &rt("reExportTarget"), &thing));
+}
+
+// ---------------------------------------------------------------------------
+// `canonicalize_paths`: `owl:sameAs` links for facade re-exports
+// ---------------------------------------------------------------------------
+
+const FACADE_CRATE_JSON: &str = r#"
+{
+  "root": "0",
+  "crate_version": "0.1.0",
+  "format_version": 35,
+  "external_crates": {},
+  "paths": {
+    "2": { "path": ["mycrate", "Facade"], "kind": "struct" }
+  },
+  "index": {
+    "0": {
+      "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+      "inner": { "module": { "items": ["1"] } }
+    },
+    "1": {
+      "id": "1", "name": "inner", "visibility": "public", "attrs": [],
+      "inner": { "module": { "items": ["2"] } }
+    },
+    "2": {
+      "id": "2", "name": "Facade", "visibility": "public", "attrs": [],
+      "inner": { "struct": {} }
+    }
+  }
+}
+"#;
+
+fn extract_facade_crate(options: ExtractionOptions) -> String {
+    let krate: Crate = serde_json::from_str(FACADE_CRATE_JSON).expect("fixture JSON should parse");
+    let mut buf = Vec::new();
+    {
+        let mut emitter = NTriplesEmitter::new(&mut buf);
+        let mut extractor = CrateExtractor::new(&mut emitter, &krate, options);
+        extractor.extract();
+        emitter.flush().unwrap();
+    }
+    String::from_utf8(buf).expect("Invalid UTF-8 in NTriples output")
+}
+
+const OWL_SAME_AS: &str = "http://www.w3.org/2002/07/owl#sameAs";
+
+#[test]
+fn canonicalize_paths_links_defining_site_iri_to_paths_index_canonical_iri() {
+    let out = extract_facade_crate(ExtractionOptions {
+        canonicalize_paths: true,
+        ..ExtractionOptions::default()
+    });
+    let defining_site = type_iri("mycrate%3A%3Ainner%3A%3AFacade");
+    let canonical = type_iri("mycrate%3A%3AFacade");
+
+    assert!(
+        has_iri_triple(&out, &defining_site, OWL_SAME_AS, &canonical),
+        "expected owl:sameAs from defining-site IRI to canonical IRI:\n{out}"
+    );
+}
+
+#[test]
+fn canonicalize_paths_is_off_by_default() {
+    let out = extract_facade_crate(ExtractionOptions::default());
+    assert!(
+        !out.contains(OWL_SAME_AS),
+        "owl:sameAs should not be emitted unless canonicalize_paths is set:\n{out}"
+    );
+}
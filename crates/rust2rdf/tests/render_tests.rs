@@ -0,0 +1,67 @@
+use rust2rdf::extraction::rustdoc_model::Type;
+use rust2rdf::render::{type_display_styled, AnsiColor, Palette};
+
+#[test]
+fn plain_palette_emits_no_escape_codes() {
+    let ty = Type::BorrowedRef {
+        lifetime: None,
+        is_mutable: true,
+        type_: Box::new(Type::Primitive("str".to_string())),
+    };
+    let rendered = type_display_styled(&ty, &Palette::plain());
+
+    assert_eq!(rendered, "&mut str");
+    assert!(!rendered.contains('\u{1b}'));
+}
+
+#[test]
+fn default_palette_is_plain() {
+    let ty = Type::Primitive("u8".to_string());
+    assert_eq!(
+        type_display_styled(&ty, &Palette::default()),
+        type_display_styled(&ty, &Palette::plain())
+    );
+}
+
+#[test]
+fn ansi_palette_colors_the_leaf_type_name() {
+    let ty = Type::Primitive("u8".to_string());
+    let rendered = type_display_styled(&ty, &Palette::ansi());
+
+    assert_eq!(rendered, "\x1b[32mu8\x1b[0m");
+}
+
+#[test]
+fn ansi_palette_colors_pointer_and_mut_keyword_separately_with_no_leaked_state() {
+    let ty = Type::RawPointer {
+        is_mutable: true,
+        type_: Box::new(Type::Primitive("u8".to_string())),
+    };
+    let rendered = type_display_styled(&ty, &Palette::ansi());
+
+    // pointer symbol, mut keyword, and leaf type name each carry their own
+    // reset so no color bleeds from one segment into the next.
+    assert_eq!(
+        rendered,
+        format!(
+            "\x1b[36m*\x1b[0m\x1b[1;33mmut\x1b[0m \x1b[32mu8\x1b[0m",
+        )
+    );
+}
+
+#[test]
+fn custom_palette_colors_are_honored() {
+    let palette = Palette {
+        pointer_color: Some(AnsiColor::Magenta),
+        pointer_bold: true,
+        ..Palette::plain()
+    };
+    let ty = Type::BorrowedRef {
+        lifetime: None,
+        is_mutable: false,
+        type_: Box::new(Type::Primitive("str".to_string())),
+    };
+    let rendered = type_display_styled(&ty, &palette);
+
+    assert_eq!(rendered, "\x1b[1;35m&\x1b[0mstr");
+}
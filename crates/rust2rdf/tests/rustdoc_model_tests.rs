@@ -218,16 +218,39 @@ fn test_item_function_with_signature() {
 
 #[test]
 fn test_unknown_item_kind_falls_through() {
-    // With externally tagged enums, serde(other) catches unrecognized tags
-    // when the value is absent or null. For tags with object values, serde
-    // cannot deserialize into the unit Unknown variant. This test verifies
-    // the behavior when the inner field is absent entirely (defaults to Unknown).
+    // A tag not present on ItemEnum at all -- including its object payload,
+    // which `serde(other)` could never have captured -- still deserializes,
+    // keeping the tag name and raw value instead of being lost or erroring.
+    let json = r#"{
+        "name": "something",
+        "inner": {
+            "async_fn": { "body": "..." }
+        }
+    }"#;
+    let item: Item = serde_json::from_str(json).unwrap();
+    assert_eq!(item.name.as_deref(), Some("something"));
+    match item.inner {
+        ItemEnum::Unknown { tag, value } => {
+            assert_eq!(tag, "async_fn");
+            assert_eq!(value, serde_json::json!({ "body": "..." }));
+        }
+        other => panic!("Expected Unknown, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_unknown_item_kind_absent_inner() {
+    // When `inner` is absent entirely, the item still defaults to Unknown,
+    // now with an empty tag rather than a bare unit variant.
     let json = r#"{
         "name": "something"
     }"#;
     let item: Item = serde_json::from_str(json).unwrap();
     assert_eq!(item.name.as_deref(), Some("something"));
-    assert!(matches!(item.inner, ItemEnum::Unknown));
+    match item.inner {
+        ItemEnum::Unknown { tag, .. } => assert_eq!(tag, ""),
+        other => panic!("Expected Unknown, got {other:?}"),
+    }
 }
 
 #[test]
@@ -332,11 +355,24 @@ fn test_type_variants() {
     let ty: Type = serde_json::from_str(json).unwrap();
     assert!(matches!(&ty, Type::Generic(s) if s == "T"));
 
-    // Unknown type kind (forward compatibility)
-    // With externally tagged enums, serde(other) catches unrecognized tags
-    // only when the value is absent. For types, we test via the Default impl.
+    // Unknown type kind (forward compatibility), including an object payload
+    // -- kept as the raw tag/value rather than lost.
+    let json = r#"{ "pat_type": { "pattern": "x" } }"#;
+    let ty: Type = serde_json::from_str(json).unwrap();
+    match ty {
+        Type::Unknown { tag, value } => {
+            assert_eq!(tag, "pat_type");
+            assert_eq!(value, serde_json::json!({ "pattern": "x" }));
+        }
+        other => panic!("Expected Unknown, got {other:?}"),
+    }
+
+    // The Default impl also falls back to Unknown, with an empty tag.
     let ty: Type = Default::default();
-    assert!(matches!(ty, Type::Unknown));
+    match ty {
+        Type::Unknown { tag, .. } => assert_eq!(tag, ""),
+        other => panic!("Expected Unknown, got {other:?}"),
+    }
 }
 
 #[test]
@@ -534,3 +570,103 @@ fn test_resolved_path_with_name_field() {
         other => panic!("Expected ResolvedPath, got {other:?}"),
     }
 }
+
+#[test]
+fn test_item_extern_crate() {
+    let json = r#"{
+        "name": "serde",
+        "visibility": "public",
+        "inner": {
+            "extern_crate": { "name": "serde", "rename": "serde_renamed" }
+        }
+    }"#;
+    let item: Item = serde_json::from_str(json).unwrap();
+    match &item.inner {
+        ItemEnum::ExternCrate { name, rename } => {
+            assert_eq!(name, "serde");
+            assert_eq!(rename.as_deref(), Some("serde_renamed"));
+        }
+        other => panic!("Expected ExternCrate, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_item_trait_alias() {
+    let json = r#"{
+        "name": "MyAlias",
+        "visibility": "public",
+        "inner": {
+            "trait_alias": {
+                "generics": { "params": [], "where_predicates": [] },
+                "params": [{ "trait_bound": { "trait": { "path": "Clone" }, "generic_params": [], "modifier": "none" } }]
+            }
+        }
+    }"#;
+    let item: Item = serde_json::from_str(json).unwrap();
+    match &item.inner {
+        ItemEnum::TraitAlias { params, .. } => {
+            assert_eq!(params.len(), 1);
+        }
+        other => panic!("Expected TraitAlias, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_item_proc_macro() {
+    let json = r#"{
+        "name": "MyDerive",
+        "visibility": "public",
+        "inner": {
+            "proc_macro": { "kind": "derive", "helpers": ["skip"] }
+        }
+    }"#;
+    let item: Item = serde_json::from_str(json).unwrap();
+    match &item.inner {
+        ItemEnum::ProcMacro { kind, helpers } => {
+            assert!(matches!(kind, MacroKind::Derive));
+            assert_eq!(helpers, &vec!["skip".to_string()]);
+        }
+        other => panic!("Expected ProcMacro, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_item_extern_type() {
+    let json = r#"{
+        "name": "OpaqueHandle",
+        "visibility": "public",
+        "inner": "extern_type"
+    }"#;
+    let item: Item = serde_json::from_str(json).unwrap();
+    assert!(matches!(item.inner, ItemEnum::ExternType));
+}
+
+#[test]
+fn test_item_primitive() {
+    let json = r#"{
+        "name": "i32",
+        "visibility": "public",
+        "inner": {
+            "primitive": { "name": "i32", "impls": [40] }
+        }
+    }"#;
+    let item: Item = serde_json::from_str(json).unwrap();
+    match &item.inner {
+        ItemEnum::Primitive { name, impls } => {
+            assert_eq!(name, "i32");
+            assert_eq!(impls.len(), 1);
+        }
+        other => panic!("Expected Primitive, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_item_keyword() {
+    let json = r#"{
+        "name": "match",
+        "visibility": "public",
+        "inner": "keyword"
+    }"#;
+    let item: Item = serde_json::from_str(json).unwrap();
+    assert!(matches!(item.inner, ItemEnum::Keyword));
+}
@@ -0,0 +1,147 @@
+//! Golden-file snapshot harness.
+//!
+//! Every `*.json` rustdoc fixture under `tests/fixtures/` is run through the
+//! `rust2rdf` binary in `--canonical` mode for each output format, and the
+//! result is compared against a checked-in `tests/fixtures/<name>.<fmt>.expected`
+//! golden file. This catches any serialization regression without having to
+//! hand-write an `assert!` per fixture.
+//!
+//! Set `RUST2RDF_BLESS=1` to (re)write the golden files from the current
+//! output instead of asserting against them -- the workflow for adding a new
+//! fixture is: drop a `.json` file in `tests/fixtures/`, run
+//! `RUST2RDF_BLESS=1 cargo test --test snapshot_tests`, then check in the
+//! generated `.expected` files.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use rust2rdf::diff::diff_lines;
+
+const FORMATS: &[&str] = &["ntriples", "turtle"];
+
+fn binary_path() -> PathBuf {
+    let mut path = std::env::current_exe()
+        .expect("current_exe")
+        .parent()
+        .expect("parent")
+        .parent()
+        .expect("grandparent")
+        .to_path_buf();
+    path.push("rust2rdf");
+    path
+}
+
+fn fixtures_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+}
+
+/// All `*.json` fixtures under `tests/fixtures/`, sorted for determinism.
+fn discover_fixtures() -> Vec<PathBuf> {
+    let dir = fixtures_dir();
+    let mut fixtures: Vec<PathBuf> = match fs::read_dir(&dir) {
+        Ok(entries) => entries
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p.extension().is_some_and(|ext| ext == "json"))
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    fixtures.sort();
+    fixtures
+}
+
+fn golden_path(fixture: &Path, format: &str) -> PathBuf {
+    let stem = fixture
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .expect("fixture file stem");
+    fixture.with_file_name(format!("{stem}.{format}.expected"))
+}
+
+fn extract(fixture: &Path, format: &str) -> String {
+    let output = Command::new(binary_path())
+        .args([
+            "--json",
+            fixture.to_str().expect("fixture path is valid UTF-8"),
+            "--format",
+            format,
+            "--canonical",
+            "-q",
+        ])
+        .output()
+        .expect("failed to execute rust2rdf binary");
+
+    assert!(
+        output.status.success(),
+        "rust2rdf failed on {}: {}",
+        fixture.display(),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    String::from_utf8(output.stdout).expect("rust2rdf produced invalid UTF-8")
+}
+
+fn lines_of(text: &str) -> Vec<String> {
+    text.lines().map(str::to_string).collect()
+}
+
+fn print_unified_diff(golden: &Path, expected: &str, actual: &str) {
+    let delta = diff_lines(&lines_of(expected), &lines_of(actual));
+    eprintln!("--- {} (expected)", golden.display());
+    eprintln!("+++ (actual)");
+    for line in &delta.removed {
+        eprintln!("-{line}");
+    }
+    for line in &delta.added {
+        eprintln!("+{line}");
+    }
+}
+
+#[test]
+fn golden_files_match_extraction_output() {
+    let bless = std::env::var("RUST2RDF_BLESS").as_deref() == Ok("1");
+    let fixtures = discover_fixtures();
+
+    if fixtures.is_empty() {
+        // No fixtures checked in yet -- nothing to snapshot.
+        return;
+    }
+
+    let mut mismatches = Vec::new();
+
+    for fixture in &fixtures {
+        for &format in FORMATS {
+            let actual = extract(fixture, format);
+            let golden = golden_path(fixture, format);
+
+            if bless {
+                fs::write(&golden, &actual).unwrap_or_else(|e| {
+                    panic!("failed to write golden file {}: {e}", golden.display())
+                });
+                continue;
+            }
+
+            let expected = fs::read_to_string(&golden).unwrap_or_else(|e| {
+                panic!(
+                    "missing golden file {} (run with RUST2RDF_BLESS=1 to create it): {e}",
+                    golden.display()
+                )
+            });
+
+            if expected != actual {
+                print_unified_diff(&golden, &expected, &actual);
+                mismatches.push(golden);
+            }
+        }
+    }
+
+    assert!(
+        mismatches.is_empty(),
+        "{} golden file(s) out of date (run with RUST2RDF_BLESS=1 to update): {:?}",
+        mismatches.len(),
+        mismatches
+    );
+}
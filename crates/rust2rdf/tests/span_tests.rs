@@ -0,0 +1,123 @@
+//! Integration tests for `span`-derived source-location triples.
+//!
+//! Uses a small hand-written rustdoc JSON crate (rather than
+//! `fixture_crate.json`) so the presence/absence of a `span` on individual
+//! items can be pinned down explicitly.
+
+use rust2rdf::emitter::ntriples::NTriplesEmitter;
+use rust2rdf::emitter::TriplesEmitter;
+use rust2rdf::extraction::extractor::{CrateExtractor, ExtractionOptions};
+use rust2rdf::extraction::rustdoc_model::Crate;
+
+const CRATE_JSON: &str = r#"
+{
+  "root": "0",
+  "crate_version": "0.1.0",
+  "format_version": 35,
+  "external_crates": {},
+  "paths": {},
+  "index": {
+    "0": {
+      "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+      "inner": { "module": { "items": ["1", "2"] } }
+    },
+    "1": {
+      "id": "1", "name": "Widget", "visibility": "public", "attrs": [],
+      "span": { "filename": "src/lib.rs", "begin": [10, 0], "end": [14, 1] },
+      "inner": { "struct": {} }
+    },
+    "2": {
+      "id": "2", "name": "NoSpan", "visibility": "public", "attrs": [],
+      "inner": { "struct": {} }
+    }
+  }
+}
+"#;
+
+fn load() -> Crate {
+    serde_json::from_str(CRATE_JSON).expect("fixture JSON should parse")
+}
+
+fn extract(opts: ExtractionOptions) -> String {
+    let krate = load();
+    let mut buf = Vec::new();
+    {
+        let mut emitter = NTriplesEmitter::new(&mut buf);
+        let mut extractor = CrateExtractor::new(&mut emitter, &krate, opts);
+        extractor.extract();
+        emitter.flush().unwrap();
+    }
+    String::from_utf8(buf).expect("Invalid UTF-8 in NTriples output")
+}
+
+fn has_literal_triple(output: &str, subject: &str, predicate: &str, value: &str) -> bool {
+    let expected = format!("<{subject}> <{predicate}> \"{value}\" .");
+    output.lines().any(|line| line.trim() == expected)
+}
+
+fn has_int_triple(output: &str, subject: &str, predicate: &str, value: i64) -> bool {
+    let expected = format!(
+        "<{subject}> <{predicate}> \"{value}\"^^<http://www.w3.org/2001/XMLSchema#integer> ."
+    );
+    output.lines().any(|line| line.trim() == expected)
+}
+
+const BASE: &str = "http://rust.example";
+
+// `path` is always a root-level item here, so its full path is
+// "mycrate::<path>" -- `IriMinter::type_iri` percent-encodes the whole
+// thing, turning `::` into `%3A%3A`.
+fn type_iri(path: &str) -> String {
+    format!("{BASE}/type/mycrate/0.1.0/mycrate%3A%3A{path}")
+}
+
+#[test]
+fn item_with_span_gets_location_triples() {
+    let out = extract(ExtractionOptions::default());
+    let widget = type_iri("Widget");
+
+    assert!(has_literal_triple(
+        &out,
+        &widget,
+        "http://typegraph.example/ontology/definedInFile",
+        "src/lib.rs"
+    ));
+    assert!(has_int_triple(
+        &out,
+        &widget,
+        "http://rust.example/ontology/lineStart",
+        10
+    ));
+    assert!(has_int_triple(
+        &out,
+        &widget,
+        "http://rust.example/ontology/lineEnd",
+        14
+    ));
+}
+
+#[test]
+fn item_without_span_emits_no_location_triples() {
+    let out = extract(ExtractionOptions::default());
+    let no_span = type_iri("NoSpan");
+
+    assert!(!out.contains(&format!(
+        "<{no_span}> <http://typegraph.example/ontology/definedInFile>"
+    )));
+    assert!(!out.contains(&format!(
+        "<{no_span}> <http://rust.example/ontology/lineStart>"
+    )));
+}
+
+#[test]
+fn spans_can_be_disabled() {
+    let out = extract(ExtractionOptions {
+        include_spans: false,
+        ..ExtractionOptions::default()
+    });
+    let widget = type_iri("Widget");
+
+    assert!(!out.contains(&format!(
+        "<{widget}> <http://typegraph.example/ontology/definedInFile>"
+    )));
+}
@@ -0,0 +1,199 @@
+//! Integration tests for `ExtractionOptions::extract_specialization`: the
+//! `isDefaultImpl`/`isSpecializable`/`specializes` triples modeled after
+//! Rust's `default impl`/`default fn` specialization feature. Uses a small
+//! hand-written rustdoc JSON crate so the specificity ordering between a
+//! blanket impl and a concrete impl of the same trait can be pinned down
+//! precisely.
+
+use rust2rdf::emitter::ntriples::NTriplesEmitter;
+use rust2rdf::emitter::TriplesEmitter;
+use rust2rdf::extraction::extractor::{CrateExtractor, ExtractionOptions};
+use rust2rdf::extraction::rustdoc_model::Crate;
+
+const CRATE_JSON: &str = r#"
+{
+  "root": "0",
+  "crate_version": "0.1.0",
+  "format_version": 35,
+  "external_crates": {},
+  "paths": {},
+  "index": {
+    "0": {
+      "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+      "inner": { "module": { "items": ["1", "2", "3", "4", "5", "21"] } }
+    },
+    "1": {
+      "id": "1", "name": "MyTrait", "visibility": "public", "attrs": [],
+      "inner": { "trait": {
+        "generics": { "params": [], "where_predicates": [] },
+        "bounds": [], "items": [], "is_auto": false, "is_unsafe": false
+      } }
+    },
+    "2": {
+      "id": "2", "name": "HasClone", "visibility": "public", "attrs": [],
+      "inner": { "struct": { "kind": "unit", "generics": { "params": [], "where_predicates": [] }, "impls": ["21", "5"] } }
+    },
+    "3": {
+      "id": "3", "name": "NoClone", "visibility": "public", "attrs": [],
+      "inner": { "struct": { "kind": "unit", "generics": { "params": [], "where_predicates": [] }, "impls": [] } }
+    },
+    "21": {
+      "id": "21", "name": null, "visibility": "default", "attrs": [],
+      "inner": { "impl": {
+        "generics": { "params": [], "where_predicates": [] },
+        "trait": { "path": "Clone", "id": null },
+        "for": { "resolved_path": { "path": "HasClone", "id": "2" } },
+        "items": [],
+        "is_unsafe": false, "is_negative": false, "is_synthetic": false
+      } }
+    },
+    "4": {
+      "id": "4", "name": null, "visibility": "default", "attrs": [],
+      "inner": { "impl": {
+        "generics": {
+          "params": [
+            { "name": "T", "kind": { "type": { "bounds": [
+              { "trait_bound": { "trait": { "path": "Clone", "id": null } } }
+            ], "default": null, "is_synthetic": false } } }
+          ],
+          "where_predicates": []
+        },
+        "trait": { "path": "MyTrait", "id": "1" },
+        "for": { "generic": "T" },
+        "items": ["40"],
+        "is_unsafe": false, "is_negative": false, "is_synthetic": false
+      } }
+    },
+    "40": {
+      "id": "40", "name": "speak", "visibility": "default", "attrs": [],
+      "inner": { "function": {
+        "sig": {
+          "inputs": [["self", { "borrowed_ref": { "is_mutable": false, "type": { "generic": "Self" } } }]],
+          "output": null
+        },
+        "generics": { "params": [], "where_predicates": [] },
+        "header": { "is_const": false, "is_unsafe": false, "is_async": false },
+        "is_default": true
+      } }
+    },
+    "5": {
+      "id": "5", "name": null, "visibility": "default", "attrs": [],
+      "inner": { "impl": {
+        "generics": { "params": [], "where_predicates": [] },
+        "trait": { "path": "MyTrait", "id": "1" },
+        "for": { "resolved_path": { "path": "HasClone", "id": "2" } },
+        "items": ["50"],
+        "is_unsafe": false, "is_negative": false, "is_synthetic": false
+      } }
+    },
+    "50": {
+      "id": "50", "name": "speak", "visibility": "default", "attrs": [],
+      "inner": { "function": {
+        "sig": {
+          "inputs": [["self", { "borrowed_ref": { "is_mutable": false, "type": { "generic": "Self" } } }]],
+          "output": null
+        },
+        "generics": { "params": [], "where_predicates": [] },
+        "header": { "is_const": false, "is_unsafe": false, "is_async": false },
+        "is_default": false
+      } }
+    }
+  }
+}
+"#;
+
+fn load() -> Crate {
+    serde_json::from_str(CRATE_JSON).expect("fixture JSON should parse")
+}
+
+fn extract(opts: ExtractionOptions) -> String {
+    let krate = load();
+    let mut buf = Vec::new();
+    {
+        let mut emitter = NTriplesEmitter::new(&mut buf);
+        let mut extractor = CrateExtractor::new(&mut emitter, &krate, opts);
+        extractor.extract();
+        emitter.flush().unwrap();
+    }
+    String::from_utf8(buf).expect("Invalid UTF-8 in NTriples output")
+}
+
+fn has_iri_triple(output: &str, subject: &str, predicate: &str, object: &str) -> bool {
+    let expected = format!("<{subject}> <{predicate}> <{object}> .");
+    output.lines().any(|line| line.trim() == expected)
+}
+
+fn has_bool_triple(output: &str, subject: &str, predicate: &str, value: bool) -> bool {
+    let val = if value { "true" } else { "false" };
+    let expected = format!(
+        "<{subject}> <{predicate}> \"{val}\"^^<http://www.w3.org/2001/XMLSchema#boolean> ."
+    );
+    output.lines().any(|line| line.trim() == expected)
+}
+
+const BASE: &str = "http://rust.example";
+
+fn rt(local: &str) -> String {
+    format!("http://rust.example/ontology/{local}")
+}
+
+fn impl_iri(id: &str) -> String {
+    format!("{BASE}/impl/mycrate/0.1.0/{id}")
+}
+
+#[test]
+fn blanket_impl_with_only_default_items_is_a_default_impl() {
+    let out = extract(ExtractionOptions::default());
+    let blanket_impl = impl_iri("4");
+    assert!(has_bool_triple(&out, &blanket_impl, &rt("isDefaultImpl"), true));
+
+    let speak_iri = format!("{blanket_impl}/member/speak");
+    assert!(has_bool_triple(&out, &speak_iri, &rt("isSpecializable"), true));
+}
+
+#[test]
+fn concrete_impl_with_a_non_default_item_is_not_a_default_impl() {
+    let out = extract(ExtractionOptions::default());
+    let concrete_impl = impl_iri("5");
+    assert!(!out.contains(&format!(
+        "<{concrete_impl}> <{}>",
+        rt("isDefaultImpl")
+    )));
+
+    let speak_iri = format!("{concrete_impl}/member/speak");
+    assert!(!out.contains(&format!("<{speak_iri}> <{}>", rt("isSpecializable"))));
+}
+
+#[test]
+fn concrete_impl_specializes_the_blanket_impl_whose_bound_it_satisfies() {
+    let out = extract(ExtractionOptions::default());
+    let concrete_impl = impl_iri("5");
+    let blanket_impl = impl_iri("4");
+
+    assert!(has_iri_triple(
+        &out,
+        &concrete_impl,
+        &rt("specializes"),
+        &blanket_impl
+    ));
+    // The ordering is one-directional: the broader blanket impl does not
+    // specialize the narrower concrete one.
+    assert!(!has_iri_triple(
+        &out,
+        &blanket_impl,
+        &rt("specializes"),
+        &concrete_impl
+    ));
+}
+
+#[test]
+fn disabling_extract_specialization_suppresses_all_specialization_triples() {
+    let opts = ExtractionOptions {
+        extract_specialization: false,
+        ..ExtractionOptions::default()
+    };
+    let out = extract(opts);
+    assert!(!out.contains(&rt("isDefaultImpl")));
+    assert!(!out.contains(&rt("isSpecializable")));
+    assert!(!out.contains(&rt("specializes")));
+}
@@ -0,0 +1,263 @@
+//! Integration tests for deprecation and stability-level extraction.
+//!
+//! Uses a small hand-written rustdoc JSON crate (rather than
+//! `fixture_crate.json`) so deprecation/stability combinations can be pinned
+//! down explicitly.
+
+use rust2rdf::emitter::ntriples::NTriplesEmitter;
+use rust2rdf::emitter::TriplesEmitter;
+use rust2rdf::extraction::extractor::{CrateExtractor, ExtractionOptions};
+use rust2rdf::extraction::rustdoc_model::Crate;
+
+const CRATE_JSON: &str = r##"
+{
+  "root": "0",
+  "crate_version": "0.1.0",
+  "format_version": 35,
+  "external_crates": {},
+  "paths": {},
+  "index": {
+    "0": {
+      "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+      "inner": { "module": { "items": ["1", "2", "3", "4", "5"] } }
+    },
+    "1": {
+      "id": "1", "name": "OldStruct", "visibility": "public",
+      "attrs": [],
+      "deprecation": { "since": "1.2.0", "note": "use NewStruct instead" },
+      "inner": { "struct": {} }
+    },
+    "2": {
+      "id": "2", "name": "UnstableStruct", "visibility": "public",
+      "attrs": ["#[unstable(feature = \"fancy_struct\", issue = \"12345\")]"],
+      "inner": { "struct": {} }
+    },
+    "3": {
+      "id": "3", "name": "PlainStruct", "visibility": "public", "attrs": [],
+      "inner": { "struct": {} }
+    },
+    "4": {
+      "id": "4", "name": "StableStruct", "visibility": "public",
+      "attrs": ["#[stable(feature = \"stable_struct\", since = \"1.0.0\")]"],
+      "inner": { "struct": {} }
+    },
+    "5": {
+      "id": "5", "name": "WithDeprecatedField", "visibility": "public",
+      "attrs": [],
+      "inner": { "struct": {
+        "kind": { "plain": { "fields": ["51"], "has_stripped_fields": false } }
+      } }
+    },
+    "51": {
+      "id": "51", "name": "old_field", "visibility": "public", "attrs": [],
+      "deprecation": { "since": "2.0.0", "note": "use new_field instead" },
+      "inner": { "struct_field": { "primitive": "i32" } }
+    }
+  }
+}
+"##;
+
+fn load() -> Crate {
+    serde_json::from_str(CRATE_JSON).expect("fixture JSON should parse")
+}
+
+fn extract() -> String {
+    let krate = load();
+    let mut buf = Vec::new();
+    {
+        let mut emitter = NTriplesEmitter::new(&mut buf);
+        let mut extractor =
+            CrateExtractor::new(&mut emitter, &krate, ExtractionOptions::default());
+        extractor.extract();
+        emitter.flush().unwrap();
+    }
+    String::from_utf8(buf).expect("Invalid UTF-8 in NTriples output")
+}
+
+fn has_literal_triple(output: &str, subject: &str, predicate: &str, value: &str) -> bool {
+    let expected = format!("<{subject}> <{predicate}> \"{value}\" .");
+    output.lines().any(|line| line.trim() == expected)
+}
+
+fn has_bool_triple(output: &str, subject: &str, predicate: &str, value: bool) -> bool {
+    let val = if value { "true" } else { "false" };
+    let expected = format!(
+        "<{subject}> <{predicate}> \"{val}\"^^<http://www.w3.org/2001/XMLSchema#boolean> ."
+    );
+    output.lines().any(|line| line.trim() == expected)
+}
+
+const BASE: &str = "http://rust.example";
+
+// Each `path` here is a root-level item, so its full path is
+// "mycrate::<path>", which `IriMinter::type_iri` percent-encodes.
+fn type_iri(path: &str) -> String {
+    format!("{BASE}/type/mycrate/0.1.0/mycrate%3A%3A{path}")
+}
+
+#[test]
+fn deprecated_item_gets_deprecation_triples() {
+    let out = extract();
+    let old_struct = type_iri("OldStruct");
+
+    assert!(has_bool_triple(
+        &out,
+        &old_struct,
+        "http://rust.example/ontology/deprecated",
+        true
+    ));
+    assert!(has_literal_triple(
+        &out,
+        &old_struct,
+        "http://rust.example/ontology/deprecatedSince",
+        "1.2.0"
+    ));
+    assert!(has_literal_triple(
+        &out,
+        &old_struct,
+        "http://rust.example/ontology/deprecationNote",
+        "use NewStruct instead"
+    ));
+}
+
+#[test]
+fn unstable_attr_emits_stability_level_and_feature_gate() {
+    let out = extract();
+    let unstable_struct = type_iri("UnstableStruct");
+
+    assert!(has_literal_triple(
+        &out,
+        &unstable_struct,
+        "http://rust.example/ontology/stabilityLevel",
+        "unstable"
+    ));
+    assert!(has_literal_triple(
+        &out,
+        &unstable_struct,
+        "http://rust.example/ontology/featureGate",
+        "fancy_struct"
+    ));
+}
+
+#[test]
+fn stability_and_deprecation_are_mirrored_under_the_shared_tg_predicates() {
+    let out = extract();
+    let old_struct = type_iri("OldStruct");
+    let unstable_struct = type_iri("UnstableStruct");
+    let stable_struct = type_iri("StableStruct");
+
+    assert!(has_bool_triple(
+        &out,
+        &old_struct,
+        "http://typegraph.example/ontology/deprecated",
+        true
+    ));
+    assert!(has_literal_triple(
+        &out,
+        &old_struct,
+        "http://typegraph.example/ontology/deprecatedSince",
+        "1.2.0"
+    ));
+    assert!(has_literal_triple(
+        &out,
+        &unstable_struct,
+        "http://typegraph.example/ontology/stability",
+        "Unstable"
+    ));
+    assert!(has_literal_triple(
+        &out,
+        &unstable_struct,
+        "http://typegraph.example/ontology/unstableFeature",
+        "fancy_struct"
+    ));
+    assert!(has_literal_triple(
+        &out,
+        &stable_struct,
+        "http://typegraph.example/ontology/stability",
+        "Stable"
+    ));
+    assert!(has_literal_triple(
+        &out,
+        &stable_struct,
+        "http://typegraph.example/ontology/stableSince",
+        "1.0.0"
+    ));
+}
+
+#[test]
+fn stable_since_is_also_emitted_under_the_rt_predicate() {
+    let out = extract();
+    let stable_struct = type_iri("StableStruct");
+
+    assert!(has_literal_triple(
+        &out,
+        &stable_struct,
+        "http://rust.example/ontology/stableSince",
+        "1.0.0"
+    ));
+}
+
+#[test]
+fn extract_stability_false_suppresses_all_stability_and_deprecation_triples() {
+    let krate = load();
+    let mut buf = Vec::new();
+    {
+        let mut emitter = NTriplesEmitter::new(&mut buf);
+        let options = ExtractionOptions {
+            extract_stability: false,
+            ..ExtractionOptions::default()
+        };
+        let mut extractor = CrateExtractor::new(&mut emitter, &krate, options);
+        extractor.extract();
+        emitter.flush().unwrap();
+    }
+    let out = String::from_utf8(buf).expect("Invalid UTF-8 in NTriples output");
+    let old_struct = type_iri("OldStruct");
+    let unstable_struct = type_iri("UnstableStruct");
+
+    assert!(!out.contains(&format!(
+        "<{old_struct}> <http://rust.example/ontology/deprecated>"
+    )));
+    assert!(!out.contains(&format!(
+        "<{unstable_struct}> <http://rust.example/ontology/stabilityLevel>"
+    )));
+}
+
+#[test]
+fn deprecated_struct_field_gets_deprecation_triples() {
+    let out = extract();
+    let with_deprecated_field = type_iri("WithDeprecatedField");
+    let old_field = format!("{with_deprecated_field}/member/old_field");
+
+    assert!(has_bool_triple(
+        &out,
+        &old_field,
+        "http://rust.example/ontology/deprecated",
+        true
+    ));
+    assert!(has_literal_triple(
+        &out,
+        &old_field,
+        "http://rust.example/ontology/deprecatedSince",
+        "2.0.0"
+    ));
+    assert!(has_literal_triple(
+        &out,
+        &old_field,
+        "http://rust.example/ontology/deprecationNote",
+        "use new_field instead"
+    ));
+}
+
+#[test]
+fn plain_item_gets_no_deprecation_or_stability_triples() {
+    let out = extract();
+    let plain_struct = type_iri("PlainStruct");
+
+    assert!(!out.contains(&format!(
+        "<{plain_struct}> <http://rust.example/ontology/deprecated>"
+    )));
+    assert!(!out.contains(&format!(
+        "<{plain_struct}> <http://rust.example/ontology/stabilityLevel>"
+    )));
+}
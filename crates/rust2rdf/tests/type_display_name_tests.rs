@@ -0,0 +1,125 @@
+//! Integration tests for `type_display_name` coverage of generic
+//! instantiations, function pointers, and trait objects, exercised
+//! indirectly through the IRIs `resolve_type_to_iri` mints from them (the
+//! function itself is private to the extraction module).
+
+use rust2rdf::emitter::ntriples::NTriplesEmitter;
+use rust2rdf::emitter::TriplesEmitter;
+use rust2rdf::extraction::extractor::{CrateExtractor, ExtractionOptions};
+use rust2rdf::extraction::rustdoc_model::Crate;
+
+const CRATE_JSON: &str = r#"
+{
+  "root": "0",
+  "crate_version": "0.1.0",
+  "format_version": 35,
+  "external_crates": {},
+  "paths": {},
+  "index": {
+    "0": {
+      "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+      "inner": { "module": { "items": ["1"] } }
+    },
+    "1": {
+      "id": "1", "name": "take_shapes", "visibility": "public", "attrs": [],
+      "inner": { "function": {
+        "sig": {
+          "inputs": [
+            ["a", { "slice": { "dyn_trait": {
+              "traits": [{ "trait": { "path": "MyTrait", "id": null } }],
+              "lifetime": null
+            } } }],
+            ["b", { "raw_pointer": { "is_mutable": false, "type": { "function_pointer": {
+              "sig": { "inputs": [], "output": null },
+              "generic_params": [],
+              "header": { "is_const": false, "is_unsafe": false, "is_async": false }
+            } } } }],
+            ["c", { "array": { "type": { "resolved_path": {
+              "path": "Vec",
+              "id": null,
+              "args": { "angle_bracketed": {
+                "args": [{ "type": { "primitive": "i32" } }],
+                "constraints": []
+              } }
+            } }, "len": "3" } }]
+          ],
+          "output": null
+        },
+        "generics": { "params": [], "where_predicates": [] },
+        "header": { "is_const": false, "is_unsafe": false, "is_async": false }
+      } }
+    }
+  }
+}
+"#;
+
+fn load() -> Crate {
+    serde_json::from_str(CRATE_JSON).expect("fixture JSON should parse")
+}
+
+fn extract() -> String {
+    let krate = load();
+    let mut buf = Vec::new();
+    {
+        let mut emitter = NTriplesEmitter::new(&mut buf);
+        let mut extractor =
+            CrateExtractor::new(&mut emitter, &krate, ExtractionOptions::default());
+        extractor.extract();
+        emitter.flush().unwrap();
+    }
+    String::from_utf8(buf).expect("Invalid UTF-8 in NTriples output")
+}
+
+fn has_iri_triple(output: &str, subject: &str, predicate: &str, object: &str) -> bool {
+    let expected = format!("<{subject}> <{predicate}> <{object}> .");
+    output.lines().any(|line| line.trim() == expected)
+}
+
+const BASE: &str = "http://rust.example";
+
+fn fn_iri() -> String {
+    format!("{BASE}/module/mycrate/0.1.0/mycrate/member/take_shapes")
+}
+
+fn param_iri(ordinal: usize) -> String {
+    format!("{}/param/{ordinal}", fn_iri())
+}
+
+#[test]
+fn slice_of_dyn_trait_renders_its_full_display_name_in_the_iri() {
+    let out = extract();
+    let expected = format!("{BASE}/type/_slice_/dyn%20MyTrait");
+
+    assert!(has_iri_triple(
+        &out,
+        &param_iri(0),
+        "http://typegraph.example/ontology/parameterType",
+        &expected
+    ));
+}
+
+#[test]
+fn raw_pointer_to_a_bare_function_pointer_renders_fn_syntax_in_the_iri() {
+    let out = extract();
+    let expected = format!("{BASE}/type/_ptr_const_/fn%28%29");
+
+    assert!(has_iri_triple(
+        &out,
+        &param_iri(1),
+        "http://typegraph.example/ontology/parameterType",
+        &expected
+    ));
+}
+
+#[test]
+fn array_of_a_generic_instantiation_renders_angle_bracket_args_in_the_iri() {
+    let out = extract();
+    let expected = format!("{BASE}/type/_array_/Vec%3Ci32%3E/3");
+
+    assert!(has_iri_triple(
+        &out,
+        &param_iri(2),
+        "http://typegraph.example/ontology/parameterType",
+        &expected
+    ));
+}
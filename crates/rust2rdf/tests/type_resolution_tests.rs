@@ -0,0 +1,173 @@
+//! Integration tests for resolving trait objects, `impl Trait`, and
+//! qualified/associated-type paths in `resolve_type_to_iri`. Uses a small
+//! hand-written rustdoc JSON crate, since these type shapes are easiest to
+//! pin down explicitly rather than via the `fixture_crate.json` golden.
+
+use rust2rdf::emitter::ntriples::NTriplesEmitter;
+use rust2rdf::emitter::TriplesEmitter;
+use rust2rdf::extraction::extractor::{CrateExtractor, ExtractionOptions};
+use rust2rdf::extraction::rustdoc_model::Crate;
+
+const CRATE_JSON: &str = r#"
+{
+  "root": "0",
+  "crate_version": "0.1.0",
+  "format_version": 35,
+  "external_crates": {},
+  "paths": {},
+  "index": {
+    "0": {
+      "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+      "inner": { "module": { "items": ["1", "2", "3"] } }
+    },
+    "1": {
+      "id": "1", "name": "take_impl", "visibility": "public", "attrs": [],
+      "inner": { "function": {
+        "sig": {
+          "inputs": [["x", { "impl_trait": [
+            { "trait_bound": { "trait": { "path": "MyTrait", "id": null } } }
+          ] }]],
+          "output": null
+        },
+        "generics": { "params": [], "where_predicates": [] },
+        "header": { "is_const": false, "is_unsafe": false, "is_async": false }
+      } }
+    },
+    "2": {
+      "id": "2", "name": "make_dyn", "visibility": "public", "attrs": [],
+      "inner": { "function": {
+        "sig": {
+          "inputs": [],
+          "output": { "dyn_trait": {
+            "traits": [{ "trait": { "path": "MyTrait", "id": null } }],
+            "lifetime": null
+          } }
+        },
+        "generics": { "params": [], "where_predicates": [] },
+        "header": { "is_const": false, "is_unsafe": false, "is_async": false }
+      } }
+    },
+    "3": {
+      "id": "3", "name": "take_projection", "visibility": "public", "attrs": [],
+      "inner": { "function": {
+        "sig": {
+          "inputs": [["x", { "qualified_path": {
+            "name": "Item",
+            "self_type": { "resolved_path": { "path": "T", "id": null } },
+            "trait": { "path": "Iterator", "id": null }
+          } }]],
+          "output": null
+        },
+        "generics": { "params": [], "where_predicates": [] },
+        "header": { "is_const": false, "is_unsafe": false, "is_async": false }
+      } }
+    }
+  }
+}
+"#;
+
+fn load() -> Crate {
+    serde_json::from_str(CRATE_JSON).expect("fixture JSON should parse")
+}
+
+fn extract() -> String {
+    let krate = load();
+    let mut buf = Vec::new();
+    {
+        let mut emitter = NTriplesEmitter::new(&mut buf);
+        let mut extractor =
+            CrateExtractor::new(&mut emitter, &krate, ExtractionOptions::default());
+        extractor.extract();
+        emitter.flush().unwrap();
+    }
+    String::from_utf8(buf).expect("Invalid UTF-8 in NTriples output")
+}
+
+fn has_iri_triple(output: &str, subject: &str, predicate: &str, object: &str) -> bool {
+    let expected = format!("<{subject}> <{predicate}> <{object}> .");
+    output.lines().any(|line| line.trim() == expected)
+}
+
+const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+const BASE: &str = "http://rust.example";
+
+fn rt(local: &str) -> String {
+    format!("http://rust.example/ontology/{local}")
+}
+
+fn fn_iri(name: &str) -> String {
+    format!("{BASE}/module/mycrate/0.1.0/mycrate/member/{name}")
+}
+
+fn param_iri(fn_iri: &str, ordinal: usize) -> String {
+    format!("{fn_iri}/param/{ordinal}")
+}
+
+fn trait_type_iri(name: &str) -> String {
+    format!("{BASE}/type/mycrate/0.1.0/{name}")
+}
+
+#[test]
+fn impl_trait_parameter_gets_a_type_node_with_bound_edges() {
+    let out = extract();
+    let f = fn_iri("take_impl");
+    let param = param_iri(&f, 0);
+    let impl_iri = format!("{BASE}/type/_impl_/MyTrait");
+
+    assert!(has_iri_triple(
+        &out,
+        &param,
+        "http://typegraph.example/ontology/parameterType",
+        &impl_iri
+    ));
+    assert!(has_iri_triple(&out, &impl_iri, RDF_TYPE, "http://typegraph.example/ontology/Type"));
+    assert!(has_iri_triple(
+        &out,
+        &impl_iri,
+        &rt("implTraitBound"),
+        &trait_type_iri("MyTrait")
+    ));
+}
+
+#[test]
+fn dyn_trait_return_type_gets_a_type_node_with_bound_edges() {
+    let out = extract();
+    let f = fn_iri("make_dyn");
+    let dyn_iri = format!("{BASE}/type/_dyn_/MyTrait");
+
+    assert!(has_iri_triple(
+        &out,
+        &f,
+        "http://typegraph.example/ontology/returnType",
+        &dyn_iri
+    ));
+    assert!(has_iri_triple(&out, &dyn_iri, RDF_TYPE, "http://typegraph.example/ontology/Type"));
+    assert!(has_iri_triple(
+        &out,
+        &dyn_iri,
+        &rt("dynTraitBound"),
+        &trait_type_iri("MyTrait")
+    ));
+}
+
+#[test]
+fn qualified_path_parameter_gets_a_projection_node() {
+    let out = extract();
+    let f = fn_iri("take_projection");
+    let param = param_iri(&f, 0);
+    let self_iri = trait_type_iri("T");
+    let projection_iri = format!("{self_iri}/projection/Iterator/Item");
+
+    assert!(has_iri_triple(
+        &out,
+        &param,
+        "http://typegraph.example/ontology/parameterType",
+        &projection_iri
+    ));
+    assert!(has_iri_triple(
+        &out,
+        &projection_iri,
+        &rt("projectionTrait"),
+        &trait_type_iri("Iterator")
+    ));
+}
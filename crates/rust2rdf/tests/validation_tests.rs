@@ -0,0 +1,241 @@
+//! Integration tests for `extraction::validation::validate`: dangling `Id`
+//! references and `ItemKind` mismatches in a loaded `Crate`.
+
+use rust2rdf::extraction::rustdoc_model::Crate;
+use rust2rdf::extraction::validation::{validate, Severity};
+
+fn load(json: &str) -> Crate {
+    Crate::load(json.as_bytes()).expect("fixture crate should load")
+}
+
+#[test]
+fn clean_crate_has_no_validation_issues() {
+    let crate_data = load(
+        r#"
+        {
+          "root": "0",
+          "format_version": 35,
+          "index": {
+            "0": {
+              "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+              "inner": { "module": { "items": ["1"] } }
+            },
+            "1": {
+              "id": "1", "name": "Thing", "visibility": "public", "attrs": [],
+              "inner": { "struct": {
+                "kind": "unit",
+                "generics": { "params": [], "where_predicates": [] },
+                "impls": []
+              } }
+            }
+          }
+        }
+        "#,
+    );
+
+    assert!(validate(&crate_data).is_empty());
+}
+
+#[test]
+fn module_items_reference_to_a_missing_id_is_a_warning() {
+    let crate_data = load(
+        r#"
+        {
+          "root": "0",
+          "format_version": 35,
+          "index": {
+            "0": {
+              "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+              "inner": { "module": { "items": ["99"] } }
+            }
+          }
+        }
+        "#,
+    );
+
+    let issues = validate(&crate_data);
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].severity, Severity::Warning);
+    assert_eq!(issues[0].owner.0, "0");
+    assert_eq!(issues[0].reference.0, "99");
+    assert_eq!(issues[0].context, "module items");
+}
+
+#[test]
+fn module_items_pointing_at_a_struct_field_is_a_kind_error() {
+    let crate_data = load(
+        r#"
+        {
+          "root": "0",
+          "format_version": 35,
+          "index": {
+            "0": {
+              "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+              "inner": { "module": { "items": ["1"] } }
+            },
+            "1": {
+              "id": "1", "name": "field", "visibility": "public", "attrs": [],
+              "inner": { "struct_field": { "primitive": "i32" } }
+            }
+          }
+        }
+        "#,
+    );
+
+    let issues = validate(&crate_data);
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].severity, Severity::Error);
+    assert_eq!(issues[0].context, "module items");
+}
+
+#[test]
+fn a_reference_resolved_via_paths_is_also_kind_checked() {
+    let crate_data = load(
+        r#"
+        {
+          "root": "0",
+          "format_version": 35,
+          "index": {
+            "0": {
+              "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+              "inner": { "struct": {
+                "kind": "unit",
+                "generics": { "params": [], "where_predicates": [] },
+                "impls": ["1"]
+              } }
+            }
+          },
+          "paths": {
+            "1": { "path": ["other", "Thing"], "kind": "struct" }
+          }
+        }
+        "#,
+    );
+
+    let issues = validate(&crate_data);
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].severity, Severity::Error);
+    assert_eq!(issues[0].context, "struct impls");
+}
+
+#[test]
+fn impl_trait_target_must_resolve_to_a_trait() {
+    let crate_data = load(
+        r#"
+        {
+          "root": "0",
+          "format_version": 35,
+          "index": {
+            "0": {
+              "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+              "inner": { "impl": {
+                "generics": { "params": [], "where_predicates": [] },
+                "trait": { "path": "NotATrait", "id": "1" },
+                "for": { "primitive": "i32" },
+                "items": [],
+                "is_unsafe": false,
+                "is_negative": false,
+                "is_synthetic": false
+              } }
+            },
+            "1": {
+              "id": "1", "name": "NotATrait", "visibility": "public", "attrs": [],
+              "inner": { "struct": {
+                "kind": "unit",
+                "generics": { "params": [], "where_predicates": [] },
+                "impls": []
+              } }
+            }
+          }
+        }
+        "#,
+    );
+
+    let issues = validate(&crate_data);
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].severity, Severity::Error);
+    assert_eq!(issues[0].context, "impl trait target");
+}
+
+#[test]
+fn restricted_visibility_parent_must_resolve_to_a_module() {
+    let crate_data = load(
+        r#"
+        {
+          "root": "0",
+          "format_version": 35,
+          "index": {
+            "0": {
+              "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+              "inner": { "module": { "items": [] } }
+            },
+            "1": {
+              "id": "1", "name": "restricted_thing", "attrs": [],
+              "visibility": { "restricted": { "parent": "2", "path": "crate::inner" } },
+              "inner": { "struct_field": { "primitive": "i32" } }
+            },
+            "2": {
+              "id": "2", "name": "inner", "visibility": "public", "attrs": [],
+              "inner": { "module": { "items": [] } }
+            }
+          }
+        }
+        "#,
+    );
+
+    assert!(validate(&crate_data).is_empty());
+}
+
+#[test]
+fn a_type_referencing_a_missing_id_is_found_through_a_function_signature() {
+    let crate_data = load(
+        r#"
+        {
+          "root": "0",
+          "format_version": 35,
+          "index": {
+            "0": {
+              "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+              "inner": { "function": {
+                "sig": {
+                  "inputs": [["x", { "resolved_path": { "path": "Ghost", "id": "404" } }]],
+                  "output": null
+                },
+                "generics": { "params": [], "where_predicates": [] },
+                "header": { "is_const": false, "is_unsafe": false, "is_async": false }
+              } }
+            }
+          }
+        }
+        "#,
+    );
+
+    let issues = validate(&crate_data);
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].severity, Severity::Warning);
+    assert_eq!(issues[0].context, "function parameter type");
+    assert_eq!(issues[0].reference.0, "404");
+}
+
+#[test]
+fn an_unrecognized_item_kind_is_an_info_issue_naming_the_tag() {
+    let crate_data = load(
+        r#"
+        {
+          "root": "0",
+          "format_version": 35,
+          "index": {
+            "0": {
+              "id": "0", "name": "something", "visibility": "public", "attrs": [],
+              "inner": { "async_fn": { "body": "..." } }
+            }
+          }
+        }
+        "#,
+    );
+
+    let issues = validate(&crate_data);
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].severity, Severity::Info);
+    assert!(issues[0].message.contains("async_fn"));
+}
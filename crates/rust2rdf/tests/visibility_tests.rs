@@ -0,0 +1,126 @@
+//! Integration tests for `VisibilityMode`-based filtering: `All` (no
+//! filtering), `DocReachable` (a private type still counts if it's reachable
+//! from a public signature), and `PublicApi` (additionally requires the item
+//! itself be `pub`). Uses a small hand-written rustdoc JSON crate so the
+//! reachability closure can be pinned down precisely.
+
+use rust2rdf::emitter::ntriples::NTriplesEmitter;
+use rust2rdf::emitter::TriplesEmitter;
+use rust2rdf::extraction::extractor::{CrateExtractor, ExtractionOptions, VisibilityMode};
+use rust2rdf::extraction::rustdoc_model::Crate;
+
+const CRATE_JSON: &str = r#"
+{
+  "root": "0",
+  "crate_version": "0.1.0",
+  "format_version": 35,
+  "external_crates": {},
+  "paths": {},
+  "index": {
+    "0": {
+      "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+      "inner": { "module": { "items": ["1", "2", "3", "4"] } }
+    },
+    "1": {
+      "id": "1", "name": "make", "visibility": "public", "attrs": [],
+      "inner": { "function": {
+        "sig": {
+          "inputs": [],
+          "output": { "resolved_path": { "path": "PrivateResult", "id": "2" } }
+        },
+        "generics": { "params": [], "where_predicates": [] },
+        "header": { "is_const": false, "is_unsafe": false, "is_async": false }
+      } }
+    },
+    "2": {
+      "id": "2", "name": "PrivateResult", "visibility": "default", "attrs": [],
+      "inner": { "struct": { "kind": "unit", "generics": { "params": [], "where_predicates": [] }, "impls": [] } }
+    },
+    "3": {
+      "id": "3", "name": "PrivateHelper", "visibility": "default", "attrs": [],
+      "inner": { "struct": { "kind": "unit", "generics": { "params": [], "where_predicates": [] }, "impls": [] } }
+    },
+    "4": {
+      "id": "4", "name": "PublicThing", "visibility": "public", "attrs": [],
+      "inner": { "struct": { "kind": "unit", "generics": { "params": [], "where_predicates": [] }, "impls": [] } }
+    }
+  }
+}
+"#;
+
+fn load() -> Crate {
+    serde_json::from_str(CRATE_JSON).expect("fixture JSON should parse")
+}
+
+fn extract(visibility: VisibilityMode) -> String {
+    let krate = load();
+    let mut buf = Vec::new();
+    {
+        let mut emitter = NTriplesEmitter::new(&mut buf);
+        let options = ExtractionOptions {
+            visibility,
+            ..ExtractionOptions::default()
+        };
+        let mut extractor = CrateExtractor::new(&mut emitter, &krate, options);
+        extractor.extract();
+        emitter.flush().unwrap();
+    }
+    String::from_utf8(buf).expect("Invalid UTF-8 in NTriples output")
+}
+
+const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+const BASE: &str = "http://rust.example";
+
+fn has_iri_triple(output: &str, subject: &str, predicate: &str, object: &str) -> bool {
+    let expected = format!("<{subject}> <{predicate}> <{object}> .");
+    output.lines().any(|line| line.trim() == expected)
+}
+
+// Each `path` here is a root-level item, so its full path is
+// "mycrate::<path>" -- `IriMinter::type_iri` percent-encodes the whole
+// thing, turning `::` into `%3A%3A`.
+fn type_iri(path: &str) -> String {
+    format!("{BASE}/type/mycrate/0.1.0/mycrate%3A%3A{path}")
+}
+
+fn is_struct_node(output: &str, path: &str) -> bool {
+    has_iri_triple(
+        output,
+        &type_iri(path),
+        RDF_TYPE,
+        "http://typegraph.example/ontology/Struct",
+    )
+}
+
+#[test]
+fn all_mode_includes_every_type_regardless_of_visibility() {
+    let out = extract(VisibilityMode::All);
+
+    assert!(is_struct_node(&out, "PrivateResult"));
+    assert!(is_struct_node(&out, "PrivateHelper"));
+    assert!(is_struct_node(&out, "PublicThing"));
+}
+
+#[test]
+fn doc_reachable_mode_keeps_a_private_type_referenced_from_a_public_signature() {
+    let out = extract(VisibilityMode::DocReachable);
+
+    assert!(is_struct_node(&out, "PrivateResult"));
+}
+
+#[test]
+fn doc_reachable_mode_drops_an_unreferenced_private_type() {
+    let out = extract(VisibilityMode::DocReachable);
+
+    assert!(!is_struct_node(&out, "PrivateHelper"));
+    assert!(is_struct_node(&out, "PublicThing"));
+}
+
+#[test]
+fn public_api_mode_also_drops_a_private_type_merely_leaked_into_a_signature() {
+    let out = extract(VisibilityMode::PublicApi);
+
+    assert!(!is_struct_node(&out, "PrivateResult"));
+    assert!(!is_struct_node(&out, "PrivateHelper"));
+    assert!(is_struct_node(&out, "PublicThing"));
+}
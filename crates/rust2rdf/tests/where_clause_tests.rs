@@ -0,0 +1,168 @@
+//! Integration tests for where-clause predicates: trait bounds and
+//! associated-type-equality constraints on types that aren't themselves a
+//! direct generic parameter, plus lifetime-outlives predicates. Uses a small
+//! hand-written rustdoc JSON crate, since these shapes are easiest to pin
+//! down explicitly rather than via the `fixture_crate.json` golden.
+
+use rust2rdf::emitter::ntriples::NTriplesEmitter;
+use rust2rdf::emitter::TriplesEmitter;
+use rust2rdf::extraction::extractor::{CrateExtractor, ExtractionOptions};
+use rust2rdf::extraction::rustdoc_model::Crate;
+
+const CRATE_JSON: &str = r#"
+{
+  "root": "0",
+  "crate_version": "0.1.0",
+  "format_version": 35,
+  "external_crates": {},
+  "paths": {},
+  "index": {
+    "0": {
+      "id": "0", "name": "mycrate", "visibility": "public", "attrs": [],
+      "inner": { "module": { "items": ["1"] } }
+    },
+    "1": {
+      "id": "1", "name": "process", "visibility": "public", "attrs": [],
+      "inner": { "function": {
+        "sig": {
+          "inputs": [],
+          "output": null
+        },
+        "generics": {
+          "params": [
+            { "name": "'a", "kind": { "lifetime": { "outlives": [] } } },
+            { "name": "'b", "kind": { "lifetime": { "outlives": [] } } },
+            { "name": "T", "kind": { "type": { "bounds": [], "default": null, "is_synthetic": false } } }
+          ],
+          "where_predicates": [
+            { "bound_predicate": {
+              "type": { "generic": "T" },
+              "bounds": [
+                { "trait_bound": { "trait": {
+                  "path": "Iterator",
+                  "id": null,
+                  "args": { "angle_bracketed": {
+                    "args": [],
+                    "constraints": [
+                      { "name": "Item", "args": null, "binding": { "equality": { "primitive": "u32" } } }
+                    ]
+                  } }
+                } } },
+                { "outlives": "'a" }
+              ],
+              "generic_params": []
+            } },
+            { "lifetime_predicate": { "lifetime": "'a", "outlives": ["'b"] } }
+          ]
+        },
+        "header": { "is_const": false, "is_unsafe": false, "is_async": false }
+      } }
+    }
+  }
+}
+"#;
+
+fn load() -> Crate {
+    serde_json::from_str(CRATE_JSON).expect("fixture JSON should parse")
+}
+
+fn extract() -> String {
+    let krate = load();
+    let mut buf = Vec::new();
+    {
+        let mut emitter = NTriplesEmitter::new(&mut buf);
+        let mut extractor =
+            CrateExtractor::new(&mut emitter, &krate, ExtractionOptions::default());
+        extractor.extract();
+        emitter.flush().unwrap();
+    }
+    String::from_utf8(buf).expect("Invalid UTF-8 in NTriples output")
+}
+
+fn has_iri_triple(output: &str, subject: &str, predicate: &str, object: &str) -> bool {
+    let expected = format!("<{subject}> <{predicate}> <{object}> .");
+    output.lines().any(|line| line.trim() == expected)
+}
+
+fn has_literal_triple(output: &str, subject: &str, predicate: &str, value: &str) -> bool {
+    let expected = format!("<{subject}> <{predicate}> \"{value}\" .");
+    output.lines().any(|line| line.trim() == expected)
+}
+
+const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+const BASE: &str = "http://rust.example";
+
+fn rt(local: &str) -> String {
+    format!("http://rust.example/ontology/{local}")
+}
+
+fn fn_iri() -> String {
+    format!("{BASE}/module/mycrate/0.1.0/mycrate/member/process")
+}
+
+fn type_param_iri(ordinal: usize) -> String {
+    format!("{}/typeparam/{ordinal}", fn_iri())
+}
+
+fn lifetime_iri(name: &str) -> String {
+    format!("{}/lifetime/{name}", fn_iri())
+}
+
+fn type_iri(path: &str) -> String {
+    format!("{BASE}/type/mycrate/0.1.0/{path}")
+}
+
+#[test]
+fn where_clause_trait_bound_on_a_type_parameter_is_emitted() {
+    let out = extract();
+    let t = type_param_iri(2);
+    let iterator = type_iri("Iterator");
+
+    assert!(has_iri_triple(&out, &t, &rt("traitBound"), &iterator));
+}
+
+#[test]
+fn where_clause_assoc_type_equality_constraint_gets_a_binding_node() {
+    let out = extract();
+    let t = type_param_iri(2);
+    let binding_iri = format!("{t}/assoc-binding/Iterator/Item");
+    let u32_type = format!("{BASE}/type/_primitive_/u32");
+
+    assert!(has_iri_triple(
+        &out,
+        &binding_iri,
+        RDF_TYPE,
+        &rt("AssocTypeBinding")
+    ));
+    assert!(has_literal_triple(
+        &out,
+        &binding_iri,
+        "http://typegraph.example/ontology/name",
+        "Item"
+    ));
+    assert!(has_iri_triple(&out, &t, &rt("hasAssocBinding"), &binding_iri));
+    assert!(has_iri_triple(
+        &out,
+        &binding_iri,
+        &rt("assocBindingType"),
+        &u32_type
+    ));
+}
+
+#[test]
+fn where_clause_outlives_bound_links_the_type_parameter_to_the_lifetime() {
+    let out = extract();
+    let t = type_param_iri(2);
+    let a = lifetime_iri("a");
+
+    assert!(has_iri_triple(&out, &t, &rt("outlives"), &a));
+}
+
+#[test]
+fn lifetime_predicate_outlives_links_the_two_lifetimes() {
+    let out = extract();
+    let a = lifetime_iri("a");
+    let b = lifetime_iri("b");
+
+    assert!(has_iri_triple(&out, &a, &rt("outlives"), &b));
+}
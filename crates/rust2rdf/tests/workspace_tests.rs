@@ -0,0 +1,98 @@
+//! Integration tests for `extraction::workspace::enumerate_workspace_members`:
+//! resolving `[workspace]` members/default-members/exclude into concrete
+//! directories, including the trailing-`*` glob form.
+
+use rust2rdf::extraction::workspace::enumerate_workspace_members;
+use std::path::PathBuf;
+
+/// Scratch directory under the OS temp dir, unique per test, so parallel
+/// test runs don't collide.
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("rust2rdf-workspace-test-{}-{name}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).expect("create scratch dir");
+    dir
+}
+
+fn make_member(workspace_dir: &std::path::Path, relative: &str) {
+    let dir = workspace_dir.join(relative);
+    std::fs::create_dir_all(&dir).expect("create member dir");
+    std::fs::write(dir.join("Cargo.toml"), "[package]\nname = \"member\"\nversion = \"0.1.0\"\n")
+        .expect("write member Cargo.toml");
+}
+
+#[test]
+fn glob_members_are_expanded_and_exclude_is_honored() {
+    let workspace_dir = scratch_dir("glob");
+    std::fs::write(
+        workspace_dir.join("Cargo.toml"),
+        r#"
+[workspace]
+members = ["crates/*", "standalone"]
+exclude = ["crates/skip"]
+"#,
+    )
+    .expect("write workspace Cargo.toml");
+
+    make_member(&workspace_dir, "crates/a");
+    make_member(&workspace_dir, "crates/b");
+    make_member(&workspace_dir, "crates/skip");
+    make_member(&workspace_dir, "standalone");
+
+    let mut members = enumerate_workspace_members(&workspace_dir).expect("enumerate members");
+    members.sort();
+
+    let mut expected = vec![
+        workspace_dir.join("crates/a"),
+        workspace_dir.join("crates/b"),
+        workspace_dir.join("standalone"),
+    ];
+    expected.sort();
+
+    assert_eq!(members, expected);
+}
+
+#[test]
+fn default_members_are_included_and_deduplicated_against_members() {
+    let workspace_dir = scratch_dir("default-members");
+    std::fs::write(
+        workspace_dir.join("Cargo.toml"),
+        r#"
+[workspace]
+members = ["crates/a"]
+default-members = ["crates/a", "crates/b"]
+"#,
+    )
+    .expect("write workspace Cargo.toml");
+
+    make_member(&workspace_dir, "crates/a");
+    make_member(&workspace_dir, "crates/b");
+
+    let mut members = enumerate_workspace_members(&workspace_dir).expect("enumerate members");
+    members.sort();
+
+    let mut expected = vec![workspace_dir.join("crates/a"), workspace_dir.join("crates/b")];
+    expected.sort();
+
+    assert_eq!(members, expected);
+}
+
+#[test]
+fn a_member_entry_without_a_cargo_toml_is_skipped() {
+    let workspace_dir = scratch_dir("missing-member");
+    std::fs::write(
+        workspace_dir.join("Cargo.toml"),
+        r#"
+[workspace]
+members = ["crates/real", "crates/ghost"]
+"#,
+    )
+    .expect("write workspace Cargo.toml");
+
+    make_member(&workspace_dir, "crates/real");
+    std::fs::create_dir_all(workspace_dir.join("crates/ghost")).expect("create dir without Cargo.toml");
+
+    let members = enumerate_workspace_members(&workspace_dir).expect("enumerate members");
+
+    assert_eq!(members, vec![workspace_dir.join("crates/real")]);
+}